@@ -0,0 +1,247 @@
+//! Generates the vector, vector mask, matrix and quaternion sources under
+//! `src/f32` from the templates in `codegen/templates`, along with the scalar
+//! copies under `src/f32/scalar` used by the SIMD/scalar parity tests and the
+//! fixed-point types under `src/fixed`.
+//!
+//! Run `cargo run -p codegen` from the repository root after editing a
+//! template, or `cargo run -p codegen -- --check` to verify the checked-in
+//! sources are up to date without writing anything.
+
+use serde_json::{json, Value};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::{env, fs};
+use tera::{Context, Tera};
+
+const COMPONENTS: [&str; 4] = ["x", "y", "z", "w"];
+
+// `_mm_shuffle_ps` control words broadcasting a lane to all four lanes.
+const DUP_SHUFFLES: [&str; 4] = ["0b00_00_00_00", "0b01_01_01_01", "0b10_10_10_10", "0b11_11_11_11"];
+
+// `_mm_shuffle_ps` control words used by `set_y`, `set_z` and `set_w` to move
+// the new value from lane 0 into place.
+const SET_SHUFFLES: [&str; 4] = ["", "0b11_10_00_00", "0b11_00_01_00", "0b00_10_01_00"];
+
+struct Output {
+    template: &'static str,
+    path: String,
+    context: Value,
+}
+
+fn components(dim: usize) -> Vec<Value> {
+    (0..dim)
+        .map(|i| {
+            json!({
+                "name": COMPONENTS[i],
+                "index": i,
+                "dup_shuffle": DUP_SHUFFLES[i],
+                "set_shuffle": SET_SHUFFLES[i],
+            })
+        })
+        .collect()
+}
+
+fn vec(self_t: &str, dim: usize, is_sse2: bool) -> Value {
+    json!({
+        "self_t": self_t,
+        "ctor": self_t.to_lowercase(),
+        "mask_t": format!("{}Mask", self_t),
+        "dim": dim,
+        "is_sse2": is_sse2,
+        "components": components(dim),
+        "cmp_ops": [
+            { "name": "eq", "sym": "==", "sse": "_mm_cmpeq_ps" },
+            { "name": "ne", "sym": "!=", "sse": "_mm_cmpneq_ps" },
+            { "name": "ge", "sym": ">=", "sse": "_mm_cmpge_ps" },
+            { "name": "gt", "sym": ">", "sse": "_mm_cmpgt_ps" },
+            { "name": "le", "sym": "<=", "sse": "_mm_cmple_ps" },
+            { "name": "lt", "sym": "<", "sse": "_mm_cmplt_ps" },
+        ],
+    })
+}
+
+fn mat(self_t: &str, dim: usize, col_t: &str, is_sse2: bool) -> Value {
+    json!({
+        "self_t": self_t,
+        "ctor": self_t.to_lowercase(),
+        "dim": dim,
+        "col_t": col_t,
+        "is_sse2": is_sse2,
+        "components": components(dim),
+    })
+}
+
+fn fixed(module: &str, repr: &str, wide: &str, frac: i32, normalized_bits: i32) -> Value {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU};
+    let bits = |value: f64| (value * 2f64.powi(frac)).round() as i64;
+    let int_bits = repr[1..].parse::<i32>().unwrap() - frac;
+    json!({
+        "mod": module,
+        "q": format!("Q{}.{}", int_bits, frac),
+        "repr": repr,
+        "wide": wide,
+        "frac": frac,
+        "int_bits": int_bits - 1,
+        "normalized_bits": normalized_bits,
+        "pi": bits(PI),
+        "frac_pi_2": bits(FRAC_PI_2),
+        "frac_pi_4": bits(FRAC_PI_4),
+        "tau": bits(TAU),
+    })
+}
+
+fn outputs() -> Vec<Output> {
+    let mut outputs = Vec::new();
+    for &(self_t, dim, is_sse2) in &[
+        ("Vec2", 2, false),
+        ("Vec3", 3, false),
+        ("Vec3A", 3, true),
+        ("Vec4", 4, true),
+    ] {
+        let lower = self_t.to_lowercase();
+        outputs.push(Output {
+            template: "vec.rs.tera",
+            path: format!("src/f32/{}.rs", lower),
+            context: vec(self_t, dim, is_sse2),
+        });
+        outputs.push(Output {
+            template: "vec_mask.rs.tera",
+            path: format!("src/f32/{}_mask.rs", lower),
+            context: vec(self_t, dim, is_sse2),
+        });
+    }
+    for &(self_t, dim, col_t, is_sse2) in &[
+        ("Mat2", 2, "Vec2", true),
+        ("Mat3", 3, "Vec3", false),
+        ("Mat3A", 3, "Vec3A", true),
+        ("Mat4", 4, "Vec4", true),
+    ] {
+        outputs.push(Output {
+            template: "mat.rs.tera",
+            path: format!("src/f32/{}.rs", self_t.to_lowercase()),
+            context: mat(self_t, dim, col_t, is_sse2),
+        });
+    }
+    outputs.push(Output {
+        template: "quat.rs.tera",
+        path: "src/f32/quat.rs".to_string(),
+        context: json!({
+            "self_t": "Quat",
+            "ctor": "quat",
+            "is_sse2": true,
+            "components": components(4),
+        }),
+    });
+
+    // Scalar copies of every type are compiled alongside the SSE2 types in
+    // test builds so the parity tests can compare the two implementations.
+    let scalar: Vec<Output> = outputs
+        .iter()
+        .map(|output| {
+            let mut context = output.context.clone();
+            context["is_sse2"] = json!(false);
+            Output {
+                template: output.template,
+                path: output.path.replace("src/f32/", "src/f32/scalar/"),
+                context,
+            }
+        })
+        .collect();
+    outputs.extend(scalar);
+
+    for &(module, repr, wide, frac, normalized_bits) in
+        &[("q16", "i32", "i64", 16, 12), ("q32", "i64", "i128", 32, 20)]
+    {
+        outputs.push(Output {
+            template: "fixed.rs.tera",
+            path: format!("src/fixed/{}.rs", module),
+            context: fixed(module, repr, wide, frac, normalized_bits),
+        });
+    }
+    outputs
+}
+
+fn rustfmt(source: &str) -> String {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2018", "--emit", "stdout", "--quiet"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run rustfmt");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(source.as_bytes())
+        .expect("failed to write to rustfmt");
+    let output = child.wait_with_output().expect("failed to run rustfmt");
+    assert!(output.status.success(), "rustfmt failed on generated source");
+    String::from_utf8(output.stdout).expect("rustfmt output is not utf-8")
+}
+
+fn root_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// Renders every template, returning the output path relative to the
+/// repository root and the formatted source.
+fn generate() -> Vec<(String, String)> {
+    let templates = root_dir().join("codegen/templates/*.tera");
+    let tera = Tera::new(templates.to_str().unwrap()).expect("failed to parse templates");
+    outputs()
+        .into_iter()
+        .map(|output| {
+            let context = Context::from_value(output.context).unwrap();
+            let path = &output.path;
+            let source = tera
+                .render(output.template, &context)
+                .unwrap_or_else(|e| panic!("failed to render {}: {:?}", path, e));
+            (output.path, rustfmt(&source))
+        })
+        .collect()
+}
+
+/// Returns the paths of all checked-in sources that differ from the
+/// generated output.
+fn stale_sources() -> Vec<String> {
+    let root = root_dir();
+    generate()
+        .into_iter()
+        .filter(|(path, source)| {
+            fs::read_to_string(root.join(path)).map_or(true, |current| current != *source)
+        })
+        .map(|(path, _)| path)
+        .collect()
+}
+
+fn main() {
+    if env::args().any(|arg| arg == "--check") {
+        let stale = stale_sources();
+        if !stale.is_empty() {
+            eprintln!("generated sources are out of date, run `cargo run -p codegen`:");
+            for path in stale {
+                eprintln!("    {}", path);
+            }
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let root = root_dir();
+    for (path, source) in generate() {
+        fs::write(root.join(&path), source)
+            .unwrap_or_else(|e| panic!("failed to write {}: {}", path, e));
+        println!("generated {}", path);
+    }
+}
+
+#[test]
+fn generated_sources_match() {
+    let stale = stale_sources();
+    assert!(
+        stale.is_empty(),
+        "generated sources are out of date, run `cargo run -p codegen`: {:?}",
+        stale
+    );
+}
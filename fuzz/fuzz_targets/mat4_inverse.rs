@@ -0,0 +1,21 @@
+#![no_main]
+use glam::{Invertible, Mat4};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (Mat4, Invertible<Mat4>)| {
+    let (m, Invertible(invertible)) = input;
+
+    // singular and non-finite matrices give a non-finite inverse but must
+    // not panic
+    let _ = m.inverse();
+
+    // the condition number of `Invertible` matrices is at most 64
+    let inverse = invertible.inverse();
+    assert!(
+        (invertible * inverse).abs_diff_eq(Mat4::identity(), 1e-3),
+        "{:?} * {:?} is not the identity",
+        invertible,
+        inverse
+    );
+    assert!(inverse.inverse().abs_diff_eq(invertible, 1e-3));
+});
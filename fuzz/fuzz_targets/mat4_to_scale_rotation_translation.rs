@@ -0,0 +1,30 @@
+#![no_main]
+use glam::{Affine, Mat4};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (Mat4, Affine<Mat4>)| {
+    let (m, Affine(affine)) = input;
+
+    // the translation is always the last column, whatever the rest of the
+    // matrix contains
+    let (_, _, translation) = m.to_scale_rotation_translation();
+    assert_eq!(
+        <[f32; 3]>::from(translation).map(f32::to_bits),
+        <[f32; 3]>::from(m.w_axis().truncate()).map(f32::to_bits)
+    );
+
+    let (scale, rotation, translation) = affine.to_scale_rotation_translation();
+    assert!(scale.cmpgt(glam::Vec3::zero()).all(), "{:?}", scale);
+    assert!(
+        (rotation.length() - 1.0).abs() < 1e-4,
+        "{:?} is not normalized",
+        rotation
+    );
+    let recomposed = Mat4::from_scale_rotation_translation(scale, rotation, translation);
+    assert!(
+        recomposed.abs_diff_eq(affine, 1e-3),
+        "{:?} recomposed to {:?}",
+        affine,
+        recomposed
+    );
+});
@@ -0,0 +1,20 @@
+#![no_main]
+use glam::{Mat3, Orthonormal, Quat};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: (Mat3, Orthonormal<Mat3>)| {
+    let (m, Orthonormal(rotation)) = input;
+
+    // matrices which are not rotations give a meaningless quaternion but
+    // must not panic
+    let _ = Quat::from_rotation_mat3(&m);
+
+    let q = Quat::from_rotation_mat3(&rotation);
+    assert!((q.length() - 1.0).abs() < 1e-4, "{:?} is not normalized", q);
+    assert!(
+        Mat3::from_quat(q).abs_diff_eq(rotation, 1e-4),
+        "{:?} converted to {:?}",
+        rotation,
+        q
+    );
+});
@@ -0,0 +1,24 @@
+#![no_main]
+use glam::{Quat, Vec4};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: ([f32; 4], [f32; 4], Quat, Quat, f32)| {
+    let (a, b, start, end, s) = input;
+
+    // non-normalized and non-finite inputs give a meaningless result but must
+    // not panic when glam asserts are disabled
+    let _ = Quat::from(Vec4::from(a)).slerp(Quat::from(Vec4::from(b)), s);
+
+    // the arbitrary quaternions are normalized
+    if (0.0..=1.0).contains(&s) && start.dot(end) > -0.999 {
+        let q = start.slerp(end, s);
+        assert!(
+            (q.length() - 1.0).abs() < 1e-3,
+            "{:?}.slerp({:?}, {}) = {:?} is not normalized",
+            start,
+            end,
+            s,
+            q
+        );
+    }
+});
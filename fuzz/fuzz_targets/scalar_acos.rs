@@ -0,0 +1,26 @@
+#![no_main]
+use glam::f32::fuzzing::scalar_acos;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|x: f32| {
+    let result = scalar_acos(x);
+    if x.is_nan() {
+        assert!(result.is_nan());
+    } else {
+        // the input is clamped to [-1, 1]
+        assert!(
+            (0.0..=core::f32::consts::PI).contains(&result),
+            "scalar_acos({}) = {}",
+            x,
+            result
+        );
+        let expected = x.clamp(-1.0, 1.0).acos();
+        assert!(
+            (result - expected).abs() < 1e-6,
+            "scalar_acos({}) = {}, expected {}",
+            x,
+            result,
+            expected
+        );
+    }
+});
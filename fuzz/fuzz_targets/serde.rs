@@ -0,0 +1,45 @@
+#![no_main]
+use glam::*;
+use libfuzzer_sys::fuzz_target;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Deserializes `T` from `data` and checks any value that was accepted
+/// serializes and deserializes to the same JSON.
+fn round_trip<T: DeserializeOwned + Serialize>(data: &[u8]) {
+    if let Ok(value) = serde_json::from_slice::<T>(data) {
+        // JSON can't represent non-finite floats, which serialize as `null`
+        // and fail to deserialize
+        let json = serde_json::to_string(&value).unwrap();
+        if let Ok(again) = serde_json::from_str::<T>(&json) {
+            assert_eq!(serde_json::to_string(&again).unwrap(), json);
+        }
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    round_trip::<Vec2>(data);
+    round_trip::<Vec3>(data);
+    round_trip::<Vec3A>(data);
+    round_trip::<Vec4>(data);
+    round_trip::<Vec2Mask>(data);
+    round_trip::<Vec3Mask>(data);
+    round_trip::<Vec3AMask>(data);
+    round_trip::<Vec4Mask>(data);
+    round_trip::<Quat>(data);
+    round_trip::<Mat2>(data);
+    round_trip::<Mat3>(data);
+    round_trip::<Mat3A>(data);
+    round_trip::<Mat4>(data);
+    round_trip::<Rot2>(data);
+    round_trip::<DualQuat>(data);
+    round_trip::<LinearRgba>(data);
+    round_trip::<SRgba>(data);
+    round_trip::<HVec2>(data);
+    round_trip::<HVec3>(data);
+    round_trip::<HVec4>(data);
+    round_trip::<HQuat>(data);
+    round_trip::<TransformRT>(data);
+    round_trip::<TransformSRT>(data);
+    round_trip::<TransformRT2>(data);
+    round_trip::<TransformSRT2>(data);
+});
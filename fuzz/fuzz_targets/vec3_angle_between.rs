@@ -0,0 +1,34 @@
+#![no_main]
+use glam::Vec3;
+use libfuzzer_sys::fuzz_target;
+
+fn is_finite(v: Vec3) -> bool {
+    <[f32; 3]>::from(v).iter().all(|x| x.is_finite())
+}
+
+/// Returns `true` if the product of the squared lengths of `a` and `b`, which
+/// `angle_between` divides by, neither overflows nor underflows.
+fn is_well_scaled(a: Vec3, b: Vec3) -> bool {
+    is_finite(a) && is_finite(b) && (a.length_squared() * b.length_squared()).is_normal()
+}
+
+fuzz_target!(|input: (Vec3, Vec3)| {
+    let (a, b) = input;
+
+    // zero and non-finite vectors may give NaN but must not panic
+    let angle = a.angle_between(b);
+    if is_well_scaled(a, b) {
+        assert!(
+            (0.0..=core::f32::consts::PI).contains(&angle),
+            "{:?}.angle_between({:?}) = {}",
+            a,
+            b,
+            angle
+        );
+    }
+
+    if is_well_scaled(a, a) {
+        let angle = a.angle_between(a);
+        assert!(angle < 1e-3, "{:?}.angle_between(itself) = {}", a, angle);
+    }
+});
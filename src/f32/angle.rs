@@ -0,0 +1,250 @@
+use super::{scalar_cos, scalar_sin, scalar_sin_cos, scalar_tan};
+use core::{
+    f32::consts::PI,
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+macro_rules! impl_angle {
+    ($t:ident, $half_turn:expr, $suffix:expr) => {
+        impl $t {
+            /// Wraps the angle to the range `(-half turn, half turn]`.
+            #[inline]
+            pub fn wrap_pi(self) -> Self {
+                // `%` is exact so this doesn't add any rounding error
+                let r = self.0 % (2.0 * $half_turn);
+                Self(if r > $half_turn {
+                    r - 2.0 * $half_turn
+                } else if r <= -$half_turn {
+                    r + 2.0 * $half_turn
+                } else {
+                    r
+                })
+            }
+
+            /// Wraps the angle to the range `[0, full turn)`.
+            #[inline]
+            pub fn wrap_two_pi(self) -> Self {
+                let r = self.0 % (2.0 * $half_turn);
+                if r < 0.0 {
+                    let r = r + 2.0 * $half_turn;
+                    // adding a tiny negative value can round up to a full turn
+                    Self(if r < 2.0 * $half_turn { r } else { 0.0 })
+                } else {
+                    Self(r)
+                }
+            }
+
+            /// Returns the signed difference from `self` to `other` going the
+            /// shortest way around the circle, in the range
+            /// `(-half turn, half turn]`.
+            #[inline]
+            pub fn shortest_difference(self, other: Self) -> Self {
+                (other - self).wrap_pi()
+            }
+
+            /// Performs a linear interpolation from `self` to `end` going the
+            /// shortest way around the circle.
+            ///
+            /// When `s` is `0.0` the result is `self` and when `s` is `1.0`
+            /// the result is equivalent to `end`, though it may differ by a
+            /// whole number of turns. The result is not wrapped.
+            #[inline]
+            pub fn lerp(self, end: Self, s: f32) -> Self {
+                self + self.shortest_difference(end) * s
+            }
+
+            /// Returns the sine of the angle.
+            #[inline]
+            pub fn sin(self) -> f32 {
+                scalar_sin(Radians::from(self).0)
+            }
+
+            /// Returns the cosine of the angle.
+            #[inline]
+            pub fn cos(self) -> f32 {
+                scalar_cos(Radians::from(self).0)
+            }
+
+            /// Returns the tangent of the angle.
+            #[inline]
+            pub fn tan(self) -> f32 {
+                scalar_tan(Radians::from(self).0)
+            }
+
+            /// Returns the sine and cosine of the angle.
+            #[inline]
+            pub fn sin_cos(self) -> (f32, f32) {
+                scalar_sin_cos(Radians::from(self).0)
+            }
+
+            /// Returns the absolute value of the angle.
+            #[inline]
+            pub fn abs(self) -> Self {
+                Self(self.0.abs())
+            }
+        }
+
+        impl From<f32> for $t {
+            #[inline]
+            fn from(angle: f32) -> Self {
+                Self(angle)
+            }
+        }
+
+        impl From<$t> for f32 {
+            #[inline]
+            fn from(angle: $t) -> Self {
+                angle.0
+            }
+        }
+
+        impl Add for $t {
+            type Output = Self;
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                Self(self.0 + other.0)
+            }
+        }
+
+        impl AddAssign for $t {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                self.0 += other.0;
+            }
+        }
+
+        impl Sub for $t {
+            type Output = Self;
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                Self(self.0 - other.0)
+            }
+        }
+
+        impl SubAssign for $t {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                self.0 -= other.0;
+            }
+        }
+
+        impl Neg for $t {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl Mul<f32> for $t {
+            type Output = Self;
+            #[inline]
+            fn mul(self, other: f32) -> Self {
+                Self(self.0 * other)
+            }
+        }
+
+        impl MulAssign<f32> for $t {
+            #[inline]
+            fn mul_assign(&mut self, other: f32) {
+                self.0 *= other;
+            }
+        }
+
+        impl Mul<$t> for f32 {
+            type Output = $t;
+            #[inline]
+            fn mul(self, other: $t) -> $t {
+                $t(self * other.0)
+            }
+        }
+
+        impl Div<f32> for $t {
+            type Output = Self;
+            #[inline]
+            fn div(self, other: f32) -> Self {
+                Self(self.0 / other)
+            }
+        }
+
+        impl DivAssign<f32> for $t {
+            #[inline]
+            fn div_assign(&mut self, other: f32) {
+                self.0 /= other;
+            }
+        }
+
+        impl Div for $t {
+            type Output = f32;
+            #[inline]
+            fn div(self, other: Self) -> f32 {
+                self.0 / other.0
+            }
+        }
+
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)?;
+                f.write_str($suffix)
+            }
+        }
+    };
+}
+
+/// An angle in radians.
+///
+/// Functions which take an angle, such as `Quat::from_rotation_y`, accept
+/// anything that converts to `Radians`, so either a `Degrees` value or an
+/// `f32` in radians may be passed.
+///
+/// ```
+/// use glam::{Degrees, Quat, Radians};
+/// let a = Quat::from_rotation_y(Degrees(90.0));
+/// let b = Quat::from_rotation_y(Radians(core::f32::consts::FRAC_PI_2));
+/// let c = Quat::from_rotation_y(core::f32::consts::FRAC_PI_2);
+/// assert!(a.abs_diff_eq(b, 1e-6) && b.abs_diff_eq(c, 1e-6));
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
+#[repr(transparent)]
+pub struct Radians(pub f32);
+
+/// An angle in degrees.
+///
+/// Converts to `Radians` for passing to functions that take an angle.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
+#[repr(transparent)]
+pub struct Degrees(pub f32);
+
+impl_angle!(Radians, PI, " rad");
+impl_angle!(Degrees, 180.0, "°");
+
+impl Radians {
+    /// Converts the angle to degrees.
+    #[inline]
+    pub fn to_degrees(self) -> Degrees {
+        Degrees(self.0.to_degrees())
+    }
+}
+
+impl Degrees {
+    /// Converts the angle to radians.
+    #[inline]
+    pub fn to_radians(self) -> Radians {
+        Radians(self.0.to_radians())
+    }
+}
+
+impl From<Degrees> for Radians {
+    #[inline]
+    fn from(angle: Degrees) -> Self {
+        angle.to_radians()
+    }
+}
+
+impl From<Radians> for Degrees {
+    #[inline]
+    fn from(angle: Radians) -> Self {
+        angle.to_degrees()
+    }
+}
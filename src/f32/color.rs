@@ -0,0 +1,596 @@
+use super::{Mat3, ParseError, Vec3, Vec4};
+use core::{
+    fmt,
+    ops::{Add, Mul, Sub},
+};
+
+#[cfg(all(vec4sse2, target_arch = "x86"))]
+use core::arch::x86::*;
+#[cfg(all(vec4sse2, target_arch = "x86_64"))]
+use core::arch::x86_64::*;
+
+/// Converts an sRGB encoded component to linear.
+#[inline]
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear component to sRGB encoding.
+#[inline]
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[inline]
+fn sqrt4(v: Vec4) -> Vec4 {
+    #[cfg(vec4sse2)]
+    unsafe {
+        Vec4(_mm_sqrt_ps(v.0))
+    }
+
+    #[cfg(vec4f32)]
+    {
+        Vec4::new(v.x().sqrt(), v.y().sqrt(), v.z().sqrt(), v.w().sqrt())
+    }
+}
+
+/// Returns the hue in degrees, the maximum component and the range of the
+/// components of an RGB color.
+#[inline]
+fn hue_max_range(rgb: Vec3) -> (f32, f32, f32) {
+    let (r, g, b) = rgb.into();
+    let max = rgb.max_element();
+    let range = max - rgb.min_element();
+    let hue = if range == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / range)
+    } else if max == g {
+        60.0 * ((b - r) / range + 2.0)
+    } else {
+        60.0 * ((r - g) / range + 4.0)
+    };
+    let hue = if hue < 0.0 { hue + 360.0 } else { hue };
+    (hue, max, range)
+}
+
+/// Implements the accessors and conversions shared by `LinearRgba` and
+/// `SRgba`.
+macro_rules! impl_rgba {
+    ($t:ident) => {
+        impl $t {
+            /// Opaque black.
+            pub const BLACK: Self = Self::black();
+
+            /// Opaque white.
+            pub const WHITE: Self = Self::white();
+
+            /// Fully transparent black.
+            pub const TRANSPARENT: Self = Self::transparent();
+
+            /// Creates a new color from red, green, blue and alpha components.
+            #[inline]
+            pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+                Self(Vec4::new(r, g, b, a))
+            }
+
+            /// Creates a new opaque color from red, green and blue components.
+            #[inline]
+            pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+                Self(Vec4::new(r, g, b, 1.0))
+            }
+
+            /// Returns opaque black.
+            #[inline]
+            pub const fn black() -> Self {
+                Self::rgb(0.0, 0.0, 0.0)
+            }
+
+            /// Returns opaque white.
+            #[inline]
+            pub const fn white() -> Self {
+                Self::rgb(1.0, 1.0, 1.0)
+            }
+
+            /// Returns fully transparent black.
+            #[inline]
+            pub const fn transparent() -> Self {
+                Self(Vec4::zero())
+            }
+
+            /// Returns the red component.
+            #[inline]
+            pub fn r(self) -> f32 {
+                self.0.x()
+            }
+
+            /// Returns the green component.
+            #[inline]
+            pub fn g(self) -> f32 {
+                self.0.y()
+            }
+
+            /// Returns the blue component.
+            #[inline]
+            pub fn b(self) -> f32 {
+                self.0.z()
+            }
+
+            /// Returns the alpha component.
+            #[inline]
+            pub fn a(self) -> f32 {
+                self.0.w()
+            }
+
+            /// Sets the red component.
+            #[inline]
+            pub fn set_r(&mut self, r: f32) {
+                self.0.set_x(r);
+            }
+
+            /// Sets the green component.
+            #[inline]
+            pub fn set_g(&mut self, g: f32) {
+                self.0.set_y(g);
+            }
+
+            /// Sets the blue component.
+            #[inline]
+            pub fn set_b(&mut self, b: f32) {
+                self.0.set_z(b);
+            }
+
+            /// Sets the alpha component.
+            #[inline]
+            pub fn set_a(&mut self, a: f32) {
+                self.0.set_w(a);
+            }
+
+            /// Returns `self` with the alpha component replaced by `a`.
+            #[inline]
+            pub fn with_alpha(mut self, a: f32) -> Self {
+                self.set_a(a);
+                self
+            }
+
+            /// Returns the red, green and blue components.
+            #[inline]
+            pub fn to_rgb(self) -> Vec3 {
+                self.0.truncate()
+            }
+
+            /// Returns the red, green, blue and alpha components.
+            #[inline]
+            pub fn to_vec4(self) -> Vec4 {
+                self.0
+            }
+
+            /// Performs a linear interpolation between `self` and `other`
+            /// based on the value `s`, including alpha.
+            #[inline]
+            pub fn lerp(self, other: Self, s: f32) -> Self {
+                Self(self.0.lerp(other.0, s))
+            }
+
+            /// Returns true if the absolute difference of all components
+            /// between `self` and `other` is less than or equal to
+            /// `max_abs_diff`.
+            #[inline]
+            pub fn abs_diff_eq(self, other: Self, max_abs_diff: f32) -> bool {
+                self.0.abs_diff_eq(other.0, max_abs_diff)
+            }
+        }
+
+        impl From<Vec4> for $t {
+            #[inline]
+            fn from(v: Vec4) -> Self {
+                Self(v)
+            }
+        }
+
+        impl From<$t> for Vec4 {
+            #[inline]
+            fn from(c: $t) -> Self {
+                c.0
+            }
+        }
+
+        impl From<[f32; 4]> for $t {
+            #[inline]
+            fn from(a: [f32; 4]) -> Self {
+                Self(Vec4::from(a))
+            }
+        }
+
+        impl From<$t> for [f32; 4] {
+            #[inline]
+            fn from(c: $t) -> Self {
+                c.0.into()
+            }
+        }
+
+        impl AsRef<[f32; 4]> for $t {
+            #[inline]
+            fn as_ref(&self) -> &[f32; 4] {
+                self.0.as_ref()
+            }
+        }
+
+        impl fmt::Display for $t {
+            fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.0, fmt)
+            }
+        }
+    };
+}
+
+/// A color in the linear sRGB color space with straight (not premultiplied)
+/// alpha.
+///
+/// Lighting, blending and interpolation should be performed on linear colors.
+/// Convert to `SRgba` for display or storage in 8 bit formats.
+///
+/// This type is backed by a `Vec4` and uses SIMD where it is available.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
+#[repr(C)]
+pub struct LinearRgba(pub(crate) Vec4);
+
+/// A color encoded with the sRGB transfer function, with linear alpha.
+///
+/// This is the encoding used by most images, hex color codes and color
+/// pickers. Convert to `LinearRgba` before performing arithmetic on colors.
+///
+/// This type is backed by a `Vec4` and uses SIMD where it is available.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
+#[repr(C)]
+pub struct SRgba(pub(crate) Vec4);
+
+impl_rgba!(LinearRgba);
+impl_rgba!(SRgba);
+
+/// The linear sRGB to CIE XYZ matrix with a D65 white point.
+#[inline]
+fn linear_to_xyz_mat3() -> Mat3 {
+    Mat3::from_cols_array(&[
+        0.412_456_4,
+        0.212_672_9,
+        0.019_333_9,
+        0.357_576_1,
+        0.715_152_2,
+        0.119_192,
+        0.180_437_5,
+        0.072_175,
+        0.950_304_1,
+    ])
+}
+
+/// The CIE XYZ to linear sRGB matrix with a D65 white point.
+#[inline]
+fn xyz_to_linear_mat3() -> Mat3 {
+    Mat3::from_cols_array(&[
+        3.240_454_2,
+        -0.969_266,
+        0.055_643_4,
+        -1.537_138_5,
+        1.876_010_8,
+        -0.204_025_9,
+        -0.498_531_4,
+        0.041_556,
+        1.057_225_2,
+    ])
+}
+
+impl LinearRgba {
+    /// Converts `self` to sRGB encoding using the exact sRGB transfer
+    /// function.
+    #[inline]
+    pub fn to_srgba(self) -> SRgba {
+        SRgba::new(
+            linear_to_srgb(self.r()),
+            linear_to_srgb(self.g()),
+            linear_to_srgb(self.b()),
+            self.a(),
+        )
+    }
+
+    /// Converts `self` to sRGB encoding using a fast approximation of the
+    /// sRGB transfer function.
+    ///
+    /// The result is within `0.002` of `to_srgba` for components in the range
+    /// `[0, 1]`. Negative components are treated as zero.
+    #[inline]
+    pub fn to_srgba_fast(self) -> SRgba {
+        // "sRGB Approximations for HLSL", Ian Taylor 2012
+        let c = self.0.max(Vec4::zero());
+        let s1 = sqrt4(c);
+        let s2 = sqrt4(s1);
+        let s3 = sqrt4(s2);
+        let curve = s1 * 0.662_002_7 + s2 * 0.684_122_1 - s3 * 0.323_583_6 - c * 0.022_541_15;
+        // the fit has no linear segment, so use the exact one near zero
+        let mut srgb = c.cmple(Vec4::splat(0.003_130_8)).select(c * 12.92, curve);
+        srgb.set_w(self.a());
+        SRgba(srgb)
+    }
+
+    /// Returns the relative luminance of `self`, the `Y` component of CIE XYZ.
+    #[inline]
+    pub fn luminance(self) -> f32 {
+        self.to_rgb()
+            .dot(Vec3::new(0.212_672_9, 0.715_152_2, 0.072_175))
+    }
+
+    /// Returns the CIE XYZ coordinates of `self` using a D65 white point.
+    #[inline]
+    pub fn to_xyz(self) -> Vec3 {
+        linear_to_xyz_mat3() * self.to_rgb()
+    }
+
+    /// Creates a color from CIE XYZ coordinates with a D65 white point.
+    #[inline]
+    pub fn from_xyz(xyz: Vec3, alpha: f32) -> Self {
+        Self((xyz_to_linear_mat3() * xyz).extend(alpha))
+    }
+
+    /// Returns the Oklab `L`, `a` and `b` coordinates of `self`.
+    ///
+    /// Oklab is a perceptual color space, distances and interpolation in
+    /// Oklab correspond closely to perceived differences in color.
+    #[inline]
+    pub fn to_oklab(self) -> Vec3 {
+        // "A perceptual color space for image processing", Björn Ottosson 2020
+        let lms = Mat3::from_cols_array(&[
+            0.412_221_46,
+            0.211_903_5,
+            0.088_302_46,
+            0.536_332_55,
+            0.680_699_5,
+            0.281_718_85,
+            0.051_445_995,
+            0.107_396_96,
+            0.629_978_7,
+        ]) * self.to_rgb();
+        let (l, m, s) = lms.into();
+        let lms = Vec3::new(l.cbrt(), m.cbrt(), s.cbrt());
+        Mat3::from_cols_array(&[
+            0.210_454_26,
+            1.977_998_5,
+            0.025_904_037,
+            0.793_617_8,
+            -2.428_592_2,
+            0.782_771_77,
+            -0.004_072_047,
+            0.450_593_7,
+            -0.808_675_77,
+        ]) * lms
+    }
+
+    /// Creates a color from Oklab `L`, `a` and `b` coordinates.
+    #[inline]
+    pub fn from_oklab(lab: Vec3, alpha: f32) -> Self {
+        let lms = Mat3::from_cols_array(&[
+            1.0,
+            1.0,
+            1.0,
+            0.396_337_78,
+            -0.105_561_346,
+            -0.089_484_18,
+            0.215_803_76,
+            -0.063_854_17,
+            -1.291_485_5,
+        ]) * lab;
+        let lms = lms * lms * lms;
+        let rgb = Mat3::from_cols_array(&[
+            4.076_741_7,
+            -1.268_438,
+            -0.004_196_086_3,
+            -3.307_711_6,
+            2.609_757_4,
+            -0.703_418_6,
+            0.230_969_94,
+            -0.341_319_4,
+            1.707_614_7,
+        ]) * lms;
+        Self(rgb.extend(alpha))
+    }
+
+    /// Returns `self` with the color components multiplied by alpha.
+    #[inline]
+    pub fn premultiply(self) -> Self {
+        let mut c = self.0 * self.a();
+        c.set_w(self.a());
+        Self(c)
+    }
+
+    /// Returns `self` with the color components of a premultiplied color
+    /// divided by alpha. A color with zero alpha is returned unchanged.
+    #[inline]
+    pub fn unpremultiply(self) -> Self {
+        let a = self.a();
+        if a == 0.0 {
+            return self;
+        }
+        let mut c = self.0 / a;
+        c.set_w(a);
+        Self(c)
+    }
+}
+
+impl Add<LinearRgba> for LinearRgba {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Sub<LinearRgba> for LinearRgba {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+}
+
+impl Mul<LinearRgba> for LinearRgba {
+    type Output = Self;
+    /// Multiplies each component, modulating one color by another.
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self(self.0 * other.0)
+    }
+}
+
+impl Mul<f32> for LinearRgba {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f32) -> Self {
+        Self(self.0 * other)
+    }
+}
+
+impl SRgba {
+    /// Converts `self` to linear using the exact sRGB transfer function.
+    #[inline]
+    pub fn to_linear(self) -> LinearRgba {
+        LinearRgba::new(
+            srgb_to_linear(self.r()),
+            srgb_to_linear(self.g()),
+            srgb_to_linear(self.b()),
+            self.a(),
+        )
+    }
+
+    /// Converts `self` to linear using a fast polynomial approximation of the
+    /// sRGB transfer function.
+    ///
+    /// The result is within `0.002` of `to_linear` for components in the
+    /// range `[0, 1]`.
+    #[inline]
+    pub fn to_linear_fast(self) -> LinearRgba {
+        // "sRGB Approximations for HLSL", Ian Taylor 2012
+        let c = self.0;
+        let mut linear =
+            c * (c * (c * 0.305_306_02 + Vec4::splat(0.682_171_1)) + Vec4::splat(0.012_522_878));
+        linear.set_w(self.a());
+        LinearRgba(linear)
+    }
+
+    /// Creates a color from hue (in degrees), saturation and value
+    /// components.
+    #[inline]
+    pub fn from_hsv(hsv: Vec3, alpha: f32) -> Self {
+        let (h, s, v) = hsv.into();
+        let f = |n: f32| {
+            let k = (n + h / 60.0).rem_euclid(6.0);
+            v - v * s * k.min(4.0 - k).clamp(0.0, 1.0)
+        };
+        Self::new(f(5.0), f(3.0), f(1.0), alpha)
+    }
+
+    /// Returns the hue (in degrees in the range `[0, 360)`), saturation and
+    /// value of `self`.
+    #[inline]
+    pub fn to_hsv(self) -> Vec3 {
+        let (hue, max, range) = hue_max_range(self.to_rgb());
+        let saturation = if max == 0.0 { 0.0 } else { range / max };
+        Vec3::new(hue, saturation, max)
+    }
+
+    /// Creates a color from hue (in degrees), saturation and lightness
+    /// components.
+    #[inline]
+    pub fn from_hsl(hsl: Vec3, alpha: f32) -> Self {
+        let (h, s, l) = hsl.into();
+        let a = s * l.min(1.0 - l);
+        let f = |n: f32| {
+            let k = (n + h / 30.0).rem_euclid(12.0);
+            l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+        Self::new(f(0.0), f(8.0), f(4.0), alpha)
+    }
+
+    /// Returns the hue (in degrees in the range `[0, 360)`), saturation and
+    /// lightness of `self`.
+    #[inline]
+    pub fn to_hsl(self) -> Vec3 {
+        let (hue, max, range) = hue_max_range(self.to_rgb());
+        let lightness = max - range * 0.5;
+        let saturation = if range == 0.0 {
+            0.0
+        } else {
+            range / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+        Vec3::new(hue, saturation, lightness)
+    }
+
+    /// Creates a color from 8 bit red, green, blue and alpha components.
+    #[inline]
+    pub fn from_rgba8(rgba: [u8; 4]) -> Self {
+        let [r, g, b, a] = rgba;
+        Self(Vec4::new(r as f32, g as f32, b as f32, a as f32) / 255.0)
+    }
+
+    /// Returns the components of `self` rounded to 8 bits. Components are
+    /// clamped to the range `[0, 1]`.
+    #[inline]
+    pub fn to_rgba8(self) -> [u8; 4] {
+        let c = (self.0.max(Vec4::zero()).min(Vec4::one()) * 255.0).round();
+        [c.x() as u8, c.y() as u8, c.z() as u8, c.w() as u8]
+    }
+
+    /// Creates a color from a packed `0xRRGGBBAA` value, the same order as
+    /// hexadecimal color codes.
+    #[inline]
+    pub fn from_u32(rgba: u32) -> Self {
+        Self::from_rgba8(rgba.to_be_bytes())
+    }
+
+    /// Returns `self` packed into a `0xRRGGBBAA` value, the same order as
+    /// hexadecimal color codes.
+    #[inline]
+    pub fn to_u32(self) -> u32 {
+        u32::from_be_bytes(self.to_rgba8())
+    }
+
+    /// Parses a hexadecimal color code in the form `#rgb`, `#rgba`,
+    /// `#rrggbb` or `#rrggbbaa`. The leading `#` is optional.
+    #[inline]
+    pub fn from_hex(hex: &str) -> Result<Self, ParseError> {
+        super::parse::parse_hex_rgba8(hex).map(Self::from_rgba8)
+    }
+
+    /// Returns `self` as a `#rrggbb` hexadecimal color code, or `#rrggbbaa`
+    /// if it is not opaque.
+    pub fn to_hex(self) -> String {
+        let [r, g, b, a] = self.to_rgba8();
+        if a == 255 {
+            format!("#{:02x}{:02x}{:02x}", r, g, b)
+        } else {
+            format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+        }
+    }
+}
+
+impl From<LinearRgba> for SRgba {
+    /// Converts using the exact sRGB transfer function.
+    #[inline]
+    fn from(c: LinearRgba) -> Self {
+        c.to_srgba()
+    }
+}
+
+impl From<SRgba> for LinearRgba {
+    /// Converts using the exact sRGB transfer function.
+    #[inline]
+    fn from(c: SRgba) -> Self {
+        c.to_linear()
+    }
+}
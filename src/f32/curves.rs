@@ -0,0 +1,440 @@
+//! Cubic curve segments over `f32`, `Vec2`, `Vec3` and `Vec4` and a quaternion
+//! spline segment built on `Quat::slerp`.
+//!
+//! Bézier, Hermite, Catmull-Rom and B-spline segments are all converted into
+//! the same polynomial form, `CubicSegment`, which is evaluated with Horner's
+//! method.
+//!
+//! ```
+//! use glam::{curves::CubicSegment, Vec2};
+//! let curve = CubicSegment::from_bezier(
+//!     Vec2::new(0.0, 0.0),
+//!     Vec2::new(0.0, 1.0),
+//!     Vec2::new(1.0, 1.0),
+//!     Vec2::new(1.0, 0.0),
+//! );
+//! assert_eq!(curve.position(0.5), Vec2::new(0.5, 0.75));
+//! ```
+
+use super::{Quat, Vec2, Vec3, Vec4};
+use core::ops::{Add, Mul, Sub};
+
+/// A point type that cubic curve segments can be built from.
+pub trait CurvePoint:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<f32, Output = Self>
+{
+    /// Computes the dot product of `self` and `other`.
+    fn dot(self, other: Self) -> f32;
+
+    /// Computes the length of `self`.
+    #[inline]
+    fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+}
+
+impl CurvePoint for f32 {
+    #[inline]
+    fn dot(self, other: Self) -> f32 {
+        self * other
+    }
+}
+
+impl CurvePoint for Vec2 {
+    #[inline]
+    fn dot(self, other: Self) -> f32 {
+        Vec2::dot(self, other)
+    }
+}
+
+impl CurvePoint for Vec3 {
+    #[inline]
+    fn dot(self, other: Self) -> f32 {
+        Vec3::dot(self, other)
+    }
+
+    #[inline]
+    fn length(self) -> f32 {
+        Vec3::length(self)
+    }
+}
+
+impl CurvePoint for Vec4 {
+    #[inline]
+    fn dot(self, other: Self) -> f32 {
+        Vec4::dot(self, other)
+    }
+
+    #[inline]
+    fn length(self) -> f32 {
+        Vec4::length(self)
+    }
+}
+
+/// The knot parameterization used by a Catmull-Rom segment.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CatmullRomKind {
+    /// Uniform parameterization, `alpha = 0.0`. May produce cusps and self
+    /// intersections.
+    Uniform,
+    /// Centripetal parameterization, `alpha = 0.5`. Never produces cusps or
+    /// self intersections within a segment.
+    Centripetal,
+    /// Chordal parameterization, `alpha = 1.0`.
+    Chordal,
+}
+
+impl CatmullRomKind {
+    #[inline]
+    fn alpha(self) -> f32 {
+        match self {
+            CatmullRomKind::Uniform => 0.0,
+            CatmullRomKind::Centripetal => 0.5,
+            CatmullRomKind::Chordal => 1.0,
+        }
+    }
+}
+
+/// A cubic polynomial curve segment, parameterized over `t` in `[0, 1]`.
+///
+/// The segment is stored as the polynomial `a + b*t + c*t^2 + d*t^3`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CubicSegment<T> {
+    coeffs: [T; 4],
+}
+
+impl<T: CurvePoint> CubicSegment<T> {
+    /// Creates a segment from the polynomial coefficients `a + b*t + c*t^2 +
+    /// d*t^3`.
+    #[inline]
+    pub fn from_coefficients(a: T, b: T, c: T, d: T) -> Self {
+        Self {
+            coeffs: [a, b, c, d],
+        }
+    }
+
+    /// Returns the polynomial coefficients of the segment, lowest order first.
+    #[inline]
+    pub fn coefficients(&self) -> [T; 4] {
+        self.coeffs
+    }
+
+    /// Creates a cubic Bézier segment that starts at `p0`, ends at `p3` and
+    /// uses `p1` and `p2` as control points.
+    #[inline]
+    pub fn from_bezier(p0: T, p1: T, p2: T, p3: T) -> Self {
+        Self::from_coefficients(
+            p0,
+            (p1 - p0) * 3.0,
+            (p0 - p1 * 2.0 + p2) * 3.0,
+            p3 - p0 + (p1 - p2) * 3.0,
+        )
+    }
+
+    /// Creates a cubic Hermite segment from `p0` to `p1` with tangents `m0` at
+    /// `p0` and `m1` at `p1`.
+    #[inline]
+    pub fn from_hermite(p0: T, m0: T, p1: T, m1: T) -> Self {
+        Self::from_coefficients(
+            p0,
+            m0,
+            (p1 - p0) * 3.0 - m0 * 2.0 - m1,
+            (p0 - p1) * 2.0 + m0 + m1,
+        )
+    }
+
+    /// Creates a Catmull-Rom segment which interpolates from `p1` to `p2`,
+    /// using `p0` and `p3` to compute the tangents.
+    ///
+    /// Coincident neighbouring points are handled by falling back to a unit
+    /// knot interval.
+    #[inline]
+    pub fn from_catmull_rom(p0: T, p1: T, p2: T, p3: T, kind: CatmullRomKind) -> Self {
+        let alpha = kind.alpha();
+        let knot = |a: T, b: T| {
+            let dt = (b - a).length().powf(alpha);
+            if dt < 1e-6 {
+                1.0
+            } else {
+                dt
+            }
+        };
+        let dt0 = knot(p0, p1);
+        let dt1 = knot(p1, p2);
+        let dt2 = knot(p2, p3);
+
+        // tangents for the non-uniform knot sequence, rescaled to [0, 1]
+        let m1 = ((p1 - p0) * (1.0 / dt0) - (p2 - p0) * (1.0 / (dt0 + dt1))
+            + (p2 - p1) * (1.0 / dt1))
+            * dt1;
+        let m2 = ((p2 - p1) * (1.0 / dt1) - (p3 - p1) * (1.0 / (dt1 + dt2))
+            + (p3 - p2) * (1.0 / dt2))
+            * dt1;
+        Self::from_hermite(p1, m1, p2, m2)
+    }
+
+    /// Creates a uniform cubic B-spline segment from four consecutive control
+    /// points.
+    ///
+    /// The segment does not pass through the control points but is `C2`
+    /// continuous with neighbouring segments that share three control points.
+    #[inline]
+    pub fn from_b_spline(p0: T, p1: T, p2: T, p3: T) -> Self {
+        const SIXTH: f32 = 1.0 / 6.0;
+        Self::from_coefficients(
+            (p0 + p1 * 4.0 + p2) * SIXTH,
+            (p2 - p0) * 0.5,
+            (p0 - p1 * 2.0 + p2) * 0.5,
+            (p3 - p0 + (p1 - p2) * 3.0) * SIXTH,
+        )
+    }
+
+    /// Returns the position of the curve at `t`.
+    #[inline]
+    pub fn position(&self, t: f32) -> T {
+        let [a, b, c, d] = self.coeffs;
+        a + (b + (c + d * t) * t) * t
+    }
+
+    /// Returns the first derivative (velocity) of the curve at `t`.
+    #[inline]
+    pub fn velocity(&self, t: f32) -> T {
+        let [_, b, c, d] = self.coeffs;
+        b + (c * 2.0 + d * (3.0 * t)) * t
+    }
+
+    /// Returns the second derivative (acceleration) of the curve at `t`.
+    #[inline]
+    pub fn acceleration(&self, t: f32) -> T {
+        let [_, _, c, d] = self.coeffs;
+        c * 2.0 + d * (6.0 * t)
+    }
+
+    /// Returns the parameter `t` in `[0, 1]` of the point on the curve that is
+    /// closest to `point`.
+    ///
+    /// The curve is first sampled at `samples` evenly spaced parameters and
+    /// the best candidate is then refined with Newton's method.
+    pub fn closest_t(&self, point: T, samples: usize) -> f32 {
+        let samples = samples.max(2);
+        let step = 1.0 / (samples - 1) as f32;
+        let mut best_t = 0.0;
+        let mut best_dist_sq = f32::INFINITY;
+        for i in 0..samples {
+            let t = i as f32 * step;
+            let delta = self.position(t) - point;
+            let dist_sq = delta.dot(delta);
+            if dist_sq < best_dist_sq {
+                best_dist_sq = dist_sq;
+                best_t = t;
+            }
+        }
+
+        // minimize |P(t) - point|^2, f(t) = (P(t) - point).P'(t)
+        let mut t = best_t;
+        for _ in 0..8 {
+            let delta = self.position(t) - point;
+            let vel = self.velocity(t);
+            let f = delta.dot(vel);
+            let df = vel.dot(vel) + delta.dot(self.acceleration(t));
+            if df.abs() < 1e-12 {
+                break;
+            }
+            let next_t = (t - f / df).clamp(0.0, 1.0);
+            let converged = (next_t - t).abs() < 1e-7;
+            t = next_t;
+            if converged {
+                break;
+            }
+        }
+
+        // Newton may wander towards a worse local minimum
+        let delta = self.position(t) - point;
+        if delta.dot(delta) <= best_dist_sq {
+            t
+        } else {
+            best_t
+        }
+    }
+
+    /// Returns the point on the curve that is closest to `point`.
+    ///
+    /// See `closest_t` for details.
+    #[inline]
+    pub fn closest_point(&self, point: T, samples: usize) -> T {
+        self.position(self.closest_t(point, samples))
+    }
+}
+
+/// A lookup table mapping arc length to the curve parameter `t`, used to
+/// move along a curve at a constant speed.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ArcLengthTable {
+    // cumulative length at evenly spaced parameters from 0 to 1
+    lengths: Vec<f32>,
+}
+
+impl ArcLengthTable {
+    /// Builds a table by sampling `segment` at `samples` evenly spaced
+    /// parameters and summing the chord lengths between them.
+    ///
+    /// More samples give a more accurate table.
+    pub fn new<T: CurvePoint>(segment: &CubicSegment<T>, samples: usize) -> Self {
+        let samples = samples.max(2);
+        let step = 1.0 / (samples - 1) as f32;
+        let mut lengths = Vec::with_capacity(samples);
+        let mut prev = segment.position(0.0);
+        let mut total = 0.0;
+        lengths.push(total);
+        for i in 1..samples {
+            let p = segment.position(i as f32 * step);
+            total += (p - prev).length();
+            lengths.push(total);
+            prev = p;
+        }
+        Self { lengths }
+    }
+
+    /// Returns the total approximate length of the curve.
+    #[inline]
+    pub fn length(&self) -> f32 {
+        *self.lengths.last().unwrap()
+    }
+
+    /// Returns the approximate arc length from the start of the curve to the
+    /// parameter `t`.
+    pub fn distance_at_t(&self, t: f32) -> f32 {
+        let last = self.lengths.len() - 1;
+        let x = t.clamp(0.0, 1.0) * last as f32;
+        let i = (x as usize).min(last - 1);
+        let frac = x - i as f32;
+        self.lengths[i] + (self.lengths[i + 1] - self.lengths[i]) * frac
+    }
+
+    /// Returns the parameter `t` at the approximate arc length `distance` from
+    /// the start of the curve.
+    ///
+    /// `distance` is clamped to `[0, length]`. Returns `NaN` if `distance` or
+    /// the length of the curve is `NaN`.
+    pub fn t_at_distance(&self, distance: f32) -> f32 {
+        let last = self.lengths.len() - 1;
+        // a NaN sample makes every later sample and the total length NaN
+        if distance.is_nan() || self.length().is_nan() {
+            return f32::NAN;
+        }
+        if distance <= 0.0 {
+            return 0.0;
+        }
+        if distance >= self.length() {
+            return 1.0;
+        }
+        // index of the first sample that is past `distance`
+        let hi = match self
+            .lengths
+            .binary_search_by(|l| l.partial_cmp(&distance).unwrap())
+        {
+            Ok(i) => return i as f32 / last as f32,
+            Err(i) => i,
+        };
+        let lo = hi - 1;
+        let span = self.lengths[hi] - self.lengths[lo];
+        let frac = if span > 0.0 {
+            (distance - self.lengths[lo]) / span
+        } else {
+            0.0
+        };
+        (lo as f32 + frac) / last as f32
+    }
+}
+
+/// A cubic quaternion spline segment evaluated with repeated `Quat::slerp`
+/// (De Casteljau's algorithm on the unit sphere).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct QuatSegment {
+    controls: [Quat; 4],
+}
+
+/// Reflects `p` through `q`.
+#[inline]
+fn quat_double(p: Quat, q: Quat) -> Quat {
+    let p: Vec4 = p.into();
+    let q: Vec4 = q.into();
+    Quat::from(q * (2.0 * p.dot(q)) - p)
+}
+
+/// Returns the quaternion halfway between `p` and `q`.
+#[inline]
+fn quat_bisect(p: Quat, q: Quat) -> Quat {
+    let p: Vec4 = p.into();
+    let q: Vec4 = q.into();
+    Quat::from(p + q).normalize()
+}
+
+/// Returns `q` negated if required so that it is in the same hemisphere as
+/// `reference`.
+#[inline]
+fn quat_same_hemisphere(reference: Quat, q: Quat) -> Quat {
+    if reference.dot(q) < 0.0 {
+        -q
+    } else {
+        q
+    }
+}
+
+impl QuatSegment {
+    /// Creates a spherical Bézier segment that starts at `q0`, ends at `q3`
+    /// and uses `q1` and `q2` as control points.
+    #[inline]
+    pub fn from_bezier(q0: Quat, q1: Quat, q2: Quat, q3: Quat) -> Self {
+        glam_assert!(q0.is_normalized());
+        glam_assert!(q1.is_normalized());
+        glam_assert!(q2.is_normalized());
+        glam_assert!(q3.is_normalized());
+        let q1 = quat_same_hemisphere(q0, q1);
+        let q2 = quat_same_hemisphere(q1, q2);
+        let q3 = quat_same_hemisphere(q2, q3);
+        Self {
+            controls: [q0, q1, q2, q3],
+        }
+    }
+
+    /// Creates a segment which interpolates from `q1` to `q2` with smooth
+    /// transitions to the neighbouring keys `q0` and `q3`.
+    ///
+    /// The control points are computed using Shoemake's construction which
+    /// gives a `C1` continuous spline when consecutive keys are used for
+    /// consecutive segments.
+    #[inline]
+    pub fn from_catmull_rom(q0: Quat, q1: Quat, q2: Quat, q3: Quat) -> Self {
+        glam_assert!(q0.is_normalized());
+        glam_assert!(q1.is_normalized());
+        glam_assert!(q2.is_normalized());
+        glam_assert!(q3.is_normalized());
+        let q0 = quat_same_hemisphere(q1, q0);
+        let q2 = quat_same_hemisphere(q1, q2);
+        let q3 = quat_same_hemisphere(q2, q3);
+
+        // outgoing control point at q1
+        let a1 = quat_bisect(quat_double(q0, q1), q2);
+        // incoming control point at q2
+        let a2 = quat_bisect(quat_double(q1, q2), q3);
+        let b2 = quat_double(a2, q2);
+
+        let third = |from: Quat, to: Quat| from.slerp(to, 1.0 / 3.0).normalize();
+        Self {
+            controls: [q1, third(q1, a1), third(q2, b2), q2],
+        }
+    }
+
+    /// Returns the rotation of the segment at `t`.
+    #[inline]
+    pub fn rotation(&self, t: f32) -> Quat {
+        // renormalize each step as `Quat::slerp` may drift from unit length
+        let slerp = |from: Quat, to: Quat| from.slerp(to, t).normalize();
+        let [q0, q1, q2, q3] = self.controls;
+        let q01 = slerp(q0, q1);
+        let q12 = slerp(q1, q2);
+        let q23 = slerp(q2, q3);
+        slerp(slerp(q01, q12), slerp(q12, q23))
+    }
+}
@@ -0,0 +1,279 @@
+//! Software implementations of the transcendental functions used by `glam`,
+//! for the `deterministic` feature.
+//!
+//! `f32::sin` and friends call the platform's math library, which gives
+//! different results on different operating systems and architectures. These
+//! are evaluated in `f64` using only additions, multiplications and divisions,
+//! which IEEE 754 requires to be correctly rounded, in a fixed order, so they
+//! give the same bits on every platform. The final rounding to `f32` makes
+//! them accurate to about one ulp.
+//!
+//! The kernels are the `f32` kernels from musl, which are derived from
+//! FreeBSD's msun.
+
+use core::f64::consts::{FRAC_2_PI, FRAC_PI_2, FRAC_PI_6, PI};
+
+/// Arguments below this magnitude are their own sine and tangent when
+/// rounded to `f32`.
+const TINY: f32 = 1.0 / 4096.0;
+
+/// The first 25 bits of `PI / 2`.
+const PIO2_1: f64 = 1.570_796_310_901_641_8;
+/// `PI / 2 - PIO2_1`.
+const PIO2_1T: f64 = 1.589_325_477_352_819_6e-8;
+
+/// The first 256 bits of the fraction `2 / PI`.
+const TWO_OVER_PI: [u32; 8] = [
+    0xa2f9_836e,
+    0x4e44_1529,
+    0xfc27_57d1,
+    0xf534_ddc0,
+    0xdb62_9599,
+    0x3c43_9041,
+    0xfe51_63ab,
+    0xdebb_c561,
+];
+
+/// Reduces `x` to `r` in `[-PI / 4, PI / 4]` and the quadrant `n` such that
+/// `x = r + n * PI / 2`.
+#[inline]
+fn rem_pio2(x: f32) -> (f64, i32) {
+    if x.abs() < (1 << 28) as f32 {
+        // n has at most 28 bits so n * PIO2_1 is exact
+        let x = x as f64;
+        let n = (x * FRAC_2_PI).round();
+        ((x - n * PIO2_1) - n * PIO2_1T, n as i32)
+    } else {
+        let (r, n) = rem_pio2_large(x.abs());
+        if x < 0.0 {
+            (-r, -n)
+        } else {
+            (r, n)
+        }
+    }
+}
+
+/// Reduces a large finite `x` exactly, using integer arithmetic on the bits
+/// of `2 / PI` which contribute to `x * 2 / PI` modulo 4.
+fn rem_pio2_large(x: f32) -> (f64, i32) {
+    // x = m * 2^e
+    let bits = x.to_bits();
+    let m = ((bits & 0x7f_ffff) | 0x80_0000) as u128;
+    let e = ((bits >> 23) & 0xff) as usize - 150;
+    // the bits of 2 / PI worth 2^-(e - 1) to 2^-(e + 126), smaller ones only
+    // affect bits beyond the precision of the result and larger ones give
+    // multiples of 4
+    let start = e - 2;
+    let (word, shift) = (start / 32, start % 32);
+    let mut window = TWO_OVER_PI[word..word + 4]
+        .iter()
+        .fold(0_u128, |acc, &w| (acc << 32) | w as u128);
+    if shift != 0 {
+        window = (window << shift) | (TWO_OVER_PI[word + 4] >> (32 - shift)) as u128;
+    }
+    // x * 2 / PI modulo 4, as a fixed point number with 126 fractional bits
+    let product = m.wrapping_mul(window);
+    // round to the nearest quadrant, leaving the fraction in [-0.5, 0.5)
+    let fraction = (product << 2) as i128;
+    let n = (product >> 126) as i32 + (fraction < 0) as i32;
+    let r = fraction as f64 * (FRAC_PI_2 / (1_u128 << 127) as f64 / 2.0);
+    (r, n)
+}
+
+/// Sine of `x` in `[-PI / 4, PI / 4]`.
+#[inline]
+fn k_sin(x: f64) -> f64 {
+    const S1: f64 = -0.166_666_666_416_265_24;
+    const S2: f64 = 0.008_333_329_385_889_463;
+    const S3: f64 = -0.000_198_393_348_360_966_32;
+    const S4: f64 = 2.718_311_493_989_822e-6;
+    let z = x * x;
+    let w = z * z;
+    let r = S3 + z * S4;
+    let s = z * x;
+    (x + s * (S1 + z * S2)) + s * w * r
+}
+
+/// Cosine of `x` in `[-PI / 4, PI / 4]`.
+#[inline]
+fn k_cos(x: f64) -> f64 {
+    const C0: f64 = -0.499_999_997_251_031;
+    const C1: f64 = 0.041_666_623_323_739_06;
+    const C2: f64 = -0.001_388_676_377_460_993;
+    const C3: f64 = 2.439_044_879_627_741e-5;
+    let z = x * x;
+    let w = z * z;
+    let r = C2 + z * C3;
+    ((1.0 + z * C0) + w * C1) + (w * z) * r
+}
+
+/// Tangent of `x` in `[-PI / 4, PI / 4]`, or minus its reciprocal if `odd`.
+#[inline]
+fn k_tan(x: f64, odd: bool) -> f64 {
+    const T: [f64; 6] = [
+        0.333_331_395_030_791_4,
+        0.133_392_002_712_976_74,
+        0.053_381_237_844_567_04,
+        0.024_528_318_116_654_728,
+        0.002_974_357_433_599_673,
+        0.009_465_647_849_436_732,
+    ];
+    let z = x * x;
+    let r = T[4] + z * T[5];
+    let t = T[2] + z * T[3];
+    let w = z * z;
+    let s = z * x;
+    let u = T[0] + z * T[1];
+    let r = (x + s * u) + (s * w) * (t + w * r);
+    if odd {
+        -1.0 / r
+    } else {
+        r
+    }
+}
+
+pub(crate) fn sin_cos(x: f32) -> (f32, f32) {
+    if x.abs() < TINY {
+        return (x, 1.0);
+    }
+    if !x.is_finite() {
+        return (f32::NAN, f32::NAN);
+    }
+    let (r, n) = rem_pio2(x);
+    let (sin, cos) = (k_sin(r), k_cos(r));
+    let (sin, cos) = match n & 3 {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    };
+    (sin as f32, cos as f32)
+}
+
+pub(crate) fn sin(x: f32) -> f32 {
+    sin_cos(x).0
+}
+
+pub(crate) fn cos(x: f32) -> f32 {
+    sin_cos(x).1
+}
+
+pub(crate) fn tan(x: f32) -> f32 {
+    if x.abs() < TINY {
+        return x;
+    }
+    if !x.is_finite() {
+        return f32::NAN;
+    }
+    let (r, n) = rem_pio2(x);
+    k_tan(r, n & 1 != 0) as f32
+}
+
+/// Arctangent of `x >= 0`.
+fn atan(x: f64) -> f64 {
+    // reduce to [0, 1], then to [0, tan(PI / 12)] using
+    // atan(x) = PI / 6 + atan((x * sqrt(3) - 1) / (x + sqrt(3)))
+    const SQRT_3: f64 = 1.732_050_807_568_877_2;
+    const TAN_PI_12: f64 = 0.267_949_192_431_122_7;
+    let (x, offset, sign) = if x > 1.0 {
+        (1.0 / x, FRAC_PI_2, -1.0)
+    } else {
+        (x, 0.0, 1.0)
+    };
+    let (x, offset2) = if x > TAN_PI_12 {
+        ((x * SQRT_3 - 1.0) / (x + SQRT_3), FRAC_PI_6)
+    } else {
+        (x, 0.0)
+    };
+    // Taylor series, which converges quickly on [-tan(PI / 12), tan(PI / 12)]
+    let z = x * x;
+    let mut p = 1.0 / 21.0;
+    for &c in &[
+        -1.0 / 19.0,
+        1.0 / 17.0,
+        -1.0 / 15.0,
+        1.0 / 13.0,
+        -1.0 / 11.0,
+        1.0 / 9.0,
+        -1.0 / 7.0,
+        1.0 / 5.0,
+        -1.0 / 3.0,
+    ] {
+        p = p * z + c;
+    }
+    let atan = x + x * z * p + offset2;
+    offset + sign * atan
+}
+
+pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+    if x.is_nan() || y.is_nan() {
+        return f32::NAN;
+    }
+    let angle = if x.is_infinite() && y.is_infinite() {
+        if x > 0.0 {
+            PI / 4.0
+        } else {
+            3.0 * PI / 4.0
+        }
+    } else if y == 0.0 || x.is_infinite() {
+        if x.is_sign_positive() {
+            0.0
+        } else {
+            PI
+        }
+    } else if x == 0.0 || y.is_infinite() {
+        FRAC_PI_2
+    } else {
+        let angle = atan((y as f64 / x as f64).abs());
+        if x < 0.0 {
+            PI - angle
+        } else {
+            angle
+        }
+    };
+    let angle = angle as f32;
+    if y.is_sign_negative() {
+        -angle
+    } else {
+        angle
+    }
+}
+
+pub(crate) fn exp(x: f32) -> f32 {
+    const LN_2: f64 = core::f64::consts::LN_2;
+    if x.is_nan() {
+        return x;
+    }
+    // beyond these e^x rounds to infinity or zero
+    if x > 89.0 {
+        return f32::INFINITY;
+    }
+    if x < -104.0 {
+        return 0.0;
+    }
+    // e^x = 2^k * e^r, with |r| <= ln(2) / 2
+    let x = x as f64;
+    let k = (x * core::f64::consts::LOG2_E).round();
+    let r = x - k * LN_2;
+    // Taylor series, later terms are below the precision of the result
+    let mut p = 1.0 / 479_001_600.0;
+    for &c in &[
+        1.0 / 39_916_800.0,
+        1.0 / 3_628_800.0,
+        1.0 / 362_880.0,
+        1.0 / 40_320.0,
+        1.0 / 5_040.0,
+        1.0 / 720.0,
+        1.0 / 120.0,
+        1.0 / 24.0,
+        1.0 / 6.0,
+        0.5,
+        1.0,
+        1.0,
+    ] {
+        p = p * r + c;
+    }
+    // k is in [-150, 129] so 2^k is a normal f64
+    let scale = f64::from_bits(((1023 + k as i64) as u64) << 52);
+    (p * scale) as f32
+}
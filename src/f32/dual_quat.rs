@@ -0,0 +1,351 @@
+use super::{scalar_atan2, scalar_sin_cos, Mat4, Quat, Vec3, Vec4};
+use core::{
+    fmt,
+    ops::{Mul, MulAssign},
+};
+
+#[cfg(feature = "transform-types")]
+use super::TransformRT;
+
+/// A dual quaternion representing a rigid transform, a rotation followed by a
+/// translation.
+///
+/// The `real` part stores the rotation and the `dual` part stores half the
+/// translation multiplied by the rotation. Dual quaternions compose in the
+/// same order as `Mat4` and `Quat`, `a * b` applies `b` first and then `a`.
+///
+/// Blending dual quaternions with `DualQuat::blend` does not suffer from the
+/// "candy wrapper" artifacts produced by linear blend skinning with matrices.
+///
+/// This dual quaternion is intended to be of unit length but may denormalize
+/// due to floating point "error creep" which can occur when successive
+/// operations are applied.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[repr(C)]
+pub struct DualQuat {
+    pub(crate) real: Quat,
+    pub(crate) dual: Quat,
+}
+
+impl DualQuat {
+    /// The identity transform.
+    pub const IDENTITY: Self = Self::identity();
+
+    /// Creates a new dual quaternion from its `real` and `dual` parts.
+    ///
+    /// This should generally not be called manually unless you know what you
+    /// are doing. Use one of the other constructors instead such as `identity`
+    /// or `from_rotation_translation`.
+    #[inline]
+    pub const fn from_real_dual(real: Quat, dual: Quat) -> Self {
+        Self { real, dual }
+    }
+
+    /// Creates an identity transform.
+    #[inline]
+    pub const fn identity() -> Self {
+        Self {
+            real: Quat::identity(),
+            dual: Quat(Vec4::zero()),
+        }
+    }
+
+    /// Creates a dual quaternion from the given `rotation` and `translation`.
+    /// The rotation is applied first.
+    #[inline]
+    pub fn from_rotation_translation(rotation: Quat, translation: Vec3) -> Self {
+        glam_assert!(rotation.is_normalized());
+        let t = Quat((translation * 0.5).extend(0.0));
+        Self {
+            real: rotation,
+            dual: t.mul_quat_unchecked(rotation),
+        }
+    }
+
+    /// Creates a dual quaternion containing only the given `rotation`.
+    #[inline]
+    pub fn from_quat(rotation: Quat) -> Self {
+        glam_assert!(rotation.is_normalized());
+        Self {
+            real: rotation,
+            dual: Quat(Vec4::zero()),
+        }
+    }
+
+    /// Creates a dual quaternion containing only the given `translation`.
+    #[inline]
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self {
+            real: Quat::identity(),
+            dual: Quat((translation * 0.5).extend(0.0)),
+        }
+    }
+
+    /// Creates a dual quaternion from the rotation and translation of a
+    /// homogeneous 4x4 matrix.
+    ///
+    /// The matrix is expected to be a rigid transform, any scale or shear will
+    /// produce an invalid result.
+    #[inline]
+    pub fn from_mat4(mat: &Mat4) -> Self {
+        Self::from_rotation_translation(Quat::from_rotation_mat4(mat), mat.w_axis().truncate())
+    }
+
+    /// Returns the real part of `self`.
+    #[inline]
+    pub fn real(&self) -> Quat {
+        self.real
+    }
+
+    /// Returns the dual part of `self`.
+    #[inline]
+    pub fn dual(&self) -> Quat {
+        self.dual
+    }
+
+    /// Returns the rotation of `self`.
+    #[inline]
+    pub fn rotation(&self) -> Quat {
+        self.real
+    }
+
+    /// Returns the translation of `self`.
+    #[inline]
+    pub fn translation(&self) -> Vec3 {
+        // 2 * dual * conjugate(real)
+        let t = self.dual.mul_quat_unchecked(self.real.conjugate());
+        (t.0 * 2.0).truncate()
+    }
+
+    /// Returns the rotation and translation of `self`.
+    #[inline]
+    pub fn to_rotation_translation(&self) -> (Quat, Vec3) {
+        (self.real, self.translation())
+    }
+
+    /// Returns the quaternion conjugate of both parts of `self`. For a unit
+    /// dual quaternion the conjugate is also the inverse.
+    #[inline]
+    pub fn conjugate(&self) -> Self {
+        Self {
+            real: self.real.conjugate(),
+            dual: self.dual.conjugate(),
+        }
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// `self` is expected to be normalized.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        glam_assert!(self.is_normalized());
+        self.conjugate()
+    }
+
+    /// Returns `self` normalized so the real part is of length 1.0 and
+    /// orthogonal to the dual part.
+    ///
+    /// For valid results, the real part of `self` must _not_ be of length
+    /// zero.
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let inv_len = self.real.length_reciprocal();
+        let real = self.real.0 * inv_len;
+        let dual = self.dual.0 * inv_len;
+        // remove the part of the dual that is not orthogonal to the real part
+        let dual = dual - real * real.dot(dual);
+        Self {
+            real: Quat(real),
+            dual: Quat(dual),
+        }
+    }
+
+    /// Returns whether `self` is a unit dual quaternion or not.
+    ///
+    /// Uses a precision threshold of `1e-6`.
+    #[inline]
+    pub fn is_normalized(&self) -> bool {
+        self.real.is_normalized() && self.real.dot(self.dual).abs() <= 1e-6
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two `DualQuat`'s contain similar
+    /// elements. It works best when comparing with a known value. The
+    /// `max_abs_diff` that should be used used depends on the values being
+    /// compared against.
+    ///
+    /// For more on floating point comparisons see
+    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[inline]
+    pub fn abs_diff_eq(&self, other: Self, max_abs_diff: f32) -> bool {
+        self.real.abs_diff_eq(other.real, max_abs_diff)
+            && self.dual.abs_diff_eq(other.dual, max_abs_diff)
+    }
+
+    /// Multiplies two dual quaternions, `other` is applied first.
+    /// Note that due to floating point rounding the result may not be perfectly normalized.
+    #[inline]
+    pub fn mul_dual_quat(&self, other: &Self) -> Self {
+        let real = self.real.mul_quat_unchecked(other.real);
+        let dual =
+            self.real.mul_quat_unchecked(other.dual).0 + self.dual.mul_quat_unchecked(other.real).0;
+        Self {
+            real,
+            dual: Quat(dual),
+        }
+    }
+
+    /// Transforms the given `Vec3` as a 3D point, applying rotation and
+    /// translation.
+    #[inline]
+    pub fn transform_point3(&self, other: Vec3) -> Vec3 {
+        self.real.mul_vec3(other) + self.translation()
+    }
+
+    /// Transforms the given `Vec3` as a 3D vector, applying rotation only.
+    #[inline]
+    pub fn transform_vector3(&self, other: Vec3) -> Vec3 {
+        self.real.mul_vec3(other)
+    }
+
+    /// Raises the unit dual quaternion `self` to the power `s`, scaling both
+    /// the rotation angle and the translation along the screw axis.
+    fn pow(&self, s: f32) -> Self {
+        const EPSILON: f32 = 1.0e-6;
+        let (rx, ry, rz, rw) = self.real.0.into();
+        let (dx, dy, dz, dw) = self.dual.0.into();
+        let real_v = Vec3::new(rx, ry, rz);
+        let sin_half = real_v.length();
+        if sin_half < EPSILON {
+            // pure translation
+            return Self::from_translation(self.translation() * s);
+        }
+
+        // screw parameters: axis, angle, pitch and moment
+        let half_angle = scalar_atan2(sin_half, rw);
+        let axis = real_v / sin_half;
+        let half_pitch = -dw / sin_half;
+        let moment = (Vec3::new(dx, dy, dz) - axis * (half_pitch * rw)) / sin_half;
+
+        let half_angle = half_angle * s;
+        let half_pitch = half_pitch * s;
+        let (sin, cos) = scalar_sin_cos(half_angle);
+        Self {
+            real: Quat((axis * sin).extend(cos)),
+            dual: Quat((moment * sin + axis * (half_pitch * cos)).extend(-half_pitch * sin)),
+        }
+    }
+
+    /// Performs a screw linear interpolation (ScLERP) between `self` and `end`
+    /// based on the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
+    /// is `1.0`, the result will be equal to `end`.
+    ///
+    /// The interpolation rotates and translates along a screw axis at a
+    /// constant velocity and always takes the shortest path.
+    #[inline]
+    pub fn sclerp(&self, end: Self, s: f32) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+        let end = if self.real.dot(end.real) < 0.0 {
+            Self {
+                real: -end.real,
+                dual: -end.dual,
+            }
+        } else {
+            end
+        };
+        let diff = self.conjugate().mul_dual_quat(&end);
+        self.mul_dual_quat(&diff.pow(s))
+    }
+
+    /// Blends the given dual quaternions using dual quaternion linear blending
+    /// (DLB), returning a normalized result.
+    ///
+    /// Each dual quaternion is scaled by the matching weight in `weights`. Dual
+    /// quaternions that are in the opposite hemisphere to the first dual
+    /// quaternion are negated so that the shortest path is always used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dual_quats` and `weights` are not the same length.
+    pub fn blend(dual_quats: &[DualQuat], weights: &[f32]) -> Self {
+        assert_eq!(dual_quats.len(), weights.len());
+        glam_assert!(!dual_quats.is_empty());
+        let pivot = match dual_quats.first() {
+            Some(dq) => dq.real,
+            None => return Self::identity(),
+        };
+        let mut real = Vec4::zero();
+        let mut dual = Vec4::zero();
+        for (dq, &weight) in dual_quats.iter().zip(weights) {
+            let weight = if pivot.dot(dq.real) < 0.0 {
+                -weight
+            } else {
+                weight
+            };
+            real += dq.real.0 * weight;
+            dual += dq.dual.0 * weight;
+        }
+        Self {
+            real: Quat(real),
+            dual: Quat(dual),
+        }
+        .normalize()
+    }
+}
+
+impl Default for DualQuat {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl fmt::Display for DualQuat {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "[{}, {}]", self.real, self.dual)
+    }
+}
+
+impl Mul<DualQuat> for DualQuat {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_dual_quat(&other)
+    }
+}
+
+impl MulAssign<DualQuat> for DualQuat {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.mul_dual_quat(&other);
+    }
+}
+
+impl From<DualQuat> for Mat4 {
+    #[inline]
+    fn from(dq: DualQuat) -> Self {
+        Mat4::from_rotation_translation(dq.real, dq.translation())
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl From<TransformRT> for DualQuat {
+    #[inline]
+    fn from(rt: TransformRT) -> Self {
+        Self::from_rotation_translation(rt.rotation, rt.translation)
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl From<DualQuat> for TransformRT {
+    #[inline]
+    fn from(dq: DualQuat) -> Self {
+        let (rotation, translation) = dq.to_rotation_translation();
+        TransformRT::from_rotation_translation(rotation, translation)
+    }
+}
@@ -7,7 +7,42 @@ pub(crate) fn scalar_sin_cos(x: f32) -> (f32, f32) {
     //     (_mm_cvtss_f32(sinx), _mm_cvtss_f32(cosx))
     // }
     // #[cfg(not(target_feature = "sse2"))]
-    x.sin_cos()
+    #[cfg(not(feature = "deterministic"))]
+    {
+        x.sin_cos()
+    }
+    #[cfg(feature = "deterministic")]
+    {
+        crate::f32::deterministic::sin_cos(x)
+    }
+}
+
+// The platform's math library differs between platforms, so with the
+// `deterministic` feature these use software implementations instead.
+macro_rules! impl_scalar_funcs {
+    ($($name:ident($($arg:ident),+) => $std:expr, $soft:ident;)+) => {
+        $(
+            #[inline]
+            pub(crate) fn $name($($arg: f32),+) -> f32 {
+                #[cfg(not(feature = "deterministic"))]
+                {
+                    $std
+                }
+                #[cfg(feature = "deterministic")]
+                {
+                    crate::f32::deterministic::$soft($($arg),+)
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_funcs! {
+    scalar_sin(x) => x.sin(), sin;
+    scalar_cos(x) => x.cos(), cos;
+    scalar_tan(x) => x.tan(), tan;
+    scalar_atan2(y, x) => y.atan2(x), atan2;
+    scalar_exp(x) => x.exp(), exp;
 }
 
 #[inline]
@@ -15,12 +50,12 @@ pub fn scalar_acos(value: f32) -> f32 {
     // from DirectXMath XMScalarAcos
     // Clamp input to [-1,1].
     let nonnegative = value >= 0.0;
-    let x = value.abs();
-    let mut omx = 1.0 - x;
-    if omx < 0.0 {
-        omx = 0.0;
+    let mut x = value.abs();
+    if x > 1.0 {
+        // clamp x rather than 1 - x so the polynomial below can't overflow
+        x = 1.0;
     }
-    let root = omx.sqrt();
+    let root = (1.0 - x).sqrt();
 
     // 7-degree minimax approximation
     #[allow(clippy::approx_constant)]
@@ -43,6 +78,25 @@ pub fn scalar_acos(value: f32) -> f32 {
     }
 }
 
+/// Converts the high 24 bits of `x` to an `f32` in the range `[0, 1)`.
+#[inline]
+pub(crate) fn u32_to_unit_f32(x: u32) -> f32 {
+    (x >> 8) as f32 * (1.0 / (1 << 24) as f32)
+}
+
+/// An integer hash with good avalanche behaviour, used for scrambling and
+/// noise lattices.
+#[inline]
+pub(crate) fn hash_u32(mut x: u32) -> u32 {
+    // lowbias32 by Chris Wellons
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x7feb_352d);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x846c_a68b);
+    x ^= x >> 16;
+    x
+}
+
 #[cfg(vec4sse2)]
 pub(crate) mod sse2 {
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
@@ -111,31 +165,34 @@ pub(crate) mod sse2 {
         -0.00018524670  /*Est3*/
     );
     _ps_const_ty!(PS_ONE, f32x4, 1.0);
+    _ps_const_ty!(PS_HALF, f32x4, 0.5);
     _ps_const_ty!(PS_TWO_PI, f32x4, core::f32::consts::PI * 2.0);
     _ps_const_ty!(PS_RECIPROCAL_TWO_PI, f32x4, 0.159154943);
 
-    #[cfg(target_feature = "fma")]
+    // fused multiply-add rounds once instead of twice, so it would give
+    // different results on CPUs with and without FMA
+    #[cfg(all(target_feature = "fma", not(feature = "deterministic")))]
     macro_rules! m128_mul_add {
         ($a:expr, $b:expr, $c:expr) => {
             _mm_fmadd_ps($a, $b, $c)
         };
     }
 
-    #[cfg(not(target_feature = "fma"))]
+    #[cfg(any(not(target_feature = "fma"), feature = "deterministic"))]
     macro_rules! m128_mul_add {
         ($a:expr, $b:expr, $c:expr) => {
             _mm_add_ps(_mm_mul_ps($a, $b), $c)
         };
     }
 
-    #[cfg(target_feature = "fma")]
+    #[cfg(all(target_feature = "fma", not(feature = "deterministic")))]
     macro_rules! m128_neg_mul_sub {
         ($a:expr, $b:expr, $c:expr) => {
             _mm_fnmadd_ps($a, $b, $c)
         };
     }
 
-    #[cfg(not(target_feature = "fma"))]
+    #[cfg(any(not(target_feature = "fma"), feature = "deterministic"))]
     macro_rules! m128_neg_mul_sub {
         ($a:expr, $b:expr, $c:expr) => {
             _mm_sub_ps($c, _mm_mul_ps($a, $b))
@@ -144,16 +201,27 @@ pub(crate) mod sse2 {
 
     #[inline]
     pub(crate) unsafe fn m128_round(v: __m128) -> __m128 {
-        // From DirectXMath XMVectorRound.
+        // Rounds half-way cases away from zero to match `f32::round`. To handle NAN, INF and
+        // numbers greater than 8388608, use masking.
+        let test = _mm_and_si128(_mm_castps_si128(v), PS_INV_SIGN_MASK.m128i);
+        let test = _mm_cmplt_epi32(test, PS_NO_FRACTION.m128i);
+        // Truncate
+        let vint = _mm_cvttps_epi32(v);
+        let result = _mm_cvtepi32_ps(vint);
+        // Add one away from zero where the discarded fraction is at least a half
         let sign = _mm_and_ps(v, PS_SIGN_MASK.m128);
-        let s_magic = _mm_or_ps(PS_NO_FRACTION.m128, sign);
-        let r1 = _mm_add_ps(v, s_magic);
-        let r1 = _mm_sub_ps(r1, s_magic);
-        let r2 = _mm_and_ps(v, PS_INV_SIGN_MASK.m128);
-        let mask = _mm_cmple_ps(r2, PS_NO_FRACTION.m128);
-        let r2 = _mm_andnot_ps(mask, v);
-        let r1 = _mm_and_ps(r1, mask);
-        _mm_xor_ps(r1, r2)
+        let fraction = _mm_and_ps(_mm_sub_ps(v, result), PS_INV_SIGN_MASK.m128);
+        let away = _mm_and_ps(
+            _mm_cmpge_ps(fraction, PS_HALF.m128),
+            _mm_or_ps(PS_ONE.m128, sign),
+        );
+        // Keep the sign so negative values that round to zero give -0.0
+        let result = _mm_or_ps(_mm_add_ps(result, away), sign);
+        // All numbers less than 8388608 will use the round to int
+        let result = _mm_and_ps(result, _mm_castsi128_ps(test));
+        // All others, use the ORIGINAL value
+        let test = _mm_andnot_si128(test, _mm_castps_si128(v));
+        _mm_or_ps(result, _mm_castsi128_ps(test))
     }
 
     #[inline]
@@ -197,6 +265,7 @@ pub(crate) mod sse2 {
     }
 
     /// Returns a vector whose components are the corresponding components of Angles modulo 2PI.
+    #[cfg_attr(feature = "deterministic", allow(dead_code))]
     #[inline]
     pub(crate) unsafe fn m128_mod_angles(angles: __m128) -> __m128 {
         // From DirectXMath: XMVectorModAngles
@@ -208,6 +277,9 @@ pub(crate) mod sse2 {
     /// Computes the sine of the angle in each lane of `v`. Values outside
     /// the bounds of PI may produce an increasing error as the input angle
     /// drifts from `[-PI, PI]`.
+    ///
+    /// `Quat::slerp` doesn't use this with the `deterministic` feature.
+    #[cfg_attr(feature = "deterministic", allow(dead_code))]
     #[inline]
     pub(crate) unsafe fn m128_sin(v: __m128) -> __m128 {
         // From DirectXMath: XMVectorSin
@@ -416,6 +488,11 @@ fn test_scalar_acos() {
     // input is clamped to -1.0..1.0
     assert_approx_eq!(scalar_acos(2.0), 0.0);
     assert_approx_eq!(scalar_acos(-2.0), core::f32::consts::PI);
+    assert_eq!(scalar_acos(1e16), 0.0);
+    assert_approx_eq!(scalar_acos(-1e16), core::f32::consts::PI);
+    assert_eq!(scalar_acos(f32::INFINITY), 0.0);
+    assert_approx_eq!(scalar_acos(f32::NEG_INFINITY), core::f32::consts::PI);
+    assert!(scalar_acos(f32::NAN).is_nan());
 }
 
 #[test]
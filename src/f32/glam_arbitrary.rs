@@ -0,0 +1,121 @@
+use super::subsets::FromUnitSamples;
+use super::{
+    u32_to_unit_f32, Affine, DualQuat, Invertible, Mat2, Mat3, Mat3A, Mat4, Orthonormal, Quat,
+    Rot2, Vec2, Vec3, Vec3A, Vec4,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+#[cfg(feature = "transform-types")]
+use super::{TransformRT, TransformRT2, TransformSRT, TransformSRT2};
+
+fn from_unit_samples<T: FromUnitSamples>(u: &mut Unstructured<'_>) -> Result<T> {
+    let mut samples = [0.0; 16];
+    for x in &mut samples[..T::SAMPLES] {
+        *x = u32_to_unit_f32(u32::arbitrary(u)?);
+    }
+    Ok(T::from_unit_samples(&samples[..T::SAMPLES]))
+}
+
+fn unit_samples_size_hint<T: FromUnitSamples>() -> (usize, Option<usize>) {
+    let size = T::SAMPLES * core::mem::size_of::<u32>();
+    (size, Some(size))
+}
+
+/// Implements `Arbitrary` for types built from an array of any `f32` bit
+/// patterns, including NaNs, infinities and denormals.
+macro_rules! impl_elements {
+    ($t:ident, $n:expr, $from:expr) => {
+        impl<'a> Arbitrary<'a> for $t {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                Ok($from(&<[f32; $n]>::arbitrary(u)?))
+            }
+
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <[f32; $n]>::size_hint(depth)
+            }
+        }
+    };
+}
+
+impl_elements!(Vec2, 2, |a: &[f32; 2]| Vec2::from(*a));
+impl_elements!(Vec3, 3, |a: &[f32; 3]| Vec3::from(*a));
+impl_elements!(Vec3A, 3, |a: &[f32; 3]| Vec3A::from(*a));
+impl_elements!(Vec4, 4, |a: &[f32; 4]| Vec4::from(*a));
+impl_elements!(Mat2, 4, Mat2::from_cols_array);
+impl_elements!(Mat3, 9, Mat3::from_cols_array);
+impl_elements!(Mat3A, 9, Mat3A::from_cols_array);
+impl_elements!(Mat4, 16, Mat4::from_cols_array);
+
+/// Implements `Arbitrary` for types built from samples in `[0, 1)`, such as
+/// normalized quaternions and the matrix subsets.
+macro_rules! impl_unit_samples {
+    ($($t:ty),+) => {
+        $(
+            impl<'a> Arbitrary<'a> for $t {
+                fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                    from_unit_samples(u)
+                }
+
+                fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                    unit_samples_size_hint::<Self>()
+                }
+            }
+        )+
+    };
+}
+
+impl_unit_samples!(
+    Quat,
+    Rot2,
+    Orthonormal<Mat2>,
+    Orthonormal<Mat3>,
+    Orthonormal<Mat3A>,
+    Orthonormal<Mat4>,
+    Invertible<Mat2>,
+    Invertible<Mat3>,
+    Invertible<Mat3A>,
+    Invertible<Mat4>,
+    Affine<Mat3>,
+    Affine<Mat4>
+);
+
+/// Implements `Arbitrary` for types built from other `Arbitrary` glam types.
+macro_rules! impl_compound {
+    ($t:ident, $from:path, $($arg:ident),+) => {
+        impl<'a> Arbitrary<'a> for $t {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                Ok($from($($arg::arbitrary(u)?),+))
+            }
+
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                arbitrary::size_hint::and_all(&[$($arg::size_hint(depth)),+])
+            }
+        }
+    };
+}
+
+impl_compound!(DualQuat, DualQuat::from_rotation_translation, Quat, Vec3);
+
+#[cfg(feature = "transform-types")]
+impl_compound!(TransformRT, TransformRT::from_rotation_translation, Quat, Vec3);
+
+#[cfg(feature = "transform-types")]
+impl_compound!(
+    TransformSRT,
+    TransformSRT::from_scale_rotation_translation,
+    Vec3,
+    Quat,
+    Vec3
+);
+
+#[cfg(feature = "transform-types")]
+impl_compound!(TransformRT2, TransformRT2::from_rotation_translation, Rot2, Vec2);
+
+#[cfg(feature = "transform-types")]
+impl_compound!(
+    TransformSRT2,
+    TransformSRT2::from_scale_rotation_translation,
+    Vec2,
+    Rot2,
+    Vec2
+);
@@ -0,0 +1,13 @@
+use crate::{HQuat, HVec2, HVec3, HVec4};
+use bytemuck::{Pod, Zeroable};
+
+// These are `repr(C)` arrays of `u16` with no padding, so any bit pattern is
+// valid.
+unsafe impl Zeroable for HVec2 {}
+unsafe impl Pod for HVec2 {}
+unsafe impl Zeroable for HVec3 {}
+unsafe impl Pod for HVec3 {}
+unsafe impl Zeroable for HVec4 {}
+unsafe impl Pod for HVec4 {}
+unsafe impl Zeroable for HQuat {}
+unsafe impl Pod for HQuat {}
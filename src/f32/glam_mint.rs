@@ -1,4 +1,4 @@
-use super::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
+use super::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
 use mint;
 
 impl From<mint::Point2<f32>> for Vec2 {
@@ -53,6 +53,32 @@ impl From<Vec3> for mint::Vector3<f32> {
     }
 }
 
+impl From<mint::Point3<f32>> for Vec3A {
+    fn from(v: mint::Point3<f32>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3A> for mint::Point3<f32> {
+    fn from(v: Vec3A) -> Self {
+        let (x, y, z) = v.into();
+        Self { x, y, z }
+    }
+}
+
+impl From<mint::Vector3<f32>> for Vec3A {
+    fn from(v: mint::Vector3<f32>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3A> for mint::Vector3<f32> {
+    fn from(v: Vec3A) -> Self {
+        let (x, y, z) = v.into();
+        Self { x, y, z }
+    }
+}
+
 impl From<mint::Vector4<f32>> for Vec4 {
     fn from(v: mint::Vector4<f32>) -> Self {
         Self::new(v.x, v.y, v.z, v.w)
@@ -146,6 +172,30 @@ impl From<Mat3> for mint::ColumnMatrix3<f32> {
     }
 }
 
+impl From<mint::RowMatrix3<f32>> for Mat3A {
+    fn from(m: mint::RowMatrix3<f32>) -> Self {
+        Mat3::from(m).into()
+    }
+}
+
+impl From<Mat3A> for mint::RowMatrix3<f32> {
+    fn from(m: Mat3A) -> Self {
+        Mat3::from(m).into()
+    }
+}
+
+impl From<mint::ColumnMatrix3<f32>> for Mat3A {
+    fn from(m: mint::ColumnMatrix3<f32>) -> Self {
+        Mat3::from(m).into()
+    }
+}
+
+impl From<Mat3A> for mint::ColumnMatrix3<f32> {
+    fn from(m: Mat3A) -> Self {
+        Mat3::from(m).into()
+    }
+}
+
 impl From<mint::RowMatrix4<f32>> for Mat4 {
     fn from(m: mint::RowMatrix4<f32>) -> Self {
         Self::from_cols(m.x.into(), m.y.into(), m.z.into(), m.w.into()).transpose()
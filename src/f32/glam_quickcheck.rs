@@ -0,0 +1,150 @@
+use super::subsets::FromUnitSamples;
+use super::{
+    u32_to_unit_f32, Affine, DualQuat, Invertible, Mat2, Mat3, Mat3A, Mat4, Orthonormal, Quat,
+    Rot2, Vec2, Vec3, Vec3A, Vec4,
+};
+use quickcheck::{single_shrinker, Arbitrary, Gen};
+
+#[cfg(feature = "transform-types")]
+use super::{TransformRT, TransformRT2, TransformSRT, TransformSRT2};
+
+fn from_unit_samples<T: FromUnitSamples>(g: &mut Gen) -> T {
+    let mut u = [0.0; 16];
+    for x in &mut u[..T::SAMPLES] {
+        *x = u32_to_unit_f32(u32::arbitrary(g));
+    }
+    T::from_unit_samples(&u[..T::SAMPLES])
+}
+
+macro_rules! impl_vec {
+    ($t:ident, $tuple:ty) => {
+        impl Arbitrary for $t {
+            fn arbitrary(g: &mut Gen) -> Self {
+                <$tuple>::arbitrary(g).into()
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new(<$tuple>::from(*self).shrink().map(Self::from))
+            }
+        }
+    };
+}
+
+impl_vec!(Vec2, (f32, f32));
+impl_vec!(Vec3, (f32, f32, f32));
+impl_vec!(Vec3A, (f32, f32, f32));
+impl_vec!(Vec4, (f32, f32, f32, f32));
+
+macro_rules! impl_mat {
+    ($t:ident, $cols:ty, $($axis:ident),+) => {
+        impl Arbitrary for $t {
+            fn arbitrary(g: &mut Gen) -> Self {
+                let ($($axis,)+) = <$cols>::arbitrary(g);
+                $t::from_cols($($axis),+)
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new(
+                    ($(self.$axis(),)+)
+                        .shrink()
+                        .map(|($($axis,)+)| $t::from_cols($($axis),+)),
+                )
+            }
+        }
+    };
+}
+
+impl_mat!(Mat2, (Vec2, Vec2), x_axis, y_axis);
+impl_mat!(Mat3, (Vec3, Vec3, Vec3), x_axis, y_axis, z_axis);
+impl_mat!(Mat3A, (Vec3A, Vec3A, Vec3A), x_axis, y_axis, z_axis);
+impl_mat!(Mat4, (Vec4, Vec4, Vec4, Vec4), x_axis, y_axis, z_axis, w_axis);
+
+/// Implements `Arbitrary` for rotations, which are uniformly distributed and
+/// shrink to the identity.
+macro_rules! impl_rotation {
+    ($t:ident) => {
+        impl Arbitrary for $t {
+            fn arbitrary(g: &mut Gen) -> Self {
+                from_unit_samples(g)
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                if *self == $t::identity() {
+                    quickcheck::empty_shrinker()
+                } else {
+                    single_shrinker($t::identity())
+                }
+            }
+        }
+    };
+}
+
+impl_rotation!(Quat);
+impl_rotation!(Rot2);
+
+macro_rules! impl_subset {
+    ($($t:ty),+) => {
+        $(
+            impl Arbitrary for $t {
+                fn arbitrary(g: &mut Gen) -> Self {
+                    from_unit_samples(g)
+                }
+            }
+        )+
+    };
+}
+
+impl_subset!(
+    Orthonormal<Mat2>,
+    Orthonormal<Mat3>,
+    Orthonormal<Mat3A>,
+    Orthonormal<Mat4>,
+    Invertible<Mat2>,
+    Invertible<Mat3>,
+    Invertible<Mat3A>,
+    Invertible<Mat4>,
+    Affine<Mat3>,
+    Affine<Mat4>
+);
+
+impl Arbitrary for DualQuat {
+    fn arbitrary(g: &mut Gen) -> Self {
+        DualQuat::from_rotation_translation(Quat::arbitrary(g), Vec3::arbitrary(g))
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl Arbitrary for TransformRT {
+    fn arbitrary(g: &mut Gen) -> Self {
+        TransformRT::from_rotation_translation(Quat::arbitrary(g), Vec3::arbitrary(g))
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl Arbitrary for TransformSRT {
+    fn arbitrary(g: &mut Gen) -> Self {
+        TransformSRT::from_scale_rotation_translation(
+            Vec3::arbitrary(g),
+            Quat::arbitrary(g),
+            Vec3::arbitrary(g),
+        )
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl Arbitrary for TransformRT2 {
+    fn arbitrary(g: &mut Gen) -> Self {
+        TransformRT2::from_rotation_translation(Rot2::arbitrary(g), Vec2::arbitrary(g))
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl Arbitrary for TransformSRT2 {
+    fn arbitrary(g: &mut Gen) -> Self {
+        TransformSRT2::from_scale_rotation_translation(
+            Vec2::arbitrary(g),
+            Rot2::arbitrary(g),
+            Vec2::arbitrary(g),
+        )
+    }
+}
@@ -1,10 +1,22 @@
-use super::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
+use super::{DualQuat, Mat2, Mat3, Mat3A, Mat4, Quat, Rot2, Vec2, Vec3, Vec3A, Vec4};
 
 use rand::{
-    distributions::{Distribution, Standard},
+    distributions::{
+        uniform::{SampleBorrow, SampleUniform, UniformFloat, UniformSampler},
+        Distribution, Standard,
+    },
     Rng,
 };
 
+use core::f32::consts::PI;
+
+impl Distribution<DualQuat> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DualQuat {
+        DualQuat::from_rotation_translation(rng.gen::<Quat>(), rng.gen::<Vec3>())
+    }
+}
+
 impl Distribution<Mat2> for Standard {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Mat2 {
@@ -19,6 +31,13 @@ impl Distribution<Mat3> for Standard {
     }
 }
 
+impl Distribution<Mat3A> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Mat3A {
+        Mat3A::from_cols_array(&rng.gen())
+    }
+}
+
 impl Distribution<Mat4> for Standard {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Mat4 {
@@ -29,11 +48,14 @@ impl Distribution<Mat4> for Standard {
 impl Distribution<Quat> for Standard {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quat {
-        use core::f32::consts::PI;
-        let yaw = -PI + rng.gen::<f32>() * 2.0 * PI;
-        let pitch = -PI + rng.gen::<f32>() * 2.0 * PI;
-        let roll = -PI + rng.gen::<f32>() * 2.0 * PI;
-        Quat::from_rotation_ypr(yaw, pitch, roll)
+        UniformRotation.sample(rng)
+    }
+}
+
+impl Distribution<Rot2> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Rot2 {
+        Rot2::from_angle(-PI + rng.gen::<f32>() * 2.0 * PI)
     }
 }
 
@@ -51,9 +73,282 @@ impl Distribution<Vec3> for Standard {
     }
 }
 
+impl Distribution<Vec3A> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3A {
+        rng.gen::<(f32, f32, f32)>().into()
+    }
+}
+
 impl Distribution<Vec4> for Standard {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec4 {
         rng.gen::<[f32; 4]>().into()
     }
 }
+
+/// Samples a point uniformly distributed on the surface of the unit sphere.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitSphere;
+
+impl Distribution<Vec3> for UnitSphere {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        // Archimedes: z is uniformly distributed on a sphere
+        crate::sampling::square_to_sphere(rng.gen())
+    }
+}
+
+/// Samples a point uniformly distributed inside the unit sphere.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitBall;
+
+impl Distribution<Vec3> for UnitBall {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        UnitSphere.sample(rng) * rng.gen::<f32>().cbrt()
+    }
+}
+
+/// Samples a point uniformly distributed on the unit circle.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitCircle;
+
+impl Distribution<Vec2> for UnitCircle {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        let (sin, cos) = (rng.gen::<f32>() * 2.0 * PI).sin_cos();
+        Vec2::new(cos, sin)
+    }
+}
+
+/// Samples a point uniformly distributed inside the unit circle.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitDisk;
+
+impl Distribution<Vec2> for UnitDisk {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2 {
+        UnitCircle.sample(rng) * rng.gen::<f32>().sqrt()
+    }
+}
+
+/// Samples a direction uniformly distributed over the hemisphere around
+/// `normal`.
+#[derive(Clone, Copy, Debug)]
+pub struct Hemisphere {
+    normal: Vec3,
+}
+
+impl Hemisphere {
+    /// Creates a new hemisphere distribution around the given `normal`, which
+    /// must be normalized.
+    #[inline]
+    pub fn new(normal: Vec3) -> Self {
+        glam_assert!(normal.is_normalized());
+        Self { normal }
+    }
+}
+
+impl Distribution<Vec3> for Hemisphere {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        let v = UnitSphere.sample(rng);
+        if v.dot(self.normal) < 0.0 {
+            -v
+        } else {
+            v
+        }
+    }
+}
+
+/// Samples a direction over the hemisphere around `normal` with a probability
+/// proportional to the cosine of the angle to `normal`.
+///
+/// This is the distribution of directions used for importance sampling
+/// Lambertian reflectance.
+#[derive(Clone, Copy, Debug)]
+pub struct CosineHemisphere {
+    normal: Vec3,
+}
+
+impl CosineHemisphere {
+    /// Creates a new cosine weighted hemisphere distribution around the given
+    /// `normal`, which must be normalized.
+    #[inline]
+    pub fn new(normal: Vec3) -> Self {
+        glam_assert!(normal.is_normalized());
+        Self { normal }
+    }
+}
+
+impl Distribution<Vec3> for CosineHemisphere {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        // offsetting a point on the unit sphere by the normal produces a
+        // cosine weighted distribution
+        let v = self.normal + UnitSphere.sample(rng);
+        let length_sq = v.length_squared();
+        if length_sq > 1.0e-12 {
+            v / length_sq.sqrt()
+        } else {
+            self.normal
+        }
+    }
+}
+
+/// Samples a rotation uniformly distributed over all rotations.
+///
+/// `Quat` samples use `sampling::cube_to_rotation`.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformRotation;
+
+impl Distribution<Quat> for UniformRotation {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quat {
+        crate::sampling::cube_to_rotation(rng.gen::<(f32, f32, f32)>().into())
+    }
+}
+
+impl Distribution<Rot2> for UniformRotation {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Rot2 {
+        UnitCircle.sample(rng).into()
+    }
+}
+
+/// Samples a point uniformly distributed inside an axis aligned bounding box.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformAabb {
+    min: Vec3,
+    extent: Vec3,
+}
+
+impl UniformAabb {
+    /// Creates a new distribution over the box with corners `min` and `max`.
+    #[inline]
+    pub fn new(min: Vec3, max: Vec3) -> Self {
+        glam_assert!(min.cmple(max).all());
+        Self {
+            min,
+            extent: max - min,
+        }
+    }
+}
+
+impl Distribution<Vec3> for UniformAabb {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        self.min + self.extent * rng.gen::<Vec3>()
+    }
+}
+
+/// Samples a point uniformly distributed on the surface of a triangle.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformTriangle {
+    a: Vec3,
+    ab: Vec3,
+    ac: Vec3,
+}
+
+impl UniformTriangle {
+    /// Creates a new distribution over the triangle with vertices `a`, `b` and
+    /// `c`.
+    #[inline]
+    pub fn new(a: Vec3, b: Vec3, c: Vec3) -> Self {
+        Self {
+            a,
+            ab: b - a,
+            ac: c - a,
+        }
+    }
+}
+
+impl Distribution<Vec3> for UniformTriangle {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3 {
+        // fold samples from the parallelogram back onto the triangle
+        let (mut u, mut v): (f32, f32) = rng.gen();
+        if u + v > 1.0 {
+            u = 1.0 - u;
+            v = 1.0 - v;
+        }
+        self.a + self.ab * u + self.ac * v
+    }
+}
+
+macro_rules! impl_sample_uniform {
+    ($t:ident, $sampler:ident, $doc:expr, $($elem:ident),+) => {
+        #[doc = $doc]
+        ///
+        /// Each component is sampled independently, so the range forms an axis
+        /// aligned box between the two corners. Each component of `low` must be
+        /// less than the matching component of `high`.
+        #[derive(Clone, Copy, Debug)]
+        pub struct $sampler {
+            $($elem: UniformFloat<f32>,)+
+        }
+
+        impl SampleUniform for $t {
+            type Sampler = $sampler;
+        }
+
+        impl UniformSampler for $sampler {
+            type X = $t;
+
+            #[inline]
+            fn new<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let (low, high) = (low.borrow(), high.borrow());
+                Self {
+                    $($elem: UniformFloat::<f32>::new(low.$elem(), high.$elem()),)+
+                }
+            }
+
+            #[inline]
+            fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let (low, high) = (low.borrow(), high.borrow());
+                Self {
+                    $($elem: UniformFloat::<f32>::new_inclusive(low.$elem(), high.$elem()),)+
+                }
+            }
+
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                $t::new($(self.$elem.sample(rng)),+)
+            }
+        }
+    };
+}
+
+impl_sample_uniform!(
+    Vec2,
+    UniformVec2,
+    "The back-end implementing `UniformSampler` for `Vec2`.",
+    x,
+    y
+);
+impl_sample_uniform!(
+    Vec3,
+    UniformVec3,
+    "The back-end implementing `UniformSampler` for `Vec3`.",
+    x,
+    y,
+    z
+);
+impl_sample_uniform!(
+    Vec4,
+    UniformVec4,
+    "The back-end implementing `UniformSampler` for `Vec4`.",
+    x,
+    y,
+    z,
+    w
+);
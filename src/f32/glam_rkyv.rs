@@ -0,0 +1,215 @@
+//! `rkyv` support for zero-copy deserialization.
+//!
+//! Every glam type is archived as a `#[repr(C)]` struct of archived `f32`
+//! fields, so the archived layout does not depend on whether SIMD storage is
+//! in use. Archives written by a `scalar-math` build can be read by a SIMD
+//! build and vice versa.
+
+use crate::{DualQuat, Mat2, Mat3, Mat3A, Mat4, Quat, Rot2, Vec2, Vec3, Vec3A, Vec4};
+use rkyv::{out_field, Archive, Archived, Deserialize, Fallible, Serialize};
+
+#[cfg(feature = "transform-types")]
+use crate::{TransformRT, TransformRT2, TransformSRT, TransformSRT2};
+
+/// Declares the archived form of `$t` and implements `Archive`, `Serialize`
+/// and `Deserialize` for it.
+///
+/// Each field is read from `self` with `$get` and the deserialized fields are
+/// passed to `$ctor` by name.
+macro_rules! impl_rkyv {
+    (
+        $(#[$meta:meta])*
+        $t:ty => $archived:ident {
+            $($field:ident: $ft:ty = |$s:ident| $get:expr),+ $(,)?
+        },
+        $ctor:expr
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #[repr(C)]
+        pub struct $archived {
+            $(pub $field: Archived<$ft>,)+
+        }
+
+        impl Archive for $t {
+            type Archived = $archived;
+            type Resolver = ();
+
+            #[inline]
+            unsafe fn resolve(&self, pos: usize, _: (), out: *mut Self::Archived) {
+                $(
+                    let (fp, fo) = out_field!(out.$field);
+                    let $s = self;
+                    let value: $ft = $get;
+                    value.resolve(pos + fp, (), fo);
+                )+
+            }
+        }
+
+        impl<S: Fallible + ?Sized> Serialize<S> for $t {
+            #[inline]
+            fn serialize(&self, _: &mut S) -> Result<(), S::Error> {
+                Ok(())
+            }
+        }
+
+        impl<D: Fallible + ?Sized> Deserialize<$t, D> for $archived {
+            #[inline]
+            fn deserialize(&self, deserializer: &mut D) -> Result<$t, D::Error> {
+                $(let $field: $ft = self.$field.deserialize(deserializer)?;)+
+                Ok($ctor)
+            }
+        }
+    };
+}
+
+impl_rkyv!(
+    /// An archived `Vec2`.
+    Vec2 => ArchivedVec2 {
+        x: f32 = |v| v.x(),
+        y: f32 = |v| v.y(),
+    },
+    Vec2::new(x, y)
+);
+
+impl_rkyv!(
+    /// An archived `Vec3`.
+    Vec3 => ArchivedVec3 {
+        x: f32 = |v| v.x(),
+        y: f32 = |v| v.y(),
+        z: f32 = |v| v.z(),
+    },
+    Vec3::new(x, y, z)
+);
+
+impl_rkyv!(
+    /// An archived `Vec3A`, which has the same layout as an `ArchivedVec3`.
+    Vec3A => ArchivedVec3A {
+        x: f32 = |v| v.x(),
+        y: f32 = |v| v.y(),
+        z: f32 = |v| v.z(),
+    },
+    Vec3A::new(x, y, z)
+);
+
+impl_rkyv!(
+    /// An archived `Vec4`.
+    Vec4 => ArchivedVec4 {
+        x: f32 = |v| v.x(),
+        y: f32 = |v| v.y(),
+        z: f32 = |v| v.z(),
+        w: f32 = |v| v.w(),
+    },
+    Vec4::new(x, y, z, w)
+);
+
+impl_rkyv!(
+    /// An archived `Quat`.
+    Quat => ArchivedQuat {
+        x: f32 = |q| q.x(),
+        y: f32 = |q| q.y(),
+        z: f32 = |q| q.z(),
+        w: f32 = |q| q.w(),
+    },
+    Quat::from_xyzw(x, y, z, w)
+);
+
+impl_rkyv!(
+    /// An archived `Rot2`.
+    Rot2 => ArchivedRot2 {
+        cos: f32 = |r| r.cos(),
+        sin: f32 = |r| r.sin(),
+    },
+    Rot2::from(Vec2::new(cos, sin))
+);
+
+impl_rkyv!(
+    /// An archived `DualQuat`.
+    DualQuat => ArchivedDualQuat {
+        real: Quat = |dq| dq.real(),
+        dual: Quat = |dq| dq.dual(),
+    },
+    DualQuat::from_real_dual(real, dual)
+);
+
+impl_rkyv!(
+    /// An archived `Mat2`, stored in column major order.
+    Mat2 => ArchivedMat2 {
+        x_axis: Vec2 = |m| m.x_axis(),
+        y_axis: Vec2 = |m| m.y_axis(),
+    },
+    Mat2::from_cols(x_axis, y_axis)
+);
+
+impl_rkyv!(
+    /// An archived `Mat3`, stored in column major order.
+    Mat3 => ArchivedMat3 {
+        x_axis: Vec3 = |m| m.x_axis(),
+        y_axis: Vec3 = |m| m.y_axis(),
+        z_axis: Vec3 = |m| m.z_axis(),
+    },
+    Mat3::from_cols(x_axis, y_axis, z_axis)
+);
+
+impl_rkyv!(
+    /// An archived `Mat3A`, stored in column major order.
+    Mat3A => ArchivedMat3A {
+        x_axis: Vec3A = |m| m.x_axis(),
+        y_axis: Vec3A = |m| m.y_axis(),
+        z_axis: Vec3A = |m| m.z_axis(),
+    },
+    Mat3A::from_cols(x_axis, y_axis, z_axis)
+);
+
+impl_rkyv!(
+    /// An archived `Mat4`, stored in column major order.
+    Mat4 => ArchivedMat4 {
+        x_axis: Vec4 = |m| m.x_axis(),
+        y_axis: Vec4 = |m| m.y_axis(),
+        z_axis: Vec4 = |m| m.z_axis(),
+        w_axis: Vec4 = |m| m.w_axis(),
+    },
+    Mat4::from_cols(x_axis, y_axis, z_axis, w_axis)
+);
+
+#[cfg(feature = "transform-types")]
+impl_rkyv!(
+    /// An archived `TransformRT`.
+    TransformRT => ArchivedTransformRT {
+        rotation: Quat = |t| t.rotation,
+        translation: Vec3 = |t| t.translation,
+    },
+    TransformRT::from_rotation_translation(rotation, translation)
+);
+
+#[cfg(feature = "transform-types")]
+impl_rkyv!(
+    /// An archived `TransformSRT`.
+    TransformSRT => ArchivedTransformSRT {
+        scale: Vec3 = |t| t.scale,
+        rotation: Quat = |t| t.rotation,
+        translation: Vec3 = |t| t.translation,
+    },
+    TransformSRT::from_scale_rotation_translation(scale, rotation, translation)
+);
+
+#[cfg(feature = "transform-types")]
+impl_rkyv!(
+    /// An archived `TransformRT2`.
+    TransformRT2 => ArchivedTransformRT2 {
+        rotation: Rot2 = |t| t.rotation,
+        translation: Vec2 = |t| t.translation,
+    },
+    TransformRT2::from_rotation_translation(rotation, translation)
+);
+
+#[cfg(feature = "transform-types")]
+impl_rkyv!(
+    /// An archived `TransformSRT2`.
+    TransformSRT2 => ArchivedTransformSRT2 {
+        scale: Vec2 = |t| t.scale,
+        rotation: Rot2 = |t| t.rotation,
+        translation: Vec2 = |t| t.translation,
+    },
+    TransformSRT2::from_scale_rotation_translation(scale, rotation, translation)
+);
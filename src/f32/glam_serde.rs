@@ -1,10 +1,17 @@
-use crate::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
+use crate::{
+    DualQuat, HQuat, HVec2, HVec3, HVec4, LinearRgba, Mat2, Mat3, Mat3A, Mat4, Quat, Rot2, SRgba,
+    Vec2, Vec2Mask, Vec3, Vec3A, Vec3AMask, Vec3Mask, Vec4, Vec4Mask,
+};
 use core::fmt;
 use serde::{
-    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
-    ser::{Serialize, SerializeTupleStruct, Serializer},
+    de::{self, Deserializer, SeqAccess, Visitor},
+    ser::{SerializeTupleStruct, Serializer},
+    Deserialize, Serialize,
 };
 
+#[cfg(feature = "transform-types")]
+use crate::{TransformRT, TransformRT2, TransformSRT, TransformSRT2};
+
 impl Serialize for Vec2 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -383,3 +390,252 @@ impl<'de> Deserialize<'de> for Mat4 {
         deserializer.deserialize_tuple_struct("Mat4", 16, Mat4Visitor)
     }
 }
+
+/// Implements `Serialize` and `Deserialize` for `$t` by converting to and from
+/// the `$def` type, which derives both.
+macro_rules! impl_serde_via {
+    ($t:ty, $def:ty, |$a:ident| $to_def:expr, |$b:ident| $from_def:expr) => {
+        impl Serialize for $t {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let $a = self;
+                let def: $def = $to_def;
+                def.serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let $b: $def = Deserialize::deserialize(deserializer)?;
+                Ok($from_def)
+            }
+        }
+    };
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Vec2Mask")]
+struct Vec2MaskDef(bool, bool);
+
+impl_serde_via!(
+    Vec2Mask,
+    Vec2MaskDef,
+    |m| {
+        let [x, y]: [u32; 2] = (*m).into();
+        Vec2MaskDef(x != 0, y != 0)
+    },
+    |d| Vec2Mask::new(d.0, d.1)
+);
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Vec3Mask")]
+struct Vec3MaskDef(bool, bool, bool);
+
+impl_serde_via!(
+    Vec3Mask,
+    Vec3MaskDef,
+    |m| {
+        let [x, y, z]: [u32; 3] = (*m).into();
+        Vec3MaskDef(x != 0, y != 0, z != 0)
+    },
+    |d| Vec3Mask::new(d.0, d.1, d.2)
+);
+
+// The 16 byte aligned types use the same format as their packed counterparts.
+impl_serde_via!(Vec3A, Vec3, |v| Vec3::from(*v), |d| Vec3A::from(d));
+impl_serde_via!(Mat3A, Mat3, |m| Mat3::from(*m), |d| Mat3A::from(d));
+impl_serde_via!(
+    Vec3AMask,
+    Vec3Mask,
+    |m| {
+        let [x, y, z]: [u32; 3] = (*m).into();
+        Vec3Mask::new(x != 0, y != 0, z != 0)
+    },
+    |d| {
+        let [x, y, z]: [u32; 3] = d.into();
+        Vec3AMask::new(x != 0, y != 0, z != 0)
+    }
+);
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Vec4Mask")]
+struct Vec4MaskDef(bool, bool, bool, bool);
+
+impl_serde_via!(
+    Vec4Mask,
+    Vec4MaskDef,
+    |m| {
+        let [x, y, z, w]: [u32; 4] = (*m).into();
+        Vec4MaskDef(x != 0, y != 0, z != 0, w != 0)
+    },
+    |d| Vec4Mask::new(d.0, d.1, d.2, d.3)
+);
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Rot2")]
+struct Rot2Def(f32, f32);
+
+impl_serde_via!(
+    Rot2,
+    Rot2Def,
+    |r| Rot2Def(r.cos(), r.sin()),
+    |d| Rot2::from(Vec2::new(d.0, d.1))
+);
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "DualQuat")]
+struct DualQuatDef(Quat, Quat);
+
+impl_serde_via!(
+    DualQuat,
+    DualQuatDef,
+    |dq| DualQuatDef(dq.real(), dq.dual()),
+    |d| DualQuat::from_real_dual(d.0, d.1)
+);
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "LinearRgba")]
+struct LinearRgbaDef(f32, f32, f32, f32);
+
+impl_serde_via!(
+    LinearRgba,
+    LinearRgbaDef,
+    |c| LinearRgbaDef(c.r(), c.g(), c.b(), c.a()),
+    |d| LinearRgba::new(d.0, d.1, d.2, d.3)
+);
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "SRgba")]
+struct SRgbaDef(f32, f32, f32, f32);
+
+impl_serde_via!(
+    SRgba,
+    SRgbaDef,
+    |c| SRgbaDef(c.r(), c.g(), c.b(), c.a()),
+    |d| SRgba::new(d.0, d.1, d.2, d.3)
+);
+
+// The half precision types are written as `f32` values, which represent them
+// exactly.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "HVec2")]
+struct HVec2Def(f32, f32);
+
+impl_serde_via!(HVec2, HVec2Def, |v| HVec2Def(v.x(), v.y()), |d| HVec2::new(
+    d.0, d.1
+));
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "HVec3")]
+struct HVec3Def(f32, f32, f32);
+
+impl_serde_via!(HVec3, HVec3Def, |v| HVec3Def(v.x(), v.y(), v.z()), |d| {
+    HVec3::new(d.0, d.1, d.2)
+});
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "HVec4")]
+struct HVec4Def(f32, f32, f32, f32);
+
+impl_serde_via!(
+    HVec4,
+    HVec4Def,
+    |v| HVec4Def(v.x(), v.y(), v.z(), v.w()),
+    |d| HVec4::new(d.0, d.1, d.2, d.3)
+);
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "HQuat")]
+struct HQuatDef(f32, f32, f32, f32);
+
+impl_serde_via!(
+    HQuat,
+    HQuatDef,
+    |q| HQuatDef(q.x(), q.y(), q.z(), q.w()),
+    |d| HQuat::from_xyzw(d.0, d.1, d.2, d.3)
+);
+
+#[cfg(feature = "transform-types")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "TransformRT")]
+struct TransformRTDef {
+    rotation: Quat,
+    translation: Vec3,
+}
+
+#[cfg(feature = "transform-types")]
+impl_serde_via!(
+    TransformRT,
+    TransformRTDef,
+    |t| TransformRTDef {
+        rotation: t.rotation,
+        translation: t.translation,
+    },
+    |d| TransformRT::from_rotation_translation(d.rotation, d.translation)
+);
+
+#[cfg(feature = "transform-types")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "TransformSRT")]
+struct TransformSRTDef {
+    scale: Vec3,
+    rotation: Quat,
+    translation: Vec3,
+}
+
+#[cfg(feature = "transform-types")]
+impl_serde_via!(
+    TransformSRT,
+    TransformSRTDef,
+    |t| TransformSRTDef {
+        scale: t.scale,
+        rotation: t.rotation,
+        translation: t.translation,
+    },
+    |d| TransformSRT::from_scale_rotation_translation(d.scale, d.rotation, d.translation)
+);
+
+#[cfg(feature = "transform-types")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "TransformRT2")]
+struct TransformRT2Def {
+    rotation: Rot2,
+    translation: Vec2,
+}
+
+#[cfg(feature = "transform-types")]
+impl_serde_via!(
+    TransformRT2,
+    TransformRT2Def,
+    |t| TransformRT2Def {
+        rotation: t.rotation,
+        translation: t.translation,
+    },
+    |d| TransformRT2::from_rotation_translation(d.rotation, d.translation)
+);
+
+#[cfg(feature = "transform-types")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "TransformSRT2")]
+struct TransformSRT2Def {
+    scale: Vec2,
+    rotation: Rot2,
+    translation: Vec2,
+}
+
+#[cfg(feature = "transform-types")]
+impl_serde_via!(
+    TransformSRT2,
+    TransformSRT2Def,
+    |t| TransformSRT2Def {
+        scale: t.scale,
+        rotation: t.rotation,
+        translation: t.translation,
+    },
+    |d| TransformSRT2::from_scale_rotation_translation(d.scale, d.rotation, d.translation)
+);
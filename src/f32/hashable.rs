@@ -0,0 +1,179 @@
+use super::{DualQuat, Mat2, Mat3, Mat3A, Mat4, Quat, Rot2, Vec2, Vec3, Vec3A, Vec4};
+use core::{cmp::Ordering, fmt, hash};
+
+#[cfg(feature = "transform-types")]
+use super::{TransformRT, TransformRT2, TransformSRT, TransformSRT2};
+
+/// The canonical quiet NaN bit pattern that all NaNs are mapped to.
+const CANONICAL_NAN: u32 = 0x7fc0_0000;
+
+/// Returns the bits of `f` with `-0.0` mapped to `+0.0` and every NaN mapped
+/// to the same quiet NaN.
+#[inline]
+pub(crate) fn canonical_f32_bits(f: f32) -> u32 {
+    if f.is_nan() {
+        CANONICAL_NAN
+    } else if f == 0.0 {
+        0
+    } else {
+        f.to_bits()
+    }
+}
+
+/// Maps canonical float bits to a key whose unsigned integer ordering matches
+/// the numeric ordering of the floats, with NaN ordered after `INFINITY`.
+#[inline]
+fn order_key(bits: u32) -> u32 {
+    if bits & 0x8000_0000 != 0 {
+        !bits
+    } else {
+        bits | 0x8000_0000
+    }
+}
+
+/// Types which can be converted to an array of canonical `f32` bit patterns.
+///
+/// Canonical bits map `-0.0` to `+0.0` and every NaN to the same quiet NaN, so
+/// values that compare equal (and all NaNs) have identical bits.
+pub trait CanonicalBits {
+    /// An array of `u32`, one per `f32` element.
+    type Bits: AsRef<[u32]> + Copy + fmt::Debug;
+
+    /// Returns the canonical bits of every element of `self`.
+    fn canonical_bits(&self) -> Self::Bits;
+}
+
+/// A wrapper providing `Hash`, `Eq` and `Ord` for glam types by comparing
+/// canonical bit patterns, making it possible to use glam types as keys in a
+/// `HashMap`, `HashSet` or `BTreeMap`.
+///
+/// `-0.0` and `+0.0` are considered equal and all NaNs are equal to each
+/// other. Elements are ordered numerically in memory order, with NaN ordered
+/// after `INFINITY`.
+///
+/// ```
+/// use glam::{Hashable, Vec3};
+/// use std::collections::HashMap;
+/// let mut indices = HashMap::new();
+/// indices.insert(Hashable(Vec3::new(0.0, 1.0, 2.0)), 0_u32);
+/// assert_eq!(indices.get(&Hashable(Vec3::new(-0.0, 1.0, 2.0))), Some(&0));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct Hashable<T>(pub T);
+
+impl<T> From<T> for Hashable<T> {
+    #[inline]
+    fn from(t: T) -> Self {
+        Hashable(t)
+    }
+}
+
+impl<T: CanonicalBits> PartialEq for Hashable<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.canonical_bits().as_ref() == other.0.canonical_bits().as_ref()
+    }
+}
+
+impl<T: CanonicalBits> Eq for Hashable<T> {}
+
+impl<T: CanonicalBits> hash::Hash for Hashable<T> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.0.canonical_bits().as_ref().hash(state);
+    }
+}
+
+impl<T: CanonicalBits> PartialOrd for Hashable<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: CanonicalBits> Ord for Hashable<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lhs = self.0.canonical_bits();
+        let rhs = other.0.canonical_bits();
+        lhs.as_ref()
+            .iter()
+            .map(|&b| order_key(b))
+            .cmp(rhs.as_ref().iter().map(|&b| order_key(b)))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Hashable<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! impl_canonical_bits {
+    ($t:ty, $n:expr, |$v:ident| $to_array:expr) => {
+        impl CanonicalBits for $t {
+            type Bits = [u32; $n];
+            #[inline]
+            fn canonical_bits(&self) -> Self::Bits {
+                let $v = self;
+                let floats: [f32; $n] = $to_array;
+                let mut bits = [0; $n];
+                for (b, &f) in bits.iter_mut().zip(floats.iter()) {
+                    *b = canonical_f32_bits(f);
+                }
+                bits
+            }
+        }
+    };
+}
+
+impl_canonical_bits!(Vec2, 2, |v| (*v).into());
+impl_canonical_bits!(Vec3, 3, |v| (*v).into());
+impl_canonical_bits!(Vec3A, 3, |v| (*v).into());
+impl_canonical_bits!(Vec4, 4, |v| (*v).into());
+impl_canonical_bits!(Quat, 4, |q| (*q).into());
+impl_canonical_bits!(Rot2, 2, |r| (*r).into());
+impl_canonical_bits!(Mat2, 4, |m| m.to_cols_array());
+impl_canonical_bits!(Mat3, 9, |m| m.to_cols_array());
+impl_canonical_bits!(Mat3A, 9, |m| m.to_cols_array());
+impl_canonical_bits!(Mat4, 16, |m| m.to_cols_array());
+impl_canonical_bits!(DualQuat, 8, |dq| {
+    let (r, d) = (dq.real(), dq.dual());
+    [r.x(), r.y(), r.z(), r.w(), d.x(), d.y(), d.z(), d.w()]
+});
+
+#[cfg(feature = "transform-types")]
+impl_canonical_bits!(TransformRT, 7, |t| {
+    let (r, p) = (t.rotation, t.translation);
+    [r.x(), r.y(), r.z(), r.w(), p.x(), p.y(), p.z()]
+});
+
+#[cfg(feature = "transform-types")]
+impl_canonical_bits!(TransformSRT, 10, |t| {
+    let (s, r, p) = (t.scale, t.rotation, t.translation);
+    [
+        s.x(),
+        s.y(),
+        s.z(),
+        r.x(),
+        r.y(),
+        r.z(),
+        r.w(),
+        p.x(),
+        p.y(),
+        p.z(),
+    ]
+});
+
+#[cfg(feature = "transform-types")]
+impl_canonical_bits!(TransformRT2, 4, |t| {
+    let (r, p) = (t.rotation, t.translation);
+    [r.cos(), r.sin(), p.x(), p.y()]
+});
+
+#[cfg(feature = "transform-types")]
+impl_canonical_bits!(TransformSRT2, 6, |t| {
+    let (s, r, p) = (t.scale, t.rotation, t.translation);
+    [s.x(), s.y(), r.cos(), r.sin(), p.x(), p.y()]
+});
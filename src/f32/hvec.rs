@@ -0,0 +1,400 @@
+//! Half precision storage types.
+//!
+//! The conversion from `f32` rounds to nearest even, supports denormals and
+//! converts values that are too large to infinity, as IEEE 754 binary16 does.
+//! When the `f16c` target feature is enabled the conversions use the F16C
+//! instructions, which give the same results.
+
+use super::{
+    packing::{f16_to_f32, f32_to_f16},
+    Quat, Vec2, Vec3, Vec4,
+};
+use core::{fmt, mem, slice};
+
+#[cfg(all(vec4sse2, target_feature = "f16c", target_arch = "x86"))]
+use core::arch::x86::*;
+#[cfg(all(vec4sse2, target_feature = "f16c", target_arch = "x86_64"))]
+use core::arch::x86_64::*;
+
+/// Converts four `f32` values to the bits of half precision floats.
+#[inline]
+fn f32x4_to_f16x4(v: [f32; 4]) -> [u16; 4] {
+    #[cfg(all(vec4sse2, target_feature = "f16c"))]
+    unsafe {
+        let mut out = [0_u16; 4];
+        let h = _mm_cvtps_ph(_mm_loadu_ps(v.as_ptr()), _MM_FROUND_TO_NEAREST_INT);
+        _mm_storel_epi64(out.as_mut_ptr() as *mut __m128i, h);
+        out
+    }
+
+    #[cfg(not(all(vec4sse2, target_feature = "f16c")))]
+    {
+        [
+            f32_to_f16(v[0]),
+            f32_to_f16(v[1]),
+            f32_to_f16(v[2]),
+            f32_to_f16(v[3]),
+        ]
+    }
+}
+
+/// Converts the bits of four half precision floats to `f32` values.
+#[inline]
+fn f16x4_to_f32x4(h: [u16; 4]) -> [f32; 4] {
+    #[cfg(all(vec4sse2, target_feature = "f16c"))]
+    unsafe {
+        let mut out = [0.0; 4];
+        let v = _mm_cvtph_ps(_mm_loadl_epi64(h.as_ptr() as *const __m128i));
+        _mm_storeu_ps(out.as_mut_ptr(), v);
+        out
+    }
+
+    #[cfg(not(all(vec4sse2, target_feature = "f16c")))]
+    {
+        [
+            f16_to_f32(h[0]),
+            f16_to_f32(h[1]),
+            f16_to_f32(h[2]),
+            f16_to_f32(h[3]),
+        ]
+    }
+}
+
+/// Converts `f32` lanes to the bits of half precision floats, four at a time
+/// with the software conversion for the remainder.
+fn f32_to_f16_lanes(src: &[f32], dst: &mut [u16]) {
+    debug_assert_eq!(src.len(), dst.len());
+    let mut src_chunks = src.chunks_exact(4);
+    let mut dst_chunks = dst.chunks_exact_mut(4);
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        d.copy_from_slice(&f32x4_to_f16x4([s[0], s[1], s[2], s[3]]));
+    }
+    for (&s, d) in src_chunks
+        .remainder()
+        .iter()
+        .zip(dst_chunks.into_remainder())
+    {
+        *d = f32_to_f16(s);
+    }
+}
+
+/// Converts the bits of half precision floats to `f32` lanes, four at a time
+/// with the software conversion for the remainder.
+fn f16_to_f32_lanes(src: &[u16], dst: &mut [f32]) {
+    debug_assert_eq!(src.len(), dst.len());
+    let mut src_chunks = src.chunks_exact(4);
+    let mut dst_chunks = dst.chunks_exact_mut(4);
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        d.copy_from_slice(&f16x4_to_f32x4([s[0], s[1], s[2], s[3]]));
+    }
+    for (&s, d) in src_chunks
+        .remainder()
+        .iter()
+        .zip(dst_chunks.into_remainder())
+    {
+        *d = f16_to_f32(s);
+    }
+}
+
+/// Implements the conversions, accessors and slice functions shared by the
+/// half precision types.
+macro_rules! impl_hvec {
+    ($t:ident, $vt:ident, $n:expr, $to:ident, [$($i:expr),+], $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone, Copy)]
+        #[repr(C)]
+        pub struct $t(pub(crate) [u16; $n]);
+
+        impl $t {
+            /// Creates a value from the bits of its half precision components.
+            #[inline]
+            pub fn from_bits(bits: [u16; $n]) -> Self {
+                Self(bits)
+            }
+
+            /// Returns the bits of the half precision components.
+            #[inline]
+            pub fn to_bits(self) -> [u16; $n] {
+                self.0
+            }
+
+            /// Converts each element of `src` to half precision and writes
+            /// the results to `dst`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the two slices have different lengths.
+            pub fn encode_slice(src: &[$vt], dst: &mut [Self]) {
+                assert_eq!(src.len(), dst.len());
+                f32_to_f16_lanes(Self::f32_lanes(src), Self::u16_lanes_mut(dst));
+            }
+
+            /// Converts each element of `src` to single precision, without
+            /// any per type adjustment, and writes the results to `dst`.
+            fn decode_lanes(src: &[Self], dst: &mut [$vt]) {
+                assert_eq!(src.len(), dst.len());
+                f16_to_f32_lanes(Self::u16_lanes(src), Self::f32_lanes_mut(dst));
+            }
+
+            /// Views a slice of single precision values as their `f32` lanes.
+            #[inline]
+            fn f32_lanes(v: &[$vt]) -> &[f32] {
+                assert_eq!(mem::size_of::<$vt>(), $n * mem::size_of::<f32>());
+                // $vt is made of exactly $n f32s with no padding
+                unsafe { slice::from_raw_parts(v.as_ptr() as *const f32, v.len() * $n) }
+            }
+
+            #[inline]
+            fn f32_lanes_mut(v: &mut [$vt]) -> &mut [f32] {
+                assert_eq!(mem::size_of::<$vt>(), $n * mem::size_of::<f32>());
+                unsafe { slice::from_raw_parts_mut(v.as_mut_ptr() as *mut f32, v.len() * $n) }
+            }
+
+            /// Views a slice of half precision values as their `u16` lanes.
+            #[inline]
+            fn u16_lanes(h: &[Self]) -> &[u16] {
+                // Self is repr(C) around [u16; $n]
+                unsafe { slice::from_raw_parts(h.as_ptr() as *const u16, h.len() * $n) }
+            }
+
+            #[inline]
+            fn u16_lanes_mut(h: &mut [Self]) -> &mut [u16] {
+                unsafe { slice::from_raw_parts_mut(h.as_mut_ptr() as *mut u16, h.len() * $n) }
+            }
+
+            #[inline]
+            fn to_f32x4(self) -> [f32; 4] {
+                let mut h = [0; 4];
+                h[..$n].copy_from_slice(&self.0);
+                f16x4_to_f32x4(h)
+            }
+
+            #[inline]
+            fn from_f32x4(v: [f32; 4]) -> Self {
+                let h = f32x4_to_f16x4(v);
+                Self([$(h[$i]),+])
+            }
+        }
+
+        impl From<$vt> for $t {
+            #[inline]
+            fn from(v: $vt) -> Self {
+                let a: [f32; $n] = v.into();
+                let mut v = [0.0; 4];
+                v[..$n].copy_from_slice(&a);
+                Self::from_f32x4(v)
+            }
+        }
+
+        impl From<$t> for $vt {
+            #[inline]
+            fn from(h: $t) -> Self {
+                h.$to()
+            }
+        }
+
+        impl AsRef<[u16; $n]> for $t {
+            #[inline]
+            fn as_ref(&self) -> &[u16; $n] {
+                &self.0
+            }
+        }
+
+        impl AsMut<[u16; $n]> for $t {
+            #[inline]
+            fn as_mut(&mut self) -> &mut [u16; $n] {
+                &mut self.0
+            }
+        }
+
+        impl fmt::Debug for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let v = self.to_f32x4();
+                let mut t = f.debug_tuple(stringify!($t));
+                $(t.field(&v[$i]);)+
+                t.finish()
+            }
+        }
+    };
+}
+
+macro_rules! impl_hvec_n {
+    ($t:ident, $vt:ident, $n:expr, $to:ident, [$($i:expr),+], [$(($c:ident, $cdoc:expr)),+],
+     $doc:expr) => {
+        impl_hvec!($t, $vt, $n, $to, [$($i),+], $doc);
+
+        impl $t {
+            /// Creates a new half precision vector, rounding each component.
+            #[inline]
+            pub fn new($($c: f32),+) -> Self {
+                Self::from($vt::new($($c),+))
+            }
+
+            /// Converts each element of `src` to single precision and writes
+            /// the results to `dst`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the two slices have different lengths.
+            pub fn decode_slice(src: &[Self], dst: &mut [$vt]) {
+                Self::decode_lanes(src, dst);
+            }
+
+            /// Converts to a single precision vector, which is exact.
+            #[inline]
+            pub fn $to(self) -> $vt {
+                let v = self.to_f32x4();
+                $vt::new($(v[$i]),+)
+            }
+
+            $(
+                #[doc = $cdoc]
+                #[inline]
+                pub fn $c(self) -> f32 {
+                    f16_to_f32(self.0[$i])
+                }
+            )+
+        }
+
+        impl Default for $t {
+            #[inline]
+            fn default() -> Self {
+                Self([0; $n])
+            }
+        }
+
+        impl PartialEq for $t {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.$to() == other.$to()
+            }
+        }
+    };
+}
+
+impl_hvec_n!(
+    HVec2,
+    Vec2,
+    2,
+    to_vec2,
+    [0, 1],
+    [(x, "Returns element `x`."), (y, "Returns element `y`.")],
+    "A 2-dimensional vector stored as half precision floats.\n\nThis type is 4 bytes in size \
+     and 2 byte aligned. Convert it to a `Vec2` for arithmetic."
+);
+impl_hvec_n!(
+    HVec3,
+    Vec3,
+    3,
+    to_vec3,
+    [0, 1, 2],
+    [
+        (x, "Returns element `x`."),
+        (y, "Returns element `y`."),
+        (z, "Returns element `z`.")
+    ],
+    "A 3-dimensional vector stored as half precision floats.\n\nThis type is 6 bytes in size \
+     and 2 byte aligned. Convert it to a `Vec3` for arithmetic."
+);
+impl_hvec_n!(
+    HVec4,
+    Vec4,
+    4,
+    to_vec4,
+    [0, 1, 2, 3],
+    [
+        (x, "Returns element `x`."),
+        (y, "Returns element `y`."),
+        (z, "Returns element `z`."),
+        (w, "Returns element `w`.")
+    ],
+    "A 4-dimensional vector stored as half precision floats.\n\nThis type is 8 bytes in size \
+     and 2 byte aligned. Convert it to a `Vec4` for arithmetic."
+);
+impl_hvec!(
+    HQuat,
+    Quat,
+    4,
+    to_quat,
+    [0, 1, 2, 3],
+    "A quaternion stored as half precision floats.\n\nThis type is 8 bytes in size and 2 byte \
+     aligned. Each component has an error of up to about `0.0005`, so the quaternion is \
+     normalized when converting back to a `Quat`."
+);
+
+impl HQuat {
+    /// The identity quaternion.
+    pub const IDENTITY: Self = Self::identity();
+
+    /// Creates a half precision quaternion from the components of a
+    /// normalized quaternion, rounding each component.
+    #[inline]
+    pub fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self::from_f32x4([x, y, z, w])
+    }
+
+    /// Returns the identity quaternion.
+    #[inline]
+    pub const fn identity() -> Self {
+        // `1.0` in half precision
+        Self([0, 0, 0, 0x3c00])
+    }
+
+    /// Converts to a single precision quaternion and normalizes it.
+    #[inline]
+    pub fn to_quat(self) -> Quat {
+        Quat::from(Vec4::from(self.to_f32x4()).normalize())
+    }
+
+    /// Converts each element of `src` to single precision, normalizing it as
+    /// `to_quat` does, and writes the results to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
+    pub fn decode_slice(src: &[Self], dst: &mut [Quat]) {
+        Self::decode_lanes(src, dst);
+        for q in dst.iter_mut() {
+            *q = Quat::from(Vec4::from(*q).normalize());
+        }
+    }
+
+    /// Returns the stored `x` component.
+    #[inline]
+    pub fn x(self) -> f32 {
+        f16_to_f32(self.0[0])
+    }
+
+    /// Returns the stored `y` component.
+    #[inline]
+    pub fn y(self) -> f32 {
+        f16_to_f32(self.0[1])
+    }
+
+    /// Returns the stored `z` component.
+    #[inline]
+    pub fn z(self) -> f32 {
+        f16_to_f32(self.0[2])
+    }
+
+    /// Returns the stored `w` component.
+    #[inline]
+    pub fn w(self) -> f32 {
+        f16_to_f32(self.0[3])
+    }
+}
+
+impl Default for HQuat {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl PartialEq for HQuat {
+    /// Compares the stored components without normalizing them.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.to_f32x4() == other.to_f32x4()
+    }
+}
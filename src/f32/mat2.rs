@@ -1,15 +1,17 @@
-use super::{scalar_sin_cos, Vec2, Vec4};
-#[cfg(all(vec4sse2, target_arch = "x86",))]
+// Generated from mat.rs.tera template. Edit the template, not the generated file.
+
+use super::{scalar_sin_cos, Radians, Vec2, Vec4};
+#[cfg(all(vec4sse2, target_arch = "x86"))]
 use core::arch::x86::*;
-#[cfg(all(vec4sse2, target_arch = "x86_64",))]
+#[cfg(all(vec4sse2, target_arch = "x86_64"))]
 use core::arch::x86_64::*;
 use core::{
     fmt,
-    ops::{Add, Mul, Sub},
+    ops::{Add, Index, IndexMut, Mul, Sub},
 };
 
 #[inline]
-pub fn mat2(x_axis: Vec2, y_axis: Vec2) -> Mat2 {
+pub const fn mat2(x_axis: Vec2, y_axis: Vec2) -> Mat2 {
     Mat2::from_cols(x_axis, y_axis)
 }
 
@@ -32,30 +34,36 @@ impl fmt::Display for Mat2 {
 }
 
 impl Mat2 {
+    /// A matrix with all elements set to `0.0`.
+    pub const ZERO: Self = Self::zero();
+
+    /// The identity matrix.
+    pub const IDENTITY: Self = Self::identity();
+
     /// Creates a 2x2 matrix with all elements set to `0.0`.
     #[inline]
-    pub fn zero() -> Self {
-        Mat2(Vec4::zero())
+    pub const fn zero() -> Self {
+        Self(Vec4::zero())
     }
 
     /// Creates a 2x2 identity matrix.
     #[inline]
-    pub fn identity() -> Self {
+    pub const fn identity() -> Self {
         Self(Vec4::new(1.0, 0.0, 0.0, 1.0))
     }
 
-    /// Creates a 2x2 matrix from four column vectors.
+    /// Creates a 2x2 matrix from two column vectors.
     #[inline]
-    pub fn from_cols(x_axis: Vec2, y_axis: Vec2) -> Self {
-        Self(Vec4::new(x_axis.x(), x_axis.y(), y_axis.x(), y_axis.y()))
+    pub const fn from_cols(x_axis: Vec2, y_axis: Vec2) -> Self {
+        Self(Vec4::new(x_axis.0, x_axis.1, y_axis.0, y_axis.1))
     }
 
-    /// Creates a 2x2 matrix from a `[f32; 4]` stored in column major order.  If
-    /// your data is stored in row major you will need to `transpose` the
+    /// Creates a 2x2 matrix from a `[f32; 4]` stored in column major order.
+    /// If your data is stored in row major you will need to `transpose` the
     /// returned matrix.
     #[inline]
-    pub fn from_cols_array(m: &[f32; 4]) -> Self {
-        Mat2(Vec4::new(m[0], m[1], m[2], m[3]))
+    pub const fn from_cols_array(m: &[f32; 4]) -> Self {
+        Self(Vec4::new(m[0], m[1], m[2], m[3]))
     }
 
     /// Creates a `[f32; 4]` storing data in column major order.
@@ -65,12 +73,12 @@ impl Mat2 {
         self.0.into()
     }
 
-    /// Creates a 2x2 matrix from a `[[f32; 2]; 2]` stored in column major
-    /// order.  If your data is in row major order you will need to `transpose`
-    /// the returned matrix.
+    /// Creates a 2x2 matrix from a `[[f32; 2]; 2]` stored in column
+    /// major order.  If your data is in row major order you will need to
+    /// `transpose` the returned matrix.
     #[inline]
-    pub fn from_cols_array_2d(m: &[[f32; 2]; 2]) -> Self {
-        Mat2(Vec4::new(m[0][0], m[0][1], m[1][0], m[1][1]))
+    pub const fn from_cols_array_2d(m: &[[f32; 2]; 2]) -> Self {
+        Self(Vec4::new(m[0][0], m[0][1], m[1][0], m[1][1]))
     }
 
     /// Creates a `[[f32; 2]; 2]` storing data in column major order.
@@ -84,7 +92,8 @@ impl Mat2 {
     /// Creates a 2x2 matrix containing the given `scale` and rotation of
     /// `angle` (in radians).
     #[inline]
-    pub fn from_scale_angle(scale: Vec2, angle: f32) -> Self {
+    pub fn from_scale_angle(scale: Vec2, angle: impl Into<Radians>) -> Self {
+        let angle = angle.into().0;
         let (sin, cos) = scalar_sin_cos(angle);
         let (scale_x, scale_y) = scale.into();
         Self(Vec4::new(
@@ -97,7 +106,8 @@ impl Mat2 {
 
     /// Creates a 2x2 matrix containing a rotation of `angle` (in radians).
     #[inline]
-    pub fn from_angle(angle: f32) -> Self {
+    pub fn from_angle(angle: impl Into<Radians>) -> Self {
+        let angle = angle.into().0;
         let (sin, cos) = scalar_sin_cos(angle);
         Self(Vec4::new(cos, sin, -sin, cos))
     }
@@ -145,30 +155,6 @@ impl Mat2 {
         unsafe { &mut *(self.0.as_mut()[2..].as_mut_ptr() as *mut Vec2) }
     }
 
-    // #[inline]
-    // pub(crate) fn col(&self, index: usize) -> Vec2 {
-    //     match index {
-    //         0 => self.x_axis(),
-    //         1 => self.y_axis(),
-    //         _ => panic!(
-    //             "index out of bounds: the len is 2 but the index is {}",
-    //             index
-    //         ),
-    //     }
-    // }
-
-    // #[inline]
-    // pub(crate) fn col_mut(&mut self, index: usize) -> &mut Vec2 {
-    //     match index {
-    //         0 => unsafe { &mut *(self.0.as_mut().as_mut_ptr() as *mut Vec2) },
-    //         1 => unsafe { &mut *(self.0.as_mut()[2..].as_mut_ptr() as *mut Vec2) },
-    //         _ => panic!(
-    //             "index out of bounds: the len is 2 but the index is {}",
-    //             index
-    //         ),
-    //     }
-    // }
-
     /// Returns the transpose of `self`.
     #[inline]
     pub fn transpose(&self) -> Self {
@@ -232,6 +218,7 @@ impl Mat2 {
         }
     }
 
+    /// Transforms a 2D vector.
     #[inline]
     pub fn mul_vec2(&self, other: Vec2) -> Vec2 {
         // TODO: SSE2
@@ -241,6 +228,7 @@ impl Mat2 {
         Vec2::new(x0 + x1, y0 + y1)
     }
 
+    /// Multiplies two 2x2 matrices.
     #[inline]
     pub fn mul_mat2(&self, other: &Self) -> Self {
         // TODO: SSE2
@@ -251,20 +239,23 @@ impl Mat2 {
         )
     }
 
+    /// Adds two 2x2 matrices.
     #[inline]
     pub fn add_mat2(&self, other: &Self) -> Self {
-        Mat2(self.0 + other.0)
+        Self(self.0 + other.0)
     }
 
+    /// Subtracts two 2x2 matrices.
     #[inline]
     pub fn sub_mat2(&self, other: &Self) -> Self {
-        Mat2(self.0 - other.0)
+        Self(self.0 - other.0)
     }
 
+    /// Multiplies a 2x2 matrix by a scalar.
     #[inline]
     pub fn mul_scalar(&self, other: f32) -> Self {
         let s = Vec4::splat(other);
-        Mat2(self.0 * s)
+        Self(self.0 * s)
     }
 
     /// Returns true if the absolute difference of all elements between `self`
@@ -343,3 +334,32 @@ impl Mul<f32> for Mat2 {
         self.mul_scalar(other)
     }
 }
+
+impl Index<usize> for Mat2 {
+    type Output = Vec2;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => unsafe { &*(self.0.as_ref().as_ptr() as *const Vec2) },
+            1 => unsafe { &*(self.0.as_ref()[2..].as_ptr() as *const Vec2) },
+            _ => panic!(
+                "index out of bounds: the len is 2 but the index is {}",
+                index
+            ),
+        }
+    }
+}
+
+impl IndexMut<usize> for Mat2 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => self.x_axis_mut(),
+            1 => self.y_axis_mut(),
+            _ => panic!(
+                "index out of bounds: the len is 2 but the index is {}",
+                index
+            ),
+        }
+    }
+}
@@ -1,16 +1,14 @@
-use super::{scalar_sin_cos, Quat, Vec2, Vec3};
+// Generated from mat.rs.tera template. Edit the template, not the generated file.
+
+use super::{scalar_sin_cos, Quat, Radians, Vec2, Vec3};
 use core::{
     fmt,
-    ops::{Add, Mul, Sub},
+    ops::{Add, Index, IndexMut, Mul, Sub},
 };
 
 #[inline]
-pub fn mat3(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Mat3 {
-    Mat3 {
-        x_axis,
-        y_axis,
-        z_axis,
-    }
+pub const fn mat3(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Mat3 {
+    Mat3::from_cols(x_axis, y_axis, z_axis)
 }
 
 #[inline]
@@ -37,8 +35,6 @@ fn quat_to_axes(rotation: Quat) -> (Vec3, Vec3, Vec3) {
 }
 
 /// A 3x3 column major matrix.
-///
-/// This type is 16 byte aligned.
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
 #[repr(C)]
 pub struct Mat3 {
@@ -61,29 +57,27 @@ impl fmt::Display for Mat3 {
 }
 
 impl Mat3 {
+    /// A matrix with all elements set to `0.0`.
+    pub const ZERO: Self = Self::zero();
+
+    /// The identity matrix.
+    pub const IDENTITY: Self = Self::identity();
+
     /// Creates a 3x3 matrix with all elements set to `0.0`.
     #[inline]
-    pub fn zero() -> Self {
-        Self {
-            x_axis: Vec3::zero(),
-            y_axis: Vec3::zero(),
-            z_axis: Vec3::zero(),
-        }
+    pub const fn zero() -> Self {
+        Self::from_cols(Vec3::zero(), Vec3::zero(), Vec3::zero())
     }
 
     /// Creates a 3x3 identity matrix.
     #[inline]
-    pub fn identity() -> Self {
-        Self {
-            x_axis: Vec3::unit_x(),
-            y_axis: Vec3::unit_y(),
-            z_axis: Vec3::unit_z(),
-        }
+    pub const fn identity() -> Self {
+        Self::from_cols(Vec3::unit_x(), Vec3::unit_y(), Vec3::unit_z())
     }
 
     /// Creates a 3x3 matrix from three column vectors.
     #[inline]
-    pub fn from_cols(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Self {
+    pub const fn from_cols(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Self {
         Self {
             x_axis,
             y_axis,
@@ -95,12 +89,12 @@ impl Mat3 {
     /// If your data is stored in row major you will need to `transpose` the
     /// returned matrix.
     #[inline]
-    pub fn from_cols_array(m: &[f32; 9]) -> Self {
-        Mat3 {
-            x_axis: Vec3::new(m[0], m[1], m[2]),
-            y_axis: Vec3::new(m[3], m[4], m[5]),
-            z_axis: Vec3::new(m[6], m[7], m[8]),
-        }
+    pub const fn from_cols_array(m: &[f32; 9]) -> Self {
+        Self::from_cols(
+            Vec3::new(m[0], m[1], m[2]),
+            Vec3::new(m[3], m[4], m[5]),
+            Vec3::new(m[6], m[7], m[8]),
+        )
     }
 
     /// Creates a `[f32; 9]` storing data in column major order.
@@ -113,16 +107,16 @@ impl Mat3 {
         [m00, m01, m02, m10, m11, m12, m20, m21, m22]
     }
 
-    /// Creates a 3x3 matrix from a `[[f32; 3]; 3]` stored in column major order.
-    /// If your data is in row major order you will need to `transpose` the
-    /// returned matrix.
+    /// Creates a 3x3 matrix from a `[[f32; 3]; 3]` stored in column
+    /// major order.  If your data is in row major order you will need to
+    /// `transpose` the returned matrix.
     #[inline]
-    pub fn from_cols_array_2d(m: &[[f32; 3]; 3]) -> Self {
-        Mat3 {
-            x_axis: m[0].into(),
-            y_axis: m[1].into(),
-            z_axis: m[2].into(),
-        }
+    pub const fn from_cols_array_2d(m: &[[f32; 3]; 3]) -> Self {
+        Self::from_cols(
+            Vec3::new(m[0][0], m[0][1], m[0][2]),
+            Vec3::new(m[1][0], m[1][1], m[1][2]),
+            Vec3::new(m[2][0], m[2][1], m[2][2]),
+        )
     }
 
     /// Creates a `[[f32; 3]; 3]` storing data in column major order.
@@ -137,7 +131,12 @@ impl Mat3 {
     ///
     /// The resulting matrix can be used to transform 2D points and vectors.
     #[inline]
-    pub fn from_scale_angle_translation(scale: Vec2, angle: f32, translation: Vec2) -> Self {
+    pub fn from_scale_angle_translation(
+        scale: Vec2,
+        angle: impl Into<Radians>,
+        translation: Vec2,
+    ) -> Self {
+        let angle = angle.into().0;
         let (sin, cos) = scalar_sin_cos(angle);
         let (scale_x, scale_y) = scale.into();
         Self {
@@ -147,8 +146,8 @@ impl Mat3 {
         }
     }
 
-    #[inline]
     /// Creates a 3x3 rotation matrix from the given quaternion.
+    #[inline]
     pub fn from_quat(rotation: Quat) -> Self {
         let (x_axis, y_axis, z_axis) = quat_to_axes(rotation);
         Self {
@@ -158,10 +157,11 @@ impl Mat3 {
         }
     }
 
-    /// Creates a 3x3 rotation matrix from a normalized rotation `axis` and
-    /// `angle` (in radians).
+    /// Creates a 3x3 rotation matrix containing a rotation
+    /// around a normalized rotation `axis` of `angle` (in radians).
     #[inline]
-    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
+    pub fn from_axis_angle(axis: Vec3, angle: impl Into<Radians>) -> Self {
+        let angle = angle.into().0;
         glam_assert!(axis.is_normalized());
         let (sin, cos) = scalar_sin_cos(angle);
         let (x, y, z) = axis.into();
@@ -178,16 +178,23 @@ impl Mat3 {
         }
     }
 
-    /// Creates a 3x3 rotation matrix from the given Euler angles (in radians).
+    /// Creates a 3x3 rotation matrix containing a rotation
+    /// around the given Euler angles (in radians).
     #[inline]
-    pub fn from_rotation_ypr(yaw: f32, pitch: f32, roll: f32) -> Self {
+    pub fn from_rotation_ypr(
+        yaw: impl Into<Radians>,
+        pitch: impl Into<Radians>,
+        roll: impl Into<Radians>,
+    ) -> Self {
         let quat = Quat::from_rotation_ypr(yaw, pitch, roll);
         Self::from_quat(quat)
     }
 
-    /// Creates a 3x3 rotation matrix from `angle` (in radians) around the x axis.
+    /// Creates a 3x3 rotation matrix containing a rotation
+    /// around the x axis of `angle` (in radians).
     #[inline]
-    pub fn from_rotation_x(angle: f32) -> Self {
+    pub fn from_rotation_x(angle: impl Into<Radians>) -> Self {
+        let angle = angle.into().0;
         let (sina, cosa) = scalar_sin_cos(angle);
         Self {
             x_axis: Vec3::unit_x(),
@@ -196,9 +203,11 @@ impl Mat3 {
         }
     }
 
-    /// Creates a 3x3 rotation matrix from `angle` (in radians) around the y axis.
+    /// Creates a 3x3 rotation matrix containing a rotation
+    /// around the y axis of `angle` (in radians).
     #[inline]
-    pub fn from_rotation_y(angle: f32) -> Self {
+    pub fn from_rotation_y(angle: impl Into<Radians>) -> Self {
+        let angle = angle.into().0;
         let (sina, cosa) = scalar_sin_cos(angle);
         Self {
             x_axis: Vec3::new(cosa, 0.0, -sina),
@@ -207,9 +216,11 @@ impl Mat3 {
         }
     }
 
-    /// Creates a 3x3 rotation matrix from `angle` (in radians) around the z axis.
+    /// Creates a 3x3 rotation matrix containing a rotation
+    /// around the z axis of `angle` (in radians).
     #[inline]
-    pub fn from_rotation_z(angle: f32) -> Self {
+    pub fn from_rotation_z(angle: impl Into<Radians>) -> Self {
+        let angle = angle.into().0;
         let (sina, cosa) = scalar_sin_cos(angle);
         Self {
             x_axis: Vec3::new(cosa, sina, 0.0),
@@ -218,10 +229,10 @@ impl Mat3 {
         }
     }
 
-    /// Creates a 3x3 non-uniform scale matrix.
+    /// Creates a 3x3 matrix containing the given
+    /// non-uniform `scale`.
     #[inline]
     pub fn from_scale(scale: Vec3) -> Self {
-        // TODO: should have a affine 2D scale and a 3d scale?
         // Do not panic as long as any component is non-zero
         glam_assert!(scale.cmpne(Vec3::zero()).any());
         let (x, y, z) = scale.into();
@@ -277,63 +288,17 @@ impl Mat3 {
         &mut self.z_axis
     }
 
-    // #[inline]
-    // pub(crate) fn col(&self, index: usize) -> Vec3 {
-    //     match index {
-    //         0 => self.x_axis,
-    //         1 => self.y_axis,
-    //         2 => self.z_axis,
-    //         _ => panic!(
-    //             "index out of bounds: the len is 3 but the index is {}",
-    //             index
-    //         ),
-    //     }
-    // }
-
-    // #[inline]
-    // pub(crate) fn col_mut(&mut self, index: usize) -> &mut Vec3 {
-    //     match index {
-    //         0 => &mut self.x_axis,
-    //         1 => &mut self.y_axis,
-    //         2 => &mut self.z_axis,
-    //         _ => panic!(
-    //             "index out of bounds: the len is 3 but the index is {}",
-    //             index
-    //         ),
-    //     }
-    // }
-
     /// Returns the transpose of `self`.
     #[inline]
     pub fn transpose(&self) -> Self {
-        #[cfg(vec3sse2)]
-        {
-            #[cfg(target_arch = "x86")]
-            use core::arch::x86::*;
-            #[cfg(target_arch = "x86_64")]
-            use core::arch::x86_64::*;
-            unsafe {
-                let tmp0 = _mm_shuffle_ps(self.x_axis.0, self.y_axis.0, 0b01_00_01_00);
-                let tmp1 = _mm_shuffle_ps(self.x_axis.0, self.y_axis.0, 0b11_10_11_10);
-
-                Self {
-                    x_axis: _mm_shuffle_ps(tmp0, self.z_axis.0, 0b00_00_10_00).into(),
-                    y_axis: _mm_shuffle_ps(tmp0, self.z_axis.0, 0b01_01_11_01).into(),
-                    z_axis: _mm_shuffle_ps(tmp1, self.z_axis.0, 0b10_10_10_00).into(),
-                }
-            }
-        }
-        #[cfg(vec3f32)]
-        {
-            let (m00, m01, m02) = self.x_axis.into();
-            let (m10, m11, m12) = self.y_axis.into();
-            let (m20, m21, m22) = self.z_axis.into();
-
-            Self {
-                x_axis: Vec3::new(m00, m10, m20),
-                y_axis: Vec3::new(m01, m11, m21),
-                z_axis: Vec3::new(m02, m12, m22),
-            }
+        let (m00, m01, m02) = self.x_axis.into();
+        let (m10, m11, m12) = self.y_axis.into();
+        let (m20, m21, m22) = self.z_axis.into();
+
+        Self {
+            x_axis: Vec3::new(m00, m10, m20),
+            y_axis: Vec3::new(m01, m11, m21),
+            z_axis: Vec3::new(m02, m12, m22),
         }
     }
 
@@ -354,7 +319,7 @@ impl Mat3 {
         glam_assert!(det.cmpne(Vec3::zero()).all());
         let inv_det = det.reciprocal();
         // TODO: Work out if it's possible to get rid of the transpose
-        Mat3::from_cols(tmp0 * inv_det, tmp1 * inv_det, tmp2 * inv_det).transpose()
+        Self::from_cols(tmp0 * inv_det, tmp1 * inv_det, tmp2 * inv_det).transpose()
     }
 
     /// Transforms a 3D vector.
@@ -396,8 +361,8 @@ impl Mat3 {
         }
     }
 
-    #[inline]
     /// Multiplies a 3x3 matrix by a scalar.
+    #[inline]
     pub fn mul_scalar(&self, other: f32) -> Self {
         let s = Vec3::splat(other);
         Self {
@@ -495,3 +460,34 @@ impl Mul<f32> for Mat3 {
         self.mul_scalar(other)
     }
 }
+
+impl Index<usize> for Mat3 {
+    type Output = Vec3;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x_axis,
+            1 => &self.y_axis,
+            2 => &self.z_axis,
+            _ => panic!(
+                "index out of bounds: the len is 3 but the index is {}",
+                index
+            ),
+        }
+    }
+}
+
+impl IndexMut<usize> for Mat3 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => self.x_axis_mut(),
+            1 => self.y_axis_mut(),
+            2 => self.z_axis_mut(),
+            _ => panic!(
+                "index out of bounds: the len is 3 but the index is {}",
+                index
+            ),
+        }
+    }
+}
@@ -1,21 +1,18 @@
-use super::{scalar_sin_cos, Mat3, Quat, Vec3, Vec4};
+// Generated from mat.rs.tera template. Edit the template, not the generated file.
+
+use super::{scalar_sin_cos, scalar_tan, Mat3, Quat, Radians, Vec3, Vec3A, Vec4};
 #[cfg(all(vec4sse2, target_arch = "x86"))]
 use core::arch::x86::*;
 #[cfg(all(vec4sse2, target_arch = "x86_64"))]
 use core::arch::x86_64::*;
 use core::{
     fmt,
-    ops::{Add, Mul, Sub},
+    ops::{Add, Index, IndexMut, Mul, Sub},
 };
 
 #[inline]
-pub fn mat4(x_axis: Vec4, y_axis: Vec4, z_axis: Vec4, w_axis: Vec4) -> Mat4 {
-    Mat4 {
-        x_axis,
-        y_axis,
-        z_axis,
-        w_axis,
-    }
+pub const fn mat4(x_axis: Vec4, y_axis: Vec4, z_axis: Vec4, w_axis: Vec4) -> Mat4 {
+    Mat4::from_cols(x_axis, y_axis, z_axis, w_axis)
 }
 
 #[inline]
@@ -71,31 +68,32 @@ impl fmt::Display for Mat4 {
 }
 
 impl Mat4 {
+    /// A matrix with all elements set to `0.0`.
+    pub const ZERO: Self = Self::zero();
+
+    /// The identity matrix.
+    pub const IDENTITY: Self = Self::identity();
+
     /// Creates a 4x4 matrix with all elements set to `0.0`.
     #[inline]
-    pub fn zero() -> Self {
-        Self {
-            x_axis: Vec4::zero(),
-            y_axis: Vec4::zero(),
-            z_axis: Vec4::zero(),
-            w_axis: Vec4::zero(),
-        }
+    pub const fn zero() -> Self {
+        Self::from_cols(Vec4::zero(), Vec4::zero(), Vec4::zero(), Vec4::zero())
     }
 
     /// Creates a 4x4 identity matrix.
     #[inline]
-    pub fn identity() -> Self {
-        Self {
-            x_axis: Vec4::unit_x(),
-            y_axis: Vec4::unit_y(),
-            z_axis: Vec4::unit_z(),
-            w_axis: Vec4::unit_w(),
-        }
+    pub const fn identity() -> Self {
+        Self::from_cols(
+            Vec4::unit_x(),
+            Vec4::unit_y(),
+            Vec4::unit_z(),
+            Vec4::unit_w(),
+        )
     }
 
     /// Creates a 4x4 matrix from four column vectors.
     #[inline]
-    pub fn from_cols(x_axis: Vec4, y_axis: Vec4, z_axis: Vec4, w_axis: Vec4) -> Self {
+    pub const fn from_cols(x_axis: Vec4, y_axis: Vec4, z_axis: Vec4, w_axis: Vec4) -> Self {
         Self {
             x_axis,
             y_axis,
@@ -108,13 +106,13 @@ impl Mat4 {
     /// If your data is stored in row major you will need to `transpose` the
     /// returned matrix.
     #[inline]
-    pub fn from_cols_array(m: &[f32; 16]) -> Self {
-        Mat4 {
-            x_axis: Vec4::new(m[0], m[1], m[2], m[3]),
-            y_axis: Vec4::new(m[4], m[5], m[6], m[7]),
-            z_axis: Vec4::new(m[8], m[9], m[10], m[11]),
-            w_axis: Vec4::new(m[12], m[13], m[14], m[15]),
-        }
+    pub const fn from_cols_array(m: &[f32; 16]) -> Self {
+        Self::from_cols(
+            Vec4::new(m[0], m[1], m[2], m[3]),
+            Vec4::new(m[4], m[5], m[6], m[7]),
+            Vec4::new(m[8], m[9], m[10], m[11]),
+            Vec4::new(m[12], m[13], m[14], m[15]),
+        )
     }
 
     /// Creates a `[f32; 16]` storing data in column major order.
@@ -124,17 +122,17 @@ impl Mat4 {
         *self.as_ref()
     }
 
-    /// Creates a 4x4 matrix from a `[[f32; 4]; 4]` stored in column major
-    /// order.  If your data is in row major order you will need to `transpose`
-    /// the returned matrix.
+    /// Creates a 4x4 matrix from a `[[f32; 4]; 4]` stored in column
+    /// major order.  If your data is in row major order you will need to
+    /// `transpose` the returned matrix.
     #[inline]
-    pub fn from_cols_array_2d(m: &[[f32; 4]; 4]) -> Self {
-        Mat4 {
-            x_axis: m[0].into(),
-            y_axis: m[1].into(),
-            z_axis: m[2].into(),
-            w_axis: m[3].into(),
-        }
+    pub const fn from_cols_array_2d(m: &[[f32; 4]; 4]) -> Self {
+        Self::from_cols(
+            Vec4::new(m[0][0], m[0][1], m[0][2], m[0][3]),
+            Vec4::new(m[1][0], m[1][1], m[1][2], m[1][3]),
+            Vec4::new(m[2][0], m[2][1], m[2][2], m[2][3]),
+            Vec4::new(m[3][0], m[3][1], m[3][2], m[3][3]),
+        )
     }
 
     /// Creates a `[[f32; 4]; 4]` storing data in column major order.
@@ -230,7 +228,8 @@ impl Mat4 {
     /// Creates a 4x4 homogeneous transformation matrix containing a rotation
     /// around a normalized rotation `axis` of `angle` (in radians).
     #[inline]
-    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
+    pub fn from_axis_angle(axis: Vec3, angle: impl Into<Radians>) -> Self {
+        let angle = angle.into().0;
         glam_assert!(axis.is_normalized());
         let (sin, cos) = scalar_sin_cos(angle);
         let (x, y, z) = axis.into();
@@ -251,7 +250,11 @@ impl Mat4 {
     /// Creates a 4x4 homogeneous transformation matrix containing a rotation
     /// around the given Euler angles (in radians).
     #[inline]
-    pub fn from_rotation_ypr(yaw: f32, pitch: f32, roll: f32) -> Self {
+    pub fn from_rotation_ypr(
+        yaw: impl Into<Radians>,
+        pitch: impl Into<Radians>,
+        roll: impl Into<Radians>,
+    ) -> Self {
         let quat = Quat::from_rotation_ypr(yaw, pitch, roll);
         Self::from_quat(quat)
     }
@@ -259,7 +262,8 @@ impl Mat4 {
     /// Creates a 4x4 homogeneous transformation matrix containing a rotation
     /// around the x axis of `angle` (in radians).
     #[inline]
-    pub fn from_rotation_x(angle: f32) -> Self {
+    pub fn from_rotation_x(angle: impl Into<Radians>) -> Self {
+        let angle = angle.into().0;
         let (sina, cosa) = scalar_sin_cos(angle);
         Self {
             x_axis: Vec4::unit_x(),
@@ -272,7 +276,8 @@ impl Mat4 {
     /// Creates a 4x4 homogeneous transformation matrix containing a rotation
     /// around the y axis of `angle` (in radians).
     #[inline]
-    pub fn from_rotation_y(angle: f32) -> Self {
+    pub fn from_rotation_y(angle: impl Into<Radians>) -> Self {
+        let angle = angle.into().0;
         let (sina, cosa) = scalar_sin_cos(angle);
         Self {
             x_axis: Vec4::new(cosa, 0.0, -sina, 0.0),
@@ -285,7 +290,8 @@ impl Mat4 {
     /// Creates a 4x4 homogeneous transformation matrix containing a rotation
     /// around the z axis of `angle` (in radians).
     #[inline]
-    pub fn from_rotation_z(angle: f32) -> Self {
+    pub fn from_rotation_z(angle: impl Into<Radians>) -> Self {
+        let angle = angle.into().0;
         let (sina, cosa) = scalar_sin_cos(angle);
         Self {
             x_axis: Vec4::new(cosa, sina, 0.0, 0.0),
@@ -370,34 +376,6 @@ impl Mat4 {
         &mut self.w_axis
     }
 
-    // #[inline]
-    // pub(crate) fn col(&self, index: usize) -> Vec4 {
-    //     match index {
-    //         0 => self.x_axis,
-    //         1 => self.y_axis,
-    //         2 => self.z_axis,
-    //         3 => self.w_axis,
-    //         _ => panic!(
-    //             "index out of bounds: the len is 4 but the index is {}",
-    //             index
-    //         ),
-    //     }
-    // }
-
-    // #[inline]
-    // pub(crate) fn col_mut(&mut self, index: usize) -> &mut Vec4 {
-    //     match index {
-    //         0 => &mut self.x_axis,
-    //         1 => &mut self.y_axis,
-    //         2 => &mut self.z_axis,
-    //         3 => &mut self.w_axis,
-    //         _ => panic!(
-    //             "index out of bounds: the len is 4 but the index is {}",
-    //             index
-    //         ),
-    //     }
-    // }
-
     /// Returns the transpose of `self`.
     #[inline]
     pub fn transpose(&self) -> Self {
@@ -563,13 +541,14 @@ impl Mat4 {
     /// This is the same as the OpenGL `gluPerspective` function.
     /// See https://www.khronos.org/registry/OpenGL-Refpages/gl2.1/xhtml/gluPerspective.xml
     pub fn perspective_rh_gl(
-        fov_y_radians: f32,
+        fov_y_radians: impl Into<Radians>,
         aspect_ratio: f32,
         z_near: f32,
         z_far: f32,
     ) -> Self {
+        let fov_y_radians = fov_y_radians.into().0;
         let inv_length = 1.0 / (z_near - z_far);
-        let f = 1.0 / (0.5 * fov_y_radians).tan();
+        let f = 1.0 / scalar_tan(0.5 * fov_y_radians);
         let a = f / aspect_ratio;
         let b = (z_near + z_far) * inv_length;
         let c = (2.0 * z_near * z_far) * inv_length;
@@ -582,7 +561,13 @@ impl Mat4 {
     }
 
     /// Creates a left-handed perspective projection matrix with [0,1] depth range.
-    pub fn perspective_lh(fov_y_radians: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> Self {
+    pub fn perspective_lh(
+        fov_y_radians: impl Into<Radians>,
+        aspect_ratio: f32,
+        z_near: f32,
+        z_far: f32,
+    ) -> Self {
+        let fov_y_radians = fov_y_radians.into().0;
         glam_assert!(z_near > 0.0 && z_far > 0.0);
         let (sin_fov, cos_fov) = scalar_sin_cos(0.5 * fov_y_radians);
         let h = cos_fov / sin_fov;
@@ -597,7 +582,12 @@ impl Mat4 {
     }
 
     /// Creates an infinite left-handed perspective projection matrix with [0,1] depth range.
-    pub fn perspective_infinite_lh(fov_y_radians: f32, aspect_ratio: f32, z_near: f32) -> Self {
+    pub fn perspective_infinite_lh(
+        fov_y_radians: impl Into<Radians>,
+        aspect_ratio: f32,
+        z_near: f32,
+    ) -> Self {
+        let fov_y_radians = fov_y_radians.into().0;
         glam_assert!(z_near > 0.0);
         let (sin_fov, cos_fov) = scalar_sin_cos(0.5 * fov_y_radians);
         let h = cos_fov / sin_fov;
@@ -612,10 +602,11 @@ impl Mat4 {
 
     /// Creates an infinite left-handed perspective projection matrix with [0,1] depth range.
     pub fn perspective_infinite_reverse_lh(
-        fov_y_radians: f32,
+        fov_y_radians: impl Into<Radians>,
         aspect_ratio: f32,
         z_near: f32,
     ) -> Self {
+        let fov_y_radians = fov_y_radians.into().0;
         glam_assert!(z_near > 0.0);
         let (sin_fov, cos_fov) = scalar_sin_cos(0.5 * fov_y_radians);
         let h = cos_fov / sin_fov;
@@ -631,7 +622,7 @@ impl Mat4 {
     #[inline]
     #[deprecated(since = "0.8.2", note = "please use `Mat4::perspective_rh_gl` instead")]
     pub fn perspective_glu_rh(
-        fov_y_radians: f32,
+        fov_y_radians: impl Into<Radians>,
         aspect_ratio: f32,
         z_near: f32,
         z_far: f32,
@@ -641,8 +632,13 @@ impl Mat4 {
 
     /// Creates an infinite right-handed perspective projection matrix with
     /// [0,1] depth range.
-    pub fn perspective_infinite_rh(fov_y_radians: f32, aspect_ratio: f32, z_near: f32) -> Self {
-        let f = 1.0 / (0.5 * fov_y_radians).tan();
+    pub fn perspective_infinite_rh(
+        fov_y_radians: impl Into<Radians>,
+        aspect_ratio: f32,
+        z_near: f32,
+    ) -> Self {
+        let fov_y_radians = fov_y_radians.into().0;
+        let f = 1.0 / scalar_tan(0.5 * fov_y_radians);
         Mat4::from_cols(
             Vec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
             Vec4::new(0.0, f, 0.0, 0.0),
@@ -654,11 +650,12 @@ impl Mat4 {
     /// Creates an infinite reverse right-handed perspective projection matrix
     /// with [0,1] depth range.
     pub fn perspective_infinite_reverse_rh(
-        fov_y_radians: f32,
+        fov_y_radians: impl Into<Radians>,
         aspect_ratio: f32,
         z_near: f32,
     ) -> Self {
-        let f = 1.0 / (0.5 * fov_y_radians).tan();
+        let fov_y_radians = fov_y_radians.into().0;
+        let f = 1.0 / scalar_tan(0.5 * fov_y_radians);
         Mat4::from_cols(
             Vec4::new(f / aspect_ratio, 0.0, 0.0, 0.0),
             Vec4::new(0.0, f, 0.0, 0.0),
@@ -744,6 +741,7 @@ impl Mat4 {
         )
     }
 
+    /// Transforms a 4D vector.
     #[inline]
     pub fn mul_vec4(&self, other: Vec4) -> Vec4 {
         let mut res = self.x_axis * other.dup_x();
@@ -764,6 +762,7 @@ impl Mat4 {
         }
     }
 
+    /// Adds two 4x4 matrices.
     #[inline]
     pub fn add_mat4(&self, other: &Self) -> Self {
         Self {
@@ -774,6 +773,7 @@ impl Mat4 {
         }
     }
 
+    /// Subtracts two 4x4 matrices.
     #[inline]
     pub fn sub_mat4(&self, other: &Self) -> Self {
         Self {
@@ -784,6 +784,7 @@ impl Mat4 {
         }
     }
 
+    /// Multiplies a 4x4 matrix by a scalar.
     #[inline]
     pub fn mul_scalar(&self, other: f32) -> Self {
         let s = Vec4::splat(other);
@@ -825,6 +826,22 @@ impl Mat4 {
         self.mul_vec4(other.extend(0.0)).truncate()
     }
 
+    /// Transforms the given `Vec3A` as 3D point.
+    /// This is the equivalent of multiplying the `Vec3A` as a `Vec4` where `w`
+    /// is `1.0`.
+    #[inline]
+    pub fn transform_point3a(&self, other: Vec3A) -> Vec3A {
+        Vec3A::from(self.mul_vec4(other.extend(1.0)))
+    }
+
+    /// Transforms the given `Vec3A` as 3D vector.
+    /// This is the equivalent of multiplying the `Vec3A` as a `Vec4` where `w`
+    /// is `0.0`.
+    #[inline]
+    pub fn transform_vector3a(&self, other: Vec3A) -> Vec3A {
+        Vec3A::from(self.mul_vec4(other.extend(0.0)))
+    }
+
     /// Returns true if the absolute difference of all elements between `self`
     /// and `other` is less than or equal to `max_abs_diff`.
     ///
@@ -904,3 +921,36 @@ impl Mul<f32> for Mat4 {
         self.mul_scalar(other)
     }
 }
+
+impl Index<usize> for Mat4 {
+    type Output = Vec4;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x_axis,
+            1 => &self.y_axis,
+            2 => &self.z_axis,
+            3 => &self.w_axis,
+            _ => panic!(
+                "index out of bounds: the len is 4 but the index is {}",
+                index
+            ),
+        }
+    }
+}
+
+impl IndexMut<usize> for Mat4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => self.x_axis_mut(),
+            1 => self.y_axis_mut(),
+            2 => self.z_axis_mut(),
+            3 => self.w_axis_mut(),
+            _ => panic!(
+                "index out of bounds: the len is 4 but the index is {}",
+                index
+            ),
+        }
+    }
+}
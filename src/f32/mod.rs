@@ -1,44 +1,121 @@
+mod angle;
+mod color;
+pub mod curves;
+mod dual_quat;
 mod funcs;
+mod hashable;
+mod hvec;
 mod mat2;
 mod mat3;
+mod mat3a;
 mod mat4;
+pub mod noise;
+mod packing;
+#[cfg(all(test, vec4sse2))]
+mod parity;
+mod parse;
+pub mod predicates;
 mod quat;
+mod rot2;
+#[cfg(all(test, vec4sse2))]
+mod scalar;
+pub mod sampling;
+pub mod spring;
 #[cfg(feature = "transform-types")]
 mod transform;
+#[cfg(feature = "transform-types")]
+mod transform2;
 mod vec2;
 mod vec2_mask;
 mod vec3;
 mod vec3_mask;
+mod vec3a;
+mod vec3a_mask;
 mod vec4;
 mod vec4_mask;
+mod weld;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod x86_utils;
 
-pub(crate) use funcs::{scalar_acos, scalar_sin_cos};
+pub use angle::*;
+pub use color::*;
+pub use dual_quat::*;
+pub(crate) use funcs::{
+    hash_u32, scalar_acos, scalar_atan2, scalar_cos, scalar_exp, scalar_sin, scalar_sin_cos,
+    scalar_tan, u32_to_unit_f32,
+};
+pub use hashable::*;
+pub use hvec::*;
 pub use mat2::*;
 pub use mat3::*;
+pub use mat3a::*;
 pub use mat4::*;
+pub use parse::*;
 pub use quat::*;
+pub use rot2::*;
 #[cfg(feature = "transform-types")]
 pub use transform::*;
+#[cfg(feature = "transform-types")]
+pub use transform2::*;
 pub use vec2::*;
 pub use vec2_mask::*;
 pub use vec3::*;
 pub use vec3_mask::*;
+pub use vec3a::*;
+pub use vec3a_mask::*;
 pub use vec4::*;
 pub use vec4_mask::*;
+pub use weld::*;
+
+#[cfg(feature = "arbitrary")]
+mod glam_arbitrary;
+
+#[cfg(feature = "bytemuck")]
+mod glam_bytemuck;
+
+#[cfg(feature = "deterministic")]
+mod deterministic;
 
 #[cfg(feature = "mint")]
 mod glam_mint;
 #[cfg(feature = "mint")]
 pub use glam_mint::*;
 
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+#[cfg(feature = "quickcheck")]
+mod glam_quickcheck;
+
+#[cfg(any(feature = "arbitrary", feature = "quickcheck"))]
+mod subsets;
+#[cfg(any(feature = "arbitrary", feature = "quickcheck"))]
+pub use subsets::*;
+
 #[cfg(feature = "rand")]
 mod glam_rand;
 #[cfg(feature = "rand")]
 pub use glam_rand::*;
 
+#[cfg(feature = "rkyv")]
+mod glam_rkyv;
+#[cfg(feature = "rkyv")]
+pub use glam_rkyv::*;
+
 #[cfg(feature = "serde")]
 mod glam_serde;
 #[cfg(feature = "serde")]
 pub use glam_serde::*;
+#[cfg(feature = "serde")]
+pub mod serde_repr;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+/// Internal functions called by the targets in the `fuzz` crate, which
+/// enables the `fuzzing` feature.
+#[cfg(feature = "fuzzing")]
+#[doc(hidden)]
+pub mod fuzzing {
+    pub use super::funcs::scalar_acos;
+}
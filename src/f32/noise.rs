@@ -0,0 +1,1071 @@
+//! Gradient and cellular noise functions.
+//!
+//! Perlin and simplex noise are provided in two, three and four dimensions.
+//! Both return values in approximately the range `[-1, 1]` and are zero
+//! on average. The `_grad` variants also return the analytic derivative of the
+//! noise, which is useful for computing normals of noise based terrain or for
+//! curl noise without additional evaluations.
+//!
+//! The lattice is hashed rather than using a permutation table, so the noise
+//! does not repeat with a short period. Different noise patterns can be
+//! obtained by offsetting the input position.
+//!
+//! The `_x4` functions evaluate four positions at once and use SSE2 when it
+//! is available. They return the same values as the single position
+//! functions.
+//!
+//! ```
+//! use glam::{noise, Vec2};
+//! let height = noise::Fractal::default().fbm(Vec2::new(1.5, 2.5), noise::simplex2);
+//! assert!(height.abs() <= 1.0);
+//! ```
+
+use super::{hash_u32, u32_to_unit_f32, Vec2, Vec3, Vec4};
+use core::ops::Mul;
+
+#[cfg(all(vec4sse2, target_arch = "x86"))]
+use core::arch::x86::*;
+#[cfg(all(vec4sse2, target_arch = "x86_64"))]
+use core::arch::x86_64::*;
+
+// Normalization factors which scale the noise to approximately [-1, 1],
+// found by measuring the range of each function over several million points.
+const PERLIN2_SCALE: f32 = 0.632;
+const PERLIN3_SCALE: f32 = 0.96;
+const PERLIN4_SCALE: f32 = 0.86;
+const SIMPLEX2_SCALE: f32 = 44.0;
+const SIMPLEX3_SCALE: f32 = 74.0;
+const SIMPLEX4_SCALE: f32 = 60.0;
+
+/// The squared radius of influence of each simplex corner. A radius of `0.5`
+/// keeps the noise continuous in all dimensions.
+const SIMPLEX_RADIUS_SQUARED: f32 = 0.5;
+
+/// Returns the dot product of a lattice gradient selected by `hash` and `d`.
+///
+/// The gradient functions are linear in `d`, so the gradient vector itself is
+/// found by evaluating them with each unit axis.
+type GradFn = fn(u32, &[f32; 4]) -> f32;
+
+#[inline]
+fn negate_if(bit: u32, v: f32) -> f32 {
+    if bit != 0 {
+        -v
+    } else {
+        v
+    }
+}
+
+/// 8 gradients of the form `(±1, ±2)` and `(±2, ±1)`.
+#[inline]
+fn grad2(hash: u32, d: &[f32; 4]) -> f32 {
+    let (u, v) = if hash & 4 == 0 {
+        (d[0], d[1])
+    } else {
+        (d[1], d[0])
+    };
+    negate_if(hash & 1, u) + negate_if(hash & 2, 2.0 * v)
+}
+
+/// The 12 gradients pointing to the edges of a cube, with 4 repeated.
+#[inline]
+fn grad3(hash: u32, d: &[f32; 4]) -> f32 {
+    let h = hash & 15;
+    let u = if h < 8 { d[0] } else { d[1] };
+    let v = if h < 4 {
+        d[1]
+    } else if h == 12 || h == 14 {
+        d[0]
+    } else {
+        d[2]
+    };
+    negate_if(h & 1, u) + negate_if(h & 2, v)
+}
+
+/// The 32 gradients pointing to the edges of a tesseract.
+#[inline]
+fn grad4(hash: u32, d: &[f32; 4]) -> f32 {
+    let h = hash & 31;
+    let u = if h < 24 { d[0] } else { d[1] };
+    let v = if h < 16 { d[1] } else { d[2] };
+    let w = if h < 8 { d[2] } else { d[3] };
+    negate_if(h & 1, u) + negate_if(h & 2, v) + negate_if(h & 4, w)
+}
+
+/// Hashes the first `dims` coordinates of a lattice point.
+#[inline]
+fn hash_lattice(lattice: &[i32; 4], dims: usize) -> u32 {
+    lattice[..dims]
+        .iter()
+        .fold(0, |h, &c| hash_u32(h ^ c as u32))
+}
+
+/// The quintic interpolant `6t^5 - 15t^4 + 10t^3`.
+#[inline]
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+#[inline]
+fn fade_derivative(t: f32) -> f32 {
+    let s = t * (t - 1.0);
+    30.0 * s * s
+}
+
+/// Splits `p` into the integer lattice cell containing it and the offset
+/// within that cell.
+#[inline]
+fn split_cell(p: &[f32; 4], dims: usize) -> ([i32; 4], [f32; 4]) {
+    let mut cell = [0; 4];
+    let mut offset = [0.0; 4];
+    for k in 0..dims {
+        let i = p[k].floor();
+        cell[k] = i as i32;
+        offset[k] = p[k] - i;
+    }
+    (cell, offset)
+}
+
+/// Evaluates Perlin noise in `dims` dimensions, returning the noise and its
+/// gradient if `derivative` is true.
+#[inline]
+fn perlin(p: [f32; 4], dims: usize, grad: GradFn, derivative: bool) -> (f32, [f32; 4]) {
+    let (cell, f) = split_cell(&p, dims);
+    let mut u = [0.0; 4];
+    let mut du = [0.0; 4];
+    for k in 0..dims {
+        u[k] = fade(f[k]);
+        du[k] = fade_derivative(f[k]);
+    }
+
+    let mut value = 0.0;
+    let mut gradient = [0.0; 4];
+    for corner in 0..1 << dims {
+        let mut lattice = cell;
+        let mut d = f;
+        let mut weights = [0.0; 4];
+        let mut weight_derivatives = [0.0; 4];
+        for k in 0..dims {
+            if corner >> k & 1 != 0 {
+                lattice[k] = lattice[k].wrapping_add(1);
+                d[k] -= 1.0;
+                weights[k] = u[k];
+                weight_derivatives[k] = du[k];
+            } else {
+                weights[k] = 1.0 - u[k];
+                weight_derivatives[k] = -du[k];
+            }
+        }
+        let h = hash_lattice(&lattice, dims);
+        let dot = grad(h, &d);
+        let weight = weights[..dims].iter().fold(1.0, |w, &x| w * x);
+        value += weight * dot;
+
+        if derivative {
+            for k in 0..dims {
+                let mut axis = [0.0; 4];
+                axis[k] = 1.0;
+                let others = (0..dims)
+                    .filter(|&j| j != k)
+                    .fold(1.0, |w, j| w * weights[j]);
+                gradient[k] += weight_derivatives[k] * others * dot + weight * grad(h, &axis);
+            }
+        }
+    }
+    (value, gradient)
+}
+
+/// Evaluates simplex noise in `dims` dimensions, returning the noise and its
+/// gradient if `derivative` is true.
+#[inline]
+fn simplex(p: [f32; 4], dims: usize, grad: GradFn, derivative: bool) -> (f32, [f32; 4]) {
+    let n = dims as f32;
+    let sqrt_n1 = (n + 1.0).sqrt();
+    let skew = (sqrt_n1 - 1.0) / n;
+    let unskew = (1.0 - 1.0 / sqrt_n1) / n;
+
+    // skew the input space to find the simplex cell
+    let s = p[..dims].iter().fold(0.0, |s, &x| s + x) * skew;
+    let mut skewed = [0.0; 4];
+    for k in 0..dims {
+        skewed[k] = p[k] + s;
+    }
+    let (cell, _) = split_cell(&skewed, dims);
+    let t = cell[..dims].iter().fold(0.0, |t, &i| t + i as f32) * unskew;
+    let mut x0 = [0.0; 4];
+    for k in 0..dims {
+        x0[k] = p[k] - (cell[k] as f32 - t);
+    }
+
+    // rank the offset components to find which simplex of the cell p is in
+    let mut rank = [0; 4];
+    for k in 0..dims {
+        for j in k + 1..dims {
+            if x0[k] >= x0[j] {
+                rank[k] += 1;
+            } else {
+                rank[j] += 1;
+            }
+        }
+    }
+
+    let mut value = 0.0;
+    let mut gradient = [0.0; 4];
+    for corner in 0..=dims {
+        let mut lattice = cell;
+        let mut d = [0.0; 4];
+        for k in 0..dims {
+            let offset = (rank[k] + corner >= dims) as i32;
+            lattice[k] = lattice[k].wrapping_add(offset);
+            d[k] = x0[k] - offset as f32 + corner as f32 * unskew;
+        }
+        let t = SIMPLEX_RADIUS_SQUARED - d[..dims].iter().fold(0.0, |s, &x| s + x * x);
+        if t > 0.0 {
+            let h = hash_lattice(&lattice, dims);
+            let dot = grad(h, &d);
+            let t2 = t * t;
+            let t4 = t2 * t2;
+            value += t4 * dot;
+
+            if derivative {
+                for k in 0..dims {
+                    let mut axis = [0.0; 4];
+                    axis[k] = 1.0;
+                    gradient[k] += t4 * grad(h, &axis) - 8.0 * t2 * t * dot * d[k];
+                }
+            }
+        }
+    }
+    (value, gradient)
+}
+
+/// Returns 2D Perlin noise at `p`.
+#[inline]
+pub fn perlin2(p: Vec2) -> f32 {
+    perlin([p.x(), p.y(), 0.0, 0.0], 2, grad2, false).0 * PERLIN2_SCALE
+}
+
+/// Returns 2D Perlin noise at `p` and its gradient.
+#[inline]
+pub fn perlin2_grad(p: Vec2) -> (f32, Vec2) {
+    let (value, g) = perlin([p.x(), p.y(), 0.0, 0.0], 2, grad2, true);
+    (value * PERLIN2_SCALE, Vec2::new(g[0], g[1]) * PERLIN2_SCALE)
+}
+
+/// Returns 3D Perlin noise at `p`.
+#[inline]
+pub fn perlin3(p: Vec3) -> f32 {
+    perlin([p.x(), p.y(), p.z(), 0.0], 3, grad3, false).0 * PERLIN3_SCALE
+}
+
+/// Returns 3D Perlin noise at `p` and its gradient.
+#[inline]
+pub fn perlin3_grad(p: Vec3) -> (f32, Vec3) {
+    let (value, g) = perlin([p.x(), p.y(), p.z(), 0.0], 3, grad3, true);
+    (
+        value * PERLIN3_SCALE,
+        Vec3::new(g[0], g[1], g[2]) * PERLIN3_SCALE,
+    )
+}
+
+/// Returns 4D Perlin noise at `p`.
+#[inline]
+pub fn perlin4(p: Vec4) -> f32 {
+    perlin(p.into(), 4, grad4, false).0 * PERLIN4_SCALE
+}
+
+/// Returns 4D Perlin noise at `p` and its gradient.
+#[inline]
+pub fn perlin4_grad(p: Vec4) -> (f32, Vec4) {
+    let (value, g) = perlin(p.into(), 4, grad4, true);
+    (value * PERLIN4_SCALE, Vec4::from(g) * PERLIN4_SCALE)
+}
+
+/// Returns 2D simplex noise at `p`.
+///
+/// Simplex noise has fewer directional artifacts than Perlin noise and is
+/// cheaper to evaluate in higher dimensions.
+#[inline]
+pub fn simplex2(p: Vec2) -> f32 {
+    simplex([p.x(), p.y(), 0.0, 0.0], 2, grad2, false).0 * SIMPLEX2_SCALE
+}
+
+/// Returns 2D simplex noise at `p` and its gradient.
+#[inline]
+pub fn simplex2_grad(p: Vec2) -> (f32, Vec2) {
+    let (value, g) = simplex([p.x(), p.y(), 0.0, 0.0], 2, grad2, true);
+    (
+        value * SIMPLEX2_SCALE,
+        Vec2::new(g[0], g[1]) * SIMPLEX2_SCALE,
+    )
+}
+
+/// Returns 3D simplex noise at `p`.
+#[inline]
+pub fn simplex3(p: Vec3) -> f32 {
+    simplex([p.x(), p.y(), p.z(), 0.0], 3, grad3, false).0 * SIMPLEX3_SCALE
+}
+
+/// Returns 3D simplex noise at `p` and its gradient.
+#[inline]
+pub fn simplex3_grad(p: Vec3) -> (f32, Vec3) {
+    let (value, g) = simplex([p.x(), p.y(), p.z(), 0.0], 3, grad3, true);
+    (
+        value * SIMPLEX3_SCALE,
+        Vec3::new(g[0], g[1], g[2]) * SIMPLEX3_SCALE,
+    )
+}
+
+/// Returns 4D simplex noise at `p`.
+#[inline]
+pub fn simplex4(p: Vec4) -> f32 {
+    simplex(p.into(), 4, grad4, false).0 * SIMPLEX4_SCALE
+}
+
+/// Returns 4D simplex noise at `p` and its gradient.
+#[inline]
+pub fn simplex4_grad(p: Vec4) -> (f32, Vec4) {
+    let (value, g) = simplex(p.into(), 4, grad4, true);
+    (value * SIMPLEX4_SCALE, Vec4::from(g) * SIMPLEX4_SCALE)
+}
+
+/// Returns the position of the feature point of a Worley lattice cell,
+/// relative to the cell origin.
+#[inline]
+fn feature_point(lattice: &[i32; 4], dims: usize) -> [f32; 4] {
+    let h = hash_lattice(lattice, dims);
+    let mut point = [0.0; 4];
+    for (k, x) in point[..dims].iter_mut().enumerate() {
+        *x = u32_to_unit_f32(hash_u32(h.wrapping_add(k as u32)));
+    }
+    point
+}
+
+/// Returns the distances to the nearest and second nearest feature points,
+/// with one feature point randomly placed in each lattice cell.
+fn worley(p: [f32; 4], dims: usize) -> (f32, f32) {
+    let (cell, f) = split_cell(&p, dims);
+    let mut f1 = f32::INFINITY;
+    let mut f2 = f32::INFINITY;
+    let neighbours = 5usize.pow(dims as u32);
+    // visit the adjacent cells first so that most of the outer cells can be
+    // skipped by comparing their distance to the current second nearest point
+    for &adjacent in &[true, false] {
+        for index in 0..neighbours {
+            let mut offset = [0; 4];
+            let mut remainder = index;
+            for o in offset[..dims].iter_mut() {
+                *o = (remainder % 5) as i32 - 2;
+                remainder /= 5;
+            }
+            if offset[..dims].iter().all(|o| o.abs() <= 1) != adjacent {
+                continue;
+            }
+            if !adjacent {
+                // the closest any point in this cell can be to p
+                let bound = (0..dims).fold(0.0, |s, k| {
+                    let o = offset[k] as f32;
+                    let d = if offset[k] > 0 {
+                        o - f[k]
+                    } else if offset[k] < 0 {
+                        f[k] - (o + 1.0)
+                    } else {
+                        0.0
+                    };
+                    s + d * d
+                });
+                if bound >= f2 {
+                    continue;
+                }
+            }
+
+            let mut lattice = cell;
+            for k in 0..dims {
+                lattice[k] = lattice[k].wrapping_add(offset[k]);
+            }
+            let point = feature_point(&lattice, dims);
+            let distance_squared = (0..dims).fold(0.0, |s, k| {
+                let d = offset[k] as f32 + point[k] - f[k];
+                s + d * d
+            });
+            if distance_squared < f1 {
+                f2 = f1;
+                f1 = distance_squared;
+            } else if distance_squared < f2 {
+                f2 = distance_squared;
+            }
+        }
+    }
+    (f1.sqrt(), f2.sqrt())
+}
+
+/// Returns the distances from `p` to the nearest and second nearest feature
+/// points of 2D Worley (cellular) noise.
+///
+/// Each unit lattice cell contains one randomly placed feature point. The
+/// nearest distance is at most `sqrt(2)`.
+#[inline]
+pub fn worley2(p: Vec2) -> (f32, f32) {
+    worley([p.x(), p.y(), 0.0, 0.0], 2)
+}
+
+/// Returns the distances from `p` to the nearest and second nearest feature
+/// points of 3D Worley (cellular) noise.
+///
+/// Each unit lattice cell contains one randomly placed feature point. The
+/// nearest distance is at most `sqrt(3)`.
+#[inline]
+pub fn worley3(p: Vec3) -> (f32, f32) {
+    worley([p.x(), p.y(), p.z(), 0.0], 3)
+}
+
+/// Sums octaves of a noise function to produce fractal noise.
+///
+/// Each octave samples the noise at `lacunarity` times the frequency and
+/// `gain` times the amplitude of the previous octave.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fractal {
+    /// The number of octaves to sum.
+    pub octaves: u32,
+    /// The frequency multiplier between successive octaves.
+    pub lacunarity: f32,
+    /// The amplitude multiplier between successive octaves.
+    pub gain: f32,
+}
+
+impl Default for Fractal {
+    /// Returns 5 octaves with a lacunarity of 2 and a gain of 0.5.
+    #[inline]
+    fn default() -> Self {
+        Self {
+            octaves: 5,
+            lacunarity: 2.0,
+            gain: 0.5,
+        }
+    }
+}
+
+impl Fractal {
+    /// Creates a `Fractal` with the given number of octaves, a lacunarity of 2
+    /// and a gain of 0.5.
+    #[inline]
+    pub fn new(octaves: u32) -> Self {
+        Self {
+            octaves,
+            ..Self::default()
+        }
+    }
+
+    /// Returns fractional Brownian motion, the sum of the octaves of `noise`
+    /// at `p`.
+    ///
+    /// The sum is normalized by the total amplitude so the result has the same
+    /// range as `noise`.
+    #[inline]
+    pub fn fbm<P, F>(&self, p: P, noise: F) -> f32
+    where
+        P: Copy + Mul<f32, Output = P>,
+        F: Fn(P) -> f32,
+    {
+        glam_assert!(self.octaves > 0);
+        let mut sum = 0.0;
+        let mut total = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        for _ in 0..self.octaves {
+            sum += noise(p * frequency) * amplitude;
+            total += amplitude;
+            amplitude *= self.gain;
+            frequency *= self.lacunarity;
+        }
+        sum / total
+    }
+
+    /// Returns ridged multifractal noise in the range `[0, 1]`.
+    ///
+    /// Each octave is folded with `1 - |noise|` to form sharp ridges and is
+    /// weighted by the previous octave, so detail accumulates along the
+    /// ridges. `noise` should return values in the range `[-1, 1]`.
+    #[inline]
+    pub fn ridged<P, F>(&self, p: P, noise: F) -> f32
+    where
+        P: Copy + Mul<f32, Output = P>,
+        F: Fn(P) -> f32,
+    {
+        glam_assert!(self.octaves > 0);
+        let mut sum = 0.0;
+        let mut total = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        let mut weight = 1.0;
+        for _ in 0..self.octaves {
+            let signal = (1.0 - noise(p * frequency).abs()).max(0.0);
+            let signal = signal * signal * weight;
+            weight = signal.min(1.0);
+            sum += signal * amplitude;
+            total += amplitude;
+            amplitude *= self.gain;
+            frequency *= self.lacunarity;
+        }
+        sum / total
+    }
+}
+
+/// Returns 2D Perlin noise at four positions.
+#[inline]
+pub fn perlin2_x4(p: [Vec2; 4]) -> Vec4 {
+    #[cfg(vec4sse2)]
+    unsafe {
+        let (x, y) = sse2::transpose2(&p);
+        sse2::perlin2(x, y).into()
+    }
+
+    #[cfg(vec4f32)]
+    {
+        Vec4::new(perlin2(p[0]), perlin2(p[1]), perlin2(p[2]), perlin2(p[3]))
+    }
+}
+
+/// Returns 3D Perlin noise at four positions.
+#[inline]
+pub fn perlin3_x4(p: [Vec3; 4]) -> Vec4 {
+    #[cfg(vec4sse2)]
+    unsafe {
+        let (x, y, z) = sse2::transpose3(&p);
+        sse2::perlin3(x, y, z).into()
+    }
+
+    #[cfg(vec4f32)]
+    {
+        Vec4::new(perlin3(p[0]), perlin3(p[1]), perlin3(p[2]), perlin3(p[3]))
+    }
+}
+
+/// Returns 4D Perlin noise at four positions.
+#[inline]
+pub fn perlin4_x4(p: [Vec4; 4]) -> Vec4 {
+    #[cfg(vec4sse2)]
+    unsafe {
+        let (x, y, z, w) = sse2::transpose4(&p);
+        sse2::perlin4(x, y, z, w).into()
+    }
+
+    #[cfg(vec4f32)]
+    {
+        Vec4::new(perlin4(p[0]), perlin4(p[1]), perlin4(p[2]), perlin4(p[3]))
+    }
+}
+
+/// Returns 2D simplex noise at four positions.
+#[inline]
+pub fn simplex2_x4(p: [Vec2; 4]) -> Vec4 {
+    #[cfg(vec4sse2)]
+    unsafe {
+        let (x, y) = sse2::transpose2(&p);
+        sse2::simplex2(x, y).into()
+    }
+
+    #[cfg(vec4f32)]
+    {
+        Vec4::new(
+            simplex2(p[0]),
+            simplex2(p[1]),
+            simplex2(p[2]),
+            simplex2(p[3]),
+        )
+    }
+}
+
+/// Returns 3D simplex noise at four positions.
+#[inline]
+pub fn simplex3_x4(p: [Vec3; 4]) -> Vec4 {
+    #[cfg(vec4sse2)]
+    unsafe {
+        let (x, y, z) = sse2::transpose3(&p);
+        sse2::simplex3(x, y, z).into()
+    }
+
+    #[cfg(vec4f32)]
+    {
+        Vec4::new(
+            simplex3(p[0]),
+            simplex3(p[1]),
+            simplex3(p[2]),
+            simplex3(p[3]),
+        )
+    }
+}
+
+/// Returns 4D simplex noise at four positions.
+#[inline]
+pub fn simplex4_x4(p: [Vec4; 4]) -> Vec4 {
+    #[cfg(vec4sse2)]
+    unsafe {
+        let (x, y, z, w) = sse2::transpose4(&p);
+        sse2::simplex4(x, y, z, w).into()
+    }
+
+    #[cfg(vec4f32)]
+    {
+        Vec4::new(
+            simplex4(p[0]),
+            simplex4(p[1]),
+            simplex4(p[2]),
+            simplex4(p[3]),
+        )
+    }
+}
+
+/// SSE2 implementations of the noise functions, evaluating one position per
+/// lane. These mirror the scalar implementations operation for operation so
+/// that both produce the same results.
+#[cfg(vec4sse2)]
+mod sse2 {
+    use super::*;
+
+    #[inline]
+    pub(super) unsafe fn transpose2(p: &[Vec2; 4]) -> (__m128, __m128) {
+        (
+            _mm_set_ps(p[3].x(), p[2].x(), p[1].x(), p[0].x()),
+            _mm_set_ps(p[3].y(), p[2].y(), p[1].y(), p[0].y()),
+        )
+    }
+
+    #[inline]
+    pub(super) unsafe fn transpose3(p: &[Vec3; 4]) -> (__m128, __m128, __m128) {
+        (
+            _mm_set_ps(p[3].x(), p[2].x(), p[1].x(), p[0].x()),
+            _mm_set_ps(p[3].y(), p[2].y(), p[1].y(), p[0].y()),
+            _mm_set_ps(p[3].z(), p[2].z(), p[1].z(), p[0].z()),
+        )
+    }
+
+    #[inline]
+    pub(super) unsafe fn transpose4(p: &[Vec4; 4]) -> (__m128, __m128, __m128, __m128) {
+        let (mut x, mut y, mut z, mut w) = (p[0].0, p[1].0, p[2].0, p[3].0);
+        _MM_TRANSPOSE4_PS(&mut x, &mut y, &mut z, &mut w);
+        (x, y, z, w)
+    }
+
+    /// Multiplies packed 32 bit integers keeping the low 32 bits of each
+    /// product, which SSE2 lacks an instruction for.
+    #[inline]
+    unsafe fn mullo_epi32(a: __m128i, b: __m128i) -> __m128i {
+        let even = _mm_mul_epu32(a, b);
+        let odd = _mm_mul_epu32(_mm_srli_epi64(a, 32), _mm_srli_epi64(b, 32));
+        _mm_unpacklo_epi32(
+            _mm_shuffle_epi32(even, 0b00_00_10_00),
+            _mm_shuffle_epi32(odd, 0b00_00_10_00),
+        )
+    }
+
+    /// The SSE2 version of `hash_u32`.
+    #[inline]
+    unsafe fn hash(mut x: __m128i) -> __m128i {
+        x = _mm_xor_si128(x, _mm_srli_epi32(x, 16));
+        x = mullo_epi32(x, _mm_set1_epi32(0x7feb_352d));
+        x = _mm_xor_si128(x, _mm_srli_epi32(x, 15));
+        x = mullo_epi32(x, _mm_set1_epi32(0x846c_a68b_u32 as i32));
+        _mm_xor_si128(x, _mm_srli_epi32(x, 16))
+    }
+
+    /// Returns `floor(x)` as integers and floats. SSE2 has no floor
+    /// instruction so this truncates and corrects negative values.
+    #[inline]
+    unsafe fn floor(x: __m128) -> (__m128i, __m128) {
+        let truncated = _mm_cvttps_epi32(x);
+        let truncated_f = _mm_cvtepi32_ps(truncated);
+        let too_big = _mm_cmpgt_ps(truncated_f, x);
+        (
+            _mm_add_epi32(truncated, _mm_castps_si128(too_big)),
+            _mm_sub_ps(truncated_f, _mm_and_ps(too_big, _mm_set1_ps(1.0))),
+        )
+    }
+
+    /// Selects `a` where `mask` is set and `b` elsewhere.
+    #[inline]
+    unsafe fn select(mask: __m128i, a: __m128, b: __m128) -> __m128 {
+        let mask = _mm_castsi128_ps(mask);
+        _mm_or_ps(_mm_and_ps(mask, a), _mm_andnot_ps(mask, b))
+    }
+
+    /// Negates lanes of `v` where `bit` of `hash` is set.
+    #[inline]
+    unsafe fn negate_if(hash: __m128i, bit: i32, v: __m128) -> __m128 {
+        let set = _mm_cmpeq_epi32(
+            _mm_and_si128(hash, _mm_set1_epi32(bit)),
+            _mm_set1_epi32(bit),
+        );
+        let sign = _mm_and_si128(set, _mm_set1_epi32(0x8000_0000_u32 as i32));
+        _mm_xor_ps(v, _mm_castsi128_ps(sign))
+    }
+
+    #[inline]
+    unsafe fn lt(h: __m128i, n: i32) -> __m128i {
+        _mm_cmplt_epi32(h, _mm_set1_epi32(n))
+    }
+
+    #[inline]
+    unsafe fn grad2(hash: __m128i, x: __m128, y: __m128) -> __m128 {
+        let swap = _mm_cmpeq_epi32(_mm_and_si128(hash, _mm_set1_epi32(4)), _mm_setzero_si128());
+        let u = select(swap, x, y);
+        let v = select(swap, y, x);
+        _mm_add_ps(
+            negate_if(hash, 1, u),
+            negate_if(hash, 2, _mm_mul_ps(_mm_set1_ps(2.0), v)),
+        )
+    }
+
+    #[inline]
+    unsafe fn grad3(hash: __m128i, x: __m128, y: __m128, z: __m128) -> __m128 {
+        let h = _mm_and_si128(hash, _mm_set1_epi32(15));
+        let u = select(lt(h, 8), x, y);
+        let is_x = _mm_or_si128(
+            _mm_cmpeq_epi32(h, _mm_set1_epi32(12)),
+            _mm_cmpeq_epi32(h, _mm_set1_epi32(14)),
+        );
+        let v = select(lt(h, 4), y, select(is_x, x, z));
+        _mm_add_ps(negate_if(h, 1, u), negate_if(h, 2, v))
+    }
+
+    #[inline]
+    unsafe fn grad4(hash: __m128i, x: __m128, y: __m128, z: __m128, w: __m128) -> __m128 {
+        let h = _mm_and_si128(hash, _mm_set1_epi32(31));
+        let u = select(lt(h, 24), x, y);
+        let v = select(lt(h, 16), y, z);
+        let w = select(lt(h, 8), z, w);
+        _mm_add_ps(
+            _mm_add_ps(negate_if(h, 1, u), negate_if(h, 2, v)),
+            negate_if(h, 4, w),
+        )
+    }
+
+    #[inline]
+    unsafe fn fade(t: __m128) -> __m128 {
+        let t3 = _mm_mul_ps(_mm_mul_ps(t, t), t);
+        let inner = _mm_sub_ps(_mm_mul_ps(t, _mm_set1_ps(6.0)), _mm_set1_ps(15.0));
+        let inner = _mm_add_ps(_mm_mul_ps(t, inner), _mm_set1_ps(10.0));
+        _mm_mul_ps(t3, inner)
+    }
+
+    #[inline]
+    unsafe fn one_minus(x: __m128) -> __m128 {
+        _mm_sub_ps(_mm_set1_ps(1.0), x)
+    }
+
+    #[inline]
+    unsafe fn hash2(x: __m128i, y: __m128i) -> __m128i {
+        hash(_mm_xor_si128(hash(x), y))
+    }
+
+    #[inline]
+    unsafe fn hash3(x: __m128i, y: __m128i, z: __m128i) -> __m128i {
+        hash(_mm_xor_si128(hash2(x, y), z))
+    }
+
+    #[inline]
+    unsafe fn hash4(x: __m128i, y: __m128i, z: __m128i, w: __m128i) -> __m128i {
+        hash(_mm_xor_si128(hash3(x, y, z), w))
+    }
+
+    pub(super) unsafe fn perlin2(x: __m128, y: __m128) -> __m128 {
+        let (ix, fx) = floor(x);
+        let (iy, fy) = floor(y);
+        let fx = _mm_sub_ps(x, fx);
+        let fy = _mm_sub_ps(y, fy);
+        let (ux, uy) = (fade(fx), fade(fy));
+        let one_i = _mm_set1_epi32(1);
+        let one = _mm_set1_ps(1.0);
+
+        let mut value = _mm_setzero_ps();
+        for corner in 0..4 {
+            let (lx, dx, wx) = if corner & 1 != 0 {
+                (_mm_add_epi32(ix, one_i), _mm_sub_ps(fx, one), ux)
+            } else {
+                (ix, fx, one_minus(ux))
+            };
+            let (ly, dy, wy) = if corner & 2 != 0 {
+                (_mm_add_epi32(iy, one_i), _mm_sub_ps(fy, one), uy)
+            } else {
+                (iy, fy, one_minus(uy))
+            };
+            let dot = grad2(hash2(lx, ly), dx, dy);
+            value = _mm_add_ps(value, _mm_mul_ps(_mm_mul_ps(wx, wy), dot));
+        }
+        _mm_mul_ps(value, _mm_set1_ps(PERLIN2_SCALE))
+    }
+
+    pub(super) unsafe fn perlin3(x: __m128, y: __m128, z: __m128) -> __m128 {
+        let (ix, fx) = floor(x);
+        let (iy, fy) = floor(y);
+        let (iz, fz) = floor(z);
+        let fx = _mm_sub_ps(x, fx);
+        let fy = _mm_sub_ps(y, fy);
+        let fz = _mm_sub_ps(z, fz);
+        let (ux, uy, uz) = (fade(fx), fade(fy), fade(fz));
+        let one_i = _mm_set1_epi32(1);
+        let one = _mm_set1_ps(1.0);
+
+        let mut value = _mm_setzero_ps();
+        for corner in 0..8 {
+            let (lx, dx, wx) = if corner & 1 != 0 {
+                (_mm_add_epi32(ix, one_i), _mm_sub_ps(fx, one), ux)
+            } else {
+                (ix, fx, one_minus(ux))
+            };
+            let (ly, dy, wy) = if corner & 2 != 0 {
+                (_mm_add_epi32(iy, one_i), _mm_sub_ps(fy, one), uy)
+            } else {
+                (iy, fy, one_minus(uy))
+            };
+            let (lz, dz, wz) = if corner & 4 != 0 {
+                (_mm_add_epi32(iz, one_i), _mm_sub_ps(fz, one), uz)
+            } else {
+                (iz, fz, one_minus(uz))
+            };
+            let dot = grad3(hash3(lx, ly, lz), dx, dy, dz);
+            let weight = _mm_mul_ps(_mm_mul_ps(wx, wy), wz);
+            value = _mm_add_ps(value, _mm_mul_ps(weight, dot));
+        }
+        _mm_mul_ps(value, _mm_set1_ps(PERLIN3_SCALE))
+    }
+
+    pub(super) unsafe fn perlin4(x: __m128, y: __m128, z: __m128, w: __m128) -> __m128 {
+        let (ix, fx) = floor(x);
+        let (iy, fy) = floor(y);
+        let (iz, fz) = floor(z);
+        let (iw, fw) = floor(w);
+        let fx = _mm_sub_ps(x, fx);
+        let fy = _mm_sub_ps(y, fy);
+        let fz = _mm_sub_ps(z, fz);
+        let fw = _mm_sub_ps(w, fw);
+        let (ux, uy, uz, uw) = (fade(fx), fade(fy), fade(fz), fade(fw));
+        let one_i = _mm_set1_epi32(1);
+        let one = _mm_set1_ps(1.0);
+
+        let mut value = _mm_setzero_ps();
+        for corner in 0..16 {
+            let (lx, dx, wx) = if corner & 1 != 0 {
+                (_mm_add_epi32(ix, one_i), _mm_sub_ps(fx, one), ux)
+            } else {
+                (ix, fx, one_minus(ux))
+            };
+            let (ly, dy, wy) = if corner & 2 != 0 {
+                (_mm_add_epi32(iy, one_i), _mm_sub_ps(fy, one), uy)
+            } else {
+                (iy, fy, one_minus(uy))
+            };
+            let (lz, dz, wz) = if corner & 4 != 0 {
+                (_mm_add_epi32(iz, one_i), _mm_sub_ps(fz, one), uz)
+            } else {
+                (iz, fz, one_minus(uz))
+            };
+            let (lw, dw, ww) = if corner & 8 != 0 {
+                (_mm_add_epi32(iw, one_i), _mm_sub_ps(fw, one), uw)
+            } else {
+                (iw, fw, one_minus(uw))
+            };
+            let dot = grad4(hash4(lx, ly, lz, lw), dx, dy, dz, dw);
+            let weight = _mm_mul_ps(_mm_mul_ps(_mm_mul_ps(wx, wy), wz), ww);
+            value = _mm_add_ps(value, _mm_mul_ps(weight, dot));
+        }
+        _mm_mul_ps(value, _mm_set1_ps(PERLIN4_SCALE))
+    }
+
+    /// Returns the contribution of a simplex corner, `t^4 * dot` where
+    /// `t = r^2 - |d|^2` if `t > 0`.
+    #[inline]
+    unsafe fn falloff(distance_squared: __m128, dot: __m128) -> __m128 {
+        let t = _mm_sub_ps(_mm_set1_ps(SIMPLEX_RADIUS_SQUARED), distance_squared);
+        let t = _mm_max_ps(t, _mm_setzero_ps());
+        let t2 = _mm_mul_ps(t, t);
+        _mm_mul_ps(_mm_mul_ps(t2, t2), dot)
+    }
+
+    /// Converts an all bits set mask to the integer 1.
+    #[inline]
+    unsafe fn mask_to_one(mask: __m128i) -> __m128i {
+        _mm_and_si128(mask, _mm_set1_epi32(1))
+    }
+
+    pub(super) unsafe fn simplex2(x: __m128, y: __m128) -> __m128 {
+        let n = 2.0_f32;
+        let sqrt_n1 = (n + 1.0).sqrt();
+        let skew = _mm_set1_ps((sqrt_n1 - 1.0) / n);
+        let unskew_s = (1.0 - 1.0 / sqrt_n1) / n;
+        let unskew = _mm_set1_ps(unskew_s);
+
+        let s = _mm_mul_ps(_mm_add_ps(x, y), skew);
+        let (ix, fx) = floor(_mm_add_ps(x, s));
+        let (iy, fy) = floor(_mm_add_ps(y, s));
+        let t = _mm_mul_ps(_mm_add_ps(fx, fy), unskew);
+        let x0 = _mm_sub_ps(x, _mm_sub_ps(fx, t));
+        let y0 = _mm_sub_ps(y, _mm_sub_ps(fy, t));
+
+        let xy = _mm_castps_si128(_mm_cmpge_ps(x0, y0));
+        let all = _mm_set1_epi32(-1);
+        // the corner offsets of each simplex corner, as integer masks
+        let offsets = [
+            (_mm_setzero_si128(), _mm_setzero_si128()),
+            (xy, _mm_andnot_si128(xy, all)),
+            (all, all),
+        ];
+
+        let mut value = _mm_setzero_ps();
+        for (corner, &(ox, oy)) in offsets.iter().enumerate() {
+            let c = _mm_set1_ps(corner as f32 * unskew_s);
+            let (ox, oy) = (mask_to_one(ox), mask_to_one(oy));
+            let dx = _mm_add_ps(_mm_sub_ps(x0, _mm_cvtepi32_ps(ox)), c);
+            let dy = _mm_add_ps(_mm_sub_ps(y0, _mm_cvtepi32_ps(oy)), c);
+            let distance_squared = _mm_add_ps(_mm_mul_ps(dx, dx), _mm_mul_ps(dy, dy));
+            let h = hash2(_mm_add_epi32(ix, ox), _mm_add_epi32(iy, oy));
+            value = _mm_add_ps(value, falloff(distance_squared, grad2(h, dx, dy)));
+        }
+        _mm_mul_ps(value, _mm_set1_ps(SIMPLEX2_SCALE))
+    }
+
+    pub(super) unsafe fn simplex3(x: __m128, y: __m128, z: __m128) -> __m128 {
+        let n = 3.0_f32;
+        let sqrt_n1 = (n + 1.0).sqrt();
+        let skew = _mm_set1_ps((sqrt_n1 - 1.0) / n);
+        let unskew_s = (1.0 - 1.0 / sqrt_n1) / n;
+        let unskew = _mm_set1_ps(unskew_s);
+
+        let s = _mm_mul_ps(_mm_add_ps(_mm_add_ps(x, y), z), skew);
+        let (ix, fx) = floor(_mm_add_ps(x, s));
+        let (iy, fy) = floor(_mm_add_ps(y, s));
+        let (iz, fz) = floor(_mm_add_ps(z, s));
+        let t = _mm_mul_ps(_mm_add_ps(_mm_add_ps(fx, fy), fz), unskew);
+        let x0 = _mm_sub_ps(x, _mm_sub_ps(fx, t));
+        let y0 = _mm_sub_ps(y, _mm_sub_ps(fy, t));
+        let z0 = _mm_sub_ps(z, _mm_sub_ps(fz, t));
+
+        let xy = _mm_castps_si128(_mm_cmpge_ps(x0, y0));
+        let xz = _mm_castps_si128(_mm_cmpge_ps(x0, z0));
+        let yz = _mm_castps_si128(_mm_cmpge_ps(y0, z0));
+        let all = _mm_set1_epi32(-1);
+        let (yx, zx, zy) = (
+            _mm_andnot_si128(xy, all),
+            _mm_andnot_si128(xz, all),
+            _mm_andnot_si128(yz, all),
+        );
+        // the corner offsets of each simplex corner, as integer masks
+        let offsets = [
+            (
+                _mm_setzero_si128(),
+                _mm_setzero_si128(),
+                _mm_setzero_si128(),
+            ),
+            (
+                _mm_and_si128(xy, xz),
+                _mm_and_si128(yx, yz),
+                _mm_and_si128(zx, zy),
+            ),
+            (
+                _mm_or_si128(xy, xz),
+                _mm_or_si128(yx, yz),
+                _mm_or_si128(zx, zy),
+            ),
+            (all, all, all),
+        ];
+
+        let mut value = _mm_setzero_ps();
+        for (corner, &(ox, oy, oz)) in offsets.iter().enumerate() {
+            let c = _mm_set1_ps(corner as f32 * unskew_s);
+            let (ox, oy, oz) = (mask_to_one(ox), mask_to_one(oy), mask_to_one(oz));
+            let dx = _mm_add_ps(_mm_sub_ps(x0, _mm_cvtepi32_ps(ox)), c);
+            let dy = _mm_add_ps(_mm_sub_ps(y0, _mm_cvtepi32_ps(oy)), c);
+            let dz = _mm_add_ps(_mm_sub_ps(z0, _mm_cvtepi32_ps(oz)), c);
+            let distance_squared = _mm_add_ps(
+                _mm_add_ps(_mm_mul_ps(dx, dx), _mm_mul_ps(dy, dy)),
+                _mm_mul_ps(dz, dz),
+            );
+            let h = hash3(
+                _mm_add_epi32(ix, ox),
+                _mm_add_epi32(iy, oy),
+                _mm_add_epi32(iz, oz),
+            );
+            value = _mm_add_ps(value, falloff(distance_squared, grad3(h, dx, dy, dz)));
+        }
+        _mm_mul_ps(value, _mm_set1_ps(SIMPLEX3_SCALE))
+    }
+
+    /// Returns 1 where `a >= b` and 0 elsewhere.
+    #[inline]
+    unsafe fn ge_one(a: __m128, b: __m128) -> __m128i {
+        mask_to_one(_mm_castps_si128(_mm_cmpge_ps(a, b)))
+    }
+
+    pub(super) unsafe fn simplex4(x: __m128, y: __m128, z: __m128, w: __m128) -> __m128 {
+        let n = 4.0_f32;
+        let sqrt_n1 = (n + 1.0).sqrt();
+        let skew = _mm_set1_ps((sqrt_n1 - 1.0) / n);
+        let unskew_s = (1.0 - 1.0 / sqrt_n1) / n;
+        let unskew = _mm_set1_ps(unskew_s);
+
+        let s = _mm_mul_ps(_mm_add_ps(_mm_add_ps(_mm_add_ps(x, y), z), w), skew);
+        let (ix, fx) = floor(_mm_add_ps(x, s));
+        let (iy, fy) = floor(_mm_add_ps(y, s));
+        let (iz, fz) = floor(_mm_add_ps(z, s));
+        let (iw, fw) = floor(_mm_add_ps(w, s));
+        let t = _mm_mul_ps(
+            _mm_add_ps(_mm_add_ps(_mm_add_ps(fx, fy), fz), fw),
+            unskew,
+        );
+        let x0 = _mm_sub_ps(x, _mm_sub_ps(fx, t));
+        let y0 = _mm_sub_ps(y, _mm_sub_ps(fy, t));
+        let z0 = _mm_sub_ps(z, _mm_sub_ps(fz, t));
+        let w0 = _mm_sub_ps(w, _mm_sub_ps(fw, t));
+
+        // rank the offset components, as the scalar version does
+        let one = _mm_set1_epi32(1);
+        let (xy, xz, xw) = (ge_one(x0, y0), ge_one(x0, z0), ge_one(x0, w0));
+        let (yz, yw, zw) = (ge_one(y0, z0), ge_one(y0, w0), ge_one(z0, w0));
+        let rank_x = _mm_add_epi32(_mm_add_epi32(xy, xz), xw);
+        let rank_y = _mm_add_epi32(_mm_add_epi32(_mm_sub_epi32(one, xy), yz), yw);
+        let rank_z = _mm_add_epi32(
+            _mm_add_epi32(_mm_sub_epi32(one, xz), _mm_sub_epi32(one, yz)),
+            zw,
+        );
+        let rank_w = _mm_add_epi32(
+            _mm_add_epi32(_mm_sub_epi32(one, xw), _mm_sub_epi32(one, yw)),
+            _mm_sub_epi32(one, zw),
+        );
+
+        let mut value = _mm_setzero_ps();
+        for corner in 0..=4 {
+            // a component is offset once its rank reaches 4 - corner
+            let threshold = _mm_set1_epi32(3 - corner);
+            let offset = |rank| mask_to_one(_mm_cmpgt_epi32(rank, threshold));
+            let (ox, oy, oz, ow) = (
+                offset(rank_x),
+                offset(rank_y),
+                offset(rank_z),
+                offset(rank_w),
+            );
+            let c = _mm_set1_ps(corner as f32 * unskew_s);
+            let dx = _mm_add_ps(_mm_sub_ps(x0, _mm_cvtepi32_ps(ox)), c);
+            let dy = _mm_add_ps(_mm_sub_ps(y0, _mm_cvtepi32_ps(oy)), c);
+            let dz = _mm_add_ps(_mm_sub_ps(z0, _mm_cvtepi32_ps(oz)), c);
+            let dw = _mm_add_ps(_mm_sub_ps(w0, _mm_cvtepi32_ps(ow)), c);
+            let distance_squared = _mm_add_ps(
+                _mm_add_ps(
+                    _mm_add_ps(_mm_mul_ps(dx, dx), _mm_mul_ps(dy, dy)),
+                    _mm_mul_ps(dz, dz),
+                ),
+                _mm_mul_ps(dw, dw),
+            );
+            let h = hash4(
+                _mm_add_epi32(ix, ox),
+                _mm_add_epi32(iy, oy),
+                _mm_add_epi32(iz, oz),
+                _mm_add_epi32(iw, ow),
+            );
+            value = _mm_add_ps(
+                value,
+                falloff(distance_squared, grad4(h, dx, dy, dz, dw)),
+            );
+        }
+        _mm_mul_ps(value, _mm_set1_ps(SIMPLEX4_SCALE))
+    }
+}
@@ -0,0 +1,498 @@
+//! Packed encodings for compact vertex formats.
+//!
+//! Conversions to normalized integers follow the D3D11 functional
+//! specification, which Vulkan also permits. Floats are clamped to `[0, 1]`
+//! for unorm and `[-1, 1]` for snorm, with NaN converted to zero. They are
+//! then scaled and rounded to the nearest integer with halfway cases rounded
+//! away from zero. When unpacking, the most negative snorm value is clamped to
+//! `-1.0` so that the range is symmetric.
+//!
+//! Conversions to floating point formats round to nearest even and support
+//! denormals. Unsigned floats convert negative values to zero and finite values
+//! that are too large to the largest finite value, and keep infinity and NaN.
+
+use super::{Quat, Vec2, Vec3, Vec4};
+
+/// Converts `c` to an unsigned normalized integer with `bits` bits.
+#[inline]
+fn float_to_unorm(c: f32, bits: u32) -> u32 {
+    let scale = ((1_u32 << bits) - 1) as f32;
+    // a NaN stays NaN and the saturating cast converts it to zero
+    (c.clamp(0.0, 1.0) * scale + 0.5) as u32
+}
+
+#[inline]
+fn unorm_to_float(u: u32, bits: u32) -> f32 {
+    let scale = ((1_u32 << bits) - 1) as f32;
+    u as f32 / scale
+}
+
+/// Converts `c` to a signed normalized integer with `bits` bits.
+#[inline]
+fn float_to_snorm(c: f32, bits: u32) -> i32 {
+    if c.is_nan() {
+        return 0;
+    }
+    let scale = ((1_i32 << (bits - 1)) - 1) as f32;
+    let c = c.clamp(-1.0, 1.0) * scale;
+    (if c >= 0.0 { c + 0.5 } else { c - 0.5 }) as i32
+}
+
+#[inline]
+fn snorm_to_float(s: i32, bits: u32) -> f32 {
+    let scale = ((1_i32 << (bits - 1)) - 1) as f32;
+    (s as f32 / scale).max(-1.0)
+}
+
+/// Sign extends the low `bits` bits of `u`.
+#[inline]
+fn sign_extend(u: u32, bits: u32) -> i32 {
+    ((u << (32 - bits)) as i32) >> (32 - bits)
+}
+
+/// Rounds the magnitude of a finite `f32`, given as bits without the sign,
+/// to a float with a 5 bit exponent and `mantissa_bits` mantissa bits, using
+/// round to nearest even.
+///
+/// Values that are too large round to the infinity encoding.
+#[inline]
+fn round_to_minifloat(bits: u32, mantissa_bits: u32) -> u32 {
+    let exponent = (bits >> 23) as i32 - 127 + 15;
+    let mantissa = bits & 0x7f_ffff;
+    let shift = 23 - mantissa_bits;
+    if exponent >= 31 {
+        return 31 << mantissa_bits;
+    }
+    if exponent <= 0 {
+        // the result is denormal, shift in the implicit leading one
+        let shift = shift + 1 + (-exponent) as u32;
+        if shift > 24 {
+            return 0;
+        }
+        let mantissa = mantissa | 0x80_0000;
+        let result = mantissa >> shift;
+        let round_bit = 1 << (shift - 1);
+        return if mantissa & round_bit != 0 && mantissa & (3 * round_bit - 1) != 0 {
+            result + 1
+        } else {
+            result
+        };
+    }
+    let result = (exponent as u32) << mantissa_bits | mantissa >> shift;
+    let round_bit = 1 << (shift - 1);
+    // a carry out of the mantissa correctly increments the exponent
+    if mantissa & round_bit != 0 && mantissa & (3 * round_bit - 1) != 0 {
+        result + 1
+    } else {
+        result
+    }
+}
+
+/// Converts a float with a 5 bit exponent and `mantissa_bits` mantissa bits,
+/// given without a sign bit, to an `f32`.
+#[inline]
+fn minifloat_to_f32(bits: u32, mantissa_bits: u32) -> f32 {
+    let exponent = bits >> mantissa_bits;
+    let mantissa = bits & ((1 << mantissa_bits) - 1);
+    let shift = 23 - mantissa_bits;
+    if exponent == 31 {
+        f32::from_bits(0x7f80_0000 | mantissa << shift)
+    } else if exponent == 0 {
+        let denormal_scale = f32::from_bits((127 - 14 - mantissa_bits) << 23);
+        mantissa as f32 * denormal_scale
+    } else {
+        f32::from_bits((exponent + 127 - 15) << 23 | mantissa << shift)
+    }
+}
+
+/// Converts an `f32` to the bits of an IEEE 754 half precision float.
+#[inline]
+pub(crate) fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = (bits >> 16) & 0x8000;
+    let magnitude = bits & 0x7fff_ffff;
+    let half = if magnitude > 0x7f80_0000 {
+        // keep the top of the NaN payload and make sure it stays a NaN
+        0x7e00 | (magnitude & 0x7f_ffff) >> 13
+    } else if magnitude == 0x7f80_0000 {
+        0x7c00
+    } else {
+        round_to_minifloat(magnitude, 10)
+    };
+    (sign | half) as u16
+}
+
+/// Converts the bits of an IEEE 754 half precision float to an `f32`.
+#[inline]
+pub(crate) fn f16_to_f32(half: u16) -> f32 {
+    // quiet signaling NaNs, as the F16C instructions do
+    let half = if half & 0x7c00 == 0x7c00 && half & 0x3ff != 0 {
+        half | 0x200
+    } else {
+        half
+    };
+    let magnitude = minifloat_to_f32(u32::from(half & 0x7fff), 10);
+    if half & 0x8000 != 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Converts an `f32` to an unsigned float with a 5 bit exponent and
+/// `mantissa_bits` mantissa bits, as used by `R11G11B10F`.
+#[inline]
+fn f32_to_ufloat(value: f32, mantissa_bits: u32) -> u32 {
+    let infinity = 31 << mantissa_bits;
+    if value.is_nan() {
+        infinity | 1 << (mantissa_bits - 1)
+    } else if value.is_sign_negative() {
+        0
+    } else if value.is_infinite() {
+        infinity
+    } else {
+        round_to_minifloat(value.to_bits(), mantissa_bits).min(infinity - 1)
+    }
+}
+
+/// Implements a packing function and its inverse converting each component of
+/// a vector to and from an integer type.
+macro_rules! impl_pack_components {
+    ($t:ident, $n:expr, $pack:ident, $unpack:ident, $int:ty,
+     |$c:ident| $to_int:expr, |$i:ident| $to_float:expr,
+     $pack_doc:expr, $unpack_doc:expr) => {
+        impl $t {
+            #[doc = $pack_doc]
+            #[inline]
+            pub fn $pack(self) -> [$int; $n] {
+                let a: [f32; $n] = self.into();
+                let mut out = [0; $n];
+                for (o, &$c) in out.iter_mut().zip(a.iter()) {
+                    *o = $to_int;
+                }
+                out
+            }
+
+            #[doc = $unpack_doc]
+            #[inline]
+            pub fn $unpack(packed: [$int; $n]) -> Self {
+                let mut a = [0.0; $n];
+                for (o, &$i) in a.iter_mut().zip(packed.iter()) {
+                    *o = $to_float;
+                }
+                Self::from(a)
+            }
+        }
+    };
+}
+
+macro_rules! impl_pack_vec {
+    ($t:ident, $n:expr) => {
+        impl_pack_components!(
+            $t,
+            $n,
+            pack_unorm8,
+            unpack_unorm8,
+            u8,
+            |c| float_to_unorm(c, 8) as u8,
+            |i| unorm_to_float(u32::from(i), 8),
+            "Converts each component to an 8 bit unsigned normalized integer.",
+            "Converts each 8 bit unsigned normalized integer to a float in `[0, 1]`."
+        );
+        impl_pack_components!(
+            $t,
+            $n,
+            pack_snorm8,
+            unpack_snorm8,
+            i8,
+            |c| float_to_snorm(c, 8) as i8,
+            |i| snorm_to_float(i32::from(i), 8),
+            "Converts each component to an 8 bit signed normalized integer.",
+            "Converts each 8 bit signed normalized integer to a float in `[-1, 1]`."
+        );
+        impl_pack_components!(
+            $t,
+            $n,
+            pack_unorm16,
+            unpack_unorm16,
+            u16,
+            |c| float_to_unorm(c, 16) as u16,
+            |i| unorm_to_float(u32::from(i), 16),
+            "Converts each component to a 16 bit unsigned normalized integer.",
+            "Converts each 16 bit unsigned normalized integer to a float in `[0, 1]`."
+        );
+        impl_pack_components!(
+            $t,
+            $n,
+            pack_snorm16,
+            unpack_snorm16,
+            i16,
+            |c| float_to_snorm(c, 16) as i16,
+            |i| snorm_to_float(i32::from(i), 16),
+            "Converts each component to a 16 bit signed normalized integer.",
+            "Converts each 16 bit signed normalized integer to a float in `[-1, 1]`."
+        );
+        impl_pack_components!(
+            $t,
+            $n,
+            pack_f16,
+            unpack_f16,
+            u16,
+            |c| f32_to_f16(c),
+            |i| f16_to_f32(i),
+            "Converts each component to the bits of a half precision float.",
+            "Converts the bits of each half precision float to an `f32`."
+        );
+    };
+}
+
+impl_pack_vec!(Vec2, 2);
+impl_pack_vec!(Vec3, 3);
+impl_pack_vec!(Vec4, 4);
+
+impl Vec4 {
+    /// Packs `self` into the `RGB10A2` unsigned normalized format, with 10
+    /// bits for each of `x`, `y` and `z` and 2 bits for `w`.
+    ///
+    /// `x` is stored in the least significant bits, matching
+    /// `DXGI_FORMAT_R10G10B10A2_UNORM` and `VK_FORMAT_A2B10G10R10_UNORM_PACK32`.
+    #[inline]
+    pub fn pack_rgb10a2(self) -> u32 {
+        float_to_unorm(self.x(), 10)
+            | float_to_unorm(self.y(), 10) << 10
+            | float_to_unorm(self.z(), 10) << 20
+            | float_to_unorm(self.w(), 2) << 30
+    }
+
+    /// Unpacks a value in the `RGB10A2` unsigned normalized format.
+    #[inline]
+    pub fn unpack_rgb10a2(packed: u32) -> Self {
+        Self::new(
+            unorm_to_float(packed & 0x3ff, 10),
+            unorm_to_float(packed >> 10 & 0x3ff, 10),
+            unorm_to_float(packed >> 20 & 0x3ff, 10),
+            unorm_to_float(packed >> 30, 2),
+        )
+    }
+}
+
+impl Vec3 {
+    /// Packs `self` into the `R11G11B10F` unsigned float format, with 11 bit
+    /// floats for `x` and `y` and a 10 bit float for `z`.
+    ///
+    /// `x` is stored in the least significant bits, matching
+    /// `DXGI_FORMAT_R11G11B10_FLOAT` and `VK_FORMAT_B10G11R11_UFLOAT_PACK32`.
+    /// Negative values are converted to zero.
+    #[inline]
+    pub fn pack_r11g11b10f(self) -> u32 {
+        f32_to_ufloat(self.x(), 6)
+            | f32_to_ufloat(self.y(), 6) << 11
+            | f32_to_ufloat(self.z(), 5) << 22
+    }
+
+    /// Unpacks a value in the `R11G11B10F` unsigned float format.
+    #[inline]
+    pub fn unpack_r11g11b10f(packed: u32) -> Self {
+        Self::new(
+            minifloat_to_f32(packed & 0x7ff, 6),
+            minifloat_to_f32(packed >> 11 & 0x7ff, 6),
+            minifloat_to_f32(packed >> 22, 5),
+        )
+    }
+
+    /// Returns the octahedral encoding of the normalized vector `self`, a
+    /// point in the square `[-1, 1]²`.
+    ///
+    /// The octahedral encoding maps the sphere onto an octahedron which is
+    /// then unfolded into a square, giving an almost uniform distribution of
+    /// precision over the sphere. See "A Survey of Efficient Representations
+    /// for Independent Unit Vectors", Cigolle et al. 2014.
+    #[inline]
+    pub fn to_octahedral(self) -> Vec2 {
+        glam_assert!(self.is_normalized());
+        let p = self.truncate() / (self.x().abs() + self.y().abs() + self.z().abs());
+        if self.z() < 0.0 {
+            (Vec2::one() - Vec2::new(p.y(), p.x()).abs()) * p.sign()
+        } else {
+            p
+        }
+    }
+
+    /// Returns the normalized vector for a point in the octahedral encoding
+    /// square `[-1, 1]²`.
+    #[inline]
+    pub fn from_octahedral(e: Vec2) -> Self {
+        let z = 1.0 - e.x().abs() - e.y().abs();
+        let xy = if z < 0.0 {
+            (Vec2::one() - Vec2::new(e.y(), e.x()).abs()) * e.sign()
+        } else {
+            e
+        };
+        xy.extend(z).normalize()
+    }
+
+    /// Packs the normalized vector `self` into two 16 bit signed normalized
+    /// octahedral coordinates, with `x` in the least significant bits.
+    ///
+    /// Of the four nearest quantized points the one that decodes closest to
+    /// `self` is chosen, for a maximum error of about 0.008 degrees.
+    #[inline]
+    pub fn pack_octahedral16(self) -> u32 {
+        let [x, y] = pack_octahedral(self, 16);
+        (x as u32 & 0xffff) | (y as u32) << 16
+    }
+
+    /// Unpacks a normalized vector packed with `pack_octahedral16`.
+    #[inline]
+    pub fn unpack_octahedral16(packed: u32) -> Self {
+        Self::from_octahedral(Vec2::new(
+            snorm_to_float(sign_extend(packed, 16), 16),
+            snorm_to_float(sign_extend(packed >> 16, 16), 16),
+        ))
+    }
+
+    /// Packs the normalized vector `self` into two 8 bit signed normalized
+    /// octahedral coordinates, with `x` in the least significant bits.
+    ///
+    /// Of the four nearest quantized points the one that decodes closest to
+    /// `self` is chosen, for a maximum error of about 0.7 degrees.
+    #[inline]
+    pub fn pack_octahedral8(self) -> u16 {
+        let [x, y] = pack_octahedral(self, 8);
+        ((x as u32 & 0xff) | (y as u32 & 0xff) << 8) as u16
+    }
+
+    /// Unpacks a normalized vector packed with `pack_octahedral8`.
+    #[inline]
+    pub fn unpack_octahedral8(packed: u16) -> Self {
+        let packed = u32::from(packed);
+        Self::from_octahedral(Vec2::new(
+            snorm_to_float(sign_extend(packed, 8), 8),
+            snorm_to_float(sign_extend(packed >> 8, 8), 8),
+        ))
+    }
+}
+
+/// Quantizes the octahedral encoding of `n` to signed normalized integers,
+/// choosing the rounding of each coordinate which decodes closest to `n`.
+#[inline]
+fn pack_octahedral(n: Vec3, bits: u32) -> [i32; 2] {
+    let max = (1_i32 << (bits - 1)) - 1;
+    let scale = max as f32;
+    let e = n.to_octahedral() * scale;
+    let (x0, y0) = (e.x().floor() as i32, e.y().floor() as i32);
+    let mut best = [0; 2];
+    let mut best_dot = f32::NEG_INFINITY;
+    for &(dx, dy) in &[(0, 0), (1, 0), (0, 1), (1, 1)] {
+        let x = (x0 + dx).max(-max).min(max);
+        let y = (y0 + dy).max(-max).min(max);
+        let decoded = Vec3::from_octahedral(Vec2::new(x as f32 / scale, y as f32 / scale));
+        let dot = decoded.dot(n);
+        if dot > best_dot {
+            best_dot = dot;
+            best = [x, y];
+        }
+    }
+    best
+}
+
+/// The scale applied to the three smallest components of a quaternion, which
+/// are in the range `[-1/sqrt(2), 1/sqrt(2)]`.
+const SMALLEST_THREE_SCALE: f32 = core::f32::consts::SQRT_2;
+
+impl Quat {
+    /// Returns the index of the largest component and the remaining three
+    /// components, negated if necessary so that the largest is positive.
+    #[inline]
+    fn smallest_three(self) -> (u32, [f32; 3]) {
+        glam_assert!(self.is_normalized());
+        let q: [f32; 4] = Vec4::from(self).into();
+        let mut largest = 0;
+        for i in 1..4 {
+            if q[i].abs() > q[largest].abs() {
+                largest = i;
+            }
+        }
+        let sign = if q[largest] < 0.0 { -1.0 } else { 1.0 };
+        let mut rest = [0.0; 3];
+        for (r, &c) in rest.iter_mut().zip(
+            q.iter()
+                .enumerate()
+                .filter(|&(i, _)| i != largest)
+                .map(|(_, c)| c),
+        ) {
+            *r = c * sign * SMALLEST_THREE_SCALE;
+        }
+        (largest as u32, rest)
+    }
+
+    /// Reconstructs a quaternion from the index of its largest component and
+    /// the remaining three components.
+    #[inline]
+    fn from_smallest_three(largest: u32, rest: [f32; 3]) -> Self {
+        let rest = Vec3::from(rest) / SMALLEST_THREE_SCALE;
+        let w = (1.0 - rest.dot(rest)).max(0.0).sqrt();
+        let (a, b, c) = rest.into();
+        let q = match largest {
+            0 => Vec4::new(w, a, b, c),
+            1 => Vec4::new(a, w, b, c),
+            2 => Vec4::new(a, b, w, c),
+            _ => Vec4::new(a, b, c, w),
+        };
+        Self::from(q).normalize()
+    }
+
+    /// Packs the normalized quaternion `self` into 32 bits using smallest
+    /// three compression.
+    ///
+    /// The largest component is dropped and reconstructed from the other
+    /// three, which are stored as 10 bit signed normalized integers in the
+    /// lowest 30 bits. The index of the largest component is stored in the
+    /// top 2 bits. The maximum error of each component is about `0.0016`.
+    #[inline]
+    pub fn pack_smallest_three(self) -> u32 {
+        let (largest, rest) = self.smallest_three();
+        let mut packed = largest << 30;
+        for (i, &c) in rest.iter().enumerate() {
+            packed |= (float_to_snorm(c, 10) as u32 & 0x3ff) << (10 * i);
+        }
+        packed
+    }
+
+    /// Unpacks a quaternion packed with `pack_smallest_three`.
+    #[inline]
+    pub fn unpack_smallest_three(packed: u32) -> Self {
+        let mut rest = [0.0; 3];
+        for (i, r) in rest.iter_mut().enumerate() {
+            *r = snorm_to_float(sign_extend(packed >> (10 * i), 10), 10);
+        }
+        Self::from_smallest_three(packed >> 30, rest)
+    }
+
+    /// Packs the normalized quaternion `self` into 64 bits using smallest
+    /// three compression.
+    ///
+    /// The three smallest components are stored as 20 bit signed normalized
+    /// integers in the lowest 60 bits and the index of the largest component is
+    /// stored in the top 2 bits. The maximum error of each component is about
+    /// `1.7e-6`.
+    #[inline]
+    pub fn pack_smallest_three_u64(self) -> u64 {
+        let (largest, rest) = self.smallest_three();
+        let mut packed = u64::from(largest) << 62;
+        for (i, &c) in rest.iter().enumerate() {
+            packed |= u64::from(float_to_snorm(c, 20) as u32 & 0xf_ffff) << (20 * i);
+        }
+        packed
+    }
+
+    /// Unpacks a quaternion packed with `pack_smallest_three_u64`.
+    #[inline]
+    pub fn unpack_smallest_three_u64(packed: u64) -> Self {
+        let mut rest = [0.0; 3];
+        for (i, r) in rest.iter_mut().enumerate() {
+            *r = snorm_to_float(sign_extend((packed >> (20 * i)) as u32, 20), 20);
+        }
+        Self::from_smallest_three((packed >> 62) as u32, rest)
+    }
+}
@@ -0,0 +1,1031 @@
+//! Differential tests comparing the SSE2 implementation of every type in
+//! `glam::f32` that has one against the scalar implementation of the same
+//! type from `super::scalar`.
+//!
+//! Each public function is run through both implementations on the same
+//! inputs and the results are compared element by element. Inputs are drawn
+//! from a fixed seed per function and mix random values with edge cases: `±0`,
+//! denormals, `±1`, huge values, `±inf` and `NaN`.
+//!
+//! Differences are measured in units in the last place (ULPs) of the larger of
+//! the two results. Two `NaN`s are considered equal, as are `0.0` and `-0.0`.
+//! Every function must produce bit for bit the same result up to those two
+//! exceptions unless listed below. The documented tolerances are:
+//!
+//! * `normalize` on `Vec3A`, `Vec4` and `Quat`, along with `Quat::lerp`: 4
+//!   ULPs relative to `1.0`. The SSE2 versions divide by the length where the
+//!   scalar versions multiply by its reciprocal, so these are only compared on
+//!   inputs in `[-1, 1]` and the difference is measured against a magnitude of
+//!   at least `1.0`.
+//! * `Quat::slerp`: 4096 ULPs relative to `1.0`. The SSE2 version uses a
+//!   polynomial `sin` approximation and `_mm_rcp_ps`, which has 12 bits of
+//!   precision. Interpolating between opposite quaternions is undefined and
+//!   gives different results so it is not compared.
+//! * `min`, `max`, `min_element` and `max_element`: `_mm_min_ps` and
+//!   `_mm_max_ps` return the second operand when either operand is `NaN`, so
+//!   these are only compared on inputs without `NaN`.
+//!
+//! With the `deterministic` feature `normalize`, `Quat::lerp` and
+//! `Quat::slerp` must also match exactly.
+//!
+//! Functions that `glam_assert!` their arguments are only compared on inputs
+//! that pass the assertions. `Debug` output is not compared as the SSE2 types
+//! print their `__m128`. `Vec2`, `Vec3` and `Mat3` have no SSE2 implementation
+//! and are only used as arguments and results here.
+//!
+//! Run `cargo test parity -- --nocapture` to see the largest difference found
+//! for every function.
+
+use core::cmp::Ordering;
+
+const ITERATIONS: usize = 1000;
+
+/// Values that are likely to expose differences between implementations.
+const EDGE_CASES: [f32; 18] = [
+    0.0,
+    -0.0,
+    1.0,
+    -1.0,
+    0.5,
+    -1.5,
+    f32::MIN_POSITIVE,
+    -f32::MIN_POSITIVE,
+    1.0e-40,
+    -1.0e-40,
+    1.0e30,
+    -1.0e30,
+    f32::MAX,
+    f32::MIN,
+    f32::EPSILON,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::NAN,
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Domain {
+    /// Random bit patterns, moderate values and all of `EDGE_CASES`.
+    Any,
+    /// As `Any` but without `NaN`.
+    NotNan,
+    /// Values in `[-1, 1]` including `±0` and denormals.
+    Unit,
+    /// As `Unit` but vectors and quaternions are normalized.
+    Normalized,
+}
+
+#[derive(Clone, Copy)]
+struct Tolerance {
+    ulps: u32,
+    /// Differences are measured in ULPs of at least this magnitude.
+    scale: f32,
+}
+
+const EXACT: Tolerance = Tolerance {
+    ulps: 0,
+    scale: 0.0,
+};
+
+#[cfg(not(feature = "deterministic"))]
+const NORMALIZE: Tolerance = Tolerance {
+    ulps: 4,
+    scale: 1.0,
+};
+
+#[cfg(not(feature = "deterministic"))]
+const SLERP: Tolerance = Tolerance {
+    ulps: 4096,
+    scale: 1.0,
+};
+
+#[cfg(feature = "deterministic")]
+const NORMALIZE: Tolerance = EXACT;
+
+#[cfg(feature = "deterministic")]
+const SLERP: Tolerance = EXACT;
+
+/// Returns the difference between `a` and `b` in ULPs of the larger of `|a|`,
+/// `|b|` and `scale`.
+fn ulps(a: f32, b: f32, scale: f32) -> u32 {
+    if a.is_nan() && b.is_nan() || a == b {
+        return 0;
+    }
+    if !a.is_finite() || !b.is_finite() {
+        return u32::MAX;
+    }
+    let magnitude = a.abs().max(b.abs()).max(scale);
+    let ulp = if magnitude == f32::MAX {
+        magnitude - f32::from_bits(magnitude.to_bits() - 1)
+    } else {
+        f32::from_bits(magnitude.to_bits() + 1) - magnitude
+    };
+    let diff = ((a as f64) - (b as f64)).abs() / ulp as f64;
+    if diff >= u32::MAX as f64 {
+        u32::MAX
+    } else {
+        diff.ceil() as u32
+    }
+}
+
+/// A xorshift generator so every run tests the same inputs.
+struct Rng(u32);
+
+impl Rng {
+    fn new(name: &str) -> Self {
+        Self(
+            name.bytes()
+                .fold(0x9e37_79b9, |h, b| super::hash_u32(h ^ b as u32))
+                | 1,
+        )
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0
+    }
+
+    fn unit(&mut self) -> f32 {
+        super::u32_to_unit_f32(self.next_u32()) * 2.0 - 1.0
+    }
+
+    fn f32(&mut self, domain: Domain) -> f32 {
+        let r = self.next_u32();
+        let edge = EDGE_CASES[(r >> 8) as usize % EDGE_CASES.len()];
+        match domain {
+            Domain::Any | Domain::NotNan => {
+                let v = match r % 8 {
+                    0 | 1 => edge,
+                    2 => f32::from_bits(self.next_u32()),
+                    _ => self.unit() * 100.0,
+                };
+                if domain == Domain::NotNan && v.is_nan() {
+                    self.f32(domain)
+                } else {
+                    v
+                }
+            }
+            Domain::Unit | Domain::Normalized => match r % 16 {
+                0 => 0.0,
+                1 => -0.0,
+                2 => 1.0e-40,
+                3 => -1.0,
+                _ => self.unit(),
+            },
+        }
+    }
+
+    /// Generates the elements of one argument of `lanes` elements. When
+    /// normalizing, each group of `chunk` elements is scaled to unit length.
+    fn lanes(&mut self, domain: Domain, lanes: usize, chunk: usize) -> Vec<f32> {
+        let mut v: Vec<f32> = (0..lanes).map(|_| self.f32(domain)).collect();
+        if domain == Domain::Normalized && chunk > 1 {
+            for c in v.chunks_mut(chunk) {
+                let length = c.iter().map(|x| x * x).sum::<f32>().sqrt();
+                if length < 0.1 {
+                    c.iter_mut().for_each(|x| *x = 0.0);
+                    c[0] = 1.0;
+                } else {
+                    c.iter_mut().for_each(|x| *x /= length);
+                }
+            }
+        }
+        v
+    }
+}
+
+/// An argument type that can be built from generated elements.
+trait Input {
+    const LANES: usize;
+    /// Number of elements normalized together in the `Normalized` domain.
+    const CHUNK: usize;
+    fn from_lanes(lanes: &[f32]) -> Self;
+}
+
+/// A result type that can be flattened to elements for comparison.
+trait Output {
+    fn push_lanes(&self, out: &mut Vec<f32>);
+}
+
+impl Input for f32 {
+    const LANES: usize = 1;
+    const CHUNK: usize = 1;
+    fn from_lanes(lanes: &[f32]) -> Self {
+        lanes[0]
+    }
+}
+
+impl Output for f32 {
+    fn push_lanes(&self, out: &mut Vec<f32>) {
+        out.push(*self);
+    }
+}
+
+impl Output for bool {
+    fn push_lanes(&self, out: &mut Vec<f32>) {
+        out.push(*self as u32 as f32);
+    }
+}
+
+impl Output for u32 {
+    fn push_lanes(&self, out: &mut Vec<f32>) {
+        // Split so every bit pattern is exactly representable.
+        out.push((self >> 16) as f32);
+        out.push((self & 0xffff) as f32);
+    }
+}
+
+impl Output for Option<Ordering> {
+    fn push_lanes(&self, out: &mut Vec<f32>) {
+        out.push(self.map_or(f32::NAN, |o| o as i32 as f32));
+    }
+}
+
+impl Output for String {
+    fn push_lanes(&self, out: &mut Vec<f32>) {
+        out.push(self.len() as f32);
+        out.extend(self.bytes().map(|b| b as f32));
+    }
+}
+
+impl<T: Output> Output for [T] {
+    fn push_lanes(&self, out: &mut Vec<f32>) {
+        self.iter().for_each(|t| t.push_lanes(out));
+    }
+}
+
+impl<T: Output, const N: usize> Output for [T; N] {
+    fn push_lanes(&self, out: &mut Vec<f32>) {
+        self[..].push_lanes(out);
+    }
+}
+
+impl<A: Output, B: Output> Output for (A, B) {
+    fn push_lanes(&self, out: &mut Vec<f32>) {
+        self.0.push_lanes(out);
+        self.1.push_lanes(out);
+    }
+}
+
+impl<A: Output, B: Output, C: Output> Output for (A, B, C) {
+    fn push_lanes(&self, out: &mut Vec<f32>) {
+        self.0.push_lanes(out);
+        self.1.push_lanes(out);
+        self.2.push_lanes(out);
+    }
+}
+
+/// Implements `Input` and `Output` for the types in scope, which are either
+/// the SSE2 or the scalar types.
+macro_rules! impl_lanes {
+    () => {
+        macro_rules! impl_vec {
+            ($t:ident, $n:expr) => {
+                impl Input for $t {
+                    const LANES: usize = $n;
+                    const CHUNK: usize = $n;
+                    fn from_lanes(lanes: &[f32]) -> Self {
+                        let mut v = [0.0; $n];
+                        v.copy_from_slice(lanes);
+                        $t::from(v)
+                    }
+                }
+
+                impl Output for $t {
+                    fn push_lanes(&self, out: &mut Vec<f32>) {
+                        out.extend_from_slice(self.as_ref());
+                    }
+                }
+            };
+        }
+        impl_vec!(Vec2, 2);
+        impl_vec!(Vec3, 3);
+        impl_vec!(Vec3A, 3);
+        impl_vec!(Vec4, 4);
+        impl_vec!(Quat, 4);
+
+        macro_rules! impl_mat {
+            ($t:ident, $n:expr, $size:expr) => {
+                impl Input for $t {
+                    const LANES: usize = $size;
+                    const CHUNK: usize = $n;
+                    fn from_lanes(lanes: &[f32]) -> Self {
+                        let mut m = [0.0; $size];
+                        m.copy_from_slice(lanes);
+                        $t::from_cols_array(&m)
+                    }
+                }
+
+                impl Output for $t {
+                    fn push_lanes(&self, out: &mut Vec<f32>) {
+                        out.extend_from_slice(&self.to_cols_array());
+                    }
+                }
+            };
+        }
+        impl_mat!(Mat2, 2, 4);
+        impl_mat!(Mat3, 3, 9);
+        impl_mat!(Mat3A, 3, 9);
+        impl_mat!(Mat4, 4, 16);
+
+        macro_rules! impl_mask {
+            ($t:ident) => {
+                impl Output for $t {
+                    fn push_lanes(&self, out: &mut Vec<f32>) {
+                        self.as_ref().push_lanes(out);
+                    }
+                }
+            };
+        }
+        impl_mask!(Vec2Mask);
+        impl_mask!(Vec3Mask);
+        impl_mask!(Vec3AMask);
+        impl_mask!(Vec4Mask);
+    };
+}
+
+mod simd_lanes {
+    use super::{Input, Output};
+    use crate::f32::*;
+    impl_lanes!();
+}
+
+mod scalar_lanes {
+    use super::super::scalar::*;
+    use super::{Input, Output};
+    impl_lanes!();
+}
+
+/// Runs functions through both implementations and collects failures.
+struct Parity {
+    ty: &'static str,
+    failures: Vec<String>,
+}
+
+impl Parity {
+    fn new(ty: &'static str) -> Self {
+        Self {
+            ty,
+            failures: Vec::new(),
+        }
+    }
+
+    fn check(
+        &mut self,
+        name: &str,
+        domain: Domain,
+        tolerance: Tolerance,
+        args: &[(usize, usize)],
+        simd: impl Fn(&[Vec<f32>]) -> Vec<f32>,
+        scalar: impl Fn(&[Vec<f32>]) -> Vec<f32>,
+    ) {
+        let name = format!("{}::{}", self.ty, name);
+        let mut rng = Rng::new(&name);
+        let mut worst = (0, String::new());
+        for _ in 0..ITERATIONS {
+            let inputs: Vec<Vec<f32>> = args
+                .iter()
+                .map(|&(lanes, chunk)| rng.lanes(domain, lanes, chunk))
+                .collect();
+            let expected = scalar(&inputs);
+            let actual = simd(&inputs);
+            let diff = if expected.len() != actual.len() {
+                u32::MAX
+            } else {
+                expected
+                    .iter()
+                    .zip(&actual)
+                    .map(|(&a, &b)| ulps(a, b, tolerance.scale))
+                    .max()
+                    .unwrap_or(0)
+            };
+            if diff > worst.0 || worst.1.is_empty() {
+                worst = (
+                    diff,
+                    format!(
+                        "inputs {:?}, sse2 {:?}, scalar {:?}",
+                        inputs, actual, expected
+                    ),
+                );
+            }
+        }
+        println!(
+            "{:<40} max {:>10} ulps, tolerance {}",
+            name, worst.0, tolerance.ulps
+        );
+        if worst.0 > tolerance.ulps {
+            self.failures.push(format!(
+                "{} differs by {} ulps, tolerance {}: {}",
+                name, worst.0, tolerance.ulps, worst.1
+            ));
+        }
+    }
+
+    fn finish(self) {
+        assert!(
+            self.failures.is_empty(),
+            "sse2 and scalar implementations differ:\n{}",
+            self.failures.join("\n")
+        );
+    }
+}
+
+/// Checks that `$body` gives the same result with the SSE2 and the scalar
+/// types bound to the argument names.
+macro_rules! parity {
+    ($p:ident, $name:expr, $domain:expr, $tol:expr, |$($arg:ident: $t:ident),+| $body:expr) => {
+        $p.check(
+            $name,
+            $domain,
+            $tol,
+            &{
+                #[allow(unused_imports)]
+                use crate::f32::*;
+                [$((<$t as Input>::LANES, <$t as Input>::CHUNK)),+]
+            },
+            |inputs| {
+                #[allow(unused_imports)]
+                use crate::f32::*;
+                let mut inputs = inputs.iter();
+                $(let $arg = <$t as Input>::from_lanes(inputs.next().unwrap());)+
+                let mut out = Vec::new();
+                Output::push_lanes(&$body, &mut out);
+                out
+            },
+            |inputs| {
+                #[allow(unused_imports)]
+                use super::scalar::*;
+                let mut inputs = inputs.iter();
+                $(let $arg = <$t as Input>::from_lanes(inputs.next().unwrap());)+
+                let mut out = Vec::new();
+                Output::push_lanes(&$body, &mut out);
+                out
+            },
+        );
+    };
+}
+
+/// Checks the functions shared by `Vec3A` and `Vec4`.
+macro_rules! vec_parity {
+    ($p:ident, $t:ident) => {
+        use Domain::*;
+        parity!($p, "new", Any, EXACT, |a: $t| $t::from(*a.as_ref()));
+        parity!($p, "splat", Any, EXACT, |a: f32| $t::splat(a));
+        parity!($p, "constants", Any, EXACT, |a: $t| [
+            a,
+            $t::zero(),
+            $t::one(),
+            $t::unit_x(),
+            $t::unit_y(),
+            $t::unit_z()
+        ]);
+        parity!($p, "truncate", Any, EXACT, |a: $t| a.truncate());
+        parity!($p, "getters", Any, EXACT, |a: $t| [a.x(), a.y(), a.z()]);
+        parity!($p, "setters", Any, EXACT, |a: $t, b: f32| {
+            let mut v = [a; 6];
+            v[0].set_x(b);
+            v[1].set_y(b);
+            v[2].set_z(b);
+            *v[3].x_mut() = b;
+            *v[4].y_mut() = b;
+            *v[5].z_mut() = b;
+            v
+        });
+        parity!($p, "index", Any, EXACT, |a: $t, b: f32| {
+            let mut c = a;
+            c[1] = b;
+            [a[0], a[1], a[2], c[1]]
+        });
+        parity!($p, "dot", Any, EXACT, |a: $t, b: $t| a.dot(b));
+        parity!($p, "length", Any, EXACT, |a: $t| [
+            a.length(),
+            a.length_squared(),
+            a.length_reciprocal()
+        ]);
+        parity!($p, "normalize", Unit, NORMALIZE, |a: $t| a.normalize());
+        parity!($p, "is_normalized", Normalized, EXACT, |a: $t| a
+            .is_normalized());
+        parity!($p, "min", NotNan, EXACT, |a: $t, b: $t| a.min(b));
+        parity!($p, "max", NotNan, EXACT, |a: $t, b: $t| a.max(b));
+        parity!($p, "min_element", NotNan, EXACT, |a: $t| a.min_element());
+        parity!($p, "max_element", NotNan, EXACT, |a: $t| a.max_element());
+        parity!($p, "cmp", Any, EXACT, |a: $t, b: $t| [
+            a.cmpeq(b),
+            a.cmpne(b),
+            a.cmpge(b),
+            a.cmpgt(b),
+            a.cmple(b),
+            a.cmplt(b)
+        ]);
+        parity!($p, "cmp_self", Any, EXACT, |a: $t| [
+            a.cmpeq(a),
+            a.cmpne(a),
+            a.cmpge(a),
+        ]);
+        parity!($p, "slice", Any, EXACT, |a: $t| {
+            let mut s = [0.0; 5];
+            a.write_to_slice_unaligned(&mut s[1..]);
+            ($t::from_slice_unaligned(&s[1..]), s)
+        });
+        parity!($p, "abs", Any, EXACT, |a: $t| a.abs());
+        parity!($p, "round", Any, EXACT, |a: $t| a.round());
+        parity!($p, "floor", Any, EXACT, |a: $t| a.floor());
+        parity!($p, "ceil", Any, EXACT, |a: $t| a.ceil());
+        parity!($p, "sign", Any, EXACT, |a: $t| a.sign());
+        parity!($p, "reciprocal", Any, EXACT, |a: $t| a.reciprocal());
+        parity!($p, "lerp", Any, EXACT, |a: $t, b: $t, s: f32| a.lerp(b, s));
+        parity!($p, "abs_diff_eq", Any, EXACT, |a: $t, b: $t, d: f32| [
+            a.abs_diff_eq(b, d),
+            a.abs_diff_eq(a, d)
+        ]);
+        parity!($p, "ops", Any, EXACT, |a: $t, b: $t, s: f32| [
+            a + b,
+            a - b,
+            a * b,
+            a / b,
+            a * s,
+            a / s,
+            -a
+        ]);
+        parity!($p, "assign_ops", Any, EXACT, |a: $t, b: $t, s: f32| {
+            let mut v = [a; 6];
+            v[0] += b;
+            v[1] -= b;
+            v[2] *= b;
+            v[3] /= b;
+            v[4] *= s;
+            v[5] /= s;
+            v
+        });
+        parity!($p, "eq", Any, EXACT, |a: $t, b: $t| [a == b, a == a]);
+        parity!($p, "partial_cmp", Any, EXACT, |a: $t, b: $t| [
+            a.partial_cmp(&b),
+            a.partial_cmp(&a)
+        ]);
+        parity!($p, "fmt", Any, EXACT, |a: $t| format!("{}", a));
+    };
+}
+
+#[test]
+fn vec3a() {
+    let mut p = Parity::new("Vec3A");
+    vec_parity!(p, Vec3A);
+    parity!(p, "extend", Any, EXACT, |a: Vec3A, w: f32| a.extend(w));
+    parity!(p, "cross", Any, EXACT, |a: Vec3A, b: Vec3A| a.cross(b));
+    parity!(
+        p,
+        "angle_between",
+        Normalized,
+        EXACT,
+        |a: Vec3A, b: Vec3A| a.angle_between(b)
+    );
+    parity!(p, "from", Any, EXACT, |a: Vec4, b: Vec3| [
+        Vec3A::from(a),
+        Vec3A::from(b),
+        Vec3A::from((b.x(), b.y(), b.z()))
+    ]);
+    parity!(p, "into", Any, EXACT, |a: Vec3A| (
+        Vec3::from(a),
+        <[f32; 3]>::from(a),
+        <(f32, f32, f32)>::from(a).2
+    ));
+    p.finish();
+}
+
+#[test]
+fn vec4() {
+    let mut p = Parity::new("Vec4");
+    vec_parity!(p, Vec4);
+    parity!(p, "w", Any, EXACT, |a: Vec4, b: f32| {
+        let mut c = a;
+        c.set_w(b);
+        let mut d = a;
+        *d.w_mut() = b;
+        [a.w(), c.w(), d.w(), Vec4::unit_w().w()]
+    });
+    parity!(p, "from", Any, EXACT, |a: Vec4| [
+        Vec4::from((a.x(), a.y(), a.z(), a.w())),
+        Vec3A::from(a).extend(a.w())
+    ]);
+    parity!(p, "into", Any, EXACT, |a: Vec4| (
+        <[f32; 4]>::from(a),
+        <(f32, f32, f32, f32)>::from(a).3
+    ));
+    p.finish();
+}
+
+/// Checks the mask types returned by comparing `$t` values.
+macro_rules! mask_parity {
+    ($p:ident, $t:ident) => {
+        use Domain::*;
+        parity!($p, "bitmask", Any, EXACT, |a: $t, b: $t| [
+            a.cmplt(b).bitmask(),
+            a.cmpeq(b).bitmask()
+        ]);
+        parity!($p, "any_all", Any, EXACT, |a: $t, b: $t| [
+            a.cmplt(b).any(),
+            a.cmplt(b).all(),
+            a.cmpge(b).any(),
+            a.cmpge(b).all()
+        ]);
+        parity!($p, "select", Any, EXACT, |a: $t, b: $t| a
+            .cmplt(b)
+            .select(a, b));
+        parity!($p, "bit_ops", Any, EXACT, |a: $t, b: $t| {
+            let (m, n) = (a.cmplt(b), a.cmpgt(b));
+            let mut and = m;
+            and &= n;
+            let mut or = m;
+            or |= n;
+            [m & n, m | n, !m, and, or]
+        });
+        parity!($p, "eq", Any, EXACT, |a: $t, b: $t| {
+            let (m, n) = (a.cmplt(b), a.cmpge(b));
+            [m == n, m == m, m < n, m > n]
+        });
+        parity!($p, "default", Any, EXACT, |a: $t| [
+            a.cmplt(a),
+            Default::default()
+        ]);
+        parity!($p, "fmt", Any, EXACT, |a: $t, b: $t| format!(
+            "{}",
+            a.cmple(b)
+        ));
+    };
+}
+
+#[test]
+fn vec3a_mask() {
+    let mut p = Parity::new("Vec3AMask");
+    mask_parity!(p, Vec3A);
+    parity!(p, "new", Any, EXACT, |a: Vec3A| {
+        let m = Vec3AMask::new(a.x() < 0.0, a.y() < 0.0, a.z() < 0.0);
+        (m, m.bitmask(), <[u32; 3]>::from(m))
+    });
+    p.finish();
+}
+
+#[test]
+fn vec4_mask() {
+    let mut p = Parity::new("Vec4Mask");
+    mask_parity!(p, Vec4);
+    parity!(p, "new", Any, EXACT, |a: Vec4| {
+        let m = Vec4Mask::new(a.x() < 0.0, a.y() < 0.0, a.z() < 0.0, a.w() < 0.0);
+        (m, m.bitmask(), <[u32; 4]>::from(m))
+    });
+    p.finish();
+}
+
+#[test]
+fn quat() {
+    use Domain::*;
+    let mut p = Parity::new("Quat");
+    parity!(p, "new", Any, EXACT, |a: Vec4| [
+        Quat::from_xyzw(a.x(), a.y(), a.z(), a.w()),
+        quat(a.x(), a.y(), a.z(), a.w()),
+        Quat::from(a),
+        Quat::from(*a.as_ref()),
+        Quat::from((a.x(), a.y(), a.z(), a.w())),
+        Quat::identity(),
+        Quat::default()
+    ]);
+    parity!(p, "getters", Any, EXACT, |a: Quat| (
+        [a.x(), a.y(), a.z(), a.w()],
+        Vec4::from(a)
+    ));
+    parity!(p, "slice", Normalized, EXACT, |a: Quat| {
+        let mut s = [0.0; 5];
+        a.write_to_slice_unaligned(&mut s[1..]);
+        (Quat::from_slice_unaligned(&s[1..]), s)
+    });
+    parity!(
+        p,
+        "from_axis_angle",
+        Normalized,
+        EXACT,
+        |axis: Vec3, angle: f32| { Quat::from_axis_angle(axis, angle) }
+    );
+    parity!(p, "from_rotation", Any, EXACT, |angle: f32| [
+        Quat::from_rotation_x(angle),
+        Quat::from_rotation_y(angle),
+        Quat::from_rotation_z(angle)
+    ]);
+    parity!(p, "from_rotation_mat", Normalized, EXACT, |a: Quat| [
+        Quat::from_rotation_mat3(&Mat3::from_quat(a)),
+        Quat::from_rotation_mat4(&Mat4::from_quat(a))
+    ]);
+    parity!(p, "to_axis_angle", Normalized, EXACT, |a: Quat| a
+        .to_axis_angle());
+    parity!(p, "conjugate", Any, EXACT, |a: Quat| [a.conjugate(), -a]);
+    parity!(p, "dot", Any, EXACT, |a: Quat, b: Quat| a.dot(b));
+    parity!(p, "length", Any, EXACT, |a: Quat| [
+        a.length(),
+        a.length_squared(),
+        a.length_reciprocal()
+    ]);
+    parity!(p, "normalize", Unit, NORMALIZE, |a: Quat| a.normalize());
+    parity!(p, "is_normalized", Normalized, EXACT, |a: Quat| [
+        a.is_normalized(),
+        a.is_near_identity()
+    ]);
+    parity!(p, "abs_diff_eq", Any, EXACT, |a: Quat, b: Quat, d: f32| [
+        a.abs_diff_eq(b, d),
+        a.abs_diff_eq(a, d)
+    ]);
+    parity!(p, "lerp", Normalized, NORMALIZE, |a: Quat, b: Quat, s: f32| a
+        .lerp(b, s));
+    parity!(p, "slerp", Normalized, SLERP, |a: Quat, b: Quat, s: f32| [
+        a.slerp(b, s),
+        a.slerp(a, s)
+    ]);
+    parity!(p, "mul_vec3", Normalized, EXACT, |a: Quat, b: Vec3| [
+        a.mul_vec3(b),
+        a * b
+    ]);
+    parity!(p, "mul_vec3a", Normalized, EXACT, |a: Quat, b: Vec3A| [
+        a.mul_vec3a(b),
+        a * b
+    ]);
+    parity!(p, "mul_quat", Normalized, EXACT, |a: Quat, b: Quat| {
+        let mut c = a;
+        c *= b;
+        [a.mul_quat(b), a * b, c]
+    });
+    parity!(p, "eq", Any, EXACT, |a: Quat, b: Quat| [a == b, a == a]);
+    parity!(p, "partial_cmp", Any, EXACT, |a: Quat, b: Quat| [
+        a.partial_cmp(&b),
+        a.partial_cmp(&a)
+    ]);
+    parity!(p, "fmt", Any, EXACT, |a: Quat| format!("{}", a));
+    p.finish();
+}
+
+/// Checks the functions shared by `Mat2`, `Mat3A` and `Mat4`.
+macro_rules! mat_parity {
+    ($p:ident, $t:ident, $col:ident, $mul_vec:ident, $mul_mat:ident, $add_mat:ident, $sub_mat:ident) => {
+        use Domain::*;
+        parity!($p, "to_cols_array", Any, EXACT, |m: $t| (
+            m.to_cols_array(),
+            m.to_cols_array_2d(),
+            $t::from_cols_array_2d(&m.to_cols_array_2d())
+        ));
+        parity!($p, "constants", Any, EXACT, |m: $t| [
+            m,
+            $t::zero(),
+            $t::identity(),
+            $t::default()
+        ]);
+        parity!($p, "index", Any, EXACT, |m: $t, v: $col| {
+            let mut c = m;
+            c[1] = v;
+            [m[0], m[1], c[1]]
+        });
+        parity!($p, "transpose", Any, EXACT, |m: $t| m.transpose());
+        parity!($p, "determinant", Any, EXACT, |m: $t| m.determinant());
+        parity!($p, "inverse", Unit, EXACT, |m: $t| {
+            // Diagonally dominant so the matrix is well conditioned.
+            (m + $t::identity() * 4.0).inverse()
+        });
+        parity!($p, stringify!($mul_vec), Any, EXACT, |m: $t, v: $col| [
+            m.$mul_vec(v),
+            m * v
+        ]);
+        parity!($p, stringify!($mul_mat), Any, EXACT, |a: $t, b: $t| [
+            a.$mul_mat(&b),
+            a * b
+        ]);
+        parity!($p, "ops", Any, EXACT, |a: $t, b: $t, s: f32| [
+            a.$add_mat(&b),
+            a + b,
+            a.$sub_mat(&b),
+            a - b,
+            a.mul_scalar(s),
+            a * s
+        ]);
+        parity!($p, "abs_diff_eq", Any, EXACT, |a: $t, b: $t, d: f32| [
+            a.abs_diff_eq(b, d),
+            a.abs_diff_eq(a, d)
+        ]);
+        parity!($p, "eq", Any, EXACT, |a: $t, b: $t| [a == b, a == a]);
+        parity!($p, "partial_cmp", Any, EXACT, |a: $t, b: $t| [
+            a.partial_cmp(&b),
+            a.partial_cmp(&a)
+        ]);
+        parity!($p, "fmt", Any, EXACT, |m: $t| format!("{}", m));
+    };
+}
+
+#[test]
+fn mat2() {
+    let mut p = Parity::new("Mat2");
+    mat_parity!(p, Mat2, Vec2, mul_vec2, mul_mat2, add_mat2, sub_mat2);
+    parity!(p, "from_cols", Any, EXACT, |x: Vec2, y: Vec2| [
+        Mat2::from_cols(x, y),
+        mat2(x, y),
+        Mat2::from_cols_array(&[x.x(), x.y(), y.x(), y.y()])
+    ]);
+    parity!(p, "from_scale_angle", Unit, EXACT, |s: Vec2, a: f32| [
+        Mat2::from_scale_angle(s, a),
+        Mat2::from_angle(a),
+        Mat2::from_scale(s)
+    ]);
+    parity!(p, "axes", Any, EXACT, |m: Mat2, v: Vec2| {
+        let mut c = [m; 4];
+        c[0].set_x_axis(v);
+        c[1].set_y_axis(v);
+        *c[2].x_axis_mut() = v;
+        *c[3].y_axis_mut() = v;
+        (c, [m.x_axis(), m.y_axis()])
+    });
+    parity!(p, "as_ref", Any, EXACT, |m: Mat2, s: f32| {
+        let mut c = m;
+        c.as_mut()[3] = s;
+        [*m.as_ref(), *c.as_ref()]
+    });
+    p.finish();
+}
+
+#[test]
+fn mat3a() {
+    let mut p = Parity::new("Mat3A");
+    mat_parity!(p, Mat3A, Vec3A, mul_vec3, mul_mat3, add_mat3, sub_mat3);
+    parity!(
+        p,
+        "from_cols",
+        Any,
+        EXACT,
+        |x: Vec3A, y: Vec3A, z: Vec3A| [
+            Mat3A::from_cols(x, y, z),
+            mat3a(x, y, z),
+            Mat3A::from_cols_array(&[
+                x.x(),
+                x.y(),
+                x.z(),
+                y.x(),
+                y.y(),
+                y.z(),
+                z.x(),
+                z.y(),
+                z.z()
+            ])
+        ]
+    );
+    parity!(p, "from_mat3", Any, EXACT, |m: Mat3| (
+        Mat3A::from(m),
+        Mat3::from(Mat3A::from(m))
+    ));
+    parity!(p, "from_quat", Normalized, EXACT, |q: Quat| {
+        Mat3A::from_quat(q)
+    });
+    parity!(
+        p,
+        "from_axis_angle",
+        Normalized,
+        EXACT,
+        |axis: Vec3A, a: f32| { Mat3A::from_axis_angle(axis, a) }
+    );
+    parity!(p, "from_rotation", Any, EXACT, |a: f32| [
+        Mat3A::from_rotation_x(a),
+        Mat3A::from_rotation_y(a),
+        Mat3A::from_rotation_z(a)
+    ]);
+    parity!(p, "from_scale", Normalized, EXACT, |s: Vec3A| {
+        Mat3A::from_scale(s)
+    });
+    parity!(p, "axes", Any, EXACT, |m: Mat3A, v: Vec3A| {
+        let mut c = [m; 6];
+        c[0].set_x_axis(v);
+        c[1].set_y_axis(v);
+        c[2].set_z_axis(v);
+        *c[3].x_axis_mut() = v;
+        *c[4].y_axis_mut() = v;
+        *c[5].z_axis_mut() = v;
+        (c, [m.x_axis(), m.y_axis(), m.z_axis()])
+    });
+    parity!(p, "transform2", Any, EXACT, |m: Mat3A, v: Vec2| [
+        m.transform_point2(v),
+        m.transform_vector2(v)
+    ]);
+    p.finish();
+}
+
+#[test]
+fn mat4() {
+    let mut p = Parity::new("Mat4");
+    mat_parity!(p, Mat4, Vec4, mul_vec4, mul_mat4, add_mat4, sub_mat4);
+    parity!(
+        p,
+        "from_cols",
+        Any,
+        EXACT,
+        |x: Vec4, y: Vec4, z: Vec4, w: Vec4| {
+            let mut m = [0.0; 16];
+            m[..4].copy_from_slice(x.as_ref());
+            m[4..8].copy_from_slice(y.as_ref());
+            m[8..12].copy_from_slice(z.as_ref());
+            m[12..].copy_from_slice(w.as_ref());
+            [
+                Mat4::from_cols(x, y, z, w),
+                mat4(x, y, z, w),
+                Mat4::from_cols_array(&m),
+            ]
+        }
+    );
+    parity!(
+        p,
+        "from_scale_rotation_translation",
+        Normalized,
+        EXACT,
+        |s: Vec3, r: Quat, t: Vec3| [
+            Mat4::from_scale_rotation_translation(s, r, t),
+            Mat4::from_rotation_translation(r, t),
+            Mat4::from_quat(r),
+            Mat4::from_translation(t)
+        ]
+    );
+    parity!(
+        p,
+        "to_scale_rotation_translation",
+        Normalized,
+        EXACT,
+        |s: Vec3, r: Quat, t: Vec3| {
+            // Keep the scale away from zero so the rotation can be recovered.
+            Mat4::from_scale_rotation_translation(s + Vec3::splat(2.0), r, t)
+                .to_scale_rotation_translation()
+        }
+    );
+    parity!(
+        p,
+        "from_axis_angle",
+        Normalized,
+        EXACT,
+        |axis: Vec3, a: f32| { Mat4::from_axis_angle(axis, a) }
+    );
+    parity!(p, "from_rotation", Any, EXACT, |a: f32| [
+        Mat4::from_rotation_x(a),
+        Mat4::from_rotation_y(a),
+        Mat4::from_rotation_z(a)
+    ]);
+    parity!(p, "from_scale", Normalized, EXACT, |s: Vec3| {
+        Mat4::from_scale(s)
+    });
+    parity!(
+        p,
+        "look_at",
+        Normalized,
+        EXACT,
+        |eye: Vec3, dir: Vec3, up: Vec3| [
+            Mat4::look_at_lh(eye, eye + dir, up),
+            Mat4::look_at_rh(eye, eye + dir, up)
+        ]
+    );
+    parity!(p, "perspective", Unit, EXACT, |a: f32, b: f32, c: f32| {
+        let (fov, aspect, near) = (1.0 + a.abs(), 1.0 + b.abs(), 0.1 + c.abs());
+        let far = near + 10.0;
+        [
+            Mat4::perspective_rh_gl(fov, aspect, near, far),
+            Mat4::perspective_lh(fov, aspect, near, far),
+            Mat4::perspective_infinite_lh(fov, aspect, near),
+            Mat4::perspective_infinite_reverse_lh(fov, aspect, near),
+            Mat4::perspective_infinite_rh(fov, aspect, near),
+            Mat4::perspective_infinite_reverse_rh(fov, aspect, near),
+        ]
+    });
+    parity!(p, "orthographic", Unit, EXACT, |a: Vec3, b: Vec3| {
+        let (l, r) = (a.x(), a.x() + 1.0 + b.x().abs());
+        let (bottom, top) = (a.y(), a.y() + 1.0 + b.y().abs());
+        let (near, far) = (a.z(), a.z() + 1.0 + b.z().abs());
+        [
+            Mat4::orthographic_rh_gl(l, r, bottom, top, near, far),
+            Mat4::orthographic_lh(l, r, bottom, top, near, far),
+            Mat4::orthographic_rh(l, r, bottom, top, near, far),
+        ]
+    });
+    parity!(p, "axes", Any, EXACT, |m: Mat4, v: Vec4| {
+        let mut c = [m; 8];
+        c[0].set_x_axis(v);
+        c[1].set_y_axis(v);
+        c[2].set_z_axis(v);
+        c[3].set_w_axis(v);
+        *c[4].x_axis_mut() = v;
+        *c[5].y_axis_mut() = v;
+        *c[6].z_axis_mut() = v;
+        *c[7].w_axis_mut() = v;
+        (c, [m.x_axis(), m.y_axis(), m.z_axis(), m.w_axis()])
+    });
+    parity!(p, "transform3", Any, EXACT, |m: Mat4, v: Vec3| [
+        m.transform_point3(v),
+        m.transform_vector3(v)
+    ]);
+    parity!(p, "transform3a", Any, EXACT, |m: Mat4, v: Vec3A| [
+        m.transform_point3a(v),
+        m.transform_vector3a(v)
+    ]);
+    parity!(p, "as_ref", Any, EXACT, |m: Mat4, s: f32| {
+        let mut c = m;
+        c.as_mut()[15] = s;
+        [*m.as_ref(), *c.as_ref()]
+    });
+    p.finish();
+}
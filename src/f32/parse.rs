@@ -0,0 +1,321 @@
+use super::{
+    DualQuat, LinearRgba, Mat2, Mat3, Mat3A, Mat4, Quat, Rot2, SRgba, Vec2, Vec2Mask, Vec3, Vec3A,
+    Vec3AMask, Vec3Mask, Vec4, Vec4Mask,
+};
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "transform-types")]
+use super::{TransformRT, TransformRT2, TransformSRT, TransformSRT2};
+
+/// The kind of error that occurred while parsing a glam type from a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+    /// A `[` was expected.
+    ExpectedOpenBracket,
+    /// A `]` was expected, usually because there are too many elements.
+    ExpectedCloseBracket,
+    /// A number was expected but the element could not be parsed as an `f32`.
+    InvalidFloat,
+    /// A `true` or `false` was expected.
+    InvalidBool,
+    /// There are unexpected characters after the end of the value.
+    TrailingCharacters,
+    /// A hexadecimal color code contains a non hexadecimal digit or has the
+    /// wrong number of digits.
+    InvalidHex,
+}
+
+/// An error returned when parsing a glam type from a string fails.
+///
+/// The error contains the kind of error and the byte offset in the input
+/// string at which it occurred.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    position: usize,
+}
+
+impl ParseError {
+    /// Returns the kind of error.
+    #[inline]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset in the input string where the error occurred.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self.kind {
+            ParseErrorKind::ExpectedOpenBracket => "expected `[`",
+            ParseErrorKind::ExpectedCloseBracket => "expected `]`",
+            ParseErrorKind::InvalidFloat => "invalid float",
+            ParseErrorKind::InvalidBool => "expected `true` or `false`",
+            ParseErrorKind::TrailingCharacters => "trailing characters",
+            ParseErrorKind::InvalidHex => "invalid hex color",
+        };
+        write!(f, "{} at position {}", description, self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A minimal parser for the nested list format written by the glam `Display`
+/// implementations, e.g. `[[1, 0], [0, 1]]`.
+///
+/// Elements may be separated by a comma, whitespace or both and a trailing
+/// comma is permitted before a closing `]`.
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    #[inline]
+    fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    #[inline]
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            kind,
+            position: self.position,
+        }
+    }
+
+    #[inline]
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes `c` if it is the next non-whitespace character.
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(c) {
+            self.position += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn begin_list(&mut self) -> Result<(), ParseError> {
+        if self.eat('[') {
+            Ok(())
+        } else {
+            Err(self.error(ParseErrorKind::ExpectedOpenBracket))
+        }
+    }
+
+    /// Consumes an optional `,` separating list elements.
+    fn separator(&mut self) {
+        self.eat(',');
+    }
+
+    fn end_list(&mut self) -> Result<(), ParseError> {
+        self.separator();
+        if self.eat(']') {
+            Ok(())
+        } else {
+            Err(self.error(ParseErrorKind::ExpectedCloseBracket))
+        }
+    }
+
+    fn finish(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            Err(self.error(ParseErrorKind::TrailingCharacters))
+        }
+    }
+
+    /// Returns the next token, which ends at whitespace, a comma or a bracket.
+    fn token(&mut self) -> &'a str {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| c.is_whitespace() || c == ',' || c == '[' || c == ']')
+            .unwrap_or(rest.len());
+        &rest[..len]
+    }
+
+    fn parse_token<T: FromStr>(&mut self, kind: ParseErrorKind) -> Result<T, ParseError> {
+        let token = self.token();
+        let value = token.parse().map_err(|_| self.error(kind))?;
+        self.position += token.len();
+        Ok(value)
+    }
+}
+
+/// A value that can be read from a `Parser`.
+trait Parse: Sized {
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError>;
+}
+
+impl Parse for f32 {
+    #[inline]
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        parser.parse_token(ParseErrorKind::InvalidFloat)
+    }
+}
+
+impl Parse for bool {
+    #[inline]
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        parser.parse_token(ParseErrorKind::InvalidBool)
+    }
+}
+
+/// Parses a bracketed list of elements, each of which may be of a different
+/// type, and passes them to `$ctor`.
+macro_rules! parse_list {
+    ($parser:ident, $ctor:expr, $first:ty $(, $rest:ty)*) => {{
+        $parser.begin_list()?;
+        let first = <$first>::parse($parser)?;
+        let value = $ctor(first $(, {
+            $parser.separator();
+            <$rest>::parse($parser)?
+        })*);
+        $parser.end_list()?;
+        Ok(value)
+    }};
+}
+
+macro_rules! impl_parse {
+    ($t:ty, $ctor:expr, $($elem:ty),+) => {
+        impl Parse for $t {
+            #[inline]
+            fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+                parse_list!(parser, $ctor, $($elem),+)
+            }
+        }
+
+        impl FromStr for $t {
+            type Err = ParseError;
+            /// Parses the format written by `Display`.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut parser = Parser::new(s);
+                let value = <$t>::parse(&mut parser)?;
+                parser.finish()?;
+                Ok(value)
+            }
+        }
+    };
+}
+
+impl_parse!(Vec2, Vec2::new, f32, f32);
+impl_parse!(Vec3, Vec3::new, f32, f32, f32);
+impl_parse!(Vec3A, Vec3A::new, f32, f32, f32);
+impl_parse!(Vec4, Vec4::new, f32, f32, f32, f32);
+impl_parse!(Quat, Quat::from_xyzw, f32, f32, f32, f32);
+impl_parse!(Rot2, |c, s| Rot2::from(Vec2::new(c, s)), f32, f32);
+impl_parse!(Mat2, Mat2::from_cols, Vec2, Vec2);
+impl_parse!(Mat3, Mat3::from_cols, Vec3, Vec3, Vec3);
+impl_parse!(Mat3A, Mat3A::from_cols, Vec3A, Vec3A, Vec3A);
+impl_parse!(Mat4, Mat4::from_cols, Vec4, Vec4, Vec4, Vec4);
+impl_parse!(DualQuat, DualQuat::from_real_dual, Quat, Quat);
+impl_parse!(Vec2Mask, Vec2Mask::new, bool, bool);
+impl_parse!(Vec3Mask, Vec3Mask::new, bool, bool, bool);
+impl_parse!(Vec3AMask, Vec3AMask::new, bool, bool, bool);
+impl_parse!(Vec4Mask, Vec4Mask::new, bool, bool, bool, bool);
+impl_parse!(LinearRgba, LinearRgba::new, f32, f32, f32, f32);
+
+/// Parses a `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` hexadecimal color code,
+/// where the leading `#` is optional.
+pub(crate) fn parse_hex_rgba8(s: &str) -> Result<[u8; 4], ParseError> {
+    let start = if s.starts_with('#') { 1 } else { 0 };
+    let digits = &s[start..];
+    if let Some(i) = digits.find(|c: char| !c.is_ascii_hexdigit()) {
+        return Err(ParseError {
+            kind: ParseErrorKind::InvalidHex,
+            position: start + i,
+        });
+    }
+    // all characters are ASCII so each digit is one byte
+    let digit = |i: usize| u8::from_str_radix(&digits[i..i + 1], 16).unwrap();
+    let byte = |i: usize| digit(2 * i) << 4 | digit(2 * i + 1);
+    match digits.len() {
+        3 => Ok([digit(0) * 17, digit(1) * 17, digit(2) * 17, 255]),
+        4 => Ok([digit(0) * 17, digit(1) * 17, digit(2) * 17, digit(3) * 17]),
+        6 => Ok([byte(0), byte(1), byte(2), 255]),
+        8 => Ok([byte(0), byte(1), byte(2), byte(3)]),
+        _ => Err(ParseError {
+            kind: ParseErrorKind::InvalidHex,
+            position: s.len(),
+        }),
+    }
+}
+
+impl Parse for SRgba {
+    #[inline]
+    fn parse(parser: &mut Parser) -> Result<Self, ParseError> {
+        parse_list!(parser, SRgba::new, f32, f32, f32, f32)
+    }
+}
+
+impl FromStr for SRgba {
+    type Err = ParseError;
+    /// Parses the format written by `Display` or a hexadecimal color code as
+    /// accepted by `SRgba::from_hex`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.starts_with('[') {
+            let mut parser = Parser::new(s);
+            let value = SRgba::parse(&mut parser)?;
+            parser.finish()?;
+            Ok(value)
+        } else {
+            let offset = s.len() - s.trim_start().len();
+            SRgba::from_hex(trimmed).map_err(|e| ParseError {
+                kind: e.kind,
+                position: offset + e.position,
+            })
+        }
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl_parse!(
+    TransformRT,
+    TransformRT::from_rotation_translation,
+    Quat,
+    Vec3
+);
+
+#[cfg(feature = "transform-types")]
+impl_parse!(
+    TransformSRT,
+    TransformSRT::from_scale_rotation_translation,
+    Vec3,
+    Quat,
+    Vec3
+);
+
+#[cfg(feature = "transform-types")]
+impl_parse!(
+    TransformRT2,
+    TransformRT2::from_rotation_translation,
+    Rot2,
+    Vec2
+);
+
+#[cfg(feature = "transform-types")]
+impl_parse!(
+    TransformSRT2,
+    TransformSRT2::from_scale_rotation_translation,
+    Vec2,
+    Rot2,
+    Vec2
+);
@@ -0,0 +1,432 @@
+//! Robust geometric predicates which always return the correct sign.
+//!
+//! Mesh booleans, convex hulls and Delaunay triangulations make decisions
+//! based on the sign of small determinants. Evaluated directly in floating
+//! point the sign can be wrong for nearly degenerate inputs, which makes these
+//! algorithms inconsistent and can make them fail entirely.
+//!
+//! These predicates use Shewchuk's adaptive precision approach, see "Adaptive
+//! Precision Floating-Point Arithmetic and Fast Robust Geometric Predicates".
+//! Each determinant is first evaluated in `f32` using `Vec3A::cross` and
+//! `Vec3A::dot`, which is accepted if it is larger than a bound on its
+//! rounding error. Otherwise it is evaluated in `f64` with the same test, and
+//! if that fails too it is evaluated exactly using expansion arithmetic.
+//!
+//! The product of two `f32` values is exact in `f64`, and none of these
+//! determinants can overflow or underflow `f64` for finite `f32` inputs, so
+//! the result is correct for all finite inputs. The result is returned as an
+//! `f64` for the same reason, it can be too large or too small for an `f32`.
+//!
+//! ```
+//! use glam::predicates::orient2d;
+//! use glam::Vec2;
+//! let a = Vec2::new(0.1, 0.1);
+//! let b = Vec2::new(0.2, 0.2);
+//! // c is the next f32 after 0.3 so it is slightly left of the line
+//! let c = Vec2::new(0.3, f32::from_bits(0.3_f32.to_bits() + 1));
+//! assert!(orient2d(a, b, c) > 0.0);
+//! assert_eq!(orient2d(a, b, Vec2::new(0.3, 0.3)), 0.0);
+//! ```
+
+use super::{Vec2, Vec3, Vec3A, Vec4};
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Half an `f32` ulp of one, the relative error of an `f32` operation.
+const EPSILON_F32: f32 = f32::EPSILON / 2.0;
+/// Half an `f64` ulp of one, the relative error of an `f64` operation.
+const EPSILON_F64: f64 = f64::EPSILON / 2.0;
+
+// Shewchuk's bounds on the error of each determinant relative to its
+// permanent, the same determinant with the absolute values of each product.
+const ORIENT2D_BOUND_F32: f32 = (3.0 + 16.0 * EPSILON_F32) * EPSILON_F32;
+const ORIENT2D_BOUND_F64: f64 = (3.0 + 16.0 * EPSILON_F64) * EPSILON_F64;
+const ORIENT3D_BOUND_F32: f32 = (7.0 + 56.0 * EPSILON_F32) * EPSILON_F32;
+const ORIENT3D_BOUND_F64: f64 = (7.0 + 56.0 * EPSILON_F64) * EPSILON_F64;
+const INCIRCLE_BOUND_F32: f32 = (10.0 + 96.0 * EPSILON_F32) * EPSILON_F32;
+const INCIRCLE_BOUND_F64: f64 = (10.0 + 96.0 * EPSILON_F64) * EPSILON_F64;
+const INSPHERE_BOUND_F32: f32 = (16.0 + 224.0 * EPSILON_F32) * EPSILON_F32;
+const INSPHERE_BOUND_F64: f64 = (16.0 + 224.0 * EPSILON_F64) * EPSILON_F64;
+
+/// Non-zero differences smaller than this skip the `f32` filter. Above it no
+/// intermediate value can underflow, which the error bounds don't account for.
+const MIN_F32_DIFFERENCE: f32 = 1.0 / 65536.0;
+
+/// `2^27 + 1`, used to split an `f64` into two halves of 26 bits.
+const SPLITTER: f64 = 134_217_729.0;
+
+/// Returns a positive value if `a`, `b` and `c` are in counterclockwise order,
+/// a negative value if they are in clockwise order and zero if they are
+/// collinear.
+///
+/// The result is approximately twice the signed area of the triangle. Its sign
+/// is always correct for finite inputs.
+pub fn orient2d(a: Vec2, b: Vec2, c: Vec2) -> f64 {
+    let ac = a - c;
+    let bc = b - c;
+    let det = ac.perp_dot(bc);
+    let permanent = (ac.x() * bc.y()).abs() + (ac.y() * bc.x()).abs();
+    let differences = Vec4::new(ac.x(), ac.y(), bc.x(), bc.y());
+    if det.abs() > ORIENT2D_BOUND_F32 * permanent && filterable4(differences) {
+        return det as f64;
+    }
+
+    let [acx, acy] = diff2(a, c);
+    let [bcx, bcy] = diff2(b, c);
+    let det = acx * bcy - acy * bcx;
+    let permanent = (acx * bcy).abs() + (acy * bcx).abs();
+    if det.abs() > ORIENT2D_BOUND_F64 * permanent {
+        return det;
+    }
+
+    let [acx, acy] = exact2(a, c);
+    let [bcx, bcy] = exact2(b, c);
+    (&acx * &bcy - &acy * &bcx).estimate()
+}
+
+/// Returns a positive value if `d` lies below the plane through `a`, `b` and
+/// `c`, a negative value if it lies above and zero if the four points are
+/// coplanar. Below is the side from which `a`, `b` and `c` appear in
+/// clockwise order.
+///
+/// The result is approximately six times the signed volume of the
+/// tetrahedron, `(a - d).dot((b - d).cross(c - d))`. Its sign is always
+/// correct for finite inputs.
+pub fn orient3d(a: Vec3, b: Vec3, c: Vec3, d: Vec3) -> f64 {
+    let d3 = Vec3A::from(d);
+    let ad = Vec3A::from(a) - d3;
+    let bd = Vec3A::from(b) - d3;
+    let cd = Vec3A::from(c) - d3;
+    let det = ad.dot(bd.cross(cd));
+    let permanent = ad.abs().dot(abs_cross(bd, cd));
+    if det.abs() > ORIENT3D_BOUND_F32 * permanent
+        && filterable3(ad)
+        && filterable3(bd)
+        && filterable3(cd)
+    {
+        return det as f64;
+    }
+
+    let (ad, bd, cd) = (diff3(a, d), diff3(b, d), diff3(c, d));
+    let det = triple(ad, bd, cd);
+    let permanent = triple_permanent(ad, bd, cd);
+    if det.abs() > ORIENT3D_BOUND_F64 * permanent {
+        return det;
+    }
+
+    let (ad, bd, cd) = (exact3(a, d), exact3(b, d), exact3(c, d));
+    exact_triple(&ad, &bd, &cd).estimate()
+}
+
+/// Returns a positive value if `d` lies inside the circle through `a`, `b`
+/// and `c`, a negative value if it lies outside and zero if the four points
+/// are cocircular.
+///
+/// `a`, `b` and `c` must be in counterclockwise order, otherwise the sign is
+/// reversed. The sign is always correct for finite inputs.
+pub fn incircle(a: Vec2, b: Vec2, c: Vec2, d: Vec2) -> f64 {
+    // the determinant of the rows (x, y, x^2 + y^2) relative to d, evaluated
+    // as lift.dot(x.cross(y)) with a column in each vector
+    let x = Vec3A::new(a.x(), b.x(), c.x()) - Vec3A::splat(d.x());
+    let y = Vec3A::new(a.y(), b.y(), c.y()) - Vec3A::splat(d.y());
+    let lift = x * x + y * y;
+    let det = lift.dot(x.cross(y));
+    let permanent = lift.dot(abs_cross(x, y));
+    if det.abs() > INCIRCLE_BOUND_F32 * permanent && filterable3(x) && filterable3(y) {
+        return det as f64;
+    }
+
+    let [adx, ady] = diff2(a, d);
+    let [bdx, bdy] = diff2(b, d);
+    let [cdx, cdy] = diff2(c, d);
+    let x = [adx, bdx, cdx];
+    let y = [ady, bdy, cdy];
+    let lift = [
+        adx * adx + ady * ady,
+        bdx * bdx + bdy * bdy,
+        cdx * cdx + cdy * cdy,
+    ];
+    let det = triple(lift, x, y);
+    let permanent = triple_permanent(lift, x, y);
+    if det.abs() > INCIRCLE_BOUND_F64 * permanent {
+        return det;
+    }
+
+    let [adx, ady] = exact2(a, d);
+    let [bdx, bdy] = exact2(b, d);
+    let [cdx, cdy] = exact2(c, d);
+    let lift = [
+        &adx * &adx + &ady * &ady,
+        &bdx * &bdx + &bdy * &bdy,
+        &cdx * &cdx + &cdy * &cdy,
+    ];
+    exact_triple(&lift, &[adx, bdx, cdx], &[ady, bdy, cdy]).estimate()
+}
+
+/// Returns a positive value if `e` lies inside the sphere through `a`, `b`,
+/// `c` and `d`, a negative value if it lies outside and zero if the five
+/// points are cospherical.
+///
+/// `a`, `b`, `c` and `d` must be positively oriented as defined by
+/// `orient3d`, otherwise the sign is reversed. The sign is always correct for
+/// finite inputs.
+pub fn insphere(a: Vec3, b: Vec3, c: Vec3, d: Vec3, e: Vec3) -> f64 {
+    let e3 = Vec3A::from(e);
+    let ae = Vec3A::from(a) - e3;
+    let be = Vec3A::from(b) - e3;
+    let ce = Vec3A::from(c) - e3;
+    let de = Vec3A::from(d) - e3;
+    let (alift, blift, clift, dlift) = (ae.dot(ae), be.dot(be), ce.dot(ce), de.dot(de));
+    let abc = ae.dot(be.cross(ce));
+    let bcd = be.dot(ce.cross(de));
+    let cda = ce.dot(de.cross(ae));
+    let dab = de.dot(ae.cross(be));
+    let det = (dlift * abc - clift * dab) + (blift * cda - alift * bcd);
+    let (ae_abs, be_abs, ce_abs, de_abs) = (ae.abs(), be.abs(), ce.abs(), de.abs());
+    let permanent = (dlift * ae_abs.dot(abs_cross(be, ce)) + clift * de_abs.dot(abs_cross(ae, be)))
+        + (blift * ce_abs.dot(abs_cross(de, ae)) + alift * be_abs.dot(abs_cross(ce, de)));
+    if det.abs() > INSPHERE_BOUND_F32 * permanent
+        && filterable3(ae)
+        && filterable3(be)
+        && filterable3(ce)
+        && filterable3(de)
+    {
+        return det as f64;
+    }
+
+    let (ae, be, ce, de) = (diff3(a, e), diff3(b, e), diff3(c, e), diff3(d, e));
+    let lift = |v: [f64; 3]| v[0] * v[0] + v[1] * v[1] + v[2] * v[2];
+    let (alift, blift, clift, dlift) = (lift(ae), lift(be), lift(ce), lift(de));
+    let det = (dlift * triple(ae, be, ce) - clift * triple(de, ae, be))
+        + (blift * triple(ce, de, ae) - alift * triple(be, ce, de));
+    let permanent = (dlift * triple_permanent(ae, be, ce) + clift * triple_permanent(de, ae, be))
+        + (blift * triple_permanent(ce, de, ae) + alift * triple_permanent(be, ce, de));
+    if det.abs() > INSPHERE_BOUND_F64 * permanent {
+        return det;
+    }
+
+    let (ae, be, ce, de) = (exact3(a, e), exact3(b, e), exact3(c, e), exact3(d, e));
+    let lift = |v: &[Expansion; 3]| &v[0] * &v[0] + &v[1] * &v[1] + &v[2] * &v[2];
+    let (alift, blift, clift, dlift) = (lift(&ae), lift(&be), lift(&ce), lift(&de));
+    let det = (&dlift * &exact_triple(&ae, &be, &ce) - &clift * &exact_triple(&de, &ae, &be))
+        + (&blift * &exact_triple(&ce, &de, &ae) - &alift * &exact_triple(&be, &ce, &de));
+    det.estimate()
+}
+
+/// Returns true if no element of `v` is non-zero and smaller than
+/// `MIN_F32_DIFFERENCE`.
+#[inline]
+fn filterable3(v: Vec3A) -> bool {
+    (v.abs().cmpge(Vec3A::splat(MIN_F32_DIFFERENCE)) | v.cmpeq(Vec3A::zero())).all()
+}
+
+/// Returns true if no element of `v` is non-zero and smaller than
+/// `MIN_F32_DIFFERENCE`.
+#[inline]
+fn filterable4(v: Vec4) -> bool {
+    (v.abs().cmpge(Vec4::splat(MIN_F32_DIFFERENCE)) | v.cmpeq(Vec4::zero())).all()
+}
+
+/// The cross product of `a` and `b` with the absolute values of each product
+/// added together, for computing permanents.
+#[inline]
+fn abs_cross(a: Vec3A, b: Vec3A) -> Vec3A {
+    let (ax, ay, az) = a.abs().into();
+    let (bx, by, bz) = b.abs().into();
+    Vec3A::new(ay * bz + az * by, az * bx + ax * bz, ax * by + ay * bx)
+}
+
+#[inline]
+fn diff2(a: Vec2, b: Vec2) -> [f64; 2] {
+    [a.x() as f64 - b.x() as f64, a.y() as f64 - b.y() as f64]
+}
+
+#[inline]
+fn diff3(a: Vec3, b: Vec3) -> [f64; 3] {
+    [
+        a.x() as f64 - b.x() as f64,
+        a.y() as f64 - b.y() as f64,
+        a.z() as f64 - b.z() as f64,
+    ]
+}
+
+/// Returns `a.dot(b.cross(c))`.
+#[inline]
+fn triple(a: [f64; 3], b: [f64; 3], c: [f64; 3]) -> f64 {
+    a[0] * (b[1] * c[2] - b[2] * c[1])
+        + a[1] * (b[2] * c[0] - b[0] * c[2])
+        + a[2] * (b[0] * c[1] - b[1] * c[0])
+}
+
+/// Returns the permanent of `triple(a, b, c)`.
+#[inline]
+fn triple_permanent(a: [f64; 3], b: [f64; 3], c: [f64; 3]) -> f64 {
+    let [ax, ay, az] = [a[0].abs(), a[1].abs(), a[2].abs()];
+    let [bx, by, bz] = [b[0].abs(), b[1].abs(), b[2].abs()];
+    let [cx, cy, cz] = [c[0].abs(), c[1].abs(), c[2].abs()];
+    ax * (by * cz + bz * cy) + ay * (bz * cx + bx * cz) + az * (bx * cy + by * cx)
+}
+
+#[inline]
+fn exact2(a: Vec2, b: Vec2) -> [Expansion; 2] {
+    [Expansion::diff(a.x(), b.x()), Expansion::diff(a.y(), b.y())]
+}
+
+#[inline]
+fn exact3(a: Vec3, b: Vec3) -> [Expansion; 3] {
+    [
+        Expansion::diff(a.x(), b.x()),
+        Expansion::diff(a.y(), b.y()),
+        Expansion::diff(a.z(), b.z()),
+    ]
+}
+
+/// Returns `a.dot(b.cross(c))` exactly.
+fn exact_triple(a: &[Expansion; 3], b: &[Expansion; 3], c: &[Expansion; 3]) -> Expansion {
+    &a[0] * &(&b[1] * &c[2] - &b[2] * &c[1])
+        + &a[1] * &(&b[2] * &c[0] - &b[0] * &c[2])
+        + &a[2] * &(&b[0] * &c[1] - &b[1] * &c[0])
+}
+
+/// Returns `a + b` and the rounding error of the sum.
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    let b_roundoff = b - b_virtual;
+    let a_roundoff = a - a_virtual;
+    (x, a_roundoff + b_roundoff)
+}
+
+/// Splits `a` into two halves with at most 26 significant bits each.
+#[inline]
+fn split(a: f64) -> (f64, f64) {
+    let c = SPLITTER * a;
+    let a_big = c - a;
+    let a_hi = c - a_big;
+    (a_hi, a - a_hi)
+}
+
+/// Returns `a * b` and the rounding error of the product.
+#[inline]
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    let (a_hi, a_lo) = split(a);
+    let (b_hi, b_lo) = split(b);
+    let err1 = x - a_hi * b_hi;
+    let err2 = err1 - a_lo * b_hi;
+    let err3 = err2 - a_hi * b_lo;
+    (x, a_lo * b_lo - err3)
+}
+
+/// An exact value, represented as a sum of non-overlapping `f64` components
+/// in increasing order of magnitude with no zero components.
+#[derive(Clone, Debug)]
+struct Expansion(Vec<f64>);
+
+impl Expansion {
+    /// Returns `a - b` exactly.
+    fn diff(a: f32, b: f32) -> Self {
+        let (x, y) = two_sum(a as f64, -(b as f64));
+        Self([y, x].iter().copied().filter(|&c| c != 0.0).collect())
+    }
+
+    /// Returns `self * b` exactly, Shewchuk's `scale_expansion_zeroelim`.
+    fn scale(&self, b: f64) -> Self {
+        let mut h = Vec::with_capacity(self.0.len() * 2);
+        let mut components = self.0.iter();
+        let (mut q, low) = match components.next() {
+            Some(&e) => two_product(e, b),
+            None => return Self(h),
+        };
+        if low != 0.0 {
+            h.push(low);
+        }
+        for &e in components {
+            let (product, product_low) = two_product(e, b);
+            let (sum, sum_low) = two_sum(q, product_low);
+            if sum_low != 0.0 {
+                h.push(sum_low);
+            }
+            let (new_q, low) = two_sum(product, sum);
+            if low != 0.0 {
+                h.push(low);
+            }
+            q = new_q;
+        }
+        if q != 0.0 {
+            h.push(q);
+        }
+        Self(h)
+    }
+
+    /// Returns an approximation of the value with the correct sign.
+    fn estimate(&self) -> f64 {
+        self.0.iter().sum()
+    }
+}
+
+impl Add for Expansion {
+    type Output = Self;
+    /// Returns `self + other` exactly, Shewchuk's `linear_expansion_sum_zeroelim`
+    /// written as a merge followed by a single pass of two sums.
+    fn add(self, other: Self) -> Self {
+        // merge the components in increasing order of magnitude
+        let mut merged = Vec::with_capacity(self.0.len() + other.0.len());
+        let (mut i, mut j) = (0, 0);
+        while i < self.0.len() && j < other.0.len() {
+            if self.0[i].abs() < other.0[j].abs() {
+                merged.push(self.0[i]);
+                i += 1;
+            } else {
+                merged.push(other.0[j]);
+                j += 1;
+            }
+        }
+        merged.extend_from_slice(&self.0[i..]);
+        merged.extend_from_slice(&other.0[j..]);
+
+        // Shewchuk's grow-expansion pass, which is exact for any sequence of
+        // components in increasing order of magnitude
+        let mut h = Vec::with_capacity(merged.len());
+        let mut q = match merged.first() {
+            Some(&q) => q,
+            None => return Self(h),
+        };
+        for &g in &merged[1..] {
+            let (sum, low) = two_sum(q, g);
+            if low != 0.0 {
+                h.push(low);
+            }
+            q = sum;
+        }
+        if q != 0.0 {
+            h.push(q);
+        }
+        Self(h)
+    }
+}
+
+impl Neg for Expansion {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(self.0.iter().map(|&c| -c).collect())
+    }
+}
+
+impl Sub for Expansion {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<'a> Mul<&'a Expansion> for &'a Expansion {
+    type Output = Expansion;
+    fn mul(self, other: &Expansion) -> Expansion {
+        other
+            .0
+            .iter()
+            .fold(Expansion(Vec::new()), |sum, &b| sum + self.scale(b))
+    }
+}
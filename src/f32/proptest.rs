@@ -0,0 +1,250 @@
+//! `proptest` strategies for glam types.
+//!
+//! Vector and matrix strategies take the range of every element, while the
+//! strategies for rotations and the matrix subsets generate values that are
+//! valid for the functions which require them, such as normalized
+//! quaternions for `Quat::slerp` and invertible matrices for `inverse`.
+//! Scale ranges give the magnitude of each scale, which must be positive.
+//!
+//! ```
+//! use glam::proptest::{invertible_mat4, quat, vec3};
+//! use glam::Mat4;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn inverse_is_identity(m in invertible_mat4(0.5..2.0)) {
+//!         prop_assert!((m * m.inverse()).abs_diff_eq(Mat4::identity(), 1e-4));
+//!     }
+//!
+//!     fn rotation_preserves_length(q in quat(), v in vec3(-10.0..10.0)) {
+//!         prop_assert!(((q * v).length() - v.length()).abs() < 1e-4);
+//!     }
+//! }
+//! # inverse_is_identity();
+//! # rotation_preserves_length();
+//! ```
+
+use super::{DualQuat, Mat2, Mat3, Mat3A, Mat4, Quat, Rot2, Vec2, Vec3, Vec3A, Vec4};
+use crate::sampling::{cube_to_rotation, square_to_sphere};
+use core::f32::consts::PI;
+use core::ops::Range;
+use ::proptest::prelude::*;
+
+#[cfg(feature = "transform-types")]
+use super::{TransformRT, TransformRT2, TransformSRT, TransformSRT2};
+
+/// Generates a `Vec2` with elements in `range`.
+pub fn vec2(range: Range<f32>) -> impl Strategy<Value = Vec2> {
+    (range.clone(), range).prop_map(Vec2::from)
+}
+
+/// Generates a `Vec3` with elements in `range`.
+pub fn vec3(range: Range<f32>) -> impl Strategy<Value = Vec3> {
+    (range.clone(), range.clone(), range).prop_map(Vec3::from)
+}
+
+/// Generates a `Vec3A` with elements in `range`.
+pub fn vec3a(range: Range<f32>) -> impl Strategy<Value = Vec3A> {
+    vec3(range).prop_map(Vec3A::from)
+}
+
+/// Generates a `Vec4` with elements in `range`.
+pub fn vec4(range: Range<f32>) -> impl Strategy<Value = Vec4> {
+    (range.clone(), range.clone(), range.clone(), range).prop_map(Vec4::from)
+}
+
+/// Generates a normalized `Vec2` uniformly distributed on the unit circle.
+pub fn unit_vec2() -> impl Strategy<Value = Vec2> {
+    (-PI..PI).prop_map(|angle| {
+        let (sin, cos) = angle.sin_cos();
+        Vec2::new(cos, sin)
+    })
+}
+
+/// Generates a normalized `Vec3` uniformly distributed on the unit sphere.
+pub fn unit_vec3() -> impl Strategy<Value = Vec3> {
+    vec2(0.0..1.0).prop_map(square_to_sphere)
+}
+
+/// Generates a normalized `Quat` uniformly distributed over all rotations.
+pub fn quat() -> impl Strategy<Value = Quat> {
+    vec3(0.0..1.0).prop_map(cube_to_rotation)
+}
+
+/// Generates a `Rot2` uniformly distributed over all rotations.
+pub fn rot2() -> impl Strategy<Value = Rot2> {
+    (-PI..PI).prop_map(Rot2::from_angle)
+}
+
+/// Generates a `Mat2` with elements in `range`.
+pub fn mat2(range: Range<f32>) -> impl Strategy<Value = Mat2> {
+    (vec2(range.clone()), vec2(range)).prop_map(|(x, y)| Mat2::from_cols(x, y))
+}
+
+/// Generates a `Mat3` with elements in `range`.
+pub fn mat3(range: Range<f32>) -> impl Strategy<Value = Mat3> {
+    (vec3(range.clone()), vec3(range.clone()), vec3(range))
+        .prop_map(|(x, y, z)| Mat3::from_cols(x, y, z))
+}
+
+/// Generates a `Mat3A` with elements in `range`.
+pub fn mat3a(range: Range<f32>) -> impl Strategy<Value = Mat3A> {
+    mat3(range).prop_map(Mat3A::from)
+}
+
+/// Generates a `Mat4` with elements in `range`.
+pub fn mat4(range: Range<f32>) -> impl Strategy<Value = Mat4> {
+    (
+        vec4(range.clone()),
+        vec4(range.clone()),
+        vec4(range.clone()),
+        vec4(range),
+    )
+        .prop_map(|(x, y, z, w)| Mat4::from_cols(x, y, z, w))
+}
+
+/// Generates a `Mat2` with orthonormal columns, a uniformly distributed
+/// rotation.
+pub fn orthonormal_mat2() -> impl Strategy<Value = Mat2> {
+    (-PI..PI).prop_map(Mat2::from_angle)
+}
+
+/// Generates a `Mat3` with orthonormal columns, a uniformly distributed
+/// rotation.
+pub fn orthonormal_mat3() -> impl Strategy<Value = Mat3> {
+    quat().prop_map(Mat3::from_quat)
+}
+
+/// Generates a `Mat3A` with orthonormal columns, a uniformly distributed
+/// rotation.
+pub fn orthonormal_mat3a() -> impl Strategy<Value = Mat3A> {
+    quat().prop_map(Mat3A::from_quat)
+}
+
+/// Generates a `Mat4` with orthonormal columns, a uniformly distributed
+/// rotation.
+pub fn orthonormal_mat4() -> impl Strategy<Value = Mat4> {
+    quat().prop_map(Mat4::from_quat)
+}
+
+/// Generates a scale with a magnitude in `range` and a random sign.
+fn signed_scale(range: Range<f32>) -> impl Strategy<Value = f32> {
+    (range, any::<bool>()).prop_map(|(scale, negate)| if negate { -scale } else { scale })
+}
+
+/// Generates an invertible `Mat2` as the product of two rotations and a scale
+/// with a magnitude in `scale` on each axis.
+///
+/// The condition number of the matrix is at most `scale.end / scale.start`.
+pub fn invertible_mat2(scale: Range<f32>) -> impl Strategy<Value = Mat2> {
+    (
+        orthonormal_mat2(),
+        signed_scale(scale.clone()),
+        signed_scale(scale),
+        orthonormal_mat2(),
+    )
+        .prop_map(|(a, x, y, b)| a * Mat2::from_scale(Vec2::new(x, y)) * b)
+}
+
+/// Generates an invertible `Mat3` as the product of two rotations and a scale
+/// with a magnitude in `scale` on each axis.
+///
+/// The condition number of the matrix is at most `scale.end / scale.start`.
+pub fn invertible_mat3(scale: Range<f32>) -> impl Strategy<Value = Mat3> {
+    (
+        orthonormal_mat3(),
+        signed_scale(scale.clone()),
+        signed_scale(scale.clone()),
+        signed_scale(scale),
+        orthonormal_mat3(),
+    )
+        .prop_map(|(a, x, y, z, b)| a * Mat3::from_scale(Vec3::new(x, y, z)) * b)
+}
+
+/// Generates an invertible `Mat3A` as the product of two rotations and a
+/// scale with a magnitude in `scale` on each axis.
+///
+/// The condition number of the matrix is at most `scale.end / scale.start`.
+pub fn invertible_mat3a(scale: Range<f32>) -> impl Strategy<Value = Mat3A> {
+    invertible_mat3(scale).prop_map(Mat3A::from)
+}
+
+/// Generates an invertible `Mat4` as the product of two rotations and a scale
+/// with a magnitude in `scale` on each axis.
+///
+/// The condition number of the matrix is at most `scale.end / scale.start`.
+pub fn invertible_mat4(scale: Range<f32>) -> impl Strategy<Value = Mat4> {
+    (
+        orthonormal_mat4(),
+        signed_scale(scale.clone()),
+        signed_scale(scale.clone()),
+        signed_scale(scale.clone()),
+        signed_scale(scale),
+        orthonormal_mat4(),
+    )
+        .prop_map(|(a, x, y, z, w, b)| {
+            let diagonal = Mat4::from_cols(
+                Vec4::unit_x() * x,
+                Vec4::unit_y() * y,
+                Vec4::unit_z() * z,
+                Vec4::unit_w() * w,
+            );
+            a * diagonal * b
+        })
+}
+
+/// Generates a 2D affine transformation `Mat3` from a positive scale in
+/// `scale`, a rotation and a translation with elements in `translation`.
+pub fn affine_mat3(scale: Range<f32>, translation: Range<f32>) -> impl Strategy<Value = Mat3> {
+    (vec2(scale), -PI..PI, vec2(translation))
+        .prop_map(|(s, angle, t)| Mat3::from_scale_angle_translation(s, angle, t))
+}
+
+/// Generates a 3D affine transformation `Mat4` from a positive scale in
+/// `scale`, a rotation and a translation with elements in `translation`.
+pub fn affine_mat4(scale: Range<f32>, translation: Range<f32>) -> impl Strategy<Value = Mat4> {
+    (vec3(scale), quat(), vec3(translation))
+        .prop_map(|(s, r, t)| Mat4::from_scale_rotation_translation(s, r, t))
+}
+
+/// Generates a `DualQuat` from a rotation and a translation with elements in
+/// `translation`.
+pub fn dual_quat(translation: Range<f32>) -> impl Strategy<Value = DualQuat> {
+    (quat(), vec3(translation)).prop_map(|(r, t)| DualQuat::from_rotation_translation(r, t))
+}
+
+/// Generates a `TransformRT` from a rotation and a translation with elements
+/// in `translation`.
+#[cfg(feature = "transform-types")]
+pub fn transform_rt(translation: Range<f32>) -> impl Strategy<Value = TransformRT> {
+    (quat(), vec3(translation)).prop_map(|(r, t)| TransformRT::from_rotation_translation(r, t))
+}
+
+/// Generates a `TransformSRT` from a positive scale in `scale`, a rotation and
+/// a translation with elements in `translation`.
+#[cfg(feature = "transform-types")]
+pub fn transform_srt(
+    scale: Range<f32>,
+    translation: Range<f32>,
+) -> impl Strategy<Value = TransformSRT> {
+    (vec3(scale), quat(), vec3(translation))
+        .prop_map(|(s, r, t)| TransformSRT::from_scale_rotation_translation(s, r, t))
+}
+
+/// Generates a `TransformRT2` from a rotation and a translation with elements
+/// in `translation`.
+#[cfg(feature = "transform-types")]
+pub fn transform_rt2(translation: Range<f32>) -> impl Strategy<Value = TransformRT2> {
+    (rot2(), vec2(translation)).prop_map(|(r, t)| TransformRT2::from_rotation_translation(r, t))
+}
+
+/// Generates a `TransformSRT2` from a positive scale in `scale`, a rotation
+/// and a translation with elements in `translation`.
+#[cfg(feature = "transform-types")]
+pub fn transform_srt2(
+    scale: Range<f32>,
+    translation: Range<f32>,
+) -> impl Strategy<Value = TransformSRT2> {
+    (vec2(scale), rot2(), vec2(translation))
+        .prop_map(|(s, r, t)| TransformSRT2::from_scale_rotation_translation(s, r, t))
+}
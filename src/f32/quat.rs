@@ -1,7 +1,9 @@
-use super::{scalar_acos, scalar_sin_cos, Mat3, Mat4, Vec3, Vec4};
-#[cfg(all(vec4sse2, target_arch = "x86",))]
+// Generated from quat.rs.tera template. Edit the template, not the generated file.
+
+use super::{scalar_acos, scalar_sin_cos, Mat3, Mat4, Radians, Vec3, Vec3A, Vec4};
+#[cfg(all(vec4sse2, target_arch = "x86"))]
 use core::arch::x86::*;
-#[cfg(all(vec4sse2, target_arch = "x86_64",))]
+#[cfg(all(vec4sse2, target_arch = "x86_64"))]
 use core::arch::x86_64::*;
 use core::{
     cmp::Ordering,
@@ -21,11 +23,14 @@ use core::{
 pub struct Quat(pub(crate) Vec4);
 
 #[inline]
-pub fn quat(x: f32, y: f32, z: f32, w: f32) -> Quat {
+pub const fn quat(x: f32, y: f32, z: f32, w: f32) -> Quat {
     Quat::from_xyzw(x, y, z, w)
 }
 
 impl Quat {
+    /// The identity quaternion, representing no rotation.
+    pub const IDENTITY: Self = Self::identity();
+
     /// Creates a new rotation quaternion.
     ///
     /// This should generally not be called manually unless you know what you are doing. Use one of
@@ -33,12 +38,12 @@ impl Quat {
     ///
     /// `from_xyzw` is mostly used by unit tests and `serde` deserialization.
     #[inline]
-    pub fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Self {
+    pub const fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Self {
         Self(Vec4::new(x, y, z, w))
     }
 
     #[inline]
-    pub fn identity() -> Self {
+    pub const fn identity() -> Self {
         Self(Vec4::new(0.0, 0.0, 0.0, 1.0))
     }
 
@@ -71,7 +76,8 @@ impl Quat {
     /// Create a new quaterion for a normalized rotation axis and angle
     /// (in radians).
     #[inline]
-    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
+    pub fn from_axis_angle(axis: Vec3, angle: impl Into<Radians>) -> Self {
+        let angle = angle.into().0;
         glam_assert!(axis.is_normalized());
         let (s, c) = scalar_sin_cos(angle * 0.5);
         Self((axis * s).extend(c))
@@ -79,29 +85,39 @@ impl Quat {
 
     /// Creates a new quaternion from the angle (in radians) around the x axis.
     #[inline]
-    pub fn from_rotation_x(angle: f32) -> Self {
+    pub fn from_rotation_x(angle: impl Into<Radians>) -> Self {
+        let angle = angle.into().0;
         let (s, c) = scalar_sin_cos(angle * 0.5);
         Self::from_xyzw(s, 0.0, 0.0, c)
     }
 
     /// Creates a new quaternion from the angle (in radians) around the y axis.
     #[inline]
-    pub fn from_rotation_y(angle: f32) -> Self {
+    pub fn from_rotation_y(angle: impl Into<Radians>) -> Self {
+        let angle = angle.into().0;
         let (s, c) = scalar_sin_cos(angle * 0.5);
         Self::from_xyzw(0.0, s, 0.0, c)
     }
 
     /// Creates a new quaternion from the angle (in radians) around the z axis.
     #[inline]
-    pub fn from_rotation_z(angle: f32) -> Self {
+    pub fn from_rotation_z(angle: impl Into<Radians>) -> Self {
+        let angle = angle.into().0;
         let (s, c) = scalar_sin_cos(angle * 0.5);
         Self::from_xyzw(0.0, 0.0, s, c)
     }
 
-    #[inline]
     /// Create a quaternion from the given yaw (around y), pitch (around x) and roll (around z)
     /// in radians.
-    pub fn from_rotation_ypr(yaw: f32, pitch: f32, roll: f32) -> Self {
+    #[inline]
+    pub fn from_rotation_ypr(
+        yaw: impl Into<Radians>,
+        pitch: impl Into<Radians>,
+        roll: impl Into<Radians>,
+    ) -> Self {
+        let yaw = yaw.into().0;
+        let pitch = pitch.into().0;
+        let roll = roll.into().0;
         // Self::from_rotation_y(yaw) * Self::from_rotation_x(pitch) * Self::from_rotation_z(roll)
         let (y0, w0) = scalar_sin_cos(yaw * 0.5);
         let (x1, w1) = scalar_sin_cos(pitch * 0.5);
@@ -277,6 +293,7 @@ impl Quat {
         is_normalized!(self)
     }
 
+    /// Returns `true` if the rotation angle of `self` is close to zero.
     #[inline]
     pub fn is_near_identity(self) -> bool {
         // from rtm quat_near_identity
@@ -345,7 +362,8 @@ impl Quat {
             let start = self.0;
             let end = end.0;
             let dot = start.dot(end);
-            let bias = if dot >= 0.0 { 1.0 } else { -1.0 };
+            // flip on the sign bit like SSE2, so a dot product of -0.0 flips too
+            let bias = if dot.is_sign_negative() { -1.0 } else { 1.0 };
             let interpolated = start + (s * ((end * bias) - start));
             Self(interpolated.normalize())
         }
@@ -378,18 +396,7 @@ impl Quat {
             // assumes lerp returns a normalized quaternion
             self.lerp(end, s)
         } else {
-            #[cfg(vec4f32)]
-            {
-                // assumes scalar_acos clamps the input to [-1.0, 1.0]
-                let theta = crate::f32::funcs::scalar_acos(dot);
-                let scale1 = f32::sin(theta * (1.0 - s));
-                let scale2 = f32::sin(theta * s);
-                let theta_sin = f32::sin(theta);
-
-                Quat((self.0 * scale1 + end.0 * scale2) * theta_sin.recip())
-            }
-
-            #[cfg(vec4sse2)]
+            #[cfg(all(vec4sse2, not(feature = "deterministic")))]
             {
                 // assumes scalar_acos clamps the input to [-1.0, 1.0]
                 let theta = crate::f32::funcs::scalar_acos(dot);
@@ -411,19 +418,38 @@ impl Quat {
                     Quat((self.0 * Vec4(scale1) + end.0 * Vec4(scale2)) * theta_sin_recip)
                 }
             }
+
+            // the approximate sine and reciprocal above differ between CPUs
+            #[cfg(any(vec4f32, feature = "deterministic"))]
+            {
+                use crate::f32::funcs::{scalar_acos, scalar_sin};
+                // assumes scalar_acos clamps the input to [-1.0, 1.0]
+                let theta = scalar_acos(dot);
+                let scale1 = scalar_sin(theta * (1.0 - s));
+                let scale2 = scalar_sin(theta * s);
+                let theta_sin = scalar_sin(theta);
+
+                Quat((self.0 * scale1 + end.0 * scale2) * theta_sin.recip())
+            }
         }
     }
 
-    #[inline]
     /// Multiplies a quaternion and a 3D vector, rotating it.
+    #[inline]
     pub fn mul_vec3(self, other: Vec3) -> Vec3 {
+        self.mul_vec3a(other.into()).into()
+    }
+
+    /// Multiplies a quaternion and a 16 byte aligned 3D vector, rotating it.
+    #[inline]
+    pub fn mul_vec3a(self, other: Vec3A) -> Vec3A {
         glam_assert!(self.is_normalized());
 
         #[cfg(vec4sse2)]
         {
-            let w = self.0.dup_w().truncate();
-            let two = Vec3::splat(2.0);
-            let b = self.0.truncate();
+            let w = Vec3A::from(self.0.dup_w());
+            let two = Vec3A::splat(2.0);
+            let b = Vec3A::from(self.0);
             let b2 = b.dot_as_vec3(b);
             other * (w * w - b2) + b * (other.dot_as_vec3(b) * two) + b.cross(other) * (w * two)
         }
@@ -431,19 +457,24 @@ impl Quat {
         #[cfg(vec4f32)]
         {
             let w = self.0.w();
-            let b = self.0.truncate();
+            let b = Vec3A::from(self.0);
             let b2 = b.dot(b);
             other * (w * w - b2) + b * (other.dot(b) * 2.0) + b.cross(other) * (w * 2.0)
         }
     }
 
-    #[inline]
     /// Multiplies two quaternions.
     /// Note that due to floating point rounding the result may not be perfectly normalized.
+    #[inline]
     pub fn mul_quat(self, other: Self) -> Self {
         glam_assert!(self.is_normalized());
         glam_assert!(other.is_normalized());
+        self.mul_quat_unchecked(other)
+    }
 
+    /// Multiplies two quaternions without requiring them to be normalized.
+    #[inline]
+    pub(crate) fn mul_quat_unchecked(self, other: Self) -> Self {
         #[cfg(vec4sse2)]
         unsafe {
             // from rtm quat_mul
@@ -484,14 +515,16 @@ impl Quat {
         {
             let (x0, y0, z0, w0) = self.0.into();
             let (x1, y1, z1, w1) = other.0.into();
+            // summed pairwise in the same order as SSE2 so both give the same result
             Self::from_xyzw(
-                w0 * x1 + x0 * w1 + y0 * z1 - z0 * y1,
-                w0 * y1 - x0 * z1 + y0 * w1 + z0 * x1,
-                w0 * z1 + x0 * y1 - y0 * x1 + z0 * w1,
-                w0 * w1 - x0 * x1 - y0 * y1 - z0 * z1,
+                (w0 * x1 + x0 * w1) + (y0 * z1 - z0 * y1),
+                (w0 * y1 - x0 * z1) + (y0 * w1 + z0 * x1),
+                (w0 * z1 + x0 * y1) + (z0 * w1 - y0 * x1),
+                (w0 * w1 - x0 * x1) - (y0 * y1 + z0 * z1),
             )
         }
     }
+
     /// Returns element `x`.
     #[inline]
     pub fn x(self) -> f32 {
@@ -566,6 +599,14 @@ impl Mul<Vec3> for Quat {
     }
 }
 
+impl Mul<Vec3A> for Quat {
+    type Output = Vec3A;
+    #[inline]
+    fn mul(self, other: Vec3A) -> Vec3A {
+        self.mul_vec3a(other)
+    }
+}
+
 impl Neg for Quat {
     type Output = Self;
     #[inline]
@@ -0,0 +1,287 @@
+use super::{scalar_atan2, scalar_sin_cos, Mat2, Radians, Vec2};
+use core::{
+    fmt,
+    ops::{Mul, MulAssign},
+};
+
+/// A 2D rotation stored as a unit complex number.
+///
+/// The real part `cos` is stored in `x` and the imaginary part `sin` is stored
+/// in `y`. Unlike a `Mat2` rotation there are only two values to keep
+/// normalized, so a `Rot2` can be renormalized cheaply after many successive
+/// multiplications.
+///
+/// This rotation is intended to be of unit length but may denormalize due to
+/// floating point "error creep" which can occur when successive rotations are
+/// applied.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[repr(C)]
+pub struct Rot2(pub(crate) Vec2);
+
+impl Rot2 {
+    /// The identity rotation.
+    pub const IDENTITY: Self = Self::identity();
+
+    /// Creates a rotation from the given `cos` and `sin` of an angle.
+    ///
+    /// # Preconditions
+    ///
+    /// `cos * cos + sin * sin` is expected to be `1.0`.
+    #[inline]
+    pub fn from_cos_sin(cos: f32, sin: f32) -> Self {
+        let r = Self(Vec2::new(cos, sin));
+        glam_assert!(r.is_normalized());
+        r
+    }
+
+    /// Creates an identity rotation.
+    #[inline]
+    pub const fn identity() -> Self {
+        Self(Vec2::new(1.0, 0.0))
+    }
+
+    /// Creates a rotation of `angle` (in radians).
+    #[inline]
+    pub fn from_angle(angle: impl Into<Radians>) -> Self {
+        let angle = angle.into().0;
+        let (sin, cos) = scalar_sin_cos(angle);
+        Self(Vec2::new(cos, sin))
+    }
+
+    /// Creates the shortest rotation that takes the normalized vector `from`
+    /// to the normalized vector `to`.
+    #[inline]
+    pub fn from_rotation_arc(from: Vec2, to: Vec2) -> Self {
+        glam_assert!(from.is_normalized());
+        glam_assert!(to.is_normalized());
+        Self(Vec2::new(from.dot(to), from.perp_dot(to))).normalize()
+    }
+
+    /// Creates a rotation from the rotation part of a 2x2 matrix.
+    ///
+    /// The matrix is expected to be a pure rotation, any scale or shear will
+    /// be ignored.
+    #[inline]
+    pub fn from_rotation_mat2(mat: &Mat2) -> Self {
+        Self(mat.x_axis()).normalize()
+    }
+
+    /// Returns the angle (in radians) of `self` in the range `[-PI, PI]`.
+    #[inline]
+    pub fn angle(self) -> f32 {
+        scalar_atan2(self.0.y(), self.0.x())
+    }
+
+    /// Returns the cosine of the rotation angle.
+    #[inline]
+    pub fn cos(self) -> f32 {
+        self.0.x()
+    }
+
+    /// Returns the sine of the rotation angle.
+    #[inline]
+    pub fn sin(self) -> f32 {
+        self.0.y()
+    }
+
+    /// Returns the complex conjugate of `self`. For a unit rotation the
+    /// conjugate is also the inverse.
+    #[inline]
+    pub fn conjugate(self) -> Self {
+        Self(Vec2::new(self.0.x(), -self.0.y()))
+    }
+
+    /// Returns the inverse of `self`, the rotation by the negated angle.
+    #[inline]
+    pub fn inverse(self) -> Self {
+        glam_assert!(self.is_normalized());
+        self.conjugate()
+    }
+
+    /// Computes the length of `self`.
+    #[inline]
+    pub fn length(self) -> f32 {
+        self.0.length()
+    }
+
+    /// Computes the squared length of `self`.
+    ///
+    /// This is generally faster than `Rot2::length()` as it avoids a square
+    /// root operation.
+    #[inline]
+    pub fn length_squared(self) -> f32 {
+        self.0.length_squared()
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        Self(self.0.normalize())
+    }
+
+    /// Returns whether `self` of length `1.0` or not.
+    ///
+    /// Uses a precision threshold of `1e-6`.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        is_normalized!(self)
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two `Rot2`'s contain similar elements. It
+    /// works best when comparing with a known value. The `max_abs_diff` that
+    /// should be used used depends on the values being compared against.
+    ///
+    /// For more on floating point comparisons see
+    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[inline]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f32) -> bool {
+        self.0.abs_diff_eq(other.0, max_abs_diff)
+    }
+
+    /// Performs a normalized linear interpolation between `self` and `end`
+    /// based on the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
+    /// is `1.0`, the result will be equal to `end`.
+    ///
+    /// The result is undefined if `self` and `end` are opposite rotations.
+    #[inline]
+    pub fn nlerp(self, end: Self, s: f32) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+        Self(self.0.lerp(end.0, s)).normalize()
+    }
+
+    /// Performs a spherical linear interpolation between `self` and `end`
+    /// based on the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
+    /// is `1.0`, the result will be equal to `end`.
+    ///
+    /// The interpolation always takes the shortest path between the two
+    /// rotations and rotates at a constant angular velocity.
+    #[inline]
+    pub fn slerp(self, end: Self, s: f32) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+        let delta = self.conjugate().mul_rot2(end).angle();
+        self.mul_rot2(Self::from_angle(delta * s))
+    }
+
+    /// Rotates a 2D vector by `self`.
+    #[inline]
+    pub fn mul_vec2(self, other: Vec2) -> Vec2 {
+        glam_assert!(self.is_normalized());
+        let (cos, sin) = self.0.into();
+        let (x, y) = other.into();
+        Vec2::new(cos * x - sin * y, sin * x + cos * y)
+    }
+
+    /// Multiplies two rotations, the result is the sum of both angles.
+    /// Note that due to floating point rounding the result may not be perfectly normalized.
+    #[inline]
+    pub fn mul_rot2(self, other: Self) -> Self {
+        let (c0, s0) = self.0.into();
+        let (c1, s1) = other.0.into();
+        Self(Vec2::new(c0 * c1 - s0 * s1, s0 * c1 + c0 * s1))
+    }
+}
+
+impl Default for Rot2 {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl fmt::Display for Rot2 {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let (cos, sin) = self.0.into();
+        write!(fmt, "[{}, {}]", cos, sin)
+    }
+}
+
+impl Mul<Rot2> for Rot2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_rot2(other)
+    }
+}
+
+impl MulAssign<Rot2> for Rot2 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.mul_rot2(other);
+    }
+}
+
+impl Mul<Vec2> for Rot2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, other: Vec2) -> Vec2 {
+        self.mul_vec2(other)
+    }
+}
+
+impl AsRef<[f32; 2]> for Rot2 {
+    #[inline]
+    fn as_ref(&self) -> &[f32; 2] {
+        self.0.as_ref()
+    }
+}
+
+impl From<Rot2> for Mat2 {
+    #[inline]
+    fn from(r: Rot2) -> Self {
+        let (cos, sin) = r.0.into();
+        Mat2::from_cols(Vec2::new(cos, sin), Vec2::new(-sin, cos))
+    }
+}
+
+impl From<Vec2> for Rot2 {
+    #[inline]
+    fn from(v: Vec2) -> Self {
+        Self(v)
+    }
+}
+
+impl From<Rot2> for Vec2 {
+    #[inline]
+    fn from(r: Rot2) -> Self {
+        r.0
+    }
+}
+
+impl From<(f32, f32)> for Rot2 {
+    #[inline]
+    fn from(t: (f32, f32)) -> Self {
+        Self(t.into())
+    }
+}
+
+impl From<Rot2> for (f32, f32) {
+    #[inline]
+    fn from(r: Rot2) -> Self {
+        r.0.into()
+    }
+}
+
+impl From<[f32; 2]> for Rot2 {
+    #[inline]
+    fn from(a: [f32; 2]) -> Self {
+        Self(a.into())
+    }
+}
+
+impl From<Rot2> for [f32; 2] {
+    #[inline]
+    fn from(r: Rot2) -> Self {
+        r.0.into()
+    }
+}
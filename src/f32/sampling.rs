@@ -0,0 +1,408 @@
+//! Low-discrepancy sequences and sample warping functions.
+//!
+//! Low-discrepancy (quasi-random) sequences cover a domain more evenly than
+//! uniform random samples, which reduces the variance of Monte Carlo
+//! integration and produces more even procedural placement. All sequences
+//! produce values in the range `[0, 1)` and are indexed, so any sample can be
+//! computed independently of the others.
+//!
+//! The `square_to_*` and `cube_to_*` functions map samples from the unit
+//! square or cube to other domains while preserving their distribution. They
+//! use the same conventions as the `rand` feature distributions, so
+//! `square_to_sphere(rng.gen())` and `rng.sample(UnitSphere)` are equivalent.
+//!
+//! ```
+//! use glam::{sampling, Vec3};
+//! let halton = sampling::Halton::new();
+//! let directions: Vec<Vec3> = (0..64)
+//!     .map(|i| sampling::square_to_sphere(halton.sample_vec2(i)))
+//!     .collect();
+//! assert!(directions.iter().all(|d| d.is_normalized()));
+//! ```
+
+use super::{hash_u32, u32_to_unit_f32, Quat, Vec2, Vec3};
+use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+/// The largest `f32` less than `1.0`.
+const ONE_MINUS_EPSILON: f32 = 0.999_999_94;
+
+/// Owen scrambles the bits of `x` using the hash based permutation from
+/// "Practical Hash-based Owen Scrambling", Burley 2020.
+#[inline]
+fn nested_uniform_scramble(x: u32, seed: u32) -> u32 {
+    let mut x = x.reverse_bits();
+    x = x.wrapping_add(seed);
+    x ^= x.wrapping_mul(0x6c50_b47c);
+    x ^= x.wrapping_mul(0xb82f_1e52);
+    x ^= x.wrapping_mul(0xc7af_e638);
+    x ^= x.wrapping_mul(0x8d22_f6e6);
+    x.reverse_bits()
+}
+
+/// Returns the radical inverse of `index` in base 2, also known as the van der
+/// Corput sequence.
+#[inline]
+pub fn radical_inverse_base2(index: u32) -> f32 {
+    u32_to_unit_f32(index.reverse_bits())
+}
+
+/// Returns the radical inverse of `index` in the given `base`, mirroring its
+/// digits around the decimal point.
+///
+/// If `scramble` is `Some` each digit is shifted by a random amount derived
+/// from the seed.
+fn radical_inverse_scrambled(base: u32, mut index: u32, scramble: Option<u32>) -> f32 {
+    glam_assert!(base >= 2);
+    let inv_base = 1.0 / f64::from(base);
+    let mut inv_base_n = 1.0;
+    let mut result = 0.0;
+    let mut digit_index = 0;
+    // when scrambling, leading zero digits are shifted as well, up to the
+    // precision of an f32
+    while index > 0 || (scramble.is_some() && inv_base_n > 1.0 / f64::from(1 << 24)) {
+        let mut digit = index % base;
+        if let Some(seed) = scramble {
+            let shift = hash_u32(seed ^ hash_u32(base << 8 | digit_index)) % base;
+            digit = (digit + shift) % base;
+        }
+        inv_base_n *= inv_base;
+        result += f64::from(digit) * inv_base_n;
+        index /= base;
+        digit_index += 1;
+    }
+    (result as f32).min(ONE_MINUS_EPSILON)
+}
+
+/// Returns the radical inverse of `index` in the given `base`, mirroring its
+/// digits around the decimal point.
+///
+/// `base` should be a prime number for use in a Halton sequence.
+#[inline]
+pub fn radical_inverse(base: u32, index: u32) -> f32 {
+    radical_inverse_scrambled(base, index, None)
+}
+
+/// The Halton sequence, using the bases 2, 3 and 5 for the x, y and z
+/// dimensions.
+///
+/// Scrambling with `Halton::scrambled` applies a random digit permutation
+/// which removes the correlation between dimensions that is visible in the
+/// unscrambled sequence, while keeping its low discrepancy.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Halton {
+    scramble: Option<u32>,
+}
+
+impl Halton {
+    /// Creates an unscrambled Halton sequence.
+    #[inline]
+    pub fn new() -> Self {
+        Self { scramble: None }
+    }
+
+    /// Creates a Halton sequence scrambled by the given `seed`.
+    #[inline]
+    pub fn scrambled(seed: u32) -> Self {
+        Self {
+            scramble: Some(seed),
+        }
+    }
+
+    /// Returns the value of the sequence at `index` in the given `dimension`,
+    /// where dimensions 0, 1 and 2 use the bases 2, 3 and 5.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimension` is greater than 2.
+    #[inline]
+    pub fn sample(&self, dimension: usize, index: u32) -> f32 {
+        const BASES: [u32; 3] = [2, 3, 5];
+        let base = BASES[dimension];
+        let scramble = self.scramble.map(|seed| hash_u32(seed) ^ base);
+        radical_inverse_scrambled(base, index, scramble)
+    }
+
+    /// Returns the 2D sample at `index`.
+    #[inline]
+    pub fn sample_vec2(&self, index: u32) -> Vec2 {
+        Vec2::new(self.sample(0, index), self.sample(1, index))
+    }
+
+    /// Returns the 3D sample at `index`.
+    #[inline]
+    pub fn sample_vec3(&self, index: u32) -> Vec3 {
+        Vec3::new(
+            self.sample(0, index),
+            self.sample(1, index),
+            self.sample(2, index),
+        )
+    }
+}
+
+/// Sobol direction numbers for the second dimension, primitive polynomial
+/// `x + 1`.
+const SOBOL_DIRECTIONS_1: [u32; 32] = [
+    0x8000_0000,
+    0xc000_0000,
+    0xa000_0000,
+    0xf000_0000,
+    0x8800_0000,
+    0xcc00_0000,
+    0xaa00_0000,
+    0xff00_0000,
+    0x8080_0000,
+    0xc0c0_0000,
+    0xa0a0_0000,
+    0xf0f0_0000,
+    0x8888_0000,
+    0xcccc_0000,
+    0xaaaa_0000,
+    0xffff_0000,
+    0x8000_8000,
+    0xc000_c000,
+    0xa000_a000,
+    0xf000_f000,
+    0x8800_8800,
+    0xcc00_cc00,
+    0xaa00_aa00,
+    0xff00_ff00,
+    0x8080_8080,
+    0xc0c0_c0c0,
+    0xa0a0_a0a0,
+    0xf0f0_f0f0,
+    0x8888_8888,
+    0xcccc_cccc,
+    0xaaaa_aaaa,
+    0xffff_ffff,
+];
+
+/// Sobol direction numbers for the third dimension, primitive polynomial
+/// `x^2 + x + 1` with initial direction numbers `1, 3`.
+const SOBOL_DIRECTIONS_2: [u32; 32] = [
+    0x8000_0000,
+    0xc000_0000,
+    0x6000_0000,
+    0x9000_0000,
+    0xe800_0000,
+    0x5c00_0000,
+    0x8e00_0000,
+    0xc500_0000,
+    0x6880_0000,
+    0x9cc0_0000,
+    0xee60_0000,
+    0x5590_0000,
+    0x8068_0000,
+    0xc09c_0000,
+    0x60ee_0000,
+    0x9055_0000,
+    0xe880_8000,
+    0x5cc0_c000,
+    0x8e60_6000,
+    0xc590_9000,
+    0x6868_e800,
+    0x9c9c_5c00,
+    0xeeee_8e00,
+    0x5555_c500,
+    0x8000_e880,
+    0xc000_5cc0,
+    0x6000_8e60,
+    0x9000_c590,
+    0xe800_6868,
+    0x5c00_9c9c,
+    0x8e00_eeee,
+    0xc500_5555,
+];
+
+/// Returns the Sobol sample bits at `index` for the given direction numbers.
+#[inline]
+fn sobol_bits(mut index: u32, directions: &[u32; 32]) -> u32 {
+    let mut result = 0;
+    let mut i = 0;
+    while index != 0 {
+        if index & 1 != 0 {
+            result ^= directions[i];
+        }
+        index >>= 1;
+        i += 1;
+    }
+    result
+}
+
+/// The Sobol sequence in up to three dimensions.
+///
+/// The first `2^m` samples of the first two dimensions form a (0, m, 2)-net,
+/// every power of two sized grid cell of area `1 / 2^m` contains exactly one
+/// sample. Scrambling with `Sobol::scrambled` uses Owen scrambling, which
+/// preserves this property.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Sobol {
+    scramble: Option<u32>,
+}
+
+impl Sobol {
+    /// Creates an unscrambled Sobol sequence.
+    #[inline]
+    pub fn new() -> Self {
+        Self { scramble: None }
+    }
+
+    /// Creates a Sobol sequence Owen scrambled by the given `seed`.
+    #[inline]
+    pub fn scrambled(seed: u32) -> Self {
+        Self {
+            scramble: Some(seed),
+        }
+    }
+
+    /// Returns the value of the sequence at `index` in the given `dimension`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimension` is greater than 2.
+    #[inline]
+    pub fn sample(&self, dimension: usize, index: u32) -> f32 {
+        let bits = match dimension {
+            0 => index.reverse_bits(),
+            1 => sobol_bits(index, &SOBOL_DIRECTIONS_1),
+            2 => sobol_bits(index, &SOBOL_DIRECTIONS_2),
+            _ => panic!("Sobol dimension {} is out of range", dimension),
+        };
+        let bits = match self.scramble {
+            Some(seed) => nested_uniform_scramble(bits, hash_u32(seed ^ dimension as u32)),
+            None => bits,
+        };
+        u32_to_unit_f32(bits)
+    }
+
+    /// Returns the 2D sample at `index`.
+    #[inline]
+    pub fn sample_vec2(&self, index: u32) -> Vec2 {
+        Vec2::new(self.sample(0, index), self.sample(1, index))
+    }
+
+    /// Returns the 3D sample at `index`.
+    #[inline]
+    pub fn sample_vec3(&self, index: u32) -> Vec3 {
+        Vec3::new(
+            self.sample(0, index),
+            self.sample(1, index),
+            self.sample(2, index),
+        )
+    }
+}
+
+/// Returns the sample at `index` of the additive recurrence with the given
+/// 0.32 fixed point increment, offset by one half.
+#[inline]
+fn additive_recurrence(alpha: u32, index: u32) -> f32 {
+    u32_to_unit_f32(alpha.wrapping_mul(index).wrapping_add(0x8000_0000))
+}
+
+/// Returns the sample at `index` of the Roberts R2 sequence.
+///
+/// The R2 sequence is an additive recurrence based on the plastic number
+/// which has excellent 2D distribution properties and is very cheap to
+/// compute. See "The Unreasonable Effectiveness of Quasirandom Sequences",
+/// Martin Roberts 2018.
+#[inline]
+pub fn r2(index: u32) -> Vec2 {
+    // 2^32 / g and 2^32 / g^2 where g = 1.32471795724474602596
+    const ALPHA: [u32; 2] = [3_242_174_889, 2_447_445_414];
+    Vec2::new(
+        additive_recurrence(ALPHA[0], index),
+        additive_recurrence(ALPHA[1], index),
+    )
+}
+
+/// Returns the sample at `index` of the Roberts R3 sequence, the 3D
+/// generalization of `r2`.
+#[inline]
+pub fn r3(index: u32) -> Vec3 {
+    // 2^32 / g^n where g = 1.22074408460575947536
+    const ALPHA: [u32; 3] = [3_518_319_155, 2_882_110_345, 2_360_945_575];
+    Vec3::new(
+        additive_recurrence(ALPHA[0], index),
+        additive_recurrence(ALPHA[1], index),
+        additive_recurrence(ALPHA[2], index),
+    )
+}
+
+/// Returns the point at `index` of a 2D Hammersley point set of `count`
+/// points.
+///
+/// Unlike the other sequences the number of points must be known in advance.
+#[inline]
+pub fn hammersley_vec2(index: u32, count: u32) -> Vec2 {
+    glam_assert!(index < count);
+    Vec2::new(index as f32 / count as f32, radical_inverse_base2(index))
+}
+
+/// Returns the point at `index` of a 3D Hammersley point set of `count`
+/// points.
+#[inline]
+pub fn hammersley_vec3(index: u32, count: u32) -> Vec3 {
+    glam_assert!(index < count);
+    Vec3::new(
+        index as f32 / count as f32,
+        radical_inverse_base2(index),
+        radical_inverse(3, index),
+    )
+}
+
+/// Maps a sample from the unit square to the unit disk with Shirley and
+/// Chiu's concentric mapping, which has less distortion than a polar mapping.
+#[inline]
+pub fn square_to_concentric_disk(u: Vec2) -> Vec2 {
+    let (x, y) = (u * 2.0 - Vec2::one()).into();
+    if x == 0.0 && y == 0.0 {
+        return Vec2::zero();
+    }
+    let (r, theta) = if x.abs() > y.abs() {
+        (x, FRAC_PI_4 * (y / x))
+    } else {
+        (y, FRAC_PI_2 - FRAC_PI_4 * (x / y))
+    };
+    let (sin, cos) = theta.sin_cos();
+    Vec2::new(r * cos, r * sin)
+}
+
+/// Maps a sample from the unit square to a uniformly distributed point on
+/// the unit sphere.
+#[inline]
+pub fn square_to_sphere(u: Vec2) -> Vec3 {
+    let z = 1.0 - 2.0 * u.x();
+    let r = (1.0 - z * z).max(0.0).sqrt();
+    let (sin, cos) = (2.0 * PI * u.y()).sin_cos();
+    Vec3::new(r * cos, r * sin, z)
+}
+
+/// Maps a sample from the unit square to a uniformly distributed direction on
+/// the hemisphere around `+Z`.
+#[inline]
+pub fn square_to_hemisphere(u: Vec2) -> Vec3 {
+    let z = u.x();
+    let r = (1.0 - z * z).max(0.0).sqrt();
+    let (sin, cos) = (2.0 * PI * u.y()).sin_cos();
+    Vec3::new(r * cos, r * sin, z)
+}
+
+/// Maps a sample from the unit square to a cosine weighted direction on the
+/// hemisphere around `+Z` using Malley's method.
+#[inline]
+pub fn square_to_cosine_hemisphere(u: Vec2) -> Vec3 {
+    let d = square_to_concentric_disk(u);
+    let z = (1.0 - d.dot(d)).max(0.0).sqrt();
+    d.extend(z)
+}
+
+/// Maps a sample from the unit cube to a rotation uniformly distributed over
+/// all rotations using Shoemake's method from "Uniform Random Rotations",
+/// Graphics Gems III.
+#[inline]
+pub fn cube_to_rotation(u: Vec3) -> Quat {
+    let (sin2, cos2) = (u.y() * 2.0 * PI).sin_cos();
+    let (sin3, cos3) = (u.z() * 2.0 * PI).sin_cos();
+    let r1 = (1.0 - u.x()).sqrt();
+    let r2 = u.x().sqrt();
+    Quat::from_xyzw(r1 * sin2, r1 * cos2, r2 * sin3, r2 * cos3)
+}
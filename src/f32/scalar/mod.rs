@@ -0,0 +1,35 @@
+//! Scalar implementations of the SIMD types, generated from the same
+//! templates as the types in `glam::f32` with SSE2 disabled.
+//!
+//! These are only compiled into test builds on SSE2 targets so the parity
+//! tests can compare both implementations within one test binary.
+#![allow(dead_code, clippy::wrong_self_convention)]
+
+mod mat2;
+mod mat3;
+mod mat3a;
+mod mat4;
+mod quat;
+mod vec2;
+mod vec2_mask;
+mod vec3;
+mod vec3_mask;
+mod vec3a;
+mod vec3a_mask;
+mod vec4;
+mod vec4_mask;
+
+pub(crate) use super::{scalar_acos, scalar_sin_cos, scalar_tan, Radians};
+pub use mat2::*;
+pub use mat3::*;
+pub use mat3a::*;
+pub use mat4::*;
+pub use quat::*;
+pub use vec2::*;
+pub use vec2_mask::*;
+pub use vec3::*;
+pub use vec3_mask::*;
+pub use vec3a::*;
+pub use vec3a_mask::*;
+pub use vec4::*;
+pub use vec4_mask::*;
@@ -0,0 +1,133 @@
+// Generated from vec_mask.rs.tera template. Edit the template, not the generated file.
+
+use super::Vec3A;
+use core::{fmt, ops::*};
+
+/// A 3-dimensional vector mask.
+///
+/// This type is typically created by comparison methods on `Vec3A`.  It is
+/// essentially a vector of three boolean values.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[repr(C)]
+pub struct Vec3AMask(pub(crate) u32, pub(crate) u32, pub(crate) u32);
+
+impl Vec3AMask {
+    /// Creates a new `Vec3AMask`.
+    #[inline]
+    pub const fn new(x: bool, y: bool, z: bool) -> Self {
+        const MASK: [u32; 2] = [0, 0xff_ff_ff_ff];
+
+        Self(MASK[x as usize], MASK[y as usize], MASK[z as usize])
+    }
+
+    /// Returns a bitmask with the lowest three bits set from the elements of
+    /// the `Vec3AMask`.
+    ///
+    /// A true element results in a `1` bit and a false element in a `0` bit.
+    /// Element `x` goes into the first lowest bit, element `y` into the
+    /// second, etc.
+    #[inline]
+    pub fn bitmask(self) -> u32 {
+        (self.0 & 0x1) | (self.1 & 0x1) << 1 | (self.2 & 0x1) << 2
+    }
+
+    /// Returns true if any of the elements are true, false otherwise.
+    ///
+    /// In other words: `x || y || z`.
+    #[inline]
+    pub fn any(self) -> bool {
+        ((self.0 | self.1 | self.2) & 0x1) != 0
+    }
+
+    /// Returns true if all the elements are true, false otherwise.
+    ///
+    /// In other words: `x && y && z`.
+    #[inline]
+    pub fn all(self) -> bool {
+        ((self.0 & self.1 & self.2) & 0x1) != 0
+    }
+
+    /// Creates a new `Vec3A` from the elements in `if_true` and `if_false`,
+    /// selecting which to use for each element based on the `Vec3AMask`.
+    ///
+    /// A true element in the mask uses the corresponding element from
+    /// `if_true`, and false uses the element from `if_false`.
+    #[inline]
+    pub fn select(self, if_true: Vec3A, if_false: Vec3A) -> Vec3A {
+        Vec3A(
+            if self.0 != 0 { if_true.0 } else { if_false.0 },
+            if self.1 != 0 { if_true.1 } else { if_false.1 },
+            if self.2 != 0 { if_true.2 } else { if_false.2 },
+        )
+    }
+}
+
+impl BitAnd for Vec3AMask {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0, self.1 & other.1, self.2 & other.2)
+    }
+}
+
+impl BitAndAssign for Vec3AMask {
+    #[inline]
+    fn bitand_assign(&mut self, other: Self) {
+        self.0 &= other.0;
+        self.1 &= other.1;
+        self.2 &= other.2;
+    }
+}
+
+impl BitOr for Vec3AMask {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0, self.1 | other.1, self.2 | other.2)
+    }
+}
+
+impl BitOrAssign for Vec3AMask {
+    #[inline]
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+        self.1 |= other.1;
+        self.2 |= other.2;
+    }
+}
+
+impl Not for Vec3AMask {
+    type Output = Self;
+    #[inline]
+    fn not(self) -> Self {
+        Self(!self.0, !self.1, !self.2)
+    }
+}
+
+impl fmt::Debug for Vec3AMask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arr = self.as_ref();
+        write!(f, "Vec3AMask({:#x}, {:#x}, {:#x})", arr[0], arr[1], arr[2])
+    }
+}
+
+impl fmt::Display for Vec3AMask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arr = self.as_ref();
+        write!(f, "[{}, {}, {}]", arr[0] != 0, arr[1] != 0, arr[2] != 0)
+    }
+}
+
+impl From<Vec3AMask> for [u32; 3] {
+    #[inline]
+    fn from(mask: Vec3AMask) -> Self {
+        *mask.as_ref()
+    }
+}
+
+impl AsRef<[u32; 3]> for Vec3AMask {
+    #[inline]
+    fn as_ref(&self) -> &[u32; 3] {
+        unsafe { &*(self as *const Self as *const [u32; 3]) }
+    }
+}
@@ -0,0 +1,717 @@
+// Generated from vec.rs.tera template. Edit the template, not the generated file.
+
+use super::{Vec3, Vec4Mask};
+use core::{fmt, ops::*};
+
+/// A 4-dimensional vector.
+///
+/// This type is 16 byte aligned unless the `scalar-math` feature is enabled.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
+#[repr(C)]
+pub struct Vec4(
+    pub(crate) f32,
+    pub(crate) f32,
+    pub(crate) f32,
+    pub(crate) f32,
+);
+
+#[inline]
+pub const fn vec4(x: f32, y: f32, z: f32, w: f32) -> Vec4 {
+    Vec4::new(x, y, z, w)
+}
+
+impl Vec4 {
+    /// All elements set to `0.0`.
+    pub const ZERO: Self = Self::zero();
+
+    /// All elements set to `1.0`.
+    pub const ONE: Self = Self::one();
+
+    /// `[x: 1.0, y: 0.0, z: 0.0, w: 0.0]`.
+    pub const X: Self = Self::unit_x();
+
+    /// `[x: 0.0, y: 1.0, z: 0.0, w: 0.0]`.
+    pub const Y: Self = Self::unit_y();
+
+    /// `[x: 0.0, y: 0.0, z: 1.0, w: 0.0]`.
+    pub const Z: Self = Self::unit_z();
+
+    /// `[x: 0.0, y: 0.0, z: 0.0, w: 1.0]`.
+    pub const W: Self = Self::unit_w();
+
+    /// Creates a new `Vec4`.
+    #[inline]
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self(x, y, z, w)
+    }
+
+    /// Creates a new `Vec4` with all elements set to `0.0`.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::splat(0.0)
+    }
+
+    /// Creates a new `Vec4` with all elements set to `1.0`.
+    #[inline]
+    pub const fn one() -> Self {
+        Self::splat(1.0)
+    }
+
+    /// Creates a new `Vec4` with values `[x: 1.0, y: 0.0, z: 0.0, w: 0.0]`.
+    #[inline]
+    pub const fn unit_x() -> Self {
+        Self::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    /// Creates a new `Vec4` with values `[x: 0.0, y: 1.0, z: 0.0, w: 0.0]`.
+    #[inline]
+    pub const fn unit_y() -> Self {
+        Self::new(0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// Creates a new `Vec4` with values `[x: 0.0, y: 0.0, z: 1.0, w: 0.0]`.
+    #[inline]
+    pub const fn unit_z() -> Self {
+        Self::new(0.0, 0.0, 1.0, 0.0)
+    }
+
+    /// Creates a new `Vec4` with values `[x: 0.0, y: 0.0, z: 0.0, w: 1.0]`.
+    #[inline]
+    pub const fn unit_w() -> Self {
+        Self::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    /// Creates a new `Vec4` with all elements set to `v`.
+    #[inline]
+    pub const fn splat(v: f32) -> Self {
+        Self::new(v, v, v, v)
+    }
+
+    /// Creates a `Vec3` from the first three elements of `self`,
+    /// removing `w`.
+    #[inline]
+    pub fn truncate(self) -> Vec3 {
+        Vec3::new(self.0, self.1, self.2)
+    }
+
+    /// Returns element `x`.
+    #[inline]
+    pub fn x(self) -> f32 {
+        self.0
+    }
+
+    /// Returns element `y`.
+    #[inline]
+    pub fn y(self) -> f32 {
+        self.1
+    }
+
+    /// Returns element `z`.
+    #[inline]
+    pub fn z(self) -> f32 {
+        self.2
+    }
+
+    /// Returns element `w`.
+    #[inline]
+    pub fn w(self) -> f32 {
+        self.3
+    }
+
+    /// Returns a mutable reference to element `x`.
+    #[inline]
+    pub fn x_mut(&mut self) -> &mut f32 {
+        &mut self.0
+    }
+
+    /// Returns a mutable reference to element `y`.
+    #[inline]
+    pub fn y_mut(&mut self) -> &mut f32 {
+        &mut self.1
+    }
+
+    /// Returns a mutable reference to element `z`.
+    #[inline]
+    pub fn z_mut(&mut self) -> &mut f32 {
+        &mut self.2
+    }
+
+    /// Returns a mutable reference to element `w`.
+    #[inline]
+    pub fn w_mut(&mut self) -> &mut f32 {
+        &mut self.3
+    }
+
+    /// Sets element `x`.
+    #[inline]
+    pub fn set_x(&mut self, x: f32) {
+        self.0 = x;
+    }
+
+    /// Sets element `y`.
+    #[inline]
+    pub fn set_y(&mut self, y: f32) {
+        self.1 = y;
+    }
+
+    /// Sets element `z`.
+    #[inline]
+    pub fn set_z(&mut self, z: f32) {
+        self.2 = z;
+    }
+
+    /// Sets element `w`.
+    #[inline]
+    pub fn set_w(&mut self, w: f32) {
+        self.3 = w;
+    }
+
+    /// Returns a `Vec4` with all elements set to the value of element `x`.
+    #[inline]
+    pub(crate) fn dup_x(self) -> Self {
+        Self(self.0, self.0, self.0, self.0)
+    }
+
+    /// Returns a `Vec4` with all elements set to the value of element `y`.
+    #[inline]
+    pub(crate) fn dup_y(self) -> Self {
+        Self(self.1, self.1, self.1, self.1)
+    }
+
+    /// Returns a `Vec4` with all elements set to the value of element `z`.
+    #[inline]
+    pub(crate) fn dup_z(self) -> Self {
+        Self(self.2, self.2, self.2, self.2)
+    }
+
+    /// Returns a `Vec4` with all elements set to the value of element `w`.
+    #[inline]
+    pub(crate) fn dup_w(self) -> Self {
+        Self(self.3, self.3, self.3, self.3)
+    }
+
+    /// Computes the 4D dot product of `self` and `other`.
+    #[inline]
+    pub fn dot(self, other: Self) -> f32 {
+        // summed pairwise in the same order as SSE2 so both give the same result
+        ((self.0 * other.0) + (self.2 * other.2)) + ((self.1 * other.1) + (self.3 * other.3))
+    }
+
+    /// Computes the 4D length of `self`.
+    #[inline]
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Computes the squared 4D length of `self`.
+    ///
+    /// This is generally faster than `Vec4::length()` as it avoids a square
+    /// root operation.
+    #[inline]
+    pub fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    /// Computes `1.0 / Vec4::length()`.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn length_reciprocal(self) -> f32 {
+        1.0 / self.length()
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// For valid results, `self` must _not_ be of length zero.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self * self.length_reciprocal()
+    }
+
+    /// Returns the vertical minimum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: min(x1, x2), y: min(y1, y2), z: min(z1, z2), w: min(w1, w2)]`,
+    /// taking the minimum of each element individually.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self(
+            self.0.min(other.0),
+            self.1.min(other.1),
+            self.2.min(other.2),
+            self.3.min(other.3),
+        )
+    }
+
+    /// Returns the vertical maximum of `self` and `other`.
+    ///
+    /// In other words, this computes
+    /// `[x: max(x1, x2), y: max(y1, y2), z: max(z1, z2), w: max(w1, w2)]`,
+    /// taking the maximum of each element individually.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self(
+            self.0.max(other.0),
+            self.1.max(other.1),
+            self.2.max(other.2),
+            self.3.max(other.3),
+        )
+    }
+
+    /// Returns the horizontal minimum of `self`'s elements.
+    ///
+    /// In other words, this computes `min(x, y, z, w)`.
+    #[inline]
+    pub fn min_element(self) -> f32 {
+        self.0.min(self.1).min(self.2).min(self.3)
+    }
+
+    /// Returns the horizontal maximum of `self`'s elements.
+    ///
+    /// In other words, this computes `max(x, y, z, w)`.
+    #[inline]
+    pub fn max_element(self) -> f32 {
+        self.0.max(self.1).max(self.2).max(self.3)
+    }
+
+    /// Performs a vertical `==` comparison between `self` and `other`,
+    /// returning a `Vec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 == x2, y1 == y2, z1 == z2, w1 == w2]`.
+    #[inline]
+    pub fn cmpeq(self, other: Self) -> Vec4Mask {
+        Vec4Mask::new(
+            self.0.eq(&other.0),
+            self.1.eq(&other.1),
+            self.2.eq(&other.2),
+            self.3.eq(&other.3),
+        )
+    }
+
+    /// Performs a vertical `!=` comparison between `self` and `other`,
+    /// returning a `Vec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 != x2, y1 != y2, z1 != z2, w1 != w2]`.
+    #[inline]
+    pub fn cmpne(self, other: Self) -> Vec4Mask {
+        Vec4Mask::new(
+            self.0.ne(&other.0),
+            self.1.ne(&other.1),
+            self.2.ne(&other.2),
+            self.3.ne(&other.3),
+        )
+    }
+
+    /// Performs a vertical `>=` comparison between `self` and `other`,
+    /// returning a `Vec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 >= x2, y1 >= y2, z1 >= z2, w1 >= w2]`.
+    #[inline]
+    pub fn cmpge(self, other: Self) -> Vec4Mask {
+        Vec4Mask::new(
+            self.0.ge(&other.0),
+            self.1.ge(&other.1),
+            self.2.ge(&other.2),
+            self.3.ge(&other.3),
+        )
+    }
+
+    /// Performs a vertical `>` comparison between `self` and `other`,
+    /// returning a `Vec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 > x2, y1 > y2, z1 > z2, w1 > w2]`.
+    #[inline]
+    pub fn cmpgt(self, other: Self) -> Vec4Mask {
+        Vec4Mask::new(
+            self.0.gt(&other.0),
+            self.1.gt(&other.1),
+            self.2.gt(&other.2),
+            self.3.gt(&other.3),
+        )
+    }
+
+    /// Performs a vertical `<=` comparison between `self` and `other`,
+    /// returning a `Vec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 <= x2, y1 <= y2, z1 <= z2, w1 <= w2]`.
+    #[inline]
+    pub fn cmple(self, other: Self) -> Vec4Mask {
+        Vec4Mask::new(
+            self.0.le(&other.0),
+            self.1.le(&other.1),
+            self.2.le(&other.2),
+            self.3.le(&other.3),
+        )
+    }
+
+    /// Performs a vertical `<` comparison between `self` and `other`,
+    /// returning a `Vec4Mask` of the results.
+    ///
+    /// In other words, this computes `[x1 < x2, y1 < y2, z1 < z2, w1 < w2]`.
+    #[inline]
+    pub fn cmplt(self, other: Self) -> Vec4Mask {
+        Vec4Mask::new(
+            self.0.lt(&other.0),
+            self.1.lt(&other.1),
+            self.2.lt(&other.2),
+            self.3.lt(&other.3),
+        )
+    }
+
+    /// Creates a new `Vec4` from the first four values in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn from_slice_unaligned(slice: &[f32]) -> Self {
+        Self::new(slice[0], slice[1], slice[2], slice[3])
+    }
+
+    /// Writes the elements of `self` to the first four elements in `slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slice` is less than four elements long.
+    #[inline]
+    pub fn write_to_slice_unaligned(self, slice: &mut [f32]) {
+        let a = self.as_ref();
+        slice[0] = a[0];
+        slice[1] = a[1];
+        slice[2] = a[2];
+        slice[3] = a[3];
+    }
+
+    /// Per element multiplication/addition of the three inputs: b + (self * a)
+    #[inline]
+    pub(crate) fn mul_add(self, a: Self, b: Self) -> Self {
+        Self(
+            (self.0 * a.0) + b.0,
+            (self.1 * a.1) + b.1,
+            (self.2 * a.2) + b.2,
+            (self.3 * a.3) + b.3,
+        )
+    }
+
+    /// Returns a new `Vec4` containing the absolute value of each element of the original
+    /// `Vec4`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(self.0.abs(), self.1.abs(), self.2.abs(), self.3.abs())
+    }
+
+    /// Returns a new `Vec4` containing the nearest integer to each
+    /// element of `self`. Half-way cases are rounded away from `0.0`.
+    #[inline]
+    pub fn round(self) -> Self {
+        Self(
+            self.0.round(),
+            self.1.round(),
+            self.2.round(),
+            self.3.round(),
+        )
+    }
+
+    /// Returns a new `Vec4` containing the largest integer less than or
+    /// equal to each element of `self`.
+    #[inline]
+    pub fn floor(self) -> Self {
+        Self(
+            self.0.floor(),
+            self.1.floor(),
+            self.2.floor(),
+            self.3.floor(),
+        )
+    }
+
+    /// Returns a new `Vec4` containing the smallest integer greater than or
+    /// equal to each element of `self`.
+    #[inline]
+    pub fn ceil(self) -> Self {
+        Self(self.0.ceil(), self.1.ceil(), self.2.ceil(), self.3.ceil())
+    }
+
+    /// Returns a new `Vec4` with elements representing the sign of `self`.
+    ///
+    /// - `1.0` if the number is positive, `+0.0`, `-0.0` or `INFINITY`
+    /// - `-1.0` if the number is negative, `NEG_INFINITY` or `NaN`
+    #[inline]
+    pub fn sign(self) -> Self {
+        let mask = self.cmpge(Self::zero());
+        mask.select(Self::splat(1.0), Self::splat(-1.0))
+    }
+
+    /// Computes the reciprocal `1.0/n` of each element, returning the
+    /// results in a new `Vec4`.
+    #[inline]
+    pub fn reciprocal(self) -> Self {
+        // TODO: Optimize
+        Self::one() / self
+    }
+
+    /// Performs a linear interpolation between `self` and `other` based on
+    /// the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
+    /// is `1.0`, the result will be equal to `other`.
+    #[inline]
+    pub fn lerp(self, other: Self, s: f32) -> Self {
+        self + ((other - self) * s)
+    }
+
+    /// Returns whether `self` is length `1.0` or not.
+    ///
+    /// Uses a precision threshold of `1e-6`.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        is_normalized!(self)
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two `Vec4`'s contain similar elements. It
+    /// works best when comparing with a known value. The `max_abs_diff` that
+    /// should be used used depends on the values being compared against.
+    ///
+    /// For more on floating point comparisons see
+    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[inline]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f32) -> bool {
+        abs_diff_eq!(self, other, max_abs_diff)
+    }
+}
+
+impl AsRef<[f32; 4]> for Vec4 {
+    #[inline]
+    fn as_ref(&self) -> &[f32; 4] {
+        unsafe { &*(self as *const Self as *const [f32; 4]) }
+    }
+}
+
+impl AsMut<[f32; 4]> for Vec4 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f32; 4] {
+        unsafe { &mut *(self as *mut Self as *mut [f32; 4]) }
+    }
+}
+
+impl fmt::Display for Vec4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}, {}, {}]", self.0, self.1, self.2, self.3)
+    }
+}
+
+impl Div<Vec4> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self(
+            self.0 / other.0,
+            self.1 / other.1,
+            self.2 / other.2,
+            self.3 / other.3,
+        )
+    }
+}
+
+impl DivAssign<Vec4> for Vec4 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        self.0 /= other.0;
+        self.1 /= other.1;
+        self.2 /= other.2;
+        self.3 /= other.3;
+    }
+}
+
+impl Div<f32> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: f32) -> Self {
+        Self(
+            self.0 / other,
+            self.1 / other,
+            self.2 / other,
+            self.3 / other,
+        )
+    }
+}
+
+impl DivAssign<f32> for Vec4 {
+    #[inline]
+    fn div_assign(&mut self, other: f32) {
+        self.0 /= other;
+        self.1 /= other;
+        self.2 /= other;
+        self.3 /= other;
+    }
+}
+
+impl Mul<Vec4> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self(
+            self.0 * other.0,
+            self.1 * other.1,
+            self.2 * other.2,
+            self.3 * other.3,
+        )
+    }
+}
+
+impl MulAssign<Vec4> for Vec4 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        self.0 *= other.0;
+        self.1 *= other.1;
+        self.2 *= other.2;
+        self.3 *= other.3;
+    }
+}
+
+impl Mul<f32> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: f32) -> Self {
+        Self(
+            self.0 * other,
+            self.1 * other,
+            self.2 * other,
+            self.3 * other,
+        )
+    }
+}
+
+impl MulAssign<f32> for Vec4 {
+    #[inline]
+    fn mul_assign(&mut self, other: f32) {
+        self.0 *= other;
+        self.1 *= other;
+        self.2 *= other;
+        self.3 *= other;
+    }
+}
+
+impl Mul<Vec4> for f32 {
+    type Output = Vec4;
+    #[inline]
+    fn mul(self, other: Vec4) -> Vec4 {
+        Vec4(
+            self * other.0,
+            self * other.1,
+            self * other.2,
+            self * other.3,
+        )
+    }
+}
+
+impl Add<Vec4> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self(
+            self.0 + other.0,
+            self.1 + other.1,
+            self.2 + other.2,
+            self.3 + other.3,
+        )
+    }
+}
+
+impl AddAssign<Vec4> for Vec4 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        self.0 += other.0;
+        self.1 += other.1;
+        self.2 += other.2;
+        self.3 += other.3;
+    }
+}
+
+impl Sub<Vec4> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self(
+            self.0 - other.0,
+            self.1 - other.1,
+            self.2 - other.2,
+            self.3 - other.3,
+        )
+    }
+}
+
+impl SubAssign<Vec4> for Vec4 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        self.0 -= other.0;
+        self.1 -= other.1;
+        self.2 -= other.2;
+        self.3 -= other.3;
+    }
+}
+
+impl Neg for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1, -self.2, -self.3)
+    }
+}
+
+impl Index<usize> for Vec4 {
+    type Output = f32;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.as_ref()[index]
+    }
+}
+
+impl IndexMut<usize> for Vec4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.as_mut()[index]
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Vec4 {
+    #[inline]
+    fn from(t: (f32, f32, f32, f32)) -> Self {
+        Self::new(t.0, t.1, t.2, t.3)
+    }
+}
+
+impl From<Vec4> for (f32, f32, f32, f32) {
+    #[inline]
+    fn from(v: Vec4) -> Self {
+        (v.0, v.1, v.2, v.3)
+    }
+}
+
+impl From<[f32; 4]> for Vec4 {
+    #[inline]
+    fn from(a: [f32; 4]) -> Self {
+        Self::new(a[0], a[1], a[2], a[3])
+    }
+}
+
+impl From<Vec4> for [f32; 4] {
+    #[inline]
+    fn from(v: Vec4) -> Self {
+        [v.0, v.1, v.2, v.3]
+    }
+}
+
+#[test]
+fn test_vec4_private() {
+    assert_eq!(
+        vec4(1.0, 1.0, 1.0, 1.0).mul_add(vec4(0.5, 2.0, -4.0, 0.0), vec4(-1.0, -1.0, -1.0, -1.0)),
+        vec4(-0.5, 1.0, -5.0, -1.0)
+    );
+    assert_eq!(vec4(1.0, 2.0, 3.0, 4.0).dup_x(), vec4(1.0, 1.0, 1.0, 1.0));
+    assert_eq!(vec4(1.0, 2.0, 3.0, 4.0).dup_y(), vec4(2.0, 2.0, 2.0, 2.0));
+    assert_eq!(vec4(1.0, 2.0, 3.0, 4.0).dup_z(), vec4(3.0, 3.0, 3.0, 3.0));
+    assert_eq!(vec4(1.0, 2.0, 3.0, 4.0).dup_w(), vec4(4.0, 4.0, 4.0, 4.0));
+}
@@ -0,0 +1,167 @@
+// Generated from vec_mask.rs.tera template. Edit the template, not the generated file.
+
+use super::Vec4;
+use core::{fmt, ops::*};
+
+/// A 4-dimensional vector mask.
+///
+/// This type is typically created by comparison methods on `Vec4`.  It is
+/// essentially a vector of four boolean values.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[repr(C)]
+pub struct Vec4Mask(
+    pub(crate) u32,
+    pub(crate) u32,
+    pub(crate) u32,
+    pub(crate) u32,
+);
+
+impl Vec4Mask {
+    /// Creates a new `Vec4Mask`.
+    #[inline]
+    pub const fn new(x: bool, y: bool, z: bool, w: bool) -> Self {
+        const MASK: [u32; 2] = [0, 0xff_ff_ff_ff];
+
+        Self(
+            MASK[x as usize],
+            MASK[y as usize],
+            MASK[z as usize],
+            MASK[w as usize],
+        )
+    }
+
+    /// Returns a bitmask with the lowest four bits set from the elements of
+    /// the `Vec4Mask`.
+    ///
+    /// A true element results in a `1` bit and a false element in a `0` bit.
+    /// Element `x` goes into the first lowest bit, element `y` into the
+    /// second, etc.
+    #[inline]
+    pub fn bitmask(self) -> u32 {
+        (self.0 & 0x1) | (self.1 & 0x1) << 1 | (self.2 & 0x1) << 2 | (self.3 & 0x1) << 3
+    }
+
+    /// Returns true if any of the elements are true, false otherwise.
+    ///
+    /// In other words: `x || y || z || w`.
+    #[inline]
+    pub fn any(self) -> bool {
+        ((self.0 | self.1 | self.2 | self.3) & 0x1) != 0
+    }
+
+    /// Returns true if all the elements are true, false otherwise.
+    ///
+    /// In other words: `x && y && z && w`.
+    #[inline]
+    pub fn all(self) -> bool {
+        ((self.0 & self.1 & self.2 & self.3) & 0x1) != 0
+    }
+
+    /// Creates a new `Vec4` from the elements in `if_true` and `if_false`,
+    /// selecting which to use for each element based on the `Vec4Mask`.
+    ///
+    /// A true element in the mask uses the corresponding element from
+    /// `if_true`, and false uses the element from `if_false`.
+    #[inline]
+    pub fn select(self, if_true: Vec4, if_false: Vec4) -> Vec4 {
+        Vec4(
+            if self.0 != 0 { if_true.0 } else { if_false.0 },
+            if self.1 != 0 { if_true.1 } else { if_false.1 },
+            if self.2 != 0 { if_true.2 } else { if_false.2 },
+            if self.3 != 0 { if_true.3 } else { if_false.3 },
+        )
+    }
+}
+
+impl BitAnd for Vec4Mask {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        Self(
+            self.0 & other.0,
+            self.1 & other.1,
+            self.2 & other.2,
+            self.3 & other.3,
+        )
+    }
+}
+
+impl BitAndAssign for Vec4Mask {
+    #[inline]
+    fn bitand_assign(&mut self, other: Self) {
+        self.0 &= other.0;
+        self.1 &= other.1;
+        self.2 &= other.2;
+        self.3 &= other.3;
+    }
+}
+
+impl BitOr for Vec4Mask {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        Self(
+            self.0 | other.0,
+            self.1 | other.1,
+            self.2 | other.2,
+            self.3 | other.3,
+        )
+    }
+}
+
+impl BitOrAssign for Vec4Mask {
+    #[inline]
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+        self.1 |= other.1;
+        self.2 |= other.2;
+        self.3 |= other.3;
+    }
+}
+
+impl Not for Vec4Mask {
+    type Output = Self;
+    #[inline]
+    fn not(self) -> Self {
+        Self(!self.0, !self.1, !self.2, !self.3)
+    }
+}
+
+impl fmt::Debug for Vec4Mask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arr = self.as_ref();
+        write!(
+            f,
+            "Vec4Mask({:#x}, {:#x}, {:#x}, {:#x})",
+            arr[0], arr[1], arr[2], arr[3]
+        )
+    }
+}
+
+impl fmt::Display for Vec4Mask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arr = self.as_ref();
+        write!(
+            f,
+            "[{}, {}, {}, {}]",
+            arr[0] != 0,
+            arr[1] != 0,
+            arr[2] != 0,
+            arr[3] != 0
+        )
+    }
+}
+
+impl From<Vec4Mask> for [u32; 4] {
+    #[inline]
+    fn from(mask: Vec4Mask) -> Self {
+        *mask.as_ref()
+    }
+}
+
+impl AsRef<[u32; 4]> for Vec4Mask {
+    #[inline]
+    fn as_ref(&self) -> &[u32; 4] {
+        unsafe { &*(self as *const Self as *const [u32; 4]) }
+    }
+}
@@ -0,0 +1,457 @@
+//! Alternative `serde` representations for use with `#[serde(with = "...")]`.
+//!
+//! By default glam types are serialized as flat sequences of `f32`, which is
+//! compact but hard to read and edit by hand. The modules in here provide
+//! alternative representations which can be selected per field:
+//!
+//! * `named` - vectors and quaternions as `{"x": .., "y": .., ..}` and
+//!   matrices as named columns `{"x_axis": {..}, "y_axis": {..}, ..}`.
+//! * `row_major` - matrices as a flat sequence in row-major order.
+//! * `cols_array_2d` - matrices as a sequence of column arrays.
+//! * `euler_degrees` - quaternions as `{"yaw": .., "pitch": .., "roll": ..}`
+//!   in degrees, using the same convention as `Quat::from_rotation_ypr`.
+//!
+//! ```
+//! use glam::{Mat3, Quat, Vec3};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Camera {
+//!     #[serde(with = "glam::serde_repr::named")]
+//!     position: Vec3,
+//!     #[serde(with = "glam::serde_repr::euler_degrees")]
+//!     orientation: Quat,
+//!     #[serde(with = "glam::serde_repr::row_major")]
+//!     basis: Mat3,
+//! }
+//!
+//! let camera = Camera {
+//!     position: Vec3::new(1.0, 2.0, 3.0),
+//!     orientation: Quat::from_rotation_ypr(0.5, 0.25, 0.0),
+//!     basis: Mat3::identity(),
+//! };
+//! let json = serde_json::to_string(&camera).unwrap();
+//! assert!(json.starts_with(r#"{"position":{"x":1.0,"y":2.0,"z":3.0},"orientation":{"yaw":"#));
+//! assert!(json.ends_with(r#""basis":[1.0,0.0,0.0,0.0,1.0,0.0,0.0,0.0,1.0]}"#));
+//!
+//! let camera: Camera = serde_json::from_str(&json).unwrap();
+//! assert_eq!(camera.position, Vec3::new(1.0, 2.0, 3.0));
+//! assert!(camera
+//!     .orientation
+//!     .abs_diff_eq(Quat::from_rotation_ypr(0.5, 0.25, 0.0), 1e-6));
+//! ```
+
+use super::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for super::Vec2 {}
+    impl Sealed for super::Vec3 {}
+    impl Sealed for super::Vec3A {}
+    impl Sealed for super::Vec4 {}
+    impl Sealed for super::Quat {}
+    impl Sealed for super::Mat2 {}
+    impl Sealed for super::Mat3 {}
+    impl Sealed for super::Mat3A {}
+    impl Sealed for super::Mat4 {}
+}
+
+/// Implements a representation trait for `$t` by converting to and from the
+/// `$def` type, which implements `Serialize` and `Deserialize`.
+macro_rules! impl_repr {
+    ($trait:ident, $t:ty, $def:ty, |$a:ident| $to_def:expr, |$b:ident| $from_def:expr) => {
+        impl $trait for $t {
+            fn serialize_repr<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let $a = self;
+                let def: $def = $to_def;
+                def.serialize(serializer)
+            }
+
+            fn deserialize_repr<'de, D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let $b: $def = Deserialize::deserialize(deserializer)?;
+                Ok($from_def)
+            }
+        }
+    };
+}
+
+/// Declares a representation trait and the `serialize` and `deserialize`
+/// functions used by `#[serde(with = "...")]`.
+macro_rules! declare_repr {
+    ($trait:ident, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// This trait is sealed and cannot be implemented outside of glam.
+        pub trait $trait: Sized + super::private::Sealed {
+            #[doc(hidden)]
+            fn serialize_repr<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer;
+
+            #[doc(hidden)]
+            fn deserialize_repr<'de, D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>;
+        }
+
+        /// Serializes `value` using this representation.
+        pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: $trait,
+            S: Serializer,
+        {
+            value.serialize_repr(serializer)
+        }
+
+        /// Deserializes a value using this representation.
+        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+        where
+            T: $trait,
+            D: Deserializer<'de>,
+        {
+            T::deserialize_repr(deserializer)
+        }
+    };
+}
+
+/// Vectors and quaternions as structs with named fields and matrices as
+/// structs of named columns.
+pub mod named {
+    use super::*;
+
+    declare_repr!(
+        Named,
+        "Types which can be serialized as structs with named fields."
+    );
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Vec2")]
+    struct Vec2Def {
+        x: f32,
+        y: f32,
+    }
+
+    impl From<Vec2> for Vec2Def {
+        #[inline]
+        fn from(v: Vec2) -> Self {
+            Self { x: v.x(), y: v.y() }
+        }
+    }
+
+    impl From<Vec2Def> for Vec2 {
+        #[inline]
+        fn from(d: Vec2Def) -> Self {
+            Vec2::new(d.x, d.y)
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Vec3")]
+    struct Vec3Def {
+        x: f32,
+        y: f32,
+        z: f32,
+    }
+
+    impl From<Vec3> for Vec3Def {
+        #[inline]
+        fn from(v: Vec3) -> Self {
+            Self {
+                x: v.x(),
+                y: v.y(),
+                z: v.z(),
+            }
+        }
+    }
+
+    impl From<Vec3Def> for Vec3 {
+        #[inline]
+        fn from(d: Vec3Def) -> Self {
+            Vec3::new(d.x, d.y, d.z)
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Vec4")]
+    struct Vec4Def {
+        x: f32,
+        y: f32,
+        z: f32,
+        w: f32,
+    }
+
+    impl From<Vec4> for Vec4Def {
+        #[inline]
+        fn from(v: Vec4) -> Self {
+            Self {
+                x: v.x(),
+                y: v.y(),
+                z: v.z(),
+                w: v.w(),
+            }
+        }
+    }
+
+    impl From<Vec4Def> for Vec4 {
+        #[inline]
+        fn from(d: Vec4Def) -> Self {
+            Vec4::new(d.x, d.y, d.z, d.w)
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Quat")]
+    struct QuatDef {
+        x: f32,
+        y: f32,
+        z: f32,
+        w: f32,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Mat2")]
+    struct Mat2Def {
+        x_axis: Vec2Def,
+        y_axis: Vec2Def,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Mat3")]
+    struct Mat3Def {
+        x_axis: Vec3Def,
+        y_axis: Vec3Def,
+        z_axis: Vec3Def,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Mat4")]
+    struct Mat4Def {
+        x_axis: Vec4Def,
+        y_axis: Vec4Def,
+        z_axis: Vec4Def,
+        w_axis: Vec4Def,
+    }
+
+    impl_repr!(Named, Vec2, Vec2Def, |v| (*v).into(), |d| d.into());
+    impl_repr!(Named, Vec3, Vec3Def, |v| (*v).into(), |d| d.into());
+    impl_repr!(Named, Vec3A, Vec3Def, |v| Vec3::from(*v).into(), |d| {
+        Vec3::from(d).into()
+    });
+    impl_repr!(Named, Vec4, Vec4Def, |v| (*v).into(), |d| d.into());
+    impl_repr!(
+        Named,
+        Quat,
+        QuatDef,
+        |q| QuatDef {
+            x: q.x(),
+            y: q.y(),
+            z: q.z(),
+            w: q.w(),
+        },
+        |d| Quat::from_xyzw(d.x, d.y, d.z, d.w)
+    );
+    impl_repr!(
+        Named,
+        Mat2,
+        Mat2Def,
+        |m| Mat2Def {
+            x_axis: m.x_axis().into(),
+            y_axis: m.y_axis().into(),
+        },
+        |d| Mat2::from_cols(d.x_axis.into(), d.y_axis.into())
+    );
+    impl_repr!(
+        Named,
+        Mat3,
+        Mat3Def,
+        |m| Mat3Def {
+            x_axis: m.x_axis().into(),
+            y_axis: m.y_axis().into(),
+            z_axis: m.z_axis().into(),
+        },
+        |d| Mat3::from_cols(d.x_axis.into(), d.y_axis.into(), d.z_axis.into())
+    );
+    impl_repr!(
+        Named,
+        Mat3A,
+        Mat3Def,
+        |m| Mat3Def {
+            x_axis: Vec3::from(m.x_axis()).into(),
+            y_axis: Vec3::from(m.y_axis()).into(),
+            z_axis: Vec3::from(m.z_axis()).into(),
+        },
+        |d| Mat3A::from_cols(
+            Vec3::from(d.x_axis).into(),
+            Vec3::from(d.y_axis).into(),
+            Vec3::from(d.z_axis).into()
+        )
+    );
+    impl_repr!(
+        Named,
+        Mat4,
+        Mat4Def,
+        |m| Mat4Def {
+            x_axis: m.x_axis().into(),
+            y_axis: m.y_axis().into(),
+            z_axis: m.z_axis().into(),
+            w_axis: m.w_axis().into(),
+        },
+        |d| Mat4::from_cols(
+            d.x_axis.into(),
+            d.y_axis.into(),
+            d.z_axis.into(),
+            d.w_axis.into()
+        )
+    );
+}
+
+/// Matrices as a flat sequence of elements in row-major order.
+pub mod row_major {
+    use super::*;
+
+    declare_repr!(
+        RowMajor,
+        "Types which can be serialized as a flat row-major sequence."
+    );
+
+    impl_repr!(
+        RowMajor,
+        Mat2,
+        [f32; 4],
+        |m| m.transpose().to_cols_array(),
+        |d| Mat2::from_cols_array(&d).transpose()
+    );
+    impl_repr!(
+        RowMajor,
+        Mat3,
+        [f32; 9],
+        |m| m.transpose().to_cols_array(),
+        |d| Mat3::from_cols_array(&d).transpose()
+    );
+    impl_repr!(
+        RowMajor,
+        Mat3A,
+        [f32; 9],
+        |m| m.transpose().to_cols_array(),
+        |d| Mat3A::from_cols_array(&d).transpose()
+    );
+    impl_repr!(
+        RowMajor,
+        Mat4,
+        [f32; 16],
+        |m| m.transpose().to_cols_array(),
+        |d| Mat4::from_cols_array(&d).transpose()
+    );
+}
+
+/// Matrices as a sequence of column arrays, e.g. `[[1, 0], [0, 1]]`.
+pub mod cols_array_2d {
+    use super::*;
+
+    declare_repr!(
+        ColsArray2d,
+        "Types which can be serialized as a sequence of column arrays."
+    );
+
+    impl_repr!(
+        ColsArray2d,
+        Mat2,
+        [[f32; 2]; 2],
+        |m| m.to_cols_array_2d(),
+        |d| Mat2::from_cols_array_2d(&d)
+    );
+    impl_repr!(
+        ColsArray2d,
+        Mat3,
+        [[f32; 3]; 3],
+        |m| m.to_cols_array_2d(),
+        |d| Mat3::from_cols_array_2d(&d)
+    );
+    impl_repr!(
+        ColsArray2d,
+        Mat3A,
+        [[f32; 3]; 3],
+        |m| m.to_cols_array_2d(),
+        |d| Mat3A::from_cols_array_2d(&d)
+    );
+    impl_repr!(
+        ColsArray2d,
+        Mat4,
+        [[f32; 4]; 4],
+        |m| m.to_cols_array_2d(),
+        |d| Mat4::from_cols_array_2d(&d)
+    );
+}
+
+/// Quaternions as yaw, pitch and roll Euler angles in degrees.
+///
+/// The angles use the same convention as `Quat::from_rotation_ypr`. When
+/// deserializing the angles may be outside of the range produced by
+/// serialization.
+pub mod euler_degrees {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Quat")]
+    struct EulerDegrees {
+        yaw: f32,
+        pitch: f32,
+        roll: f32,
+    }
+
+    /// Returns the yaw, pitch and roll angles in radians of the rotation `q`.
+    ///
+    /// Pitch is in the range `[-PI/2, PI/2]`, yaw and roll are in the range
+    /// `[-PI, PI]`. At a pitch of +/-90 degrees roll is always zero.
+    fn to_ypr(q: Quat) -> (f32, f32, f32) {
+        // m = rot_y(yaw) * rot_x(pitch) * rot_z(roll)
+        let m = Mat3::from_quat(q);
+        let (x_axis, y_axis, z_axis) = (m.x_axis(), m.y_axis(), m.z_axis());
+        let sin_pitch = -z_axis.y();
+        if sin_pitch.abs() < 0.999_999 {
+            let yaw = z_axis.x().atan2(z_axis.z());
+            let pitch = sin_pitch.asin();
+            let roll = x_axis.y().atan2(y_axis.y());
+            (yaw, pitch, roll)
+        } else {
+            // gimbal lock, roll is folded into yaw
+            let yaw = (-x_axis.z()).atan2(x_axis.x());
+            let pitch = core::f32::consts::FRAC_PI_2.copysign(sin_pitch);
+            (yaw, pitch, 0.0)
+        }
+    }
+
+    /// Serializes `value` as yaw, pitch and roll in degrees.
+    pub fn serialize<S>(value: &Quat, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (yaw, pitch, roll) = to_ypr(*value);
+        EulerDegrees {
+            yaw: yaw.to_degrees(),
+            pitch: pitch.to_degrees(),
+            roll: roll.to_degrees(),
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserializes a `Quat` from yaw, pitch and roll in degrees.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Quat, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let e = EulerDegrees::deserialize(deserializer)?;
+        Ok(Quat::from_rotation_ypr(
+            e.yaw.to_radians(),
+            e.pitch.to_radians(),
+            e.roll.to_radians(),
+        ))
+    }
+}
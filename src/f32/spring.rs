@@ -0,0 +1,210 @@
+//! Critically damped springs and `smooth_damp` smoothing for `f32`, `Vec2`,
+//! `Vec3`, `Vec4` and `Quat`.
+//!
+//! Both approaches are unconditionally stable for any `dt`. `smooth_damp`
+//! never overshoots the target. A `Spring` does not oscillate, but it passes
+//! the target once if its velocity towards the target starts out too high.
+//!
+//! ```
+//! use glam::{spring::smooth_damp, Vec3};
+//! let target = Vec3::new(10.0, 0.0, 0.0);
+//! let mut position = Vec3::zero();
+//! let mut velocity = Vec3::zero();
+//! for _ in 0..120 {
+//!     position = smooth_damp(position, target, &mut velocity, 0.2, f32::INFINITY, 1.0 / 60.0);
+//! }
+//! assert!(position.abs_diff_eq(target, 1e-3));
+//! ```
+
+use super::curves::CurvePoint;
+use super::{scalar_atan2, scalar_exp, Quat, Vec3, Vec4};
+
+const MIN_SMOOTH_TIME: f32 = 1.0e-4;
+
+/// Returns `v` with its length clamped to `max_length`.
+#[inline]
+fn clamp_length<T: CurvePoint>(v: T, max_length: f32) -> T {
+    let length_sq = v.dot(v);
+    if length_sq > max_length * max_length {
+        v * (max_length / length_sq.sqrt())
+    } else {
+        v
+    }
+}
+
+/// Gradually moves `current` towards `target` over roughly `smooth_time`
+/// seconds, like Unity's `SmoothDamp`.
+///
+/// `velocity` is the current velocity and is updated by this function, it
+/// should be stored and passed back in on the next call. The speed is clamped
+/// to `max_speed`, pass `f32::INFINITY` to disable clamping.
+///
+/// The result never overshoots `target`. If `dt` is not positive `current` is
+/// returned unchanged.
+pub fn smooth_damp<T: CurvePoint>(
+    current: T,
+    target: T,
+    velocity: &mut T,
+    smooth_time: f32,
+    max_speed: f32,
+    dt: f32,
+) -> T {
+    if dt <= 0.0 {
+        return current;
+    }
+
+    // Game Programming Gems 4, chapter 1.10
+    let smooth_time = smooth_time.max(MIN_SMOOTH_TIME);
+    let omega = 2.0 / smooth_time;
+    let x = omega * dt;
+    let exp = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+
+    let change = clamp_length(current - target, max_speed * smooth_time);
+    let clamped_target = current - change;
+
+    let temp = (*velocity + change * omega) * dt;
+    let new_velocity = (*velocity - temp * omega) * exp;
+    let output = clamped_target + (change + temp) * exp;
+
+    // prevent overshooting the original target
+    if (target - current).dot(output - target) > 0.0 {
+        // come to rest at the target
+        *velocity = *velocity * 0.0;
+        target
+    } else {
+        *velocity = new_velocity;
+        output
+    }
+}
+
+/// Returns the rotation vector (axis scaled by angle) of the shortest
+/// rotation that takes `from` to `to`.
+#[inline]
+fn rotation_vector_between(from: Quat, to: Quat) -> Vec3 {
+    let delta = to * from.conjugate();
+    // use the shortest path
+    let delta = if delta.w() < 0.0 { -delta } else { delta };
+    // `acos(w)` loses small angles once `w` rounds to 1, so use `atan2`
+    let v = Vec4::from(delta).truncate();
+    let sin_half = v.length();
+    if sin_half > 0.0 {
+        v * (2.0 * scalar_atan2(sin_half, delta.w()) / sin_half)
+    } else {
+        Vec3::zero()
+    }
+}
+
+/// Applies the rotation vector `v` (axis scaled by angle) to `rotation`.
+#[inline]
+fn apply_rotation_vector(rotation: Quat, v: Vec3) -> Quat {
+    let angle = v.length();
+    if angle > 1.0e-8 {
+        (Quat::from_axis_angle(v / angle, angle) * rotation).normalize()
+    } else {
+        rotation
+    }
+}
+
+/// Gradually rotates `current` towards `target` over roughly `smooth_time`
+/// seconds.
+///
+/// `angular_velocity` is the current angular velocity as an axis scaled by
+/// the rotation speed in radians per second. It is updated by this function
+/// and should be passed back in on the next call. The rotation speed is
+/// clamped to `max_speed` radians per second, pass `f32::INFINITY` to disable
+/// clamping.
+pub fn smooth_damp_quat(
+    current: Quat,
+    target: Quat,
+    angular_velocity: &mut Vec3,
+    smooth_time: f32,
+    max_speed: f32,
+    dt: f32,
+) -> Quat {
+    glam_assert!(current.is_normalized());
+    glam_assert!(target.is_normalized());
+    let error = rotation_vector_between(current, target);
+    let step = smooth_damp(
+        Vec3::zero(),
+        error,
+        angular_velocity,
+        smooth_time,
+        max_speed,
+        dt,
+    );
+    apply_rotation_vector(current, step)
+}
+
+/// A critically damped spring which follows a target value without
+/// oscillating.
+///
+/// The spring is integrated analytically, so it is stable for any `dt`.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Spring<T> {
+    pub position: T,
+    pub velocity: T,
+}
+
+impl<T: CurvePoint> Spring<T> {
+    /// Creates a new spring at `position` with the given `velocity`.
+    #[inline]
+    pub fn new(position: T, velocity: T) -> Self {
+        Self { position, velocity }
+    }
+
+    /// Advances the spring by `dt` seconds towards `target`.
+    ///
+    /// `smooth_time` is roughly the time it takes to reach the target, the
+    /// spring stiffness is `(2 / smooth_time)^2`. If `dt` is not positive the
+    /// spring is unchanged.
+    pub fn update(&mut self, target: T, smooth_time: f32, dt: f32) {
+        if dt <= 0.0 {
+            return;
+        }
+        let omega = 2.0 / smooth_time.max(MIN_SMOOTH_TIME);
+        let offset = self.position - target;
+        let j = self.velocity + offset * omega;
+        let exp = scalar_exp(-omega * dt);
+        self.position = target + (offset + j * dt) * exp;
+        self.velocity = (self.velocity - j * (omega * dt)) * exp;
+    }
+}
+
+/// A critically damped spring which follows a target rotation without
+/// oscillating.
+///
+/// The angular velocity is stored as an axis scaled by the rotation speed in
+/// radians per second.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct QuatSpring {
+    pub rotation: Quat,
+    pub angular_velocity: Vec3,
+}
+
+impl QuatSpring {
+    /// Creates a new spring at `rotation` with the given `angular_velocity`.
+    #[inline]
+    pub fn new(rotation: Quat, angular_velocity: Vec3) -> Self {
+        Self {
+            rotation,
+            angular_velocity,
+        }
+    }
+
+    /// Advances the spring by `dt` seconds towards `target`.
+    ///
+    /// See `Spring::update` for details.
+    pub fn update(&mut self, target: Quat, smooth_time: f32, dt: f32) {
+        glam_assert!(self.rotation.is_normalized());
+        glam_assert!(target.is_normalized());
+        // solve the spring in the tangent space around the current rotation
+        let mut spring = Spring::new(Vec3::zero(), self.angular_velocity);
+        spring.update(
+            rotation_vector_between(self.rotation, target),
+            smooth_time,
+            dt,
+        );
+        self.rotation = apply_rotation_vector(self.rotation, spring.position);
+        self.angular_velocity = spring.velocity;
+    }
+}
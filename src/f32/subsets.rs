@@ -0,0 +1,226 @@
+use super::{Mat2, Mat3, Mat3A, Mat4, Quat, Rot2, Vec2, Vec3, Vec4};
+use crate::sampling::cube_to_rotation;
+use core::f32::consts::PI;
+
+/// The largest translation generated for `Affine` matrices.
+const MAX_TRANSLATION: f32 = 100.0;
+
+/// A matrix with orthonormal columns, generated by the `quickcheck` and
+/// `arbitrary` features as a uniformly distributed rotation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Orthonormal<T>(pub T);
+
+/// An invertible matrix, generated by the `quickcheck` and `arbitrary`
+/// features as the product of two rotations and a scale with a magnitude
+/// between `1/8` and `8` on each axis.
+///
+/// The condition number of these matrices is at most `64` so their inverses
+/// can be compared with a tolerance.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Invertible<T>(pub T);
+
+/// An affine transformation matrix, generated by the `quickcheck` and
+/// `arbitrary` features from a positive scale between `1/8` and `8`, a
+/// rotation and a translation of up to `100` on each axis.
+///
+/// The scale, rotation and translation can be recovered from these matrices,
+/// for example with `Mat4::to_scale_rotation_translation`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Affine<T>(pub T);
+
+/// Maps `u` in `[0, 1)` to a magnitude between `1/8` and `8`, distributed
+/// uniformly in log space.
+#[inline]
+fn unit_to_scale(u: f32) -> f32 {
+    (u * 6.0 - 3.0).exp2()
+}
+
+/// Maps `u` in `[0, 1)` to a scale between `1/8` and `8` or between `-8` and
+/// `-1/8`.
+#[inline]
+fn unit_to_signed_scale(u: f32) -> f32 {
+    let scale = unit_to_scale((u * 2.0).fract());
+    if u < 0.5 {
+        -scale
+    } else {
+        scale
+    }
+}
+
+#[inline]
+fn unit_to_translation(u: f32) -> f32 {
+    (u * 2.0 - 1.0) * MAX_TRANSLATION
+}
+
+#[inline]
+fn unit_to_angle(u: f32) -> f32 {
+    u * 2.0 * PI - PI
+}
+
+/// Types which can be generated from samples in `[0, 1)`, used by the
+/// `quickcheck` and `arbitrary` implementations of the types that need more
+/// than independent elements.
+pub(crate) trait FromUnitSamples {
+    /// The number of samples `from_unit_samples` reads.
+    const SAMPLES: usize;
+
+    fn from_unit_samples(u: &[f32]) -> Self;
+}
+
+impl FromUnitSamples for Quat {
+    const SAMPLES: usize = 3;
+
+    #[inline]
+    fn from_unit_samples(u: &[f32]) -> Self {
+        cube_to_rotation(Vec3::new(u[0], u[1], u[2]))
+    }
+}
+
+impl FromUnitSamples for Rot2 {
+    const SAMPLES: usize = 1;
+
+    #[inline]
+    fn from_unit_samples(u: &[f32]) -> Self {
+        Rot2::from_angle(unit_to_angle(u[0]))
+    }
+}
+
+impl FromUnitSamples for Orthonormal<Mat2> {
+    const SAMPLES: usize = 1;
+
+    #[inline]
+    fn from_unit_samples(u: &[f32]) -> Self {
+        Self(Mat2::from_angle(unit_to_angle(u[0])))
+    }
+}
+
+impl FromUnitSamples for Orthonormal<Mat3> {
+    const SAMPLES: usize = 3;
+
+    #[inline]
+    fn from_unit_samples(u: &[f32]) -> Self {
+        Self(Mat3::from_quat(Quat::from_unit_samples(u)))
+    }
+}
+
+impl FromUnitSamples for Orthonormal<Mat3A> {
+    const SAMPLES: usize = 3;
+
+    #[inline]
+    fn from_unit_samples(u: &[f32]) -> Self {
+        Self(Mat3A::from_quat(Quat::from_unit_samples(u)))
+    }
+}
+
+impl FromUnitSamples for Orthonormal<Mat4> {
+    const SAMPLES: usize = 3;
+
+    #[inline]
+    fn from_unit_samples(u: &[f32]) -> Self {
+        Self(Mat4::from_quat(Quat::from_unit_samples(u)))
+    }
+}
+
+impl FromUnitSamples for Invertible<Mat2> {
+    const SAMPLES: usize = 4;
+
+    #[inline]
+    fn from_unit_samples(u: &[f32]) -> Self {
+        let scale = Vec2::new(unit_to_signed_scale(u[1]), unit_to_signed_scale(u[2]));
+        Self(
+            Mat2::from_angle(unit_to_angle(u[0]))
+                * Mat2::from_scale(scale)
+                * Mat2::from_angle(unit_to_angle(u[3])),
+        )
+    }
+}
+
+impl FromUnitSamples for Invertible<Mat3> {
+    const SAMPLES: usize = 9;
+
+    #[inline]
+    fn from_unit_samples(u: &[f32]) -> Self {
+        let scale = Vec3::new(
+            unit_to_signed_scale(u[3]),
+            unit_to_signed_scale(u[4]),
+            unit_to_signed_scale(u[5]),
+        );
+        Self(
+            Mat3::from_quat(Quat::from_unit_samples(&u[..3]))
+                * Mat3::from_scale(scale)
+                * Mat3::from_quat(Quat::from_unit_samples(&u[6..])),
+        )
+    }
+}
+
+impl FromUnitSamples for Invertible<Mat3A> {
+    const SAMPLES: usize = 9;
+
+    #[inline]
+    fn from_unit_samples(u: &[f32]) -> Self {
+        Self(Invertible::<Mat3>::from_unit_samples(u).0.into())
+    }
+}
+
+impl FromUnitSamples for Invertible<Mat4> {
+    const SAMPLES: usize = 10;
+
+    #[inline]
+    fn from_unit_samples(u: &[f32]) -> Self {
+        let scale = Vec4::new(
+            unit_to_signed_scale(u[3]),
+            unit_to_signed_scale(u[4]),
+            unit_to_signed_scale(u[5]),
+            unit_to_signed_scale(u[6]),
+        );
+        let diagonal = Mat4::from_cols(
+            Vec4::unit_x() * scale.x(),
+            Vec4::unit_y() * scale.y(),
+            Vec4::unit_z() * scale.z(),
+            Vec4::unit_w() * scale.w(),
+        );
+        Self(
+            Mat4::from_quat(Quat::from_unit_samples(&u[..3]))
+                * diagonal
+                * Mat4::from_quat(Quat::from_unit_samples(&u[7..])),
+        )
+    }
+}
+
+impl FromUnitSamples for Affine<Mat3> {
+    const SAMPLES: usize = 5;
+
+    #[inline]
+    fn from_unit_samples(u: &[f32]) -> Self {
+        let scale = Vec2::new(unit_to_scale(u[0]), unit_to_scale(u[1]));
+        let translation = Vec2::new(unit_to_translation(u[3]), unit_to_translation(u[4]));
+        Self(Mat3::from_scale_angle_translation(
+            scale,
+            unit_to_angle(u[2]),
+            translation,
+        ))
+    }
+}
+
+impl FromUnitSamples for Affine<Mat4> {
+    const SAMPLES: usize = 9;
+
+    #[inline]
+    fn from_unit_samples(u: &[f32]) -> Self {
+        let scale = Vec3::new(
+            unit_to_scale(u[0]),
+            unit_to_scale(u[1]),
+            unit_to_scale(u[2]),
+        );
+        let translation = Vec3::new(
+            unit_to_translation(u[6]),
+            unit_to_translation(u[7]),
+            unit_to_translation(u[8]),
+        );
+        Self(Mat4::from_scale_rotation_translation(
+            scale,
+            Quat::from_unit_samples(&u[3..6]),
+            translation,
+        ))
+    }
+}
@@ -0,0 +1,435 @@
+//! Approximate equality assertions for tests of code which uses glam.
+//!
+//! `assert_approx_eq!` checks that every element of a value is within a
+//! tolerance of the same element of another, which defaults to
+//! `f32::EPSILON`. Matrices are compared element by element rather than by a
+//! norm. When the assertion fails the message lists each element which is
+//! out of tolerance by column or component name:
+//!
+//! ```should_panic
+//! use glam::{assert_approx_eq, Mat2};
+//!
+//! assert_approx_eq!(Mat2::identity(), Mat2::from_angle(0.1), 1e-3);
+//! ```
+//!
+//! ```text
+//! assertion failed: `left ≈ right` (max_abs_diff: 0.001)
+//!   left: Mat2(...)
+//!  right: Mat2(...)
+//! x_axis.x: left 1, right 0.9950042, diff 0.004995823
+//! x_axis.y: left 0, right 0.09983342, diff 0.09983342
+//! y_axis.x: left 0, right -0.09983342, diff 0.09983342
+//! y_axis.y: left 1, right 0.9950042, diff 0.004995823
+//! ```
+//!
+//! `assert_rotation_approx_eq!` compares types such as `Quat` where `q` and
+//! `-q` represent the same rotation, and passes if either is close.
+//!
+//! Both macros take an optional message after the tolerance, like
+//! `assert_eq!`.
+
+use super::{
+    DualQuat, LinearRgba, Mat2, Mat3, Mat3A, Mat4, Quat, Rot2, SRgba, Vec2, Vec3, Vec3A, Vec4,
+};
+use core::fmt;
+
+#[cfg(feature = "transform-types")]
+use super::{TransformRT, TransformRT2, TransformSRT, TransformSRT2};
+
+const ROWS: [&str; 4] = ["x", "y", "z", "w"];
+
+/// Converts `angle` in degrees to radians.
+#[inline]
+pub fn deg(angle: f32) -> f32 {
+    angle.to_radians()
+}
+
+/// Returns `angle` in radians, for symmetry with `deg`.
+#[inline]
+pub fn rad(angle: f32) -> f32 {
+    angle
+}
+
+/// The named components of a value compared by `ApproxEq`, such as the
+/// columns of a matrix, with up to four elements each.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Components {
+    len: usize,
+    names: [&'static str; 4],
+    widths: [usize; 4],
+    elements: [[f32; 4]; 4],
+}
+
+impl Components {
+    /// Creates an empty list of components.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a component called `name`. Vectors use a single component
+    /// with an empty name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are already four components or `elements` has more
+    /// than four elements.
+    #[inline]
+    pub fn with(mut self, name: &'static str, elements: &[f32]) -> Self {
+        assert!(self.len < 4 && elements.len() <= 4);
+        self.names[self.len] = name;
+        self.widths[self.len] = elements.len();
+        self.elements[self.len][..elements.len()].copy_from_slice(elements);
+        self.len += 1;
+        self
+    }
+
+    /// Returns an iterator over the name and elements of each component.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &[f32])> {
+        self.names[..self.len]
+            .iter()
+            .zip(self.widths.iter())
+            .zip(self.elements.iter())
+            .map(|((&name, &width), elements)| (name, &elements[..width]))
+    }
+}
+
+/// Types which can be compared by `assert_approx_eq!`.
+pub trait ApproxEq: Copy + fmt::Debug {
+    /// Returns the components of `self` which are compared element by
+    /// element.
+    fn components(&self) -> Components;
+
+    /// Returns `true` if every element of `self` is within `max_abs_diff` of
+    /// the same element of `other`. NaN elements are never equal.
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        max_abs_diff_of(self, other) <= max_abs_diff
+    }
+}
+
+/// Types where a value and its antipode represent the same rotation, such as
+/// `q` and `-q` for quaternions, which `assert_rotation_approx_eq!` treats as
+/// equal.
+pub trait DoubleCover: ApproxEq {
+    /// Returns the other representation of the rotation of `self`.
+    fn antipode(&self) -> Self;
+}
+
+/// Returns the largest absolute difference between the elements of `a` and
+/// `b`, or NaN if any difference is NaN.
+fn max_abs_diff_of<T: ApproxEq>(a: &T, b: &T) -> f32 {
+    let mut max = 0.0_f32;
+    for ((_, a), (_, b)) in a.components().iter().zip(b.components().iter()) {
+        for (a, b) in a.iter().zip(b.iter()) {
+            let diff = (a - b).abs();
+            if diff.is_nan() {
+                return diff;
+            }
+            max = max.max(diff);
+        }
+    }
+    max
+}
+
+/// A failed approximate comparison, which formats as a list of the elements
+/// which are out of tolerance.
+#[derive(Clone, Copy, Debug)]
+pub struct Mismatch<T> {
+    /// The left hand side of the comparison.
+    pub left: T,
+    /// The right hand side of the comparison, or its antipode if that was
+    /// closer to `left` in a rotation comparison.
+    pub right: T,
+    /// The largest difference allowed between elements.
+    pub max_abs_diff: f32,
+    /// `true` if `right` is the antipode of the value passed in.
+    pub antipode: bool,
+}
+
+impl<T: ApproxEq> fmt::Display for Mismatch<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let right = if self.antipode { "-right" } else { "right" };
+        writeln!(
+            f,
+            "assertion failed: `left ≈ {}` (max_abs_diff: {})",
+            right, self.max_abs_diff
+        )?;
+        writeln!(f, "{:>6}: {:?}", "left", self.left)?;
+        write!(f, "{:>6}: {:?}", right, self.right)?;
+        let (left, right) = (self.left.components(), self.right.components());
+        for ((name, a), (_, b)) in left.iter().zip(right.iter()) {
+            let width = a.len();
+            for (row, (a, b)) in a.iter().zip(b.iter()).enumerate() {
+                let diff = (a - b).abs();
+                if diff <= self.max_abs_diff {
+                    continue;
+                }
+                writeln!(f)?;
+                match (name, width) {
+                    ("", 1) => write!(f, "value")?,
+                    ("", _) => write!(f, "{}", ROWS[row])?,
+                    (name, 1) => write!(f, "{}", name)?,
+                    (name, _) => write!(f, "{}.{}", name, ROWS[row])?,
+                }
+                write!(f, ": left {}, right {}, diff {}", a, b, diff)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Checks every element of `left` is within `max_abs_diff` of the same
+/// element of `right`.
+pub fn check_approx_eq<T: ApproxEq>(
+    left: &T,
+    right: &T,
+    max_abs_diff: f32,
+) -> Result<(), Mismatch<T>> {
+    if left.approx_eq(right, max_abs_diff) {
+        Ok(())
+    } else {
+        Err(Mismatch {
+            left: *left,
+            right: *right,
+            max_abs_diff,
+            antipode: false,
+        })
+    }
+}
+
+/// Checks `left` is approximately equal to either `right` or its antipode,
+/// which represent the same rotation.
+pub fn check_rotation_approx_eq<T: DoubleCover>(
+    left: &T,
+    right: &T,
+    max_abs_diff: f32,
+) -> Result<(), Mismatch<T>> {
+    let antipode = right.antipode();
+    if left.approx_eq(right, max_abs_diff) || left.approx_eq(&antipode, max_abs_diff) {
+        Ok(())
+    } else {
+        // report the differences from whichever is closer
+        let use_antipode = max_abs_diff_of(left, &antipode) < max_abs_diff_of(left, right);
+        Err(Mismatch {
+            left: *left,
+            right: if use_antipode { antipode } else { *right },
+            max_abs_diff,
+            antipode: use_antipode,
+        })
+    }
+}
+
+/// Asserts that every element of two values is within a tolerance of each
+/// other, `f32::EPSILON` if not given, listing the elements which differ if
+/// not.
+///
+/// The values may be of any type which implements
+/// [`testing::ApproxEq`](testing/trait.ApproxEq.html).
+///
+/// ```
+/// use glam::{assert_approx_eq, Vec3};
+///
+/// assert_approx_eq!(Vec3::new(1.0, 2.0, 3.0).normalize().length(), 1.0);
+/// assert_approx_eq!(Vec3::one() * 0.1 * 3.0, Vec3::splat(0.3), 1e-6);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_eq!($left, $right, core::f32::EPSILON)
+    };
+    ($left:expr, $right:expr, $max_abs_diff:expr $(,)?) => {
+        if let Err(mismatch) = $crate::testing::check_approx_eq(&$left, &$right, $max_abs_diff) {
+            panic!("{}", mismatch);
+        }
+    };
+    ($left:expr, $right:expr, $max_abs_diff:expr, $($arg:tt)+) => {
+        if let Err(mismatch) = $crate::testing::check_approx_eq(&$left, &$right, $max_abs_diff) {
+            panic!("{}\n{}", mismatch, format_args!($($arg)+));
+        }
+    };
+}
+
+/// Asserts that two rotations are approximately equal like
+/// `assert_approx_eq!`, treating `q` and `-q` as equal.
+///
+/// The values may be of any type which implements
+/// [`testing::DoubleCover`](testing/trait.DoubleCover.html).
+///
+/// ```
+/// use glam::{assert_rotation_approx_eq, Quat};
+///
+/// let q = Quat::from_rotation_y(1.0);
+/// assert_rotation_approx_eq!(q, -q);
+/// ```
+#[macro_export]
+macro_rules! assert_rotation_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_rotation_approx_eq!($left, $right, core::f32::EPSILON)
+    };
+    ($left:expr, $right:expr, $max_abs_diff:expr $(,)?) => {
+        if let Err(mismatch) =
+            $crate::testing::check_rotation_approx_eq(&$left, &$right, $max_abs_diff)
+        {
+            panic!("{}", mismatch);
+        }
+    };
+    ($left:expr, $right:expr, $max_abs_diff:expr, $($arg:tt)+) => {
+        if let Err(mismatch) =
+            $crate::testing::check_rotation_approx_eq(&$left, &$right, $max_abs_diff)
+        {
+            panic!("{}\n{}", mismatch, format_args!($($arg)+));
+        }
+    };
+}
+
+impl ApproxEq for f32 {
+    #[inline]
+    fn components(&self) -> Components {
+        Components::new().with("", &[*self])
+    }
+}
+
+macro_rules! impl_vector {
+    ($($t:ty),+) => {
+        $(
+            impl ApproxEq for $t {
+                #[inline]
+                fn components(&self) -> Components {
+                    Components::new().with("", self.as_ref())
+                }
+            }
+        )+
+    };
+}
+
+impl_vector!(Vec2, Vec3, Vec3A, Vec4);
+
+impl ApproxEq for Quat {
+    #[inline]
+    fn components(&self) -> Components {
+        Components::new().with("", self.as_ref())
+    }
+}
+
+impl ApproxEq for Rot2 {
+    #[inline]
+    fn components(&self) -> Components {
+        Components::new().with("", Vec2::from(*self).as_ref())
+    }
+}
+
+macro_rules! impl_matrix {
+    ($t:ty, $($axis:ident),+) => {
+        impl ApproxEq for $t {
+            #[inline]
+            fn components(&self) -> Components {
+                Components::new()$(.with(stringify!($axis), self.$axis().as_ref()))+
+            }
+        }
+    };
+}
+
+impl_matrix!(Mat2, x_axis, y_axis);
+impl_matrix!(Mat3, x_axis, y_axis, z_axis);
+impl_matrix!(Mat3A, x_axis, y_axis, z_axis);
+impl_matrix!(Mat4, x_axis, y_axis, z_axis, w_axis);
+
+macro_rules! impl_color {
+    ($($t:ty),+) => {
+        $(
+            impl ApproxEq for $t {
+                #[inline]
+                fn components(&self) -> Components {
+                    Components::new()
+                        .with("r", &[self.r()])
+                        .with("g", &[self.g()])
+                        .with("b", &[self.b()])
+                        .with("a", &[self.a()])
+                }
+            }
+        )+
+    };
+}
+
+impl_color!(LinearRgba, SRgba);
+
+impl ApproxEq for DualQuat {
+    #[inline]
+    fn components(&self) -> Components {
+        Components::new()
+            .with("real", self.real().as_ref())
+            .with("dual", self.dual().as_ref())
+    }
+}
+
+impl DoubleCover for Quat {
+    #[inline]
+    fn antipode(&self) -> Self {
+        -*self
+    }
+}
+
+impl DoubleCover for DualQuat {
+    #[inline]
+    fn antipode(&self) -> Self {
+        DualQuat::from_real_dual(-self.real(), -self.dual())
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl ApproxEq for TransformRT {
+    #[inline]
+    fn components(&self) -> Components {
+        Components::new()
+            .with("rotation", self.rotation.as_ref())
+            .with("translation", self.translation.as_ref())
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl ApproxEq for TransformSRT {
+    #[inline]
+    fn components(&self) -> Components {
+        Components::new()
+            .with("scale", self.scale.as_ref())
+            .with("rotation", self.rotation.as_ref())
+            .with("translation", self.translation.as_ref())
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl ApproxEq for TransformRT2 {
+    #[inline]
+    fn components(&self) -> Components {
+        Components::new()
+            .with("rotation", Vec2::from(self.rotation).as_ref())
+            .with("translation", self.translation.as_ref())
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl ApproxEq for TransformSRT2 {
+    #[inline]
+    fn components(&self) -> Components {
+        Components::new()
+            .with("scale", self.scale.as_ref())
+            .with("rotation", Vec2::from(self.rotation).as_ref())
+            .with("translation", self.translation.as_ref())
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl DoubleCover for TransformRT {
+    #[inline]
+    fn antipode(&self) -> Self {
+        TransformRT::from_rotation_translation(-self.rotation, self.translation)
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl DoubleCover for TransformSRT {
+    #[inline]
+    fn antipode(&self) -> Self {
+        TransformSRT::from_scale_rotation_translation(self.scale, -self.rotation, self.translation)
+    }
+}
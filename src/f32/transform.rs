@@ -1,5 +1,5 @@
 use super::{Mat4, Quat, Vec3};
-use core::ops::Mul;
+use core::{fmt, ops::Mul};
 
 #[cfg(feature = "rand")]
 use rand::{
@@ -44,8 +44,15 @@ impl Default for TransformRT {
 }
 
 impl TransformSRT {
+    /// The identity transform.
+    pub const IDENTITY: Self = Self::identity();
+
     #[inline]
-    pub fn from_scale_rotation_translation(scale: Vec3, rotation: Quat, translation: Vec3) -> Self {
+    pub const fn from_scale_rotation_translation(
+        scale: Vec3,
+        rotation: Quat,
+        translation: Vec3,
+    ) -> Self {
         Self {
             scale,
             rotation,
@@ -63,7 +70,7 @@ impl TransformSRT {
     }
 
     #[inline]
-    pub fn identity() -> Self {
+    pub const fn identity() -> Self {
         Self {
             scale: Vec3::one(),
             rotation: Quat::identity(),
@@ -173,8 +180,11 @@ fn mul_rt_rt(lhs: &TransformRT, rhs: &TransformRT) -> TransformRT {
 }
 
 impl TransformRT {
+    /// The identity transform.
+    pub const IDENTITY: Self = Self::identity();
+
     #[inline]
-    pub fn from_rotation_translation(rotation: Quat, translation: Vec3) -> Self {
+    pub const fn from_rotation_translation(rotation: Quat, translation: Vec3) -> Self {
         Self {
             rotation,
             translation,
@@ -182,7 +192,7 @@ impl TransformRT {
     }
 
     #[inline]
-    pub fn identity() -> Self {
+    pub const fn identity() -> Self {
         Self {
             rotation: Quat::identity(),
             translation: Vec3::zero(),
@@ -236,6 +246,22 @@ impl TransformRT {
     }
 }
 
+impl fmt::Display for TransformSRT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{}, {}, {}]",
+            self.scale, self.rotation, self.translation
+        )
+    }
+}
+
+impl fmt::Display for TransformRT {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.rotation, self.translation)
+    }
+}
+
 impl AsRef<TransformRT> for TransformSRT {
     #[inline]
     fn as_ref(&self) -> &TransformRT {
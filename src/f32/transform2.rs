@@ -0,0 +1,393 @@
+use super::{Mat3, Rot2, Vec2, Vec3};
+use core::{fmt, ops::Mul};
+
+#[cfg(feature = "rand")]
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[repr(C)]
+pub struct TransformSRT2 {
+    pub scale: Vec2,
+    pub rotation: Rot2,
+    pub translation: Vec2,
+}
+
+impl Default for TransformSRT2 {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            scale: Vec2::one(),
+            rotation: Rot2::identity(),
+            translation: Vec2::zero(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[repr(C)]
+pub struct TransformRT2 {
+    pub rotation: Rot2,
+    pub translation: Vec2,
+}
+
+impl Default for TransformRT2 {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            rotation: Rot2::identity(),
+            translation: Vec2::zero(),
+        }
+    }
+}
+
+impl TransformSRT2 {
+    /// The identity transform.
+    pub const IDENTITY: Self = Self::identity();
+
+    #[inline]
+    pub const fn from_scale_rotation_translation(
+        scale: Vec2,
+        rotation: Rot2,
+        translation: Vec2,
+    ) -> Self {
+        Self {
+            scale,
+            rotation,
+            translation,
+        }
+    }
+
+    #[inline]
+    pub fn from_transform_rt2(scale: Vec2, rt: &TransformRT2) -> Self {
+        Self {
+            scale,
+            rotation: rt.rotation,
+            translation: rt.translation,
+        }
+    }
+
+    #[inline]
+    pub const fn identity() -> Self {
+        Self {
+            scale: Vec2::one(),
+            rotation: Rot2::identity(),
+            translation: Vec2::zero(),
+        }
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// A `TransformSRT2` applies scale before rotation, so the inverse of a
+    /// non-uniform scale is only exact when the rotation is a multiple of 180
+    /// degrees. The translation always matches the inverse of the equivalent
+    /// `Mat3`.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let scale = self.scale.reciprocal();
+        let rotation = self.rotation.conjugate();
+        let translation = -((rotation * self.translation) * scale);
+        Self {
+            scale,
+            rotation,
+            translation,
+        }
+    }
+
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let rotation = self.rotation.normalize();
+        Self {
+            scale: self.scale,
+            rotation,
+            translation: self.translation,
+        }
+    }
+
+    #[inline]
+    pub fn mul_transform(&self, other: &Self) -> Self {
+        mul_srt2_srt2(self, other)
+    }
+
+    #[inline]
+    pub fn transform_vec2(self, other: Vec2) -> Vec2 {
+        (self.rotation * (other * self.scale)) + self.translation
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two `TransformSRT2`'s contain similar
+    /// elements. It works best when comparing with a known value. The
+    /// `max_abs_diff` that should be used used depends on the values being
+    /// compared against.
+    ///
+    /// For more on floating point comparisons see
+    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[inline]
+    pub fn abs_diff_eq(&self, other: Self, max_abs_diff: f32) -> bool {
+        self.scale.abs_diff_eq(other.scale, max_abs_diff)
+            && self.rotation.abs_diff_eq(other.rotation, max_abs_diff)
+            && self
+                .translation
+                .abs_diff_eq(other.translation, max_abs_diff)
+    }
+}
+
+#[inline]
+fn srt2_to_mat3(srt: &TransformSRT2) -> Mat3 {
+    let (cos, sin) = srt.rotation.into();
+    let (scale_x, scale_y) = srt.scale.into();
+    Mat3::from_cols(
+        Vec3::new(cos * scale_x, sin * scale_x, 0.0),
+        Vec3::new(-sin * scale_y, cos * scale_y, 0.0),
+        srt.translation.extend(1.0),
+    )
+}
+
+#[inline]
+fn mul_srt2_srt2(lhs: &TransformSRT2, rhs: &TransformSRT2) -> TransformSRT2 {
+    let min_scale = lhs.scale.min(rhs.scale);
+    let scale = lhs.scale * rhs.scale;
+
+    if min_scale.cmplt(Vec2::zero()).any() {
+        // If negative scale, we go through a matrix. `lhs` is applied first.
+        let result_mtx = srt2_to_mat3(rhs) * srt2_to_mat3(lhs);
+
+        let sign = scale.sign();
+        let x_axis = result_mtx.x_axis().truncate().normalize() * sign.x();
+        let rotation = Rot2::from_cos_sin(x_axis.x(), x_axis.y());
+        let translation = result_mtx.z_axis().truncate();
+        TransformSRT2 {
+            scale,
+            rotation,
+            translation,
+        }
+    } else {
+        let rotation = lhs.rotation * rhs.rotation;
+        let translation = (rhs.rotation * (lhs.translation * rhs.scale)) + rhs.translation;
+        TransformSRT2 {
+            scale,
+            rotation,
+            translation,
+        }
+    }
+}
+
+#[inline]
+fn mul_rt2_rt2(lhs: &TransformRT2, rhs: &TransformRT2) -> TransformRT2 {
+    let rotation = lhs.rotation * rhs.rotation;
+    let translation = (rhs.rotation * lhs.translation) + rhs.translation;
+    TransformRT2 {
+        rotation,
+        translation,
+    }
+}
+
+impl TransformRT2 {
+    /// The identity transform.
+    pub const IDENTITY: Self = Self::identity();
+
+    #[inline]
+    pub const fn from_rotation_translation(rotation: Rot2, translation: Vec2) -> Self {
+        Self {
+            rotation,
+            translation,
+        }
+    }
+
+    #[inline]
+    pub const fn identity() -> Self {
+        Self {
+            rotation: Rot2::identity(),
+            translation: Vec2::zero(),
+        }
+    }
+
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.conjugate();
+        let translation = -(rotation * self.translation);
+        Self {
+            rotation,
+            translation,
+        }
+    }
+
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let rotation = self.rotation.normalize();
+        Self {
+            rotation,
+            translation: self.translation,
+        }
+    }
+
+    #[inline]
+    pub fn mul_transform(&self, other: &Self) -> Self {
+        mul_rt2_rt2(self, other)
+    }
+
+    #[inline]
+    pub fn transform_vec2(self, other: Vec2) -> Vec2 {
+        (self.rotation * other) + self.translation
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    ///
+    /// This can be used to compare if two `TransformRT2`'s contain similar
+    /// elements. It works best when comparing with a known value. The
+    /// `max_abs_diff` that should be used used depends on the values being
+    /// compared against.
+    ///
+    /// For more on floating point comparisons see
+    /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+    #[inline]
+    pub fn abs_diff_eq(&self, other: Self, max_abs_diff: f32) -> bool {
+        self.rotation.abs_diff_eq(other.rotation, max_abs_diff)
+            && self
+                .translation
+                .abs_diff_eq(other.translation, max_abs_diff)
+    }
+}
+
+impl fmt::Display for TransformSRT2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{}, {}, {}]",
+            self.scale, self.rotation, self.translation
+        )
+    }
+}
+
+impl fmt::Display for TransformRT2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.rotation, self.translation)
+    }
+}
+
+impl AsRef<TransformRT2> for TransformSRT2 {
+    #[inline]
+    fn as_ref(&self) -> &TransformRT2 {
+        unsafe { &*((&self.rotation) as *const Rot2 as *const TransformRT2) }
+    }
+}
+
+impl AsMut<TransformRT2> for TransformSRT2 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut TransformRT2 {
+        unsafe { &mut *((&mut self.rotation) as *mut Rot2 as *mut TransformRT2) }
+    }
+}
+
+impl Mul<Vec2> for TransformRT2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, other: Vec2) -> Vec2 {
+        self.transform_vec2(other)
+    }
+}
+
+impl Mul<Vec2> for TransformSRT2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, other: Vec2) -> Vec2 {
+        self.transform_vec2(other)
+    }
+}
+
+impl Mul<TransformRT2> for TransformRT2 {
+    type Output = TransformRT2;
+    #[inline]
+    fn mul(self, other: TransformRT2) -> TransformRT2 {
+        mul_rt2_rt2(&self, &other)
+    }
+}
+
+impl Mul<TransformSRT2> for TransformSRT2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self::Output {
+        mul_srt2_srt2(&self, &other)
+    }
+}
+
+impl Mul<TransformRT2> for TransformSRT2 {
+    type Output = TransformSRT2;
+    #[inline]
+    fn mul(self, other: TransformRT2) -> Self::Output {
+        mul_srt2_srt2(&self, &other.into())
+    }
+}
+
+impl Mul<TransformSRT2> for TransformRT2 {
+    type Output = TransformSRT2;
+    #[inline]
+    fn mul(self, other: TransformSRT2) -> Self::Output {
+        mul_srt2_srt2(&self.into(), &other)
+    }
+}
+
+impl From<TransformRT2> for TransformSRT2 {
+    #[inline]
+    fn from(tr: TransformRT2) -> Self {
+        Self {
+            translation: tr.translation,
+            rotation: tr.rotation,
+            scale: Vec2::one(),
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<TransformRT2> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TransformRT2 {
+        TransformRT2::from_rotation_translation(
+            rng.gen::<Rot2>(),
+            Vec2::new(
+                rng.gen_range(f32::MIN, f32::MAX),
+                rng.gen_range(f32::MIN, f32::MAX),
+            ),
+        )
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<TransformSRT2> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TransformSRT2 {
+        let mut gen_non_zero = || loop {
+            let f: f32 = rng.gen_range(f32::MIN, f32::MAX);
+            if f.abs() > f32::MIN_POSITIVE {
+                return f;
+            }
+        };
+        TransformSRT2::from_scale_rotation_translation(
+            Vec2::new(gen_non_zero(), gen_non_zero()),
+            rng.gen::<Rot2>(),
+            Vec2::new(
+                rng.gen_range(f32::MIN, f32::MAX),
+                rng.gen_range(f32::MIN, f32::MAX),
+            ),
+        )
+    }
+}
+
+impl From<TransformSRT2> for Mat3 {
+    #[inline]
+    fn from(srt: TransformSRT2) -> Self {
+        srt2_to_mat3(&srt)
+    }
+}
+
+impl From<TransformRT2> for Mat3 {
+    #[inline]
+    fn from(rt: TransformRT2) -> Self {
+        srt2_to_mat3(&rt.into())
+    }
+}
@@ -1,18 +1,22 @@
+// Generated from vec_mask.rs.tera template. Edit the template, not the generated file.
+
 use super::Vec2;
 use core::{fmt, ops::*};
 
 /// A 2-dimensional vector mask.
 ///
-/// This type is typically created by comparison methods on `Vec2`.
+/// This type is typically created by comparison methods on `Vec2`.  It is
+/// essentially a vector of two boolean values.
 #[derive(Clone, Copy, Default, PartialEq, Eq, Ord, PartialOrd, Hash)]
 #[repr(C)]
-pub struct Vec2Mask(u32, u32);
+pub struct Vec2Mask(pub(crate) u32, pub(crate) u32);
 
 impl Vec2Mask {
     /// Creates a new `Vec2Mask`.
     #[inline]
-    pub fn new(x: bool, y: bool) -> Self {
+    pub const fn new(x: bool, y: bool) -> Self {
         const MASK: [u32; 2] = [0, 0xff_ff_ff_ff];
+
         Self(MASK[x as usize], MASK[y as usize])
     }
 
@@ -32,7 +36,6 @@ impl Vec2Mask {
     /// In other words: `x || y`.
     #[inline]
     pub fn any(self) -> bool {
-        // implementaton matches SSE2 `Vec4Mask` version
         ((self.0 | self.1) & 0x1) != 0
     }
 
@@ -41,7 +44,6 @@ impl Vec2Mask {
     /// In other words: `x && y`.
     #[inline]
     pub fn all(self) -> bool {
-        // implementaton matches SSE2 `Vec4Mask` version
         ((self.0 & self.1) & 0x1) != 0
     }
 
@@ -101,20 +103,22 @@ impl Not for Vec2Mask {
 
 impl fmt::Debug for Vec2Mask {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Vec2Mask({:#x}, {:#x})", self.0, self.1)
+        let arr = self.as_ref();
+        write!(f, "Vec2Mask({:#x}, {:#x})", arr[0], arr[1])
     }
 }
 
 impl fmt::Display for Vec2Mask {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}, {}]", self.0 != 0, self.1 != 0)
+        let arr = self.as_ref();
+        write!(f, "[{}, {}]", arr[0] != 0, arr[1] != 0)
     }
 }
 
 impl From<Vec2Mask> for [u32; 2] {
     #[inline]
     fn from(mask: Vec2Mask) -> Self {
-        [mask.0, mask.1]
+        *mask.as_ref()
     }
 }
 
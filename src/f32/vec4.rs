@@ -1,3 +1,5 @@
+// Generated from vec.rs.tera template. Edit the template, not the generated file.
+
 use super::{Vec3, Vec4Mask};
 use core::{fmt, ops::*};
 
@@ -6,23 +8,16 @@ use core::arch::x86::*;
 #[cfg(all(vec4sse2, target_arch = "x86_64"))]
 use core::arch::x86_64::*;
 
+#[cfg(vec4sse2)]
+use super::x86_utils::UnionCast;
 #[cfg(vec4sse2)]
 use crate::Align16;
 #[cfg(vec4sse2)]
 use core::{cmp::Ordering, f32, mem::MaybeUninit};
 
-#[cfg(vec4sse2)]
-pub(crate) const X_AXIS: Align16<[f32; 4]> = Align16([1.0, 0.0, 0.0, 0.0]);
-#[cfg(vec4sse2)]
-pub(crate) const Y_AXIS: Align16<[f32; 4]> = Align16([0.0, 1.0, 0.0, 0.0]);
-#[cfg(vec4sse2)]
-pub(crate) const Z_AXIS: Align16<[f32; 4]> = Align16([0.0, 0.0, 1.0, 0.0]);
-#[cfg(vec4sse2)]
-pub(crate) const W_AXIS: Align16<[f32; 4]> = Align16([0.0, 0.0, 0.0, 1.0]);
-
 /// A 4-dimensional vector.
 ///
-/// This type is 16 byte aligned.
+/// This type is 16 byte aligned unless the `scalar-math` feature is enabled.
 #[cfg(vec4sse2)]
 #[derive(Clone, Copy, Debug)]
 #[repr(C)]
@@ -30,7 +25,7 @@ pub struct Vec4(pub(crate) __m128);
 
 /// A 4-dimensional vector.
 ///
-/// This type is 16 byte aligned unless the `scalar-math` feature is enabed.
+/// This type is 16 byte aligned unless the `scalar-math` feature is enabled.
 #[cfg(vec4f32)]
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
 // if compiling with simd enabled assume alignment needs to match the simd type
@@ -86,18 +81,43 @@ impl From<__m128> for Vec4 {
 }
 
 #[inline]
-pub fn vec4(x: f32, y: f32, z: f32, w: f32) -> Vec4 {
+pub const fn vec4(x: f32, y: f32, z: f32, w: f32) -> Vec4 {
     Vec4::new(x, y, z, w)
 }
 
 impl Vec4 {
+    /// All elements set to `0.0`.
+    pub const ZERO: Self = Self::zero();
+
+    /// All elements set to `1.0`.
+    pub const ONE: Self = Self::one();
+
+    /// `[x: 1.0, y: 0.0, z: 0.0, w: 0.0]`.
+    pub const X: Self = Self::unit_x();
+
+    /// `[x: 0.0, y: 1.0, z: 0.0, w: 0.0]`.
+    pub const Y: Self = Self::unit_y();
+
+    /// `[x: 0.0, y: 0.0, z: 1.0, w: 0.0]`.
+    pub const Z: Self = Self::unit_z();
+
+    /// `[x: 0.0, y: 0.0, z: 0.0, w: 1.0]`.
+    pub const W: Self = Self::unit_w();
+
     /// Creates a new `Vec4`.
     #[inline]
-    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
         #[cfg(vec4sse2)]
         unsafe {
-            Self(_mm_set_ps(w, z, y, x))
+            // `_mm_set_ps` is not a `const fn` so initialize through a union
+            Self(
+                UnionCast {
+                    f32x4: [x, y, z, w],
+                }
+                .m128,
+            )
         }
+
         #[cfg(vec4f32)]
         {
             Self(x, y, z, w)
@@ -106,112 +126,51 @@ impl Vec4 {
 
     /// Creates a new `Vec4` with all elements set to `0.0`.
     #[inline]
-    pub fn zero() -> Self {
-        #[cfg(vec4sse2)]
-        unsafe {
-            Self(_mm_setzero_ps())
-        }
-
-        #[cfg(vec4f32)]
-        {
-            Self(0.0, 0.0, 0.0, 0.0)
-        }
+    pub const fn zero() -> Self {
+        Self::splat(0.0)
     }
 
     /// Creates a new `Vec4` with all elements set to `1.0`.
     #[inline]
-    pub fn one() -> Self {
-        #[cfg(vec4sse2)]
-        unsafe {
-            Self(_mm_set1_ps(1.0))
-        }
-
-        #[cfg(vec4f32)]
-        {
-            Self(1.0, 1.0, 1.0, 1.0)
-        }
+    pub const fn one() -> Self {
+        Self::splat(1.0)
     }
 
     /// Creates a new `Vec4` with values `[x: 1.0, y: 0.0, z: 0.0, w: 0.0]`.
     #[inline]
-    pub fn unit_x() -> Self {
-        #[cfg(vec4sse2)]
-        unsafe {
-            Self(_mm_load_ps(X_AXIS.0.as_ptr()))
-        }
-
-        #[cfg(vec4f32)]
-        {
-            Self(1.0, 0.0, 0.0, 0.0)
-        }
+    pub const fn unit_x() -> Self {
+        Self::new(1.0, 0.0, 0.0, 0.0)
     }
 
     /// Creates a new `Vec4` with values `[x: 0.0, y: 1.0, z: 0.0, w: 0.0]`.
     #[inline]
-    pub fn unit_y() -> Self {
-        #[cfg(vec4sse2)]
-        unsafe {
-            Self(_mm_load_ps(Y_AXIS.0.as_ptr()))
-        }
-
-        #[cfg(vec4f32)]
-        {
-            Self(0.0, 1.0, 0.0, 0.0)
-        }
+    pub const fn unit_y() -> Self {
+        Self::new(0.0, 1.0, 0.0, 0.0)
     }
 
     /// Creates a new `Vec4` with values `[x: 0.0, y: 0.0, z: 1.0, w: 0.0]`.
     #[inline]
-    pub fn unit_z() -> Self {
-        #[cfg(vec4sse2)]
-        unsafe {
-            Self(_mm_load_ps(Z_AXIS.0.as_ptr()))
-        }
-
-        #[cfg(vec4f32)]
-        {
-            Self(0.0, 0.0, 1.0, 0.0)
-        }
+    pub const fn unit_z() -> Self {
+        Self::new(0.0, 0.0, 1.0, 0.0)
     }
 
     /// Creates a new `Vec4` with values `[x: 0.0, y: 0.0, z: 0.0, w: 1.0]`.
     #[inline]
-    pub fn unit_w() -> Self {
-        #[cfg(vec4sse2)]
-        unsafe {
-            Self(_mm_load_ps(W_AXIS.0.as_ptr()))
-        }
-
-        #[cfg(vec4f32)]
-        {
-            Self(0.0, 0.0, 0.0, 1.0)
-        }
+    pub const fn unit_w() -> Self {
+        Self::new(0.0, 0.0, 0.0, 1.0)
     }
 
     /// Creates a new `Vec4` with all elements set to `v`.
     #[inline]
-    pub fn splat(v: f32) -> Self {
-        #[cfg(vec4sse2)]
-        unsafe {
-            Self(_mm_set_ps1(v))
-        }
-
-        #[cfg(vec4f32)]
-        {
-            Self(v, v, v, v)
-        }
+    pub const fn splat(v: f32) -> Self {
+        Self::new(v, v, v, v)
     }
 
     /// Creates a `Vec3` from the first three elements of `self`,
     /// removing `w`.
     #[inline]
     pub fn truncate(self) -> Vec3 {
-        #[cfg(all(vec4sse2, vec3sse2))]
-        {
-            self.0.into()
-        }
-
-        #[cfg(all(vec4sse2, not(vec3sse2)))]
+        #[cfg(vec4sse2)]
         {
             let (x, y, z, _) = self.into();
             Vec3::new(x, y, z)
@@ -464,16 +423,6 @@ impl Vec4 {
         _mm_add_ps(x2z2_y2w2_0_0, y2w2_0_0_0)
     }
 
-    /// Returns Vec4 dot in all lanes of Vec4
-    #[cfg(vec4sse2)]
-    #[inline]
-    pub(crate) fn dot_as_vec4(self, other: Self) -> Self {
-        unsafe {
-            let dot_in_x = self.dot_as_m128(other);
-            Self(_mm_shuffle_ps(dot_in_x, dot_in_x, 0b00_00_00_00))
-        }
-    }
-
     /// Computes the 4D dot product of `self` and `other`.
     #[inline]
     pub fn dot(self, other: Self) -> f32 {
@@ -484,7 +433,18 @@ impl Vec4 {
 
         #[cfg(vec4f32)]
         {
-            (self.0 * other.0) + (self.1 * other.1) + (self.2 * other.2) + (self.3 * other.3)
+            // summed pairwise in the same order as SSE2 so both give the same result
+            ((self.0 * other.0) + (self.2 * other.2)) + ((self.1 * other.1) + (self.3 * other.3))
+        }
+    }
+
+    /// Returns the Vec4 dot product in all lanes of a Vec4.
+    #[cfg(vec4sse2)]
+    #[inline]
+    pub(crate) fn dot_as_vec4(self, other: Self) -> Self {
+        unsafe {
+            let dot_in_x = self.dot_as_m128(other);
+            Self(_mm_shuffle_ps(dot_in_x, dot_in_x, 0b00_00_00_00))
         }
     }
 
@@ -492,9 +452,8 @@ impl Vec4 {
     #[inline]
     pub fn length(self) -> f32 {
         #[cfg(vec4sse2)]
-        {
-            let dot = self.dot_as_vec4(self);
-            unsafe { _mm_cvtss_f32(_mm_sqrt_ps(dot.0)) }
+        unsafe {
+            _mm_cvtss_f32(_mm_sqrt_ss(self.dot_as_m128(self)))
         }
 
         #[cfg(vec4f32)]
@@ -537,12 +496,24 @@ impl Vec4 {
     /// For valid results, `self` must _not_ be of length zero.
     #[inline]
     pub fn normalize(self) -> Self {
-        #[cfg(vec4sse2)]
+        #[cfg(all(vec4sse2, not(feature = "deterministic")))]
         {
             let dot = self.dot_as_vec4(self);
             unsafe { Self(_mm_div_ps(self.0, _mm_sqrt_ps(dot.0))) }
         }
 
+        // multiply by the reciprocal like the scalar version
+        #[cfg(all(vec4sse2, feature = "deterministic"))]
+        {
+            let dot = self.dot_as_vec4(self);
+            unsafe {
+                Self(_mm_mul_ps(
+                    self.0,
+                    _mm_div_ps(_mm_set_ps1(1.0), _mm_sqrt_ps(dot.0)),
+                ))
+            }
+        }
+
         #[cfg(vec4f32)]
         {
             self * self.length_reciprocal()
@@ -610,7 +581,7 @@ impl Vec4 {
 
         #[cfg(vec4f32)]
         {
-            self.0.min(self.1.min(self.2.min(self.3)))
+            self.0.min(self.1).min(self.2).min(self.3)
         }
     }
 
@@ -629,7 +600,7 @@ impl Vec4 {
 
         #[cfg(vec4f32)]
         {
-            self.0.max(self.1.max(self.2.min(self.3)))
+            self.0.max(self.1).max(self.2).max(self.3)
         }
     }
 
@@ -780,7 +751,7 @@ impl Vec4 {
 
         #[cfg(vec4f32)]
         {
-            Self(slice[0], slice[1], slice[2], slice[3])
+            Self::new(slice[0], slice[1], slice[2], slice[3])
         }
     }
 
@@ -843,6 +814,8 @@ impl Vec4 {
         }
     }
 
+    /// Returns a new `Vec4` containing the nearest integer to each
+    /// element of `self`. Half-way cases are rounded away from `0.0`.
     #[inline]
     pub fn round(self) -> Self {
         #[cfg(vec4sse2)]
@@ -862,6 +835,8 @@ impl Vec4 {
         }
     }
 
+    /// Returns a new `Vec4` containing the largest integer less than or
+    /// equal to each element of `self`.
     #[inline]
     pub fn floor(self) -> Self {
         #[cfg(vec4sse2)]
@@ -881,6 +856,8 @@ impl Vec4 {
         }
     }
 
+    /// Returns a new `Vec4` containing the smallest integer greater than or
+    /// equal to each element of `self`.
     #[inline]
     pub fn ceil(self) -> Self {
         #[cfg(vec4sse2)]
@@ -897,25 +874,12 @@ impl Vec4 {
 
     /// Returns a new `Vec4` with elements representing the sign of `self`.
     ///
-    /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
-    /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    /// - `1.0` if the number is positive, `+0.0`, `-0.0` or `INFINITY`
+    /// - `-1.0` if the number is negative, `NEG_INFINITY` or `NaN`
     #[inline]
     pub fn sign(self) -> Self {
-        #[cfg(vec4sse2)]
-        {
-            let mask = self.cmpge(Self::zero());
-            mask.select(Self::splat(1.0), Self::splat(-1.0))
-        }
-
-        #[cfg(vec4f32)]
-        {
-            Self(
-                self.0.signum(),
-                self.1.signum(),
-                self.2.signum(),
-                self.3.signum(),
-            )
-        }
+        let mask = self.cmpge(Self::zero());
+        mask.select(Self::splat(1.0), Self::splat(-1.0))
     }
 
     /// Computes the reciprocal `1.0/n` of each element, returning the
@@ -974,16 +938,16 @@ impl AsMut<[f32; 4]> for Vec4 {
 }
 
 impl fmt::Display for Vec4 {
-    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         #[cfg(vec4sse2)]
         {
             let (x, y, z, w) = (*self).into();
-            write!(fmt, "[{}, {}, {}, {}]", x, y, z, w)
+            write!(f, "[{}, {}, {}, {}]", x, y, z, w)
         }
 
         #[cfg(vec4f32)]
         {
-            write!(fmt, "[{}, {}, {}, {}]", self.0, self.1, self.2, self.3)
+            write!(f, "[{}, {}, {}, {}]", self.0, self.1, self.2, self.3)
         }
     }
 }
@@ -1165,7 +1129,7 @@ impl Mul<Vec4> for f32 {
     }
 }
 
-impl Add for Vec4 {
+impl Add<Vec4> for Vec4 {
     type Output = Self;
     #[inline]
     fn add(self, other: Self) -> Self {
@@ -1186,7 +1150,7 @@ impl Add for Vec4 {
     }
 }
 
-impl AddAssign for Vec4 {
+impl AddAssign<Vec4> for Vec4 {
     #[inline]
     fn add_assign(&mut self, other: Self) {
         #[cfg(vec4sse2)]
@@ -1204,7 +1168,7 @@ impl AddAssign for Vec4 {
     }
 }
 
-impl Sub for Vec4 {
+impl Sub<Vec4> for Vec4 {
     type Output = Self;
     #[inline]
     fn sub(self, other: Self) -> Self {
@@ -1225,7 +1189,7 @@ impl Sub for Vec4 {
     }
 }
 
-impl SubAssign for Vec4 {
+impl SubAssign<Vec4> for Vec4 {
     #[inline]
     fn sub_assign(&mut self, other: Self) {
         #[cfg(vec4sse2)]
@@ -1310,7 +1274,7 @@ impl From<[f32; 4]> for Vec4 {
 
         #[cfg(vec4f32)]
         {
-            Self(a[0], a[1], a[2], a[3])
+            Self::new(a[0], a[1], a[2], a[3])
         }
     }
 }
@@ -1343,5 +1307,5 @@ fn test_vec4_private() {
     assert_eq!(vec4(1.0, 2.0, 3.0, 4.0).dup_x(), vec4(1.0, 1.0, 1.0, 1.0));
     assert_eq!(vec4(1.0, 2.0, 3.0, 4.0).dup_y(), vec4(2.0, 2.0, 2.0, 2.0));
     assert_eq!(vec4(1.0, 2.0, 3.0, 4.0).dup_z(), vec4(3.0, 3.0, 3.0, 3.0));
-    assert_eq!(vec4(1.0, 2.0, 4.0, 4.0).dup_w(), vec4(4.0, 4.0, 4.0, 4.0));
+    assert_eq!(vec4(1.0, 2.0, 3.0, 4.0).dup_w(), vec4(4.0, 4.0, 4.0, 4.0));
 }
@@ -1,6 +1,10 @@
-use crate::Vec4;
+// Generated from vec_mask.rs.tera template. Edit the template, not the generated file.
+
+use super::Vec4;
 use core::{fmt, ops::*};
 
+#[cfg(vec4sse2)]
+use super::x86_utils::UnionCast;
 #[cfg(all(vec4sse2, target_arch = "x86"))]
 use core::arch::x86::*;
 #[cfg(all(vec4sse2, target_arch = "x86_64"))]
@@ -17,11 +21,20 @@ use core::{cmp::Ordering, hash};
 #[repr(C)]
 pub struct Vec4Mask(pub(crate) __m128);
 
+/// A 4-dimensional vector mask.
+///
+/// This type is typically created by comparison methods on `Vec4`.  It is
+/// essentially a vector of four boolean values.
 #[cfg(vec4f32)]
 #[derive(Clone, Copy, Default, PartialEq, Eq, Ord, PartialOrd, Hash)]
 #[cfg_attr(vec4f32_align16, repr(align(16)))]
 #[repr(C)]
-pub struct Vec4Mask(u32, u32, u32, u32);
+pub struct Vec4Mask(
+    pub(crate) u32,
+    pub(crate) u32,
+    pub(crate) u32,
+    pub(crate) u32,
+);
 
 #[cfg(vec4sse2)]
 impl Default for Vec4Mask {
@@ -69,7 +82,7 @@ impl hash::Hash for Vec4Mask {
 impl Vec4Mask {
     /// Creates a new `Vec4Mask`.
     #[inline]
-    pub fn new(x: bool, y: bool, z: bool, w: bool) -> Self {
+    pub const fn new(x: bool, y: bool, z: bool, w: bool) -> Self {
         // A SSE2 mask can be any bit pattern but for the `Vec4Mask` implementation of select we
         // expect either 0 or 0xff_ff_ff_ff. This should be a safe assumption as this type can only
         // be created via this function or by `Vec4` methods.
@@ -77,12 +90,17 @@ impl Vec4Mask {
         const MASK: [u32; 2] = [0, 0xff_ff_ff_ff];
         #[cfg(vec4sse2)]
         unsafe {
-            Self(_mm_set_ps(
-                f32::from_bits(MASK[w as usize]),
-                f32::from_bits(MASK[z as usize]),
-                f32::from_bits(MASK[y as usize]),
-                f32::from_bits(MASK[x as usize]),
-            ))
+            Self(
+                UnionCast {
+                    u32x4: [
+                        MASK[x as usize],
+                        MASK[y as usize],
+                        MASK[z as usize],
+                        MASK[w as usize],
+                    ],
+                }
+                .m128,
+            )
         }
 
         #[cfg(vec4f32)]
@@ -279,24 +297,12 @@ impl Not for Vec4Mask {
 
 impl fmt::Debug for Vec4Mask {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        #[cfg(vec4sse2)]
-        {
-            let arr = self.as_ref();
-            write!(
-                f,
-                "Vec4Mask({:#x}, {:#x}, {:#x}, {:#x})",
-                arr[0], arr[1], arr[2], arr[3]
-            )
-        }
-
-        #[cfg(vec4f32)]
-        {
-            write!(
-                f,
-                "Vec4Mask({:#x}, {:#x}, {:#x}, {:#x})",
-                self.0, self.1, self.2, self.3
-            )
-        }
+        let arr = self.as_ref();
+        write!(
+            f,
+            "Vec4Mask({:#x}, {:#x}, {:#x}, {:#x})",
+            arr[0], arr[1], arr[2], arr[3]
+        )
     }
 }
 
@@ -0,0 +1,82 @@
+use super::{Hashable, Vec3};
+use std::collections::HashMap;
+
+/// Returns the spatial hash grid cell containing `p` for the given reciprocal
+/// cell size.
+#[inline]
+fn grid_cell(p: Vec3, inv_cell_size: f32) -> (i32, i32, i32) {
+    let c = (p * inv_cell_size).floor();
+    (c.x() as i32, c.y() as i32, c.z() as i32)
+}
+
+/// Merges positions that are within `epsilon` of each other, returning the
+/// unique positions and an index into them for every input position.
+///
+/// Positions are merged with the first unique position found within
+/// `epsilon`, so the result depends on the input order. Unique positions
+/// keep their original relative order. An `epsilon` of `0.0` merges only
+/// positions which are exactly equal, treating `-0.0` and `+0.0` as equal.
+///
+/// A spatial hash with a cell size of `epsilon` is used, so welding takes
+/// linear time for reasonably distributed positions.
+///
+/// ```
+/// use glam::{weld_vertices, Vec3};
+/// let positions = [
+///     Vec3::new(0.0, 0.0, 0.0),
+///     Vec3::new(1.0, 0.0, 0.0),
+///     Vec3::new(0.0, 0.0, 0.0001),
+/// ];
+/// let (unique, indices) = weld_vertices(&positions, 0.001);
+/// assert_eq!(unique, [positions[0], positions[1]]);
+/// assert_eq!(indices, [0, 1, 0]);
+/// ```
+pub fn weld_vertices(positions: &[Vec3], epsilon: f32) -> (Vec<Vec3>, Vec<u32>) {
+    glam_assert!(epsilon >= 0.0);
+    let mut unique = Vec::new();
+    let mut indices = Vec::with_capacity(positions.len());
+
+    if epsilon <= 0.0 {
+        let mut lookup = HashMap::with_capacity(positions.len());
+        for &p in positions {
+            let index = *lookup.entry(Hashable(p)).or_insert_with(|| {
+                unique.push(p);
+                (unique.len() - 1) as u32
+            });
+            indices.push(index);
+        }
+        return (unique, indices);
+    }
+
+    let inv_cell_size = 1.0 / epsilon;
+    let epsilon_sq = epsilon * epsilon;
+    let mut grid: HashMap<(i32, i32, i32), Vec<u32>> = HashMap::new();
+    for &p in positions {
+        let (x, y, z) = grid_cell(p, inv_cell_size);
+        // anything within epsilon must be in one of the neighbouring cells
+        let mut found = None;
+        'search: for dz in -1..=1 {
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let cell = (x.wrapping_add(dx), y.wrapping_add(dy), z.wrapping_add(dz));
+                    if let Some(candidates) = grid.get(&cell) {
+                        for &index in candidates {
+                            if (unique[index as usize] - p).length_squared() <= epsilon_sq {
+                                found = Some(index);
+                                break 'search;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        let index = found.unwrap_or_else(|| {
+            let index = unique.len() as u32;
+            unique.push(p);
+            grid.entry((x, y, z)).or_default().push(index);
+            index
+        });
+        indices.push(index);
+    }
+    (unique, indices)
+}
@@ -0,0 +1,250 @@
+//! Fixed-point vector, quaternion and matrix types for deterministic
+//! simulation.
+//!
+//! Fixed-point arithmetic only uses integer operations, so it gives the same
+//! results on every platform and compiler without relying on IEEE 754
+//! rounding modes, FMA contraction or the platform's math library. This makes
+//! it suitable for lockstep networking, where every client must simulate
+//! identical game state.
+//!
+//! Two precisions are provided, with the same type names and API in each
+//! module:
+//!
+//! * [`q16`] - `Q16.16` stored in an `i32`, with a range of `±32768` and a
+//!   resolution of `1 / 65536`. Its types are re-exported from this module.
+//! * [`q32`] - `Q32.32` stored in an `i64`, with a range of `±2^31` and a
+//!   resolution of `2^-32`.
+//!
+//! The API follows the `f32` types: `FVec3` has `dot`, `cross`, `length` and
+//! `normalize`, `FQuat` has `from_axis_angle` and so on. Square roots use an
+//! integer square root and trigonometric functions use CORDIC, both rounded
+//! to the nearest representable value.
+//!
+//! Arithmetic overflow panics in debug builds, like integer arithmetic, and
+//! wraps in release builds. Division by zero always panics.
+//!
+//! The types convert to and from the `f32` types with `From` and `from_vec3`
+//! style constructors, for rendering and for loading data. Conversions from
+//! `f32` round to the nearest value and saturate at the limits of the range,
+//! with `NaN` converting to zero.
+//!
+//! ```
+//! use glam::fixed::{FQuat, FVec3, Fixed};
+//! use glam::Vec3;
+//!
+//! let rotation = FQuat::from_rotation_y(Fixed::FRAC_PI_2);
+//! let v = rotation * FVec3::unit_x();
+//! assert!(v.abs_diff_eq(-FVec3::unit_z(), Fixed::from_bits(2)));
+//! let v: Vec3 = v.into();
+//! assert!(v.abs_diff_eq(-Vec3::unit_z(), 1e-4));
+//! ```
+
+pub mod q16;
+pub mod q32;
+
+pub use q16::*;
+
+/// `PI` with 90 fractional bits.
+const PI_Q90: i128 = 0xc90_fdaa_2216_8c23_4c4c_6629;
+/// `PI / 2` with 90 fractional bits.
+const FRAC_PI_2_Q90: i128 = 0x648_7ed5_110b_4611_a626_3314;
+/// `2 * PI` with 90 fractional bits.
+const TAU_Q90: i128 = 0x1921_fb54_442d_1846_9898_cc51;
+
+/// `PI` with 60 fractional bits.
+const PI_Q60: i128 = 3_622_009_729_038_561_421;
+/// `PI / 2` with 60 fractional bits.
+const FRAC_PI_2_Q60: i128 = 1_811_004_864_519_280_711;
+
+/// The number of CORDIC iterations, one per fractional bit.
+const CORDIC_ITERATIONS: u32 = 61;
+
+/// The reciprocal of the CORDIC gain after `CORDIC_ITERATIONS` iterations,
+/// with 60 fractional bits.
+const CORDIC_K_Q60: i128 = 700_114_967_507_363_238;
+
+/// `atan(2^-i)` with 60 fractional bits. For larger `i` it rounds to `2^-i`.
+const ATAN_Q60: [i128; 20] = [
+    905_502_432_259_640_355,
+    534_549_298_976_576_474,
+    282_441_168_888_798_124,
+    143_371_547_418_228_444,
+    71_963_988_336_308_046,
+    36_017_075_762_092_179,
+    18_012_932_708_689_205,
+    9_007_016_009_513_623,
+    4_503_576_721_087_964,
+    2_251_796_950_380_271,
+    1_125_899_548_928_887,
+    562_949_908_682_076,
+    281_474_971_118_251,
+    140_737_487_656_277,
+    70_368_744_090_283,
+    35_184_372_077_909,
+    17_592_186_043_051,
+    8_796_093_022_037,
+    4_398_046_511_083,
+    2_199_023_255_549,
+];
+
+#[inline]
+fn atan_q60(i: u32) -> i128 {
+    ATAN_Q60.get(i as usize).copied().unwrap_or(1 << (60 - i))
+}
+
+/// Shifts `x` right by `shift` bits, rounding to nearest with ties rounding
+/// up.
+#[inline]
+pub(crate) fn round_shr(x: i128, shift: u32) -> i128 {
+    (x + (1 << (shift - 1))) >> shift
+}
+
+/// Returns the square root of `n` rounded to the nearest integer.
+pub(crate) fn isqrt(n: u128) -> u128 {
+    if n == 0 {
+        return 0;
+    }
+    // digit by digit, two bits at a time
+    let mut remainder = n;
+    let mut root = 0_u128;
+    let mut bit = 1_u128 << ((127 - n.leading_zeros()) & !1);
+    while bit != 0 {
+        if remainder >= root + bit {
+            remainder -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    // (root + 0.5)^2 = root^2 + root + 0.25
+    if remainder > root {
+        root + 1
+    } else {
+        root
+    }
+}
+
+/// Returns the sine and cosine with 60 fractional bits of an angle in
+/// radians with 90 fractional bits.
+pub(crate) fn sin_cos(angle: i128) -> (i128, i128) {
+    // reduce to [-PI, PI), then to [-PI / 2, PI / 2] using
+    // sin(PI - a) = sin(a) and cos(PI - a) = -cos(a)
+    let mut angle = angle.rem_euclid(TAU_Q90);
+    if angle >= PI_Q90 {
+        angle -= TAU_Q90;
+    }
+    let (angle, cos_sign) = if angle > FRAC_PI_2_Q90 {
+        (PI_Q90 - angle, -1)
+    } else if angle < -FRAC_PI_2_Q90 {
+        (-PI_Q90 - angle, -1)
+    } else {
+        (angle, 1)
+    };
+
+    // CORDIC in rotation mode, rotating (K, 0) by the angle
+    let mut z = round_shr(angle, 30);
+    let (mut x, mut y) = (CORDIC_K_Q60, 0);
+    for i in 0..CORDIC_ITERATIONS {
+        let (dx, dy) = (y >> i, x >> i);
+        if z >= 0 {
+            x -= dx;
+            y += dy;
+            z -= atan_q60(i);
+        } else {
+            x += dx;
+            y -= dy;
+            z += atan_q60(i);
+        }
+    }
+    (y, cos_sign * x)
+}
+
+/// Returns the angle of `(x, y)` from the positive x axis in `[-PI, PI]`,
+/// with 60 fractional bits. `x` and `y` may have any common scale.
+pub(crate) fn atan2(y: i128, x: i128) -> i128 {
+    if x == 0 && y == 0 {
+        return 0;
+    }
+
+    // scale the larger magnitude to 2^59 so the iterations keep their
+    // precision and can't overflow
+    let magnitude = x.unsigned_abs().max(y.unsigned_abs());
+    let shift = 59 - (127 - magnitude.leading_zeros() as i32);
+    let (mut x, mut y) = if shift >= 0 {
+        (x << shift, y << shift)
+    } else {
+        (x >> -shift, y >> -shift)
+    };
+
+    // rotate into the right half plane by a quarter turn
+    let mut z = 0;
+    if x < 0 {
+        if y >= 0 {
+            z = FRAC_PI_2_Q60;
+            let t = x;
+            x = y;
+            y = -t;
+        } else {
+            z = -FRAC_PI_2_Q60;
+            let t = x;
+            x = -y;
+            y = t;
+        }
+    }
+
+    // CORDIC in vectoring mode, rotating (x, y) onto the x axis
+    for i in 0..CORDIC_ITERATIONS {
+        let (dx, dy) = (y >> i, x >> i);
+        if y > 0 {
+            x += dx;
+            y -= dy;
+            z += atan_q60(i);
+        } else {
+            x -= dx;
+            y += dy;
+            z -= atan_q60(i);
+        }
+    }
+    z.clamp(-PI_Q60, PI_Q60)
+}
+
+#[test]
+fn test_isqrt() {
+    for n in 0..10_000_u128 {
+        let root = isqrt(n);
+        let exact = (n as f64).sqrt();
+        assert!(
+            (root as f64 - exact).abs() <= 0.5,
+            "isqrt({}) = {}",
+            n,
+            root
+        );
+    }
+    assert_eq!(isqrt(u128::MAX), 1 << 64);
+    assert_eq!(isqrt((1 << 126) - 1), 1 << 63);
+}
+
+#[test]
+fn test_cordic() {
+    let mut angle = -10.0_f64;
+    while angle < 10.0 {
+        let (sin, cos) = sin_cos((angle * (1_u128 << 90) as f64) as i128);
+        let scale = (1_u64 << 60) as f64;
+        assert!(
+            (sin as f64 / scale - angle.sin()).abs() < 1e-15,
+            "sin({})",
+            angle
+        );
+        assert!(
+            (cos as f64 / scale - angle.cos()).abs() < 1e-15,
+            "cos({})",
+            angle
+        );
+        let atan2 = atan2(sin, cos) as f64 / scale;
+        assert!((atan2 - angle.sin().atan2(angle.cos())).abs() < 1e-15);
+        angle += 0.0123;
+    }
+    assert!((atan2(0, -1) - PI_Q60).abs() <= 2);
+    assert!((atan2(-1, 0) + FRAC_PI_2_Q60).abs() <= 2);
+}
@@ -0,0 +1,1784 @@
+// Generated from fixed.rs.tera template. Edit the template, not the generated file.
+
+//! `Q32.32` fixed-point types, stored in `i64`.
+//!
+//! Values have a range of `[-2^31, 2^31)` and a resolution of `2^-32`.
+
+use crate::{Mat3, Quat, Vec2, Vec3};
+use core::{convert::TryFrom, fmt, ops::*};
+
+/// The type products are accumulated in before rounding.
+type Wide = i128;
+
+/// A `Q32.32` fixed-point number.
+///
+/// Arithmetic rounds to the nearest representable value. Overflow panics in
+/// debug builds and wraps in release builds, like integer arithmetic.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Fixed(pub(crate) i64);
+
+impl Fixed {
+    /// The number of fractional bits.
+    pub const FRAC_BITS: u32 = 32;
+
+    /// `0.0`.
+    pub const ZERO: Self = Self(0);
+
+    /// `1.0`.
+    pub const ONE: Self = Self(1 << Self::FRAC_BITS);
+
+    /// `0.5`.
+    pub const HALF: Self = Self(1 << (Self::FRAC_BITS - 1));
+
+    /// The smallest value, `-2^31`.
+    pub const MIN: Self = Self(i64::MIN);
+
+    /// The largest value, `2^31 - 2^-32`.
+    pub const MAX: Self = Self(i64::MAX);
+
+    /// The smallest positive value, `2^-32`.
+    pub const EPSILON: Self = Self(1);
+
+    /// Archimedes' constant (π).
+    pub const PI: Self = Self(13493037705);
+
+    /// π/2.
+    pub const FRAC_PI_2: Self = Self(6746518852);
+
+    /// π/4.
+    pub const FRAC_PI_4: Self = Self(3373259426);
+
+    /// The full circle constant (τ = 2π).
+    pub const TAU: Self = Self(26986075409);
+
+    /// Creates a `Fixed` from its raw bits, the value multiplied by
+    /// `2^32`.
+    #[inline]
+    pub const fn from_bits(bits: i64) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw bits of `self`, the value multiplied by `2^32`.
+    #[inline]
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    /// Creates a `Fixed` from an integer.
+    #[inline]
+    pub const fn from_int(value: i64) -> Self {
+        Self(value * Self::ONE.0)
+    }
+
+    /// Returns the largest integer less than or equal to `self`.
+    #[inline]
+    pub const fn to_int(self) -> i64 {
+        self.0 >> Self::FRAC_BITS
+    }
+
+    /// Creates a `Fixed` from an `f32`, rounding to the nearest value.
+    ///
+    /// Values outside the range saturate to `MIN` or `MAX` and `NaN` converts
+    /// to zero.
+    #[inline]
+    pub fn from_f32(value: f32) -> Self {
+        Self((value * Self::ONE.0 as f32).round() as i64)
+    }
+
+    /// Creates a `Fixed` from an `f64`, rounding to the nearest value.
+    ///
+    /// Values outside the range saturate to `MIN` or `MAX` and `NaN` converts
+    /// to zero.
+    #[inline]
+    pub fn from_f64(value: f64) -> Self {
+        Self((value * Self::ONE.0 as f64).round() as i64)
+    }
+
+    /// Converts `self` to the nearest `f32`.
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / Self::ONE.0 as f32
+    }
+
+    /// Converts `self` to the nearest `f64`.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / Self::ONE.0 as f64
+    }
+
+    #[inline]
+    fn wide(self) -> Wide {
+        self.0 as Wide
+    }
+
+    /// Narrows `wide` to a `Fixed`, checking for overflow in debug builds.
+    #[inline]
+    fn narrow(wide: Wide, message: &'static str) -> Self {
+        debug_assert!(
+            wide >= i64::MIN as Wide && wide <= i64::MAX as Wide,
+            "{}",
+            message
+        );
+        Self(wide as i64)
+    }
+
+    /// Rounds a sum of products of `Fixed` values to a `Fixed`.
+    #[inline]
+    fn from_wide(wide: Wide) -> Self {
+        Self::narrow(
+            (wide + (1 << (Self::FRAC_BITS - 1))) >> Self::FRAC_BITS,
+            "attempt to multiply with overflow",
+        )
+    }
+
+    /// Rounds a value with 60 fractional bits to a `Fixed`.
+    #[inline]
+    fn from_q60(value: i128) -> Self {
+        Self(super::round_shr(value, 60 - Self::FRAC_BITS) as i64)
+    }
+
+    /// Returns the absolute value of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+
+    /// Returns `1.0 / self`.
+    #[inline]
+    pub fn recip(self) -> Self {
+        Self::ONE / self
+    }
+
+    /// Returns the square root of `self`.
+    ///
+    /// `self` must not be negative, this is checked in debug builds.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        debug_assert!(self.0 >= 0, "square root of a negative number");
+        let square = (self.0.max(0) as u128) << Self::FRAC_BITS;
+        Self(super::isqrt(square) as i64)
+    }
+
+    /// Returns the sine of `self` (in radians).
+    #[inline]
+    pub fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+
+    /// Returns the cosine of `self` (in radians).
+    #[inline]
+    pub fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+
+    /// Returns the sine and cosine of `self` (in radians).
+    #[inline]
+    pub fn sin_cos(self) -> (Self, Self) {
+        Self::sin_cos_q90((self.0 as i128) << (90 - Self::FRAC_BITS))
+    }
+
+    /// Returns the sine and cosine of half of `self` (in radians), without
+    /// rounding `self` first.
+    #[inline]
+    fn half_sin_cos(self) -> (Self, Self) {
+        Self::sin_cos_q90((self.0 as i128) << (89 - Self::FRAC_BITS))
+    }
+
+    #[inline]
+    fn sin_cos_q90(angle: i128) -> (Self, Self) {
+        let (sin, cos) = super::sin_cos(angle);
+        (Self::from_q60(sin), Self::from_q60(cos))
+    }
+
+    /// Returns the four quadrant arctangent of `self` and `other` in radians,
+    /// in `[-PI, PI]`.
+    ///
+    /// `self` is the y coordinate and `other` the x coordinate. The result is
+    /// zero if both are zero.
+    #[inline]
+    pub fn atan2(self, other: Self) -> Self {
+        Self::from_q60(super::atan2(self.0 as i128, other.0 as i128))
+    }
+
+    /// Returns `Some(self + other)`, or `None` on overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    /// Returns `Some(self - other)`, or `None` on overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    /// Returns `Some(self * other)`, or `None` on overflow.
+    #[inline]
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let product =
+            (self.wide() * other.wide() + (1 << (Self::FRAC_BITS - 1))) >> Self::FRAC_BITS;
+        i64::try_from(product).ok().map(Self)
+    }
+
+    /// Returns `Some(self / other)`, or `None` if `other` is zero or on
+    /// overflow.
+    #[inline]
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.0 == 0 {
+            return None;
+        }
+        i64::try_from(self.quotient(other)).ok().map(Self)
+    }
+
+    /// Returns `self / other` with `FRAC_BITS` fractional bits before
+    /// narrowing, rounded to nearest with ties rounding away from zero.
+    #[inline]
+    fn quotient(self, other: Self) -> Wide {
+        let (n, d) = (self.wide() << Self::FRAC_BITS, other.wide());
+        let (quotient, remainder) = (n / d, n % d);
+        if remainder.abs() >= d.abs() - remainder.abs() {
+            if (n < 0) == (d < 0) {
+                quotient + 1
+            } else {
+                quotient - 1
+            }
+        } else {
+            quotient
+        }
+    }
+}
+
+impl fmt::Debug for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_f64(), f)
+    }
+}
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl Add<Fixed> for Fixed {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl AddAssign<Fixed> for Fixed {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub<Fixed> for Fixed {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+}
+
+impl SubAssign<Fixed> for Fixed {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<Fixed> for Fixed {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self::from_wide(self.wide() * other.wide())
+    }
+}
+
+impl MulAssign<Fixed> for Fixed {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Div<Fixed> for Fixed {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self::narrow(self.quotient(other), "attempt to divide with overflow")
+    }
+}
+
+impl DivAssign<Fixed> for Fixed {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+/// The threshold used by `is_normalized`, `2^-20`.
+const NORMALIZED_THRESHOLD: Fixed = Fixed(1 << (Fixed::FRAC_BITS - 20));
+
+/// A 2-dimensional `Q32.32` fixed-point vector.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[repr(C)]
+pub struct FVec2(pub(crate) Fixed, pub(crate) Fixed);
+
+impl FVec2 {
+    /// All elements set to `0.0`.
+    pub const ZERO: Self = Self::zero();
+
+    /// All elements set to `1.0`.
+    pub const ONE: Self = Self::one();
+
+    /// A unit length vector along the x axis.
+    pub const X: Self = Self::unit_x();
+
+    /// A unit length vector along the y axis.
+    pub const Y: Self = Self::unit_y();
+
+    /// Creates a new `FVec2`.
+    #[inline]
+    pub const fn new(x: Fixed, y: Fixed) -> Self {
+        Self(x, y)
+    }
+
+    /// Creates a new `FVec2` with all elements set to `0.0`.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::splat(Fixed::ZERO)
+    }
+
+    /// Creates a new `FVec2` with all elements set to `1.0`.
+    #[inline]
+    pub const fn one() -> Self {
+        Self::splat(Fixed::ONE)
+    }
+
+    /// Creates a new unit length `FVec2` along the x axis.
+    #[inline]
+    pub const fn unit_x() -> Self {
+        Self(Fixed::ONE, Fixed::ZERO)
+    }
+
+    /// Creates a new unit length `FVec2` along the y axis.
+    #[inline]
+    pub const fn unit_y() -> Self {
+        Self(Fixed::ZERO, Fixed::ONE)
+    }
+
+    /// Creates a new `FVec2` with all elements set to `v`.
+    #[inline]
+    pub const fn splat(v: Fixed) -> Self {
+        Self(v, v)
+    }
+
+    /// Creates a `FVec2` from a `Vec2`, rounding each element
+    /// to the nearest value.
+    #[inline]
+    pub fn from_vec2(v: Vec2) -> Self {
+        Self(Fixed::from_f32(v.x()), Fixed::from_f32(v.y()))
+    }
+
+    /// Creates a `FVec3` from `self` and the given `z` value.
+    #[inline]
+    pub fn extend(self, z: Fixed) -> FVec3 {
+        FVec3(self.0, self.1, z)
+    }
+
+    /// Returns element `x`.
+    #[inline]
+    pub fn x(self) -> Fixed {
+        self.0
+    }
+
+    /// Returns element `y`.
+    #[inline]
+    pub fn y(self) -> Fixed {
+        self.1
+    }
+
+    /// Sets element `x`.
+    #[inline]
+    pub fn set_x(&mut self, x: Fixed) {
+        self.0 = x;
+    }
+
+    /// Sets element `y`.
+    #[inline]
+    pub fn set_y(&mut self, y: Fixed) {
+        self.1 = y;
+    }
+
+    /// Computes the dot product of `self` and `other`, rounding once.
+    #[inline]
+    pub fn dot(self, other: Self) -> Fixed {
+        Fixed::from_wide(self.0.wide() * other.0.wide() + self.1.wide() * other.1.wide())
+    }
+
+    /// Computes the perpendicular dot product of `self` and `other`, the z
+    /// element of the 3D cross product.
+    #[inline]
+    pub fn perp_dot(self, other: Self) -> Fixed {
+        Fixed::from_wide(self.0.wide() * other.1.wide() - self.1.wide() * other.0.wide())
+    }
+
+    /// Computes the length of `self`, rounded to the nearest value.
+    #[inline]
+    pub fn length(self) -> Fixed {
+        let square = |v: Fixed| (v.0.unsigned_abs() as u128).pow(2);
+        let length_squared = square(self.0) + square(self.1);
+        Fixed::narrow(
+            super::isqrt(length_squared) as Wide,
+            "attempt to add with overflow",
+        )
+    }
+
+    /// Computes the squared length of `self`.
+    ///
+    /// This is faster than `FVec2::length()` as it avoids a square
+    /// root operation.
+    #[inline]
+    pub fn length_squared(self) -> Fixed {
+        self.dot(self)
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero length.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self / self.length()
+    }
+
+    /// Returns the vertical minimum of `self` and `other`.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self(self.0.min(other.0), self.1.min(other.1))
+    }
+
+    /// Returns the vertical maximum of `self` and `other`.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self(self.0.max(other.0), self.1.max(other.1))
+    }
+
+    /// Returns the horizontal minimum of `self`'s elements.
+    #[inline]
+    pub fn min_element(self) -> Fixed {
+        self.0.min(self.1)
+    }
+
+    /// Returns the horizontal maximum of `self`'s elements.
+    #[inline]
+    pub fn max_element(self) -> Fixed {
+        self.0.max(self.1)
+    }
+
+    /// Returns a new `FVec2` containing the absolute value of each
+    /// element of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(self.0.abs(), self.1.abs())
+    }
+
+    /// Performs a linear interpolation between `self` and `other` based on
+    /// the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
+    /// is `1.0`, the result will be equal to `other`.
+    #[inline]
+    pub fn lerp(self, other: Self, s: Fixed) -> Self {
+        self + ((other - self) * s)
+    }
+
+    /// Returns whether `self` is length `1.0` or not.
+    ///
+    /// Uses a precision threshold of `2^-20`.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        (self.length_squared() - Fixed::ONE).abs() <= NORMALIZED_THRESHOLD
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    #[inline]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: Fixed) -> bool {
+        (self - other).abs().max_element() <= max_abs_diff
+    }
+}
+
+impl fmt::Display for FVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.0, self.1,)
+    }
+}
+
+impl Add<FVec2> for FVec2 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self(self.0.add(other.0), self.1.add(other.1))
+    }
+}
+
+impl AddAssign<FVec2> for FVec2 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = self.add(other);
+    }
+}
+
+impl Sub<FVec2> for FVec2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self(self.0.sub(other.0), self.1.sub(other.1))
+    }
+}
+
+impl SubAssign<FVec2> for FVec2 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = self.sub(other);
+    }
+}
+
+impl Mul<FVec2> for FVec2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self(self.0.mul(other.0), self.1.mul(other.1))
+    }
+}
+
+impl MulAssign<FVec2> for FVec2 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.mul(other);
+    }
+}
+
+impl Mul<Fixed> for FVec2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Fixed) -> Self {
+        Self(self.0.mul(other), self.1.mul(other))
+    }
+}
+
+impl MulAssign<Fixed> for FVec2 {
+    #[inline]
+    fn mul_assign(&mut self, other: Fixed) {
+        *self = self.mul(other);
+    }
+}
+
+impl Div<FVec2> for FVec2 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self(self.0.div(other.0), self.1.div(other.1))
+    }
+}
+
+impl DivAssign<FVec2> for FVec2 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        *self = self.div(other);
+    }
+}
+
+impl Div<Fixed> for FVec2 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Fixed) -> Self {
+        Self(self.0.div(other), self.1.div(other))
+    }
+}
+
+impl DivAssign<Fixed> for FVec2 {
+    #[inline]
+    fn div_assign(&mut self, other: Fixed) {
+        *self = self.div(other);
+    }
+}
+
+impl Mul<FVec2> for Fixed {
+    type Output = FVec2;
+    #[inline]
+    fn mul(self, other: FVec2) -> FVec2 {
+        other * self
+    }
+}
+
+impl Neg for FVec2 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1)
+    }
+}
+
+impl From<(Fixed, Fixed)> for FVec2 {
+    #[inline]
+    fn from(t: (Fixed, Fixed)) -> Self {
+        Self(t.0, t.1)
+    }
+}
+
+impl From<FVec2> for (Fixed, Fixed) {
+    #[inline]
+    fn from(v: FVec2) -> Self {
+        (v.0, v.1)
+    }
+}
+
+impl From<[Fixed; 2]> for FVec2 {
+    #[inline]
+    fn from(a: [Fixed; 2]) -> Self {
+        Self(a[0], a[1])
+    }
+}
+
+impl From<FVec2> for [Fixed; 2] {
+    #[inline]
+    fn from(v: FVec2) -> Self {
+        [v.0, v.1]
+    }
+}
+
+impl From<FVec2> for Vec2 {
+    #[inline]
+    fn from(v: FVec2) -> Self {
+        Self::new(v.0.to_f32(), v.1.to_f32())
+    }
+}
+
+/// A 3-dimensional `Q32.32` fixed-point vector.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[repr(C)]
+pub struct FVec3(pub(crate) Fixed, pub(crate) Fixed, pub(crate) Fixed);
+
+impl FVec3 {
+    /// All elements set to `0.0`.
+    pub const ZERO: Self = Self::zero();
+
+    /// All elements set to `1.0`.
+    pub const ONE: Self = Self::one();
+
+    /// A unit length vector along the x axis.
+    pub const X: Self = Self::unit_x();
+
+    /// A unit length vector along the y axis.
+    pub const Y: Self = Self::unit_y();
+
+    /// A unit length vector along the z axis.
+    pub const Z: Self = Self::unit_z();
+
+    /// Creates a new `FVec3`.
+    #[inline]
+    pub const fn new(x: Fixed, y: Fixed, z: Fixed) -> Self {
+        Self(x, y, z)
+    }
+
+    /// Creates a new `FVec3` with all elements set to `0.0`.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::splat(Fixed::ZERO)
+    }
+
+    /// Creates a new `FVec3` with all elements set to `1.0`.
+    #[inline]
+    pub const fn one() -> Self {
+        Self::splat(Fixed::ONE)
+    }
+
+    /// Creates a new unit length `FVec3` along the x axis.
+    #[inline]
+    pub const fn unit_x() -> Self {
+        Self(Fixed::ONE, Fixed::ZERO, Fixed::ZERO)
+    }
+
+    /// Creates a new unit length `FVec3` along the y axis.
+    #[inline]
+    pub const fn unit_y() -> Self {
+        Self(Fixed::ZERO, Fixed::ONE, Fixed::ZERO)
+    }
+
+    /// Creates a new unit length `FVec3` along the z axis.
+    #[inline]
+    pub const fn unit_z() -> Self {
+        Self(Fixed::ZERO, Fixed::ZERO, Fixed::ONE)
+    }
+
+    /// Creates a new `FVec3` with all elements set to `v`.
+    #[inline]
+    pub const fn splat(v: Fixed) -> Self {
+        Self(v, v, v)
+    }
+
+    /// Creates a `FVec3` from a `Vec3`, rounding each element
+    /// to the nearest value.
+    #[inline]
+    pub fn from_vec3(v: Vec3) -> Self {
+        Self(
+            Fixed::from_f32(v.x()),
+            Fixed::from_f32(v.y()),
+            Fixed::from_f32(v.z()),
+        )
+    }
+
+    /// Creates a `FVec2` from the `x` and `y` elements of `self`, discarding
+    /// `z`.
+    #[inline]
+    pub fn truncate(self) -> FVec2 {
+        FVec2(self.0, self.1)
+    }
+
+    /// Returns element `x`.
+    #[inline]
+    pub fn x(self) -> Fixed {
+        self.0
+    }
+
+    /// Returns element `y`.
+    #[inline]
+    pub fn y(self) -> Fixed {
+        self.1
+    }
+
+    /// Returns element `z`.
+    #[inline]
+    pub fn z(self) -> Fixed {
+        self.2
+    }
+
+    /// Sets element `x`.
+    #[inline]
+    pub fn set_x(&mut self, x: Fixed) {
+        self.0 = x;
+    }
+
+    /// Sets element `y`.
+    #[inline]
+    pub fn set_y(&mut self, y: Fixed) {
+        self.1 = y;
+    }
+
+    /// Sets element `z`.
+    #[inline]
+    pub fn set_z(&mut self, z: Fixed) {
+        self.2 = z;
+    }
+
+    /// Computes the dot product of `self` and `other`, rounding once.
+    #[inline]
+    pub fn dot(self, other: Self) -> Fixed {
+        Fixed::from_wide(
+            self.0.wide() * other.0.wide()
+                + self.1.wide() * other.1.wide()
+                + self.2.wide() * other.2.wide(),
+        )
+    }
+
+    /// Computes the cross product of `self` and `other`, rounding each
+    /// element once.
+    #[inline]
+    pub fn cross(self, other: Self) -> Self {
+        Self(
+            Fixed::from_wide(self.1.wide() * other.2.wide() - other.1.wide() * self.2.wide()),
+            Fixed::from_wide(self.2.wide() * other.0.wide() - other.2.wide() * self.0.wide()),
+            Fixed::from_wide(self.0.wide() * other.1.wide() - other.0.wide() * self.1.wide()),
+        )
+    }
+
+    /// Computes the length of `self`, rounded to the nearest value.
+    #[inline]
+    pub fn length(self) -> Fixed {
+        let square = |v: Fixed| (v.0.unsigned_abs() as u128).pow(2);
+        let length_squared = square(self.0) + square(self.1) + square(self.2);
+        Fixed::narrow(
+            super::isqrt(length_squared) as Wide,
+            "attempt to add with overflow",
+        )
+    }
+
+    /// Computes the squared length of `self`.
+    ///
+    /// This is faster than `FVec3::length()` as it avoids a square
+    /// root operation.
+    #[inline]
+    pub fn length_squared(self) -> Fixed {
+        self.dot(self)
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero length.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self / self.length()
+    }
+
+    /// Returns the vertical minimum of `self` and `other`.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self(
+            self.0.min(other.0),
+            self.1.min(other.1),
+            self.2.min(other.2),
+        )
+    }
+
+    /// Returns the vertical maximum of `self` and `other`.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self(
+            self.0.max(other.0),
+            self.1.max(other.1),
+            self.2.max(other.2),
+        )
+    }
+
+    /// Returns the horizontal minimum of `self`'s elements.
+    #[inline]
+    pub fn min_element(self) -> Fixed {
+        self.0.min(self.1).min(self.2)
+    }
+
+    /// Returns the horizontal maximum of `self`'s elements.
+    #[inline]
+    pub fn max_element(self) -> Fixed {
+        self.0.max(self.1).max(self.2)
+    }
+
+    /// Returns a new `FVec3` containing the absolute value of each
+    /// element of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Self(self.0.abs(), self.1.abs(), self.2.abs())
+    }
+
+    /// Performs a linear interpolation between `self` and `other` based on
+    /// the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
+    /// is `1.0`, the result will be equal to `other`.
+    #[inline]
+    pub fn lerp(self, other: Self, s: Fixed) -> Self {
+        self + ((other - self) * s)
+    }
+
+    /// Returns whether `self` is length `1.0` or not.
+    ///
+    /// Uses a precision threshold of `2^-20`.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        (self.length_squared() - Fixed::ONE).abs() <= NORMALIZED_THRESHOLD
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    #[inline]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: Fixed) -> bool {
+        (self - other).abs().max_element() <= max_abs_diff
+    }
+}
+
+impl fmt::Display for FVec3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.0, self.1, self.2,)
+    }
+}
+
+impl Add<FVec3> for FVec3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self(
+            self.0.add(other.0),
+            self.1.add(other.1),
+            self.2.add(other.2),
+        )
+    }
+}
+
+impl AddAssign<FVec3> for FVec3 {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        *self = self.add(other);
+    }
+}
+
+impl Sub<FVec3> for FVec3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self(
+            self.0.sub(other.0),
+            self.1.sub(other.1),
+            self.2.sub(other.2),
+        )
+    }
+}
+
+impl SubAssign<FVec3> for FVec3 {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        *self = self.sub(other);
+    }
+}
+
+impl Mul<FVec3> for FVec3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self(
+            self.0.mul(other.0),
+            self.1.mul(other.1),
+            self.2.mul(other.2),
+        )
+    }
+}
+
+impl MulAssign<FVec3> for FVec3 {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.mul(other);
+    }
+}
+
+impl Mul<Fixed> for FVec3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Fixed) -> Self {
+        Self(self.0.mul(other), self.1.mul(other), self.2.mul(other))
+    }
+}
+
+impl MulAssign<Fixed> for FVec3 {
+    #[inline]
+    fn mul_assign(&mut self, other: Fixed) {
+        *self = self.mul(other);
+    }
+}
+
+impl Div<FVec3> for FVec3 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Self) -> Self {
+        Self(
+            self.0.div(other.0),
+            self.1.div(other.1),
+            self.2.div(other.2),
+        )
+    }
+}
+
+impl DivAssign<FVec3> for FVec3 {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        *self = self.div(other);
+    }
+}
+
+impl Div<Fixed> for FVec3 {
+    type Output = Self;
+    #[inline]
+    fn div(self, other: Fixed) -> Self {
+        Self(self.0.div(other), self.1.div(other), self.2.div(other))
+    }
+}
+
+impl DivAssign<Fixed> for FVec3 {
+    #[inline]
+    fn div_assign(&mut self, other: Fixed) {
+        *self = self.div(other);
+    }
+}
+
+impl Mul<FVec3> for Fixed {
+    type Output = FVec3;
+    #[inline]
+    fn mul(self, other: FVec3) -> FVec3 {
+        other * self
+    }
+}
+
+impl Neg for FVec3 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1, -self.2)
+    }
+}
+
+impl From<(Fixed, Fixed, Fixed)> for FVec3 {
+    #[inline]
+    fn from(t: (Fixed, Fixed, Fixed)) -> Self {
+        Self(t.0, t.1, t.2)
+    }
+}
+
+impl From<FVec3> for (Fixed, Fixed, Fixed) {
+    #[inline]
+    fn from(v: FVec3) -> Self {
+        (v.0, v.1, v.2)
+    }
+}
+
+impl From<[Fixed; 3]> for FVec3 {
+    #[inline]
+    fn from(a: [Fixed; 3]) -> Self {
+        Self(a[0], a[1], a[2])
+    }
+}
+
+impl From<FVec3> for [Fixed; 3] {
+    #[inline]
+    fn from(v: FVec3) -> Self {
+        [v.0, v.1, v.2]
+    }
+}
+
+impl From<FVec3> for Vec3 {
+    #[inline]
+    fn from(v: FVec3) -> Self {
+        Self::new(v.0.to_f32(), v.1.to_f32(), v.2.to_f32())
+    }
+}
+
+/// A `Q32.32` fixed-point quaternion representing an orientation.
+///
+/// This quaternion is intended to be of unit length but may denormalize due
+/// to rounding.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[repr(C)]
+pub struct FQuat(
+    pub(crate) Fixed,
+    pub(crate) Fixed,
+    pub(crate) Fixed,
+    pub(crate) Fixed,
+);
+
+impl FQuat {
+    /// Creates a new rotation quaternion.
+    ///
+    /// This should generally not be called manually unless you know what you
+    /// are doing. Use one of the other constructors instead such as
+    /// `identity` or `from_axis_angle`.
+    #[inline]
+    pub const fn from_xyzw(x: Fixed, y: Fixed, z: Fixed, w: Fixed) -> Self {
+        Self(x, y, z, w)
+    }
+
+    /// Creates a new identity quaternion.
+    #[inline]
+    pub const fn identity() -> Self {
+        Self(Fixed::ZERO, Fixed::ZERO, Fixed::ZERO, Fixed::ONE)
+    }
+
+    /// Creates a `FQuat` from a `Quat`, rounding each element to the nearest
+    /// value.
+    #[inline]
+    pub fn from_quat(q: Quat) -> Self {
+        Self(
+            Fixed::from_f32(q.x()),
+            Fixed::from_f32(q.y()),
+            Fixed::from_f32(q.z()),
+            Fixed::from_f32(q.w()),
+        )
+    }
+
+    /// Create a new quaterion for a normalized rotation axis and angle
+    /// (in radians).
+    #[inline]
+    pub fn from_axis_angle(axis: FVec3, angle: Fixed) -> Self {
+        glam_assert!(axis.is_normalized());
+        let (s, c) = angle.half_sin_cos();
+        let (x, y, z) = (axis * s).into();
+        Self(x, y, z, c)
+    }
+
+    /// Creates a new quaternion from the angle (in radians) around the x axis.
+    #[inline]
+    pub fn from_rotation_x(angle: Fixed) -> Self {
+        let (s, c) = angle.half_sin_cos();
+        Self(s, Fixed::ZERO, Fixed::ZERO, c)
+    }
+
+    /// Creates a new quaternion from the angle (in radians) around the y axis.
+    #[inline]
+    pub fn from_rotation_y(angle: Fixed) -> Self {
+        let (s, c) = angle.half_sin_cos();
+        Self(Fixed::ZERO, s, Fixed::ZERO, c)
+    }
+
+    /// Creates a new quaternion from the angle (in radians) around the z axis.
+    #[inline]
+    pub fn from_rotation_z(angle: Fixed) -> Self {
+        let (s, c) = angle.half_sin_cos();
+        Self(Fixed::ZERO, Fixed::ZERO, s, c)
+    }
+
+    /// Returns the rotation axis and angle (in radians) of `self`.
+    ///
+    /// The angle is in `[0, 2 * PI]`.
+    #[inline]
+    pub fn to_axis_angle(self) -> (FVec3, Fixed) {
+        let v = FVec3(self.0, self.1, self.2);
+        let scale = v.length();
+        // twice the angle from w to the length of the vector part
+        let angle = super::atan2(scale.0 as i128, self.3 .0 as i128);
+        let angle = Fixed::from_q60(angle * 2);
+        if scale != Fixed::ZERO {
+            (v / scale, angle)
+        } else {
+            (FVec3::unit_x(), angle)
+        }
+    }
+
+    /// Returns the quaternion conjugate of `self`. For a unit quaternion the
+    /// conjugate is also the inverse.
+    #[inline]
+    pub fn conjugate(self) -> Self {
+        Self(-self.0, -self.1, -self.2, self.3)
+    }
+
+    /// Computes the dot product of `self` and `other`, rounding once.
+    #[inline]
+    pub fn dot(self, other: Self) -> Fixed {
+        Fixed::from_wide(
+            self.0.wide() * other.0.wide()
+                + self.1.wide() * other.1.wide()
+                + self.2.wide() * other.2.wide()
+                + self.3.wide() * other.3.wide(),
+        )
+    }
+
+    /// Computes the length of `self`, rounded to the nearest value.
+    #[inline]
+    pub fn length(self) -> Fixed {
+        let square = |v: Fixed| (v.0.unsigned_abs() as u128).pow(2);
+        let length_squared = square(self.0) + square(self.1) + square(self.2) + square(self.3);
+        Fixed::narrow(
+            super::isqrt(length_squared) as Wide,
+            "attempt to add with overflow",
+        )
+    }
+
+    /// Computes the squared length of `self`.
+    ///
+    /// This is faster than `FQuat::length()` as it avoids a square root
+    /// operation.
+    #[inline]
+    pub fn length_squared(self) -> Fixed {
+        self.dot(self)
+    }
+
+    /// Returns `self` normalized to length 1.0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero length.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let length = self.length();
+        Self(
+            self.0 / length,
+            self.1 / length,
+            self.2 / length,
+            self.3 / length,
+        )
+    }
+
+    /// Returns whether `self` of length `1.0` or not.
+    ///
+    /// Uses a precision threshold of `2^-20`.
+    #[inline]
+    pub fn is_normalized(self) -> bool {
+        (self.length_squared() - Fixed::ONE).abs() <= NORMALIZED_THRESHOLD
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    #[inline]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: Fixed) -> bool {
+        (self.0 - other.0).abs() <= max_abs_diff
+            && (self.1 - other.1).abs() <= max_abs_diff
+            && (self.2 - other.2).abs() <= max_abs_diff
+            && (self.3 - other.3).abs() <= max_abs_diff
+    }
+
+    /// Performs a linear interpolation between `self` and `other` based on
+    /// the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
+    /// is `1.0`, the result will be equal to `other`.
+    #[inline]
+    pub fn lerp(self, end: Self, s: Fixed) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+        // interpolate towards whichever of end and -end is closer
+        let end = if self.dot(end) < Fixed::ZERO {
+            -end
+        } else {
+            end
+        };
+        Self(
+            self.0 + (end.0 - self.0) * s,
+            self.1 + (end.1 - self.1) * s,
+            self.2 + (end.2 - self.2) * s,
+            self.3 + (end.3 - self.3) * s,
+        )
+        .normalize()
+    }
+
+    /// Performs a spherical linear interpolation between `self` and `end`
+    /// based on the value `s`.
+    ///
+    /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
+    /// is `1.0`, the result will be equal to `end`.
+    ///
+    /// Note that a rotation can be represented by two quaternions: `q` and
+    /// `-q`. The slerp path between `q` and `end` will be different from the
+    /// path between `-q` and `end`. One path will take the long way around and
+    /// one will take the short way. In order to correct for this, the `dot`
+    /// product between `self` and `end` should be positive. If the `dot`
+    /// product is negative, slerp between `-self` and `end`.
+    #[inline]
+    pub fn slerp(self, end: Self, s: Fixed) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(end.is_normalized());
+
+        // 1 - 2^-11, about 0.9995
+        const DOT_THRESHOLD: Fixed = Fixed(Fixed::ONE.0 - (Fixed::ONE.0 >> 11));
+
+        let dot = self.dot(end);
+
+        if dot > DOT_THRESHOLD {
+            // lerp returns a normalized quaternion
+            self.lerp(end, s)
+        } else {
+            // acos(dot), without requiring dot to be in [-1, 1]
+            let theta = (Fixed::ONE - dot * dot).max(Fixed::ZERO).sqrt().atan2(dot);
+            let scale1 = (theta * (Fixed::ONE - s)).sin();
+            let scale2 = (theta * s).sin();
+            let theta_sin = theta.sin();
+            Self(
+                (self.0 * scale1 + end.0 * scale2) / theta_sin,
+                (self.1 * scale1 + end.1 * scale2) / theta_sin,
+                (self.2 * scale1 + end.2 * scale2) / theta_sin,
+                (self.3 * scale1 + end.3 * scale2) / theta_sin,
+            )
+        }
+    }
+
+    /// Multiplies a quaternion and a 3D vector, rotating it.
+    #[inline]
+    pub fn mul_vec3(self, other: FVec3) -> FVec3 {
+        glam_assert!(self.is_normalized());
+        let w = self.3;
+        let two = Fixed::from_int(2);
+        let b = FVec3(self.0, self.1, self.2);
+        let b2 = b.dot(b);
+        other * (w * w - b2) + b * (other.dot(b) * two) + b.cross(other) * (w * two)
+    }
+
+    /// Multiplies two quaternions, rounding each element once.
+    /// Note that due to rounding the result may not be perfectly normalized.
+    #[inline]
+    pub fn mul_quat(self, other: Self) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(other.is_normalized());
+        let (x0, y0, z0, w0) = (self.0.wide(), self.1.wide(), self.2.wide(), self.3.wide());
+        let (x1, y1, z1, w1) = (
+            other.0.wide(),
+            other.1.wide(),
+            other.2.wide(),
+            other.3.wide(),
+        );
+        Self(
+            Fixed::from_wide(w0 * x1 + x0 * w1 + y0 * z1 - z0 * y1),
+            Fixed::from_wide(w0 * y1 - x0 * z1 + y0 * w1 + z0 * x1),
+            Fixed::from_wide(w0 * z1 + x0 * y1 - y0 * x1 + z0 * w1),
+            Fixed::from_wide(w0 * w1 - x0 * x1 - y0 * y1 - z0 * z1),
+        )
+    }
+
+    /// Returns element `x`.
+    #[inline]
+    pub fn x(self) -> Fixed {
+        self.0
+    }
+
+    /// Returns element `y`.
+    #[inline]
+    pub fn y(self) -> Fixed {
+        self.1
+    }
+
+    /// Returns element `z`.
+    #[inline]
+    pub fn z(self) -> Fixed {
+        self.2
+    }
+
+    /// Returns element `w`.
+    #[inline]
+    pub fn w(self) -> Fixed {
+        self.3
+    }
+}
+
+impl Default for FQuat {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl fmt::Display for FQuat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}, {}, {}]", self.0, self.1, self.2, self.3)
+    }
+}
+
+impl Mul<FQuat> for FQuat {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_quat(other)
+    }
+}
+
+impl MulAssign<FQuat> for FQuat {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.mul_quat(other);
+    }
+}
+
+impl Mul<FVec3> for FQuat {
+    type Output = FVec3;
+    #[inline]
+    fn mul(self, other: FVec3) -> FVec3 {
+        self.mul_vec3(other)
+    }
+}
+
+impl Neg for FQuat {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1, -self.2, -self.3)
+    }
+}
+
+impl From<(Fixed, Fixed, Fixed, Fixed)> for FQuat {
+    #[inline]
+    fn from(t: (Fixed, Fixed, Fixed, Fixed)) -> Self {
+        Self(t.0, t.1, t.2, t.3)
+    }
+}
+
+impl From<FQuat> for (Fixed, Fixed, Fixed, Fixed) {
+    #[inline]
+    fn from(q: FQuat) -> Self {
+        (q.0, q.1, q.2, q.3)
+    }
+}
+
+impl From<[Fixed; 4]> for FQuat {
+    #[inline]
+    fn from(a: [Fixed; 4]) -> Self {
+        Self(a[0], a[1], a[2], a[3])
+    }
+}
+
+impl From<FQuat> for [Fixed; 4] {
+    #[inline]
+    fn from(q: FQuat) -> Self {
+        [q.0, q.1, q.2, q.3]
+    }
+}
+
+impl From<FQuat> for Quat {
+    #[inline]
+    fn from(q: FQuat) -> Self {
+        Self::from_xyzw(q.0.to_f32(), q.1.to_f32(), q.2.to_f32(), q.3.to_f32())
+    }
+}
+
+/// A 3x3 column major `Q32.32` fixed-point matrix.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[repr(C)]
+pub struct FMat3 {
+    pub(crate) x_axis: FVec3,
+    pub(crate) y_axis: FVec3,
+    pub(crate) z_axis: FVec3,
+}
+
+impl FMat3 {
+    /// Creates a 3x3 matrix with all elements set to `0.0`.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_cols(FVec3::zero(), FVec3::zero(), FVec3::zero())
+    }
+
+    /// Creates a 3x3 identity matrix.
+    #[inline]
+    pub const fn identity() -> Self {
+        Self::from_cols(FVec3::unit_x(), FVec3::unit_y(), FVec3::unit_z())
+    }
+
+    /// Creates a 3x3 matrix from three column vectors.
+    #[inline]
+    pub const fn from_cols(x_axis: FVec3, y_axis: FVec3, z_axis: FVec3) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+
+    /// Creates a `FMat3` from a `Mat3`, rounding each element to the nearest
+    /// value.
+    #[inline]
+    pub fn from_mat3(m: Mat3) -> Self {
+        Self::from_cols(
+            FVec3::from_vec3(m.x_axis()),
+            FVec3::from_vec3(m.y_axis()),
+            FVec3::from_vec3(m.z_axis()),
+        )
+    }
+
+    /// Creates a 3x3 homogeneous transformation matrix from the given `scale`,
+    /// rotation `angle` (in radians) and `translation`.
+    ///
+    /// The resulting matrix can be used to transform 2D points and vectors.
+    #[inline]
+    pub fn from_scale_angle_translation(scale: FVec2, angle: Fixed, translation: FVec2) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let (scale_x, scale_y) = scale.into();
+        Self {
+            x_axis: FVec3(cos * scale_x, sin * scale_x, Fixed::ZERO),
+            y_axis: FVec3(-sin * scale_y, cos * scale_y, Fixed::ZERO),
+            z_axis: translation.extend(Fixed::ONE),
+        }
+    }
+
+    /// Creates a 3x3 rotation matrix from the given quaternion.
+    #[inline]
+    pub fn from_quat(rotation: FQuat) -> Self {
+        glam_assert!(rotation.is_normalized());
+        let FQuat(x, y, z, w) = rotation;
+        let (x2, y2, z2) = (x + x, y + y, z + z);
+        let (xx, xy, xz) = (x * x2, x * y2, x * z2);
+        let (yy, yz, zz) = (y * y2, y * z2, z * z2);
+        let (wx, wy, wz) = (w * x2, w * y2, w * z2);
+        Self {
+            x_axis: FVec3(Fixed::ONE - (yy + zz), xy + wz, xz - wy),
+            y_axis: FVec3(xy - wz, Fixed::ONE - (xx + zz), yz + wx),
+            z_axis: FVec3(xz + wy, yz - wx, Fixed::ONE - (xx + yy)),
+        }
+    }
+
+    /// Creates a 3x3 rotation matrix containing a rotation
+    /// around a normalized rotation `axis` of `angle` (in radians).
+    #[inline]
+    pub fn from_axis_angle(axis: FVec3, angle: Fixed) -> Self {
+        glam_assert!(axis.is_normalized());
+        let (sin, cos) = angle.sin_cos();
+        let FVec3(x, y, z) = axis;
+        let FVec3(xsin, ysin, zsin) = axis * sin;
+        let FVec3(x2, y2, z2) = axis * axis;
+        let omc = Fixed::ONE - cos;
+        let xyomc = x * y * omc;
+        let xzomc = x * z * omc;
+        let yzomc = y * z * omc;
+        Self {
+            x_axis: FVec3(x2 * omc + cos, xyomc + zsin, xzomc - ysin),
+            y_axis: FVec3(xyomc - zsin, y2 * omc + cos, yzomc + xsin),
+            z_axis: FVec3(xzomc + ysin, yzomc - xsin, z2 * omc + cos),
+        }
+    }
+
+    /// Creates a 3x3 rotation matrix containing a rotation
+    /// around the x axis of `angle` (in radians).
+    #[inline]
+    pub fn from_rotation_x(angle: Fixed) -> Self {
+        let (sina, cosa) = angle.sin_cos();
+        Self {
+            x_axis: FVec3::unit_x(),
+            y_axis: FVec3(Fixed::ZERO, cosa, sina),
+            z_axis: FVec3(Fixed::ZERO, -sina, cosa),
+        }
+    }
+
+    /// Creates a 3x3 rotation matrix containing a rotation
+    /// around the y axis of `angle` (in radians).
+    #[inline]
+    pub fn from_rotation_y(angle: Fixed) -> Self {
+        let (sina, cosa) = angle.sin_cos();
+        Self {
+            x_axis: FVec3(cosa, Fixed::ZERO, -sina),
+            y_axis: FVec3::unit_y(),
+            z_axis: FVec3(sina, Fixed::ZERO, cosa),
+        }
+    }
+
+    /// Creates a 3x3 rotation matrix containing a rotation
+    /// around the z axis of `angle` (in radians).
+    #[inline]
+    pub fn from_rotation_z(angle: Fixed) -> Self {
+        let (sina, cosa) = angle.sin_cos();
+        Self {
+            x_axis: FVec3(cosa, sina, Fixed::ZERO),
+            y_axis: FVec3(-sina, cosa, Fixed::ZERO),
+            z_axis: FVec3::unit_z(),
+        }
+    }
+
+    /// Creates a 3x3 matrix containing the given non-uniform `scale`.
+    #[inline]
+    pub fn from_scale(scale: FVec3) -> Self {
+        let FVec3(x, y, z) = scale;
+        Self {
+            x_axis: FVec3(x, Fixed::ZERO, Fixed::ZERO),
+            y_axis: FVec3(Fixed::ZERO, y, Fixed::ZERO),
+            z_axis: FVec3(Fixed::ZERO, Fixed::ZERO, z),
+        }
+    }
+
+    /// Sets the `x_axis` column.
+    #[inline]
+    pub fn set_x_axis(&mut self, x_axis: FVec3) {
+        self.x_axis = x_axis;
+    }
+
+    /// Returns the `x_axis` column.
+    #[inline]
+    pub fn x_axis(&self) -> FVec3 {
+        self.x_axis
+    }
+
+    /// Sets the `y_axis` column.
+    #[inline]
+    pub fn set_y_axis(&mut self, y_axis: FVec3) {
+        self.y_axis = y_axis;
+    }
+
+    /// Returns the `y_axis` column.
+    #[inline]
+    pub fn y_axis(&self) -> FVec3 {
+        self.y_axis
+    }
+
+    /// Sets the `z_axis` column.
+    #[inline]
+    pub fn set_z_axis(&mut self, z_axis: FVec3) {
+        self.z_axis = z_axis;
+    }
+
+    /// Returns the `z_axis` column.
+    #[inline]
+    pub fn z_axis(&self) -> FVec3 {
+        self.z_axis
+    }
+
+    /// Returns the transpose of `self`.
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        let FVec3(m00, m01, m02) = self.x_axis;
+        let FVec3(m10, m11, m12) = self.y_axis;
+        let FVec3(m20, m21, m22) = self.z_axis;
+
+        Self {
+            x_axis: FVec3(m00, m10, m20),
+            y_axis: FVec3(m01, m11, m21),
+            z_axis: FVec3(m02, m12, m22),
+        }
+    }
+
+    /// Returns the determinant of `self`.
+    #[inline]
+    pub fn determinant(&self) -> Fixed {
+        self.z_axis.dot(self.x_axis.cross(self.y_axis))
+    }
+
+    /// Returns the inverse of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the determinant of `self` is zero.
+    pub fn inverse(&self) -> Self {
+        let tmp0 = self.y_axis.cross(self.z_axis);
+        let tmp1 = self.z_axis.cross(self.x_axis);
+        let tmp2 = self.x_axis.cross(self.y_axis);
+        let det = self.z_axis.dot(tmp2);
+        Self::from_cols(tmp0 / det, tmp1 / det, tmp2 / det).transpose()
+    }
+
+    /// Transforms a 3D vector, rounding each element once.
+    #[inline]
+    pub fn mul_vec3(&self, other: FVec3) -> FVec3 {
+        let rows = self.transpose();
+        FVec3(
+            rows.x_axis.dot(other),
+            rows.y_axis.dot(other),
+            rows.z_axis.dot(other),
+        )
+    }
+
+    /// Multiplies two 3x3 matrices.
+    #[inline]
+    pub fn mul_mat3(&self, other: &Self) -> Self {
+        Self {
+            x_axis: self.mul_vec3(other.x_axis),
+            y_axis: self.mul_vec3(other.y_axis),
+            z_axis: self.mul_vec3(other.z_axis),
+        }
+    }
+
+    /// Adds two 3x3 matrices.
+    #[inline]
+    pub fn add_mat3(&self, other: &Self) -> Self {
+        Self {
+            x_axis: self.x_axis + other.x_axis,
+            y_axis: self.y_axis + other.y_axis,
+            z_axis: self.z_axis + other.z_axis,
+        }
+    }
+
+    /// Subtracts two 3x3 matrices.
+    #[inline]
+    pub fn sub_mat3(&self, other: &Self) -> Self {
+        Self {
+            x_axis: self.x_axis - other.x_axis,
+            y_axis: self.y_axis - other.y_axis,
+            z_axis: self.z_axis - other.z_axis,
+        }
+    }
+
+    /// Multiplies a 3x3 matrix by a scalar.
+    #[inline]
+    pub fn mul_scalar(&self, other: Fixed) -> Self {
+        Self {
+            x_axis: self.x_axis * other,
+            y_axis: self.y_axis * other,
+            z_axis: self.z_axis * other,
+        }
+    }
+
+    /// Transforms the given `FVec2` as 2D point.
+    /// This is the equivalent of multiplying the `FVec2` as a `FVec3` where
+    /// `z` is `1.0`.
+    #[inline]
+    pub fn transform_point2(&self, other: FVec2) -> FVec2 {
+        self.mul_vec3(other.extend(Fixed::ONE)).truncate()
+    }
+
+    /// Transforms the given `FVec2` as 2D vector.
+    /// This is the equivalent of multiplying the `FVec2` as a `FVec3` where
+    /// `z` is `0.0`.
+    #[inline]
+    pub fn transform_vector2(&self, other: FVec2) -> FVec2 {
+        self.mul_vec3(other.extend(Fixed::ZERO)).truncate()
+    }
+
+    /// Returns true if the absolute difference of all elements between `self`
+    /// and `other` is less than or equal to `max_abs_diff`.
+    #[inline]
+    pub fn abs_diff_eq(&self, other: Self, max_abs_diff: Fixed) -> bool {
+        self.x_axis.abs_diff_eq(other.x_axis, max_abs_diff)
+            && self.y_axis.abs_diff_eq(other.y_axis, max_abs_diff)
+            && self.z_axis.abs_diff_eq(other.z_axis, max_abs_diff)
+    }
+}
+
+impl Default for FMat3 {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl fmt::Display for FMat3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}, {}]", self.x_axis, self.y_axis, self.z_axis)
+    }
+}
+
+impl Add<FMat3> for FMat3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.add_mat3(&other)
+    }
+}
+
+impl Sub<FMat3> for FMat3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.sub_mat3(&other)
+    }
+}
+
+impl Mul<FMat3> for FMat3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        self.mul_mat3(&other)
+    }
+}
+
+impl Mul<FVec3> for FMat3 {
+    type Output = FVec3;
+    #[inline]
+    fn mul(self, other: FVec3) -> FVec3 {
+        self.mul_vec3(other)
+    }
+}
+
+impl Mul<FMat3> for Fixed {
+    type Output = FMat3;
+    #[inline]
+    fn mul(self, other: FMat3) -> FMat3 {
+        other.mul_scalar(self)
+    }
+}
+
+impl Mul<Fixed> for FMat3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, other: Fixed) -> Self {
+        self.mul_scalar(other)
+    }
+}
+
+impl From<FMat3> for Mat3 {
+    #[inline]
+    fn from(m: FMat3) -> Self {
+        Self::from_cols(m.x_axis.into(), m.y_axis.into(), m.z_axis.into())
+    }
+}
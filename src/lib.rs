@@ -12,6 +12,11 @@ supported as this is what stable Rust supports.
 * SSE2 implementation for most types, including `Mat2`, `Mat3`, `Mat4`, `Quat`,
   `Vec3` and `Vec4`
 * Scalar fallback implementations exist when SSE2 is not available
+* `Q16.16` and `Q32.32` fixed-point vectors, quaternions and matrices in the
+  [`fixed`] module, for simulations which must give the same results on every
+  platform
+* Robust `orient2d`, `orient3d`, `incircle` and `insphere` predicates in the
+  [`predicates`] module, which always return the correct sign
 * Most functionality includes unit tests and benchmarks
 
 ## Linear algebra conventions
@@ -41,32 +46,50 @@ let v = m * Vec3::unit_x();
 assert!(v.abs_diff_eq(-Vec3::unit_z(), core::f32::EPSILON));
 ```
 
+Functions that take an angle accept `impl Into<Radians>`, so the angle may be
+an `f32` in radians or a `Radians` or `Degrees` value.
+
+```
+use glam::{Degrees, Mat3, Vec3};
+let m = Mat3::from_rotation_y(Degrees(90.0));
+let v = m * Vec3::unit_x();
+assert!(v.abs_diff_eq(-Vec3::unit_z(), core::f32::EPSILON));
+```
+
 ## Size and alignment of types
 
 Most `glam` types use SIMD for storage meaning most types are 16 byte aligned.
-The only exception is Vec2`. When SSE2 is not available on the target
-architecture the types will still be 16 byte aligned, so object sizes and
-layouts will not change between architectures.
+The exceptions are `Vec2`, `Vec3` and `Mat3`. When SSE2 is not available on
+the target architecture the types will still be 16 byte aligned, so object
+sizes and layouts will not change between architectures.
+
+`Vec3` and `Mat3` are stored as plain `f32` values without padding. `Vec3A`
+and `Mat3A` are their 16 byte aligned SIMD counterparts with the same API,
+which have a stride larger than their size resulting in some wasted space.
 
-16 byte alignment means that some types will have a stride larger than their
-size resulting in some wasted space.
+| Type  | f32 bytes | SIMD bytes | Wasted bytes |
+|:------|----------:|-----------:|-------------:|
+|`Vec3A`|         12|          16|             4|
+|`Mat3A`|         36|          48|            12|
 
-| Type | f32 bytes | SIMD bytes | Wasted bytes |
-|:-----|----------:|-----------:|-------------:|
-|`Vec3`|         12|          16|             4|
-|`Mat3`|         36|          48|            12|
+Despite this wasted space the SIMD versions tend to outperform the `f32`
+versions in [**mathbench**](https://github.com/bitshifter/mathbench-rs)
+benchmarks. Convert between the two with `From`:
 
-Despite this wasted space the SIMD version tends to outperform the `f32`
-implementation in [**mathbench**](https://github.com/bitshifter/mathbench-rs)
-benchmarks.
+```
+use glam::{Vec3, Vec3A};
+let a = Vec3A::from(Vec3::new(1.0, 2.0, 3.0));
+let b = Vec3::from(a * 2.0);
+assert_eq!(b, Vec3::new(2.0, 4.0, 6.0));
+```
 
 SIMD support can be disabled entirely using the `scalar-math` feature. This
 feature will also disable SIMD alignment meaning most types will use native
-`f32` alignment of 4 bytes.
+`f32` alignment of 4 bytes. `Vec3A` and `Mat3A` remain 16 byte aligned.
 
 All the main `glam` types are tagged with #[repr(C)], so they are possible
-to expose as struct members to C interfaces if desired. Be mindful of Vec3's
-extra float though.
+to expose as struct members to C interfaces if desired. Be mindful of the
+extra float in `Vec3A`.
 
 ## Accessing internal data
 
@@ -107,6 +130,12 @@ let a = Vec3::new(1.0, 2.0, 3.0);
 assert_eq!(format!("{}", a), "[1, 2, 3]");
 ```
 
+The SIMD and scalar implementations of each type are tested against each other
+and give identical results for most functions. The exceptions, such as
+`normalize` which divides by the length with SSE2 and `Quat::slerp`, have
+documented tolerances in `src/f32/parity.rs`. The `deterministic` feature
+removes these differences.
+
 ## Feature gates
 
 All `glam` dependencies are optional, however some are required for tests
@@ -115,9 +144,29 @@ and benchmarks.
 * `std` - the default feature, has no dependencies.
 * `rand` - used to generate random values. Used in benchmarks.
 * `serde` - used for serialization and deserialization of types.
+* `rkyv` - used for zero-copy deserialization of types.
+* `bytemuck` - used for casting the half precision storage types to bytes.
 * `mint` - used for interoperating with other linear algebra libraries.
+* `proptest` - provides `proptest` strategies for all types in the
+  `glam::proptest` module.
+* `quickcheck` - implements `quickcheck::Arbitrary` for all types.
+* `arbitrary` - implements `arbitrary::Arbitrary` for all types, for use with
+  fuzzers.
+* `testing` - provides the `assert_approx_eq!` and
+  `assert_rotation_approx_eq!` macros and the `glam::testing` module for
+  tests of code which uses `glam`.
 * `scalar-math` - disables SIMD support and uses native alignment for all
   types.
+* `deterministic` - gives bit identical results with and without SIMD and on
+  every platform, for lockstep networking and replays. SSE2 functions which
+  would round differently use the same arithmetic as the scalar versions,
+  fused multiply-add is never used, and `sin`, `cos`, `tan`, `atan2` and `exp`
+  use software implementations instead of the platform's math library. `sqrt`
+  is correctly rounded on every platform so it doesn't need one. Colors,
+  noise, random sampling, Catmull-Rom parameterization and the `serde_repr`
+  Euler angles still use the platform's math library. 32-bit x86 targets
+  without SSE2 aren't supported since x87 rounds intermediate results
+  differently.
 * `debug-glam-assert` - adds assertions in debug builds which check the validity
   of parameters passed to `glam` to help catch runtime errors.
 * `glam-assert` - adds assertions to all builds which check the validity of
@@ -130,14 +179,37 @@ and benchmarks.
 mod macros;
 
 pub mod f32;
+pub mod fixed;
 
 pub use self::f32::{
-    mat2, mat3, mat4, quat, vec2, vec3, vec4, Mat2, Mat3, Mat4, Quat, Vec2, Vec2Mask, Vec3,
+    mat2, mat3, mat3a, mat4, quat, vec2, vec3, vec3a, vec4, weld_vertices, CanonicalBits, Degrees,
+    DualQuat, HQuat, HVec2, HVec3, HVec4, Hashable, LinearRgba, Mat2, Mat3, Mat3A, Mat4,
+    ParseError, ParseErrorKind, Quat, Radians, Rot2, SRgba, Vec2, Vec2Mask, Vec3, Vec3A, Vec3AMask,
     Vec3Mask, Vec4, Vec4Mask,
 };
 
+pub use self::f32::{curves, noise, predicates, sampling, spring};
+
+#[cfg(feature = "rand")]
+pub use self::f32::{
+    CosineHemisphere, Hemisphere, UniformAabb, UniformRotation, UniformTriangle, UniformVec2,
+    UniformVec3, UniformVec4, UnitBall, UnitCircle, UnitDisk, UnitSphere,
+};
+
+#[cfg(feature = "serde")]
+pub use self::f32::serde_repr;
+
+#[cfg(feature = "proptest")]
+pub use self::f32::proptest;
+
+#[cfg(any(feature = "arbitrary", feature = "quickcheck"))]
+pub use self::f32::{Affine, Invertible, Orthonormal};
+
+#[cfg(feature = "testing")]
+pub use self::f32::testing;
+
 #[cfg(feature = "transform-types")]
-pub use self::f32::{TransformRT, TransformSRT};
+pub use self::f32::{TransformRT, TransformRT2, TransformSRT, TransformSRT2};
 
 #[repr(align(16))]
 pub(crate) struct Align16<T>(T);
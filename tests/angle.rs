@@ -0,0 +1,143 @@
+mod support;
+
+use core::f32::consts::{FRAC_PI_2, PI};
+use glam::{Degrees, Mat2, Mat3, Mat4, Quat, Radians, Rot2, Vec2, Vec3};
+
+#[test]
+fn test_angle_conversion() {
+    assert_eq!(Degrees(180.0).to_radians(), Radians(PI));
+    assert_eq!(Radians(PI).to_degrees(), Degrees(180.0));
+    assert_eq!(Radians::from(Degrees(90.0)), Radians(FRAC_PI_2));
+    assert_eq!(Degrees::from(Radians(FRAC_PI_2)), Degrees(90.0));
+    assert_eq!(Radians::from(1.5), Radians(1.5));
+    assert_eq!(f32::from(Degrees(45.0)), 45.0);
+    assert_eq!(Radians::default(), Radians(0.0));
+}
+
+#[test]
+fn test_angle_arithmetic() {
+    let mut a = Degrees(30.0) + Degrees(60.0);
+    assert_eq!(a, Degrees(90.0));
+    assert_eq!(a - Degrees(100.0), Degrees(-10.0));
+    assert_eq!(-a, Degrees(-90.0));
+    assert_eq!(a * 2.0, Degrees(180.0));
+    assert_eq!(0.5 * a, Degrees(45.0));
+    assert_eq!(a / 3.0, Degrees(30.0));
+    assert_eq!(a / Degrees(45.0), 2.0);
+    a += Degrees(10.0);
+    a -= Degrees(20.0);
+    a *= 3.0;
+    a /= 2.0;
+    assert_eq!(a, Degrees(120.0));
+    assert_eq!(Radians(-1.0).abs(), Radians(1.0));
+    assert!(Radians(1.0) < Radians(2.0));
+    assert_eq!(format!("{}", Degrees(90.0)), "90°");
+    assert_eq!(format!("{:.2}", Radians(PI)), "3.14 rad");
+}
+
+#[test]
+fn test_angle_trig() {
+    assert_approx_eq!(Degrees(30.0).sin(), 0.5);
+    assert_approx_eq!(Degrees(60.0).cos(), 0.5);
+    assert_approx_eq!(Degrees(45.0).tan(), 1.0);
+    let (s, c) = Radians(1.0).sin_cos();
+    assert_approx_eq!(s, 1.0_f32.sin());
+    assert_approx_eq!(c, 1.0_f32.cos());
+}
+
+#[test]
+fn test_angle_wrap() {
+    assert_eq!(Degrees(190.0).wrap_pi(), Degrees(-170.0));
+    assert_eq!(Degrees(-190.0).wrap_pi(), Degrees(170.0));
+    assert_eq!(Degrees(180.0).wrap_pi(), Degrees(180.0));
+    assert_eq!(Degrees(-180.0).wrap_pi(), Degrees(180.0));
+    assert_eq!(Degrees(720.0 + 45.0).wrap_pi(), Degrees(45.0));
+    assert_eq!(Degrees(-30.0).wrap_two_pi(), Degrees(330.0));
+    assert_eq!(Degrees(360.0).wrap_two_pi(), Degrees(0.0));
+    assert_eq!(Degrees(-720.0).wrap_two_pi(), Degrees(0.0));
+    assert_eq!(Degrees(1000.0).wrap_two_pi(), Degrees(280.0));
+    // a tiny negative angle must not wrap to a full turn
+    assert_eq!(Radians(-1e-9).wrap_two_pi(), Radians(0.0));
+
+    for i in -2000..2000 {
+        let a = Radians(i as f32 * 0.01);
+        let w = a.wrap_pi();
+        assert!(w.0 > -PI && w.0 <= PI, "{:?}", a);
+        assert_approx_eq!(w.sin(), a.sin(), 1e-5);
+        assert_approx_eq!(w.cos(), a.cos(), 1e-5);
+        let w = a.wrap_two_pi();
+        assert!(w.0 >= 0.0 && w.0 < 2.0 * PI, "{:?}", a);
+        assert_approx_eq!(w.sin(), a.sin(), 1e-5);
+    }
+}
+
+#[test]
+fn test_angle_shortest_difference() {
+    assert_eq!(
+        Degrees(10.0).shortest_difference(Degrees(350.0)),
+        Degrees(-20.0)
+    );
+    assert_eq!(
+        Degrees(350.0).shortest_difference(Degrees(10.0)),
+        Degrees(20.0)
+    );
+    assert_eq!(
+        Degrees(-170.0).shortest_difference(Degrees(170.0)),
+        Degrees(-20.0)
+    );
+    assert_eq!(
+        Degrees(0.0).shortest_difference(Degrees(180.0)),
+        Degrees(180.0)
+    );
+    assert_eq!(
+        Degrees(90.0).shortest_difference(Degrees(90.0 + 720.0)),
+        Degrees(0.0)
+    );
+}
+
+#[test]
+fn test_angle_lerp() {
+    assert_eq!(Degrees(350.0).lerp(Degrees(10.0), 0.0), Degrees(350.0));
+    assert_eq!(Degrees(350.0).lerp(Degrees(10.0), 0.5), Degrees(360.0));
+    assert_eq!(Degrees(350.0).lerp(Degrees(10.0), 1.0), Degrees(370.0));
+    assert_eq!(Degrees(10.0).lerp(Degrees(350.0), 0.25), Degrees(5.0));
+    assert_eq!(
+        Degrees(-90.0).lerp(Degrees(90.0), 0.5),
+        Degrees(-90.0 + 90.0)
+    );
+    let a = Radians(3.0).lerp(Radians(-3.0), 0.5);
+    assert_approx_eq!(a.wrap_pi().0.abs(), PI, 1e-6);
+}
+
+#[test]
+fn test_angle_constructors() {
+    let r = FRAC_PI_2;
+    let d = Degrees(90.0);
+    assert_approx_eq!(Quat::from_rotation_x(d), Quat::from_rotation_x(r));
+    assert_approx_eq!(Quat::from_rotation_y(d), Quat::from_rotation_y(Radians(r)));
+    assert_approx_eq!(
+        Quat::from_axis_angle(Vec3::unit_z(), d),
+        Quat::from_rotation_z(r)
+    );
+    assert_approx_eq!(
+        Quat::from_rotation_ypr(d, Degrees(45.0), 0.5),
+        Quat::from_rotation_ypr(r, PI / 4.0, 0.5)
+    );
+    assert_approx_eq!(Mat2::from_angle(d), Mat2::from_angle(r));
+    assert_approx_eq!(Mat2::from_scale_angle(Vec2::one(), d), Mat2::from_angle(r));
+    assert_approx_eq!(Mat3::from_rotation_z(d), Mat3::from_rotation_z(r));
+    assert_approx_eq!(
+        Mat3::from_scale_angle_translation(Vec2::one(), d, Vec2::zero()),
+        Mat3::from_scale_angle_translation(Vec2::one(), r, Vec2::zero())
+    );
+    assert_approx_eq!(Mat4::from_rotation_y(d), Mat4::from_rotation_y(r));
+    assert_approx_eq!(
+        Mat4::perspective_lh(Degrees(60.0), 1.5, 0.1, 100.0),
+        Mat4::perspective_lh(PI / 3.0, 1.5, 0.1, 100.0)
+    );
+    assert_approx_eq!(
+        Mat4::perspective_infinite_reverse_rh(Degrees(60.0), 1.5, 0.1),
+        Mat4::perspective_infinite_reverse_rh(PI / 3.0, 1.5, 0.1)
+    );
+    assert_approx_eq!(Rot2::from_angle(d), Rot2::from_angle(r));
+}
@@ -0,0 +1,237 @@
+mod support;
+
+use glam::{LinearRgba, ParseErrorKind, SRgba, Vec3, Vec4};
+
+#[test]
+fn test_color_accessors() {
+    let mut c = LinearRgba::new(0.1, 0.2, 0.3, 0.4);
+    assert_eq!((c.r(), c.g(), c.b(), c.a()), (0.1, 0.2, 0.3, 0.4));
+    assert_eq!(c.to_vec4(), Vec4::new(0.1, 0.2, 0.3, 0.4));
+    assert_eq!(c.to_rgb(), Vec3::new(0.1, 0.2, 0.3));
+    c.set_r(1.0);
+    c.set_g(2.0);
+    c.set_b(3.0);
+    c.set_a(4.0);
+    assert_eq!(c, LinearRgba::from([1.0, 2.0, 3.0, 4.0]));
+    assert_eq!(c.with_alpha(0.5).a(), 0.5);
+    assert_eq!(SRgba::rgb(0.1, 0.2, 0.3).a(), 1.0);
+    assert_eq!(SRgba::white(), SRgba::new(1.0, 1.0, 1.0, 1.0));
+    assert_eq!(LinearRgba::black(), LinearRgba::rgb(0.0, 0.0, 0.0));
+    assert_eq!(LinearRgba::transparent(), LinearRgba::default());
+    assert_eq!(Vec4::from(SRgba::from(Vec4::one())), Vec4::one());
+    let a: [f32; 4] = SRgba::new(1.0, 2.0, 3.0, 4.0).into();
+    assert_eq!(a, [1.0, 2.0, 3.0, 4.0]);
+}
+
+#[test]
+fn test_srgb_transfer() {
+    let c = SRgba::new(0.5, 0.0, 1.0, 0.5).to_linear();
+    assert_approx_eq!(c.to_vec4(), Vec4::new(0.214_041_14, 0.0, 1.0, 0.5), 1e-6);
+    let c = LinearRgba::new(0.5, 0.18, 0.001, 0.25).to_srgba();
+    assert_approx_eq!(
+        c.to_vec4(),
+        Vec4::new(0.735_357, 0.461_356_13, 0.012_92, 0.25),
+        1e-6
+    );
+    for i in 0..=255 {
+        let x = i as f32 / 255.0;
+        let srgb = SRgba::new(x, x, x, x);
+        assert_approx_eq!(srgb.to_linear().to_srgba(), srgb, 1e-5);
+        assert_approx_eq!(srgb.to_linear_fast(), srgb.to_linear(), 0.002);
+        let linear = LinearRgba::new(x, x, x, x);
+        assert_approx_eq!(linear.to_srgba_fast(), linear.to_srgba(), 0.002);
+        assert_eq!(SRgba::from(linear), linear.to_srgba());
+        assert_eq!(LinearRgba::from(srgb), srgb.to_linear());
+    }
+    // densely sample around the linear segment of the transfer function
+    for i in 1..=400 {
+        let x = i as f32 * 0.000_01;
+        let linear = LinearRgba::new(x, x, x, x);
+        let srgb = linear.to_srgba_fast();
+        assert!(srgb.r() >= 0.0);
+        assert_approx_eq!(srgb, linear.to_srgba(), 0.002);
+    }
+}
+
+#[test]
+fn test_luminance_xyz() {
+    assert_approx_eq!(LinearRgba::white().luminance(), 1.0);
+    assert_approx_eq!(LinearRgba::rgb(0.0, 1.0, 0.0).luminance(), 0.715_152_2);
+    // D65 white point
+    assert_approx_eq!(
+        LinearRgba::white().to_xyz(),
+        Vec3::new(0.950_47, 1.0, 1.088_83),
+        1e-4
+    );
+    let c = LinearRgba::new(0.2, 0.5, 0.8, 0.3);
+    assert_approx_eq!(c.to_xyz().y(), c.luminance());
+    assert_approx_eq!(LinearRgba::from_xyz(c.to_xyz(), 0.3), c, 1e-5);
+}
+
+#[test]
+fn test_oklab() {
+    assert_approx_eq!(
+        LinearRgba::white().to_oklab(),
+        Vec3::new(1.0, 0.0, 0.0),
+        1e-4
+    );
+    assert_approx_eq!(
+        LinearRgba::rgb(1.0, 0.0, 0.0).to_oklab(),
+        Vec3::new(0.627_955, 0.224_863, 0.125_846),
+        1e-4
+    );
+    assert_approx_eq!(LinearRgba::black().to_oklab(), Vec3::zero());
+    for &c in &[
+        LinearRgba::new(0.2, 0.5, 0.8, 0.3),
+        LinearRgba::rgb(0.0, 1.0, 0.0),
+        LinearRgba::rgb(0.9, 0.1, 0.05),
+    ] {
+        assert_approx_eq!(LinearRgba::from_oklab(c.to_oklab(), c.a()), c, 1e-4);
+    }
+}
+
+#[test]
+fn test_hsv_hsl() {
+    let c = SRgba::rgb(0.2, 0.4, 0.8);
+    assert_approx_eq!(c.to_hsv(), Vec3::new(220.0, 0.75, 0.8), 1e-4);
+    assert_approx_eq!(c.to_hsl(), Vec3::new(220.0, 0.6, 0.5), 1e-4);
+    assert_approx_eq!(SRgba::from_hsv(Vec3::new(220.0, 0.75, 0.8), 1.0), c, 1e-5);
+    assert_approx_eq!(SRgba::from_hsl(Vec3::new(220.0, 0.6, 0.5), 1.0), c, 1e-5);
+
+    assert_eq!(SRgba::rgb(1.0, 0.0, 0.0).to_hsv(), Vec3::new(0.0, 1.0, 1.0));
+    assert_eq!(
+        SRgba::rgb(0.0, 1.0, 0.0).to_hsv(),
+        Vec3::new(120.0, 1.0, 1.0)
+    );
+    assert_eq!(
+        SRgba::rgb(1.0, 0.0, 1.0).to_hsv(),
+        Vec3::new(300.0, 1.0, 1.0)
+    );
+    assert_eq!(SRgba::rgb(0.5, 0.5, 0.5).to_hsl(), Vec3::new(0.0, 0.0, 0.5));
+    assert_eq!(SRgba::black().to_hsv(), Vec3::zero());
+    // hue wraps around
+    assert_approx_eq!(
+        SRgba::from_hsv(Vec3::new(-120.0, 1.0, 1.0), 0.5),
+        SRgba::new(0.0, 0.0, 1.0, 0.5)
+    );
+    assert_approx_eq!(
+        SRgba::from_hsl(Vec3::new(480.0, 1.0, 0.5), 1.0),
+        SRgba::rgb(0.0, 1.0, 0.0)
+    );
+
+    for i in 0..100 {
+        let hsv = Vec3::new(
+            i as f32 * 3.6,
+            (i % 7) as f32 / 7.0 + 0.1,
+            (i % 5) as f32 / 5.0 + 0.1,
+        );
+        let c = SRgba::from_hsv(hsv, 1.0);
+        assert_approx_eq!(c.to_hsv(), hsv, 1e-3);
+        let c = SRgba::from_hsl(hsv, 1.0);
+        assert_approx_eq!(c.to_hsl(), hsv, 1e-3);
+    }
+}
+
+#[test]
+fn test_premultiply() {
+    let c = LinearRgba::new(0.2, 0.4, 0.8, 0.5);
+    assert_eq!(c.premultiply(), LinearRgba::new(0.1, 0.2, 0.4, 0.5));
+    assert_eq!(c.premultiply().unpremultiply(), c);
+    assert_eq!(
+        LinearRgba::new(0.0, 0.0, 0.0, 0.0).unpremultiply(),
+        LinearRgba::transparent()
+    );
+}
+
+#[test]
+fn test_color_ops() {
+    let a = LinearRgba::new(0.2, 0.4, 0.8, 1.0);
+    let b = LinearRgba::new(0.5, 0.5, 0.5, 0.5);
+    assert_eq!(a + b, LinearRgba::new(0.7, 0.9, 1.3, 1.5));
+    assert_approx_eq!((a - b).to_vec4(), Vec4::new(-0.3, -0.1, 0.3, 0.5));
+    assert_eq!(a * b, LinearRgba::new(0.1, 0.2, 0.4, 0.5));
+    assert_eq!(a * 2.0, LinearRgba::new(0.4, 0.8, 1.6, 2.0));
+    assert_approx_eq!(a.lerp(b, 0.5).to_vec4(), Vec4::new(0.35, 0.45, 0.65, 0.75));
+}
+
+#[test]
+fn test_rgba8() {
+    let c = SRgba::from_rgba8([255, 128, 0, 51]);
+    assert_approx_eq!(c, SRgba::new(1.0, 128.0 / 255.0, 0.0, 0.2));
+    assert_eq!(c.to_rgba8(), [255, 128, 0, 51]);
+    assert_eq!(c.to_u32(), 0xff80_0033);
+    assert_eq!(SRgba::from_u32(0xff80_0033), c);
+    // out of range components are clamped
+    assert_eq!(
+        SRgba::new(-1.0, 2.0, 0.5, 1.0).to_rgba8(),
+        [0, 255, 128, 255]
+    );
+    for i in 0..=255 {
+        let rgba = [i as u8, 255 - i as u8, (i * 7 % 256) as u8, 255];
+        assert_eq!(SRgba::from_rgba8(rgba).to_rgba8(), rgba);
+    }
+}
+
+#[test]
+fn test_hex() {
+    assert_eq!(
+        SRgba::from_hex("#ff8000").unwrap().to_rgba8(),
+        [255, 128, 0, 255]
+    );
+    assert_eq!(
+        SRgba::from_hex("FF800033").unwrap().to_rgba8(),
+        [255, 128, 0, 51]
+    );
+    assert_eq!(
+        SRgba::from_hex("#f80").unwrap().to_rgba8(),
+        [255, 136, 0, 255]
+    );
+    assert_eq!(
+        SRgba::from_hex("#f803").unwrap().to_rgba8(),
+        [255, 136, 0, 51]
+    );
+    assert_eq!(SRgba::from_rgba8([255, 128, 0, 255]).to_hex(), "#ff8000");
+    assert_eq!(SRgba::from_rgba8([1, 2, 3, 4]).to_hex(), "#01020304");
+
+    let err = SRgba::from_hex("#ff80g0").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::InvalidHex);
+    assert_eq!(err.position(), 5);
+    let err = SRgba::from_hex("#ff800").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::InvalidHex);
+    assert_eq!(err.position(), 6);
+    assert!(SRgba::from_hex("").is_err());
+    assert!(SRgba::from_hex("#").is_err());
+    assert!(SRgba::from_hex("#ff80000").is_err());
+    assert!(SRgba::from_hex("#ééé").is_err());
+}
+
+#[test]
+fn test_color_display_from_str() {
+    let c = SRgba::new(0.25, 0.5, 1.0, 0.75);
+    assert_eq!(format!("{}", c), "[0.25, 0.5, 1, 0.75]");
+    assert_eq!(format!("{}", c).parse::<SRgba>().unwrap(), c);
+    assert_eq!(
+        " #4080ff ".parse::<SRgba>().unwrap().to_rgba8(),
+        [64, 128, 255, 255]
+    );
+    let err = " #4080fz".parse::<SRgba>().unwrap_err();
+    assert_eq!(err.position(), 7);
+
+    let c = LinearRgba::new(0.1, 0.2, 0.3, 0.4);
+    assert_eq!(format!("{}", c).parse::<LinearRgba>().unwrap(), c);
+    assert!("#ffffff".parse::<LinearRgba>().is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_color_serde() {
+    let c = LinearRgba::new(0.25, 0.5, 1.0, 0.75);
+    let serialized = serde_json::to_string(&c).unwrap();
+    assert_eq!(serialized, "[0.25,0.5,1.0,0.75]");
+    assert_eq!(serde_json::from_str::<LinearRgba>(&serialized).unwrap(), c);
+    let c = SRgba::new(0.25, 0.5, 1.0, 0.75);
+    let serialized = serde_json::to_string(&c).unwrap();
+    assert_eq!(serialized, "[0.25,0.5,1.0,0.75]");
+    assert_eq!(serde_json::from_str::<SRgba>(&serialized).unwrap(), c);
+    assert!(serde_json::from_str::<SRgba>("[0.25,0.5,1.0]").is_err());
+}
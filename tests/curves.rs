@@ -0,0 +1,240 @@
+mod support;
+
+use glam::curves::{ArcLengthTable, CatmullRomKind, CubicSegment, QuatSegment};
+use glam::f32::{Quat, Vec2, Vec3, Vec4};
+use support::deg;
+
+#[test]
+fn test_bezier() {
+    let (p0, p1, p2, p3) = (
+        Vec2::new(0.0, 0.0),
+        Vec2::new(0.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 0.0),
+    );
+    let c = CubicSegment::from_bezier(p0, p1, p2, p3);
+    assert_eq!(c.position(0.0), p0);
+    assert_approx_eq!(c.position(1.0), p3);
+    assert_approx_eq!(c.position(0.5), Vec2::new(0.5, 0.75));
+    // end tangents point at the adjacent control points
+    assert_approx_eq!(c.velocity(0.0), (p1 - p0) * 3.0);
+    assert_approx_eq!(c.velocity(1.0), (p3 - p2) * 3.0);
+    assert_approx_eq!(c.acceleration(0.0), (p0 - p1 * 2.0 + p2) * 6.0);
+    assert_approx_eq!(c.acceleration(1.0), (p1 - p2 * 2.0 + p3) * 6.0);
+
+    // compare against de Casteljau
+    let t = 0.3;
+    let a = p0.lerp(p1, t);
+    let b = p1.lerp(p2, t);
+    let d = p2.lerp(p3, t);
+    let expected = a.lerp(b, t).lerp(b.lerp(d, t), t);
+    assert_approx_eq!(c.position(t), expected, 1e-6);
+}
+
+#[test]
+fn test_hermite() {
+    let p0 = Vec3::new(1.0, 2.0, 3.0);
+    let m0 = Vec3::new(1.0, 0.0, 0.0);
+    let p1 = Vec3::new(-1.0, 0.0, 2.0);
+    let m1 = Vec3::new(0.0, 0.0, -4.0);
+    let c = CubicSegment::from_hermite(p0, m0, p1, m1);
+    assert_approx_eq!(c.position(0.0), p0);
+    assert_approx_eq!(c.position(1.0), p1, 1e-6);
+    assert_approx_eq!(c.velocity(0.0), m0);
+    assert_approx_eq!(c.velocity(1.0), m1, 1e-6);
+
+    // a Bézier with control points a third along the tangents is identical
+    let b = CubicSegment::from_bezier(p0, p0 + m0 / 3.0, p1 - m1 / 3.0, p1);
+    for &t in &[0.0, 0.25, 0.5, 0.75, 1.0] {
+        assert_approx_eq!(c.position(t), b.position(t), 1e-6);
+    }
+}
+
+#[test]
+fn test_derivatives() {
+    let c = CubicSegment::from_bezier(
+        Vec4::new(0.0, 1.0, 2.0, 3.0),
+        Vec4::new(4.0, -1.0, 2.0, 0.0),
+        Vec4::new(2.0, 3.0, -2.0, 1.0),
+        Vec4::new(-1.0, 0.0, 1.0, 2.0),
+    );
+    let h = 1e-2;
+    for &t in &[0.1, 0.5, 0.9] {
+        let fd_vel = (c.position(t + h) - c.position(t - h)) / (2.0 * h);
+        assert_approx_eq!(c.velocity(t), fd_vel, 1e-2);
+        let fd_acc = (c.velocity(t + h) - c.velocity(t - h)) / (2.0 * h);
+        assert_approx_eq!(c.acceleration(t), fd_acc, 1e-2);
+    }
+}
+
+#[test]
+fn test_catmull_rom() {
+    let p0 = Vec2::new(0.0, 0.0);
+    let p1 = Vec2::new(1.0, 1.0);
+    let p2 = Vec2::new(3.0, 1.0);
+    let p3 = Vec2::new(4.0, 0.0);
+
+    for &kind in &[
+        CatmullRomKind::Uniform,
+        CatmullRomKind::Centripetal,
+        CatmullRomKind::Chordal,
+    ] {
+        let c = CubicSegment::from_catmull_rom(p0, p1, p2, p3, kind);
+        assert_approx_eq!(c.position(0.0), p1);
+        assert_approx_eq!(c.position(1.0), p2, 1e-6);
+        // symmetric control points give a symmetric curve
+        assert_approx_eq!(c.position(0.5).x(), 2.0, 1e-6);
+    }
+
+    let uniform = CubicSegment::from_catmull_rom(p0, p1, p2, p3, CatmullRomKind::Uniform);
+    assert_approx_eq!(uniform.velocity(0.0), (p2 - p0) * 0.5);
+    assert_approx_eq!(uniform.velocity(1.0), (p3 - p1) * 0.5);
+
+    // coincident points do not produce NaNs
+    let c = CubicSegment::from_catmull_rom(p1, p1, p2, p2, CatmullRomKind::Centripetal);
+    assert!(c.position(0.5).x().is_finite());
+    assert_approx_eq!(c.position(1.0), p2, 1e-6);
+}
+
+#[test]
+fn test_catmull_rom_continuity() {
+    let points = [
+        Vec3::new(0.0, 0.0, 0.0),
+        Vec3::new(1.0, 2.0, 0.0),
+        Vec3::new(3.0, 2.5, 1.0),
+        Vec3::new(4.0, 0.0, 2.0),
+        Vec3::new(6.0, -1.0, 2.0),
+    ];
+    let a = CubicSegment::from_catmull_rom(
+        points[0],
+        points[1],
+        points[2],
+        points[3],
+        CatmullRomKind::Centripetal,
+    );
+    let b = CubicSegment::from_catmull_rom(
+        points[1],
+        points[2],
+        points[3],
+        points[4],
+        CatmullRomKind::Centripetal,
+    );
+    assert_approx_eq!(a.position(1.0), b.position(0.0), 1e-6);
+    // tangent directions match at the shared key
+    assert_approx_eq!(
+        a.velocity(1.0).normalize(),
+        b.velocity(0.0).normalize(),
+        1e-5
+    );
+}
+
+#[test]
+fn test_b_spline() {
+    let points = [0.0_f32, 1.0, 4.0, 2.0, 5.0];
+    let a = CubicSegment::from_b_spline(points[0], points[1], points[2], points[3]);
+    let b = CubicSegment::from_b_spline(points[1], points[2], points[3], points[4]);
+    assert_approx_eq!(a.position(0.0), (0.0 + 4.0 + 4.0) / 6.0);
+    // C2 continuous between segments
+    assert_approx_eq!(a.position(1.0), b.position(0.0), 1e-6);
+    assert_approx_eq!(a.velocity(1.0), b.velocity(0.0), 1e-6);
+    assert_approx_eq!(a.acceleration(1.0), b.acceleration(0.0), 1e-5);
+}
+
+#[test]
+fn test_arc_length_table_nan() {
+    let c = CubicSegment::from_bezier(
+        Vec2::new(0.0, 0.0),
+        Vec2::new(f32::NAN, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(2.0, 0.0),
+    );
+    let table = ArcLengthTable::new(&c, 16);
+    assert!(table.length().is_nan());
+    assert!(table.t_at_distance(0.5).is_nan());
+    assert!(table.t_at_distance(f32::NAN).is_nan());
+}
+
+#[test]
+fn test_arc_length_table() {
+    // a straight line with uneven parameterization
+    let c = CubicSegment::from_bezier(
+        Vec2::new(0.0, 0.0),
+        Vec2::new(0.0, 0.0),
+        Vec2::new(0.0, 0.0),
+        Vec2::new(10.0, 0.0),
+    );
+    let table = ArcLengthTable::new(&c, 256);
+    assert_approx_eq!(table.length(), 10.0, 1e-4);
+    assert_eq!(table.t_at_distance(-1.0), 0.0);
+    assert_eq!(table.t_at_distance(11.0), 1.0);
+    assert!(table.t_at_distance(f32::NAN).is_nan());
+    assert_eq!(table.distance_at_t(0.0), 0.0);
+    assert_approx_eq!(table.distance_at_t(1.0), 10.0, 1e-4);
+    for &d in &[1.0, 2.5, 5.0, 7.5, 9.0] {
+        let t = table.t_at_distance(d);
+        assert_approx_eq!(c.position(t).x(), d, 1e-2);
+        assert_approx_eq!(table.distance_at_t(t), d, 1e-4);
+    }
+
+    // quarter circle approximation
+    const K: f32 = 0.552_284_8;
+    let c = CubicSegment::from_bezier(
+        Vec2::new(1.0, 0.0),
+        Vec2::new(1.0, K),
+        Vec2::new(K, 1.0),
+        Vec2::new(0.0, 1.0),
+    );
+    let table = ArcLengthTable::new(&c, 128);
+    assert_approx_eq!(table.length(), core::f32::consts::FRAC_PI_2, 1e-3);
+}
+
+#[test]
+fn test_closest_point() {
+    let c = CubicSegment::from_bezier(
+        Vec2::new(0.0, 0.0),
+        Vec2::new(0.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 0.0),
+    );
+    // above the apex of the symmetric curve
+    let t = c.closest_t(Vec2::new(0.5, 2.0), 16);
+    assert_approx_eq!(t, 0.5, 1e-5);
+    assert_approx_eq!(
+        c.closest_point(Vec2::new(0.5, 2.0), 16),
+        Vec2::new(0.5, 0.75),
+        1e-5
+    );
+
+    // points beyond the ends clamp to the end points
+    assert_eq!(c.closest_t(Vec2::new(-1.0, -1.0), 16), 0.0);
+    assert_eq!(c.closest_t(Vec2::new(2.0, -1.0), 16), 1.0);
+
+    // a point on the curve is its own closest point
+    let p = c.position(0.3);
+    assert_approx_eq!(c.closest_point(p, 8), p, 1e-5);
+}
+
+#[test]
+fn test_quat_segment() {
+    let q0 = Quat::from_rotation_y(deg(0.0));
+    let q1 = Quat::from_rotation_y(deg(30.0));
+    let q2 = Quat::from_rotation_y(deg(60.0));
+    let q3 = Quat::from_rotation_y(deg(90.0));
+
+    let s = QuatSegment::from_catmull_rom(q0, q1, q2, q3);
+    assert_approx_eq!(s.rotation(0.0), q1, 1e-3);
+    assert_approx_eq!(s.rotation(1.0), q2, 1e-3);
+    // evenly spaced keys about a single axis interpolate evenly
+    assert_approx_eq!(s.rotation(0.5), Quat::from_rotation_y(deg(45.0)), 1e-3);
+    assert!(s.rotation(0.25).is_normalized());
+
+    // keys in the opposite hemisphere give the same rotations
+    let s2 = QuatSegment::from_catmull_rom(-q0, q1, -q2, q3);
+    let (a, b) = (s.rotation(0.5), s2.rotation(0.5));
+    assert!(a.dot(b).abs() > 0.999_99);
+
+    let b = QuatSegment::from_bezier(q0, q1, q2, q3);
+    assert_approx_eq!(b.rotation(0.0), q0, 1e-3);
+    assert_approx_eq!(b.rotation(1.0), q3, 1e-3);
+    assert_approx_eq!(b.rotation(0.5), Quat::from_rotation_y(deg(45.0)), 1e-3);
+}
@@ -0,0 +1,294 @@
+#![cfg(feature = "deterministic")]
+
+//! Golden values for the `deterministic` feature. Every platform and backend,
+//! SSE2 or `scalar-math`, must reproduce these bit for bit, so a failure here
+//! means lockstep simulations would desync.
+
+use glam::spring::{QuatSpring, Spring};
+use glam::{DualQuat, Mat3, Mat4, Quat, Radians, Rot2, Vec2, Vec3, Vec3A, Vec4};
+
+trait Bits {
+    fn bits(&self) -> Vec<u32>;
+}
+
+impl Bits for f32 {
+    fn bits(&self) -> Vec<u32> {
+        vec![self.to_bits()]
+    }
+}
+
+macro_rules! impl_bits {
+    ($($t:ty => |$v:ident| $array:expr),+) => {
+        $(
+            impl Bits for $t {
+                fn bits(&self) -> Vec<u32> {
+                    let $v = *self;
+                    $array.iter().map(|x| x.to_bits()).collect()
+                }
+            }
+        )+
+    };
+}
+
+impl_bits!(
+    Vec2 => |v| <[f32; 2]>::from(v),
+    Vec3 => |v| <[f32; 3]>::from(v),
+    Vec3A => |v| <[f32; 3]>::from(v),
+    Vec4 => |v| <[f32; 4]>::from(v),
+    Quat => |q| <[f32; 4]>::from(Vec4::from(q)),
+    Mat3 => |m| m.to_cols_array(),
+    Mat4 => |m| m.to_cols_array()
+);
+
+impl<A: Bits, B: Bits> Bits for (A, B) {
+    fn bits(&self) -> Vec<u32> {
+        let mut bits = self.0.bits();
+        bits.extend(self.1.bits());
+        bits
+    }
+}
+
+impl<A: Bits, B: Bits, C: Bits> Bits for (A, B, C) {
+    fn bits(&self) -> Vec<u32> {
+        let mut bits = (&self.0, &self.1).bits();
+        bits.extend(self.2.bits());
+        bits
+    }
+}
+
+impl<T: Bits> Bits for &T {
+    fn bits(&self) -> Vec<u32> {
+        (*self).bits()
+    }
+}
+
+#[track_caller]
+fn assert_bits(value: impl Bits, expected: &[u32]) {
+    assert_eq!(
+        value.bits(),
+        expected,
+        "expected {:?}",
+        expected
+            .iter()
+            .map(|&x| f32::from_bits(x))
+            .collect::<Vec<_>>()
+    );
+}
+
+fn quat_a() -> Quat {
+    Quat::from_rotation_ypr(0.3, -1.1, 2.7)
+}
+
+fn quat_b() -> Quat {
+    Quat::from_axis_angle(Vec3::new(0.2, 0.9, -0.4).normalize(), 1.9)
+}
+
+fn mat4_a() -> Mat4 {
+    Mat4::from_scale_rotation_translation(
+        Vec3::new(1.3, 0.7, 2.1),
+        quat_a(),
+        Vec3::new(-3.2, 5.5, 0.1),
+    )
+}
+
+#[test]
+fn test_vec_golden() {
+    let a = Vec3::new(0.3, -1.7, 2.9);
+    let b = Vec3::new(-4.1, 0.6, 1.3);
+    assert_bits(a.dot(b), &[0x3fc28f5c]);
+    assert_bits(a.length(), &[0x4057fe7c]);
+    assert_bits(a.normalize(), &[0x3db60ca8, 0xbf00f3a1, 0x3f5bf9f5]);
+    assert_bits(a.cross(b), &[0xc07cccce, 0xc1447ae2, 0xc0d947af]);
+    assert_bits(a.angle_between(b), &[0x3fbbc370]);
+
+    let (a, b) = (Vec3A::from(a), Vec3A::from(b));
+    assert_bits(a.dot(b), &[0x3fc28f5c]);
+    assert_bits(
+        (a.length(), a.length_reciprocal()),
+        &[0x4057fe7c, 0x3e97b536],
+    );
+    assert_bits(a.normalize(), &[0x3db60ca8, 0xbf00f3a1, 0x3f5bf9f5]);
+
+    let a = Vec4::new(0.3, -1.7, 2.9, 0.45);
+    let b = Vec4::new(-4.1, 0.6, 1.3, -2.2);
+    assert_bits(a.dot(b), &[0x3f07ae10]);
+    assert_bits(
+        (a.length(), a.length_reciprocal()),
+        &[0x4059e7da, 0x3e966083],
+    );
+    assert_bits(
+        a.normalize(),
+        &[0x3db473d1, 0xbeffa412, 0x3f5a0bf2, 0x3e0756dc],
+    );
+    assert_bits(
+        a.lerp(b, 0.3),
+        &[0xbf828f5c, 0xbf8147ae, 0x401ae148, 0xbeb0a3da],
+    );
+}
+
+#[test]
+fn test_mat_golden() {
+    let a = mat4_a();
+    let b = Mat4::from_rotation_x(0.7) * Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+    assert_bits(
+        a,
+        &[
+            0xbfa272c8, 0x3e810821, 0xbe00ba65, 0x00000000, 0xbdf3fa9d, 0xbe92f959, 0x3f209183,
+            0x00000000, 0x3e90208c, 0x3fef8e79, 0x3f68f640, 0x00000000, 0xc04ccccd, 0x40b00000,
+            0x3dcccccd, 0x3f800000,
+        ],
+    );
+    assert_bits(
+        a * b,
+        &[
+            0xbfa272c8, 0x3e810821, 0xbe00ba65, 0x00000000, 0x3db8caa6, 0x3f7c7278, 0x3f8871a0,
+            0x00000000, 0x3e958746, 0x3fcee4ce, 0x3e9579e9, 0x00000000, 0xc05a66cc, 0x41492c5d,
+            0x403eda16, 0x3f800000,
+        ],
+    );
+    assert_bits(
+        a * Vec4::new(0.3, -1.7, 2.9, 1.0),
+        &[0xc023f5b4, 0x4137db5f, 0x3fd148cb, 0x3f800000],
+    );
+    assert_bits(
+        a.transform_point3(Vec3::new(0.3, -1.7, 2.9)),
+        &[0xc023f5b4, 0x4137db5f, 0x3fd148cb],
+    );
+    assert_bits(
+        a.inverse(),
+        &[
+            0xbf403f1d, 0xbe78f543, 0x3d82ba3f, 0x80000000, 0x3e18b345, 0xbf15f933, 0x3ed948eb,
+            0x80000000, 0xbd985746, 0x3fa3d866, 0x3e534daa, 0x80000000, 0xc04dcfd8, 0x40143ac7,
+            0xc009a197, 0x3f800000,
+        ],
+    );
+    assert_bits(a.determinant(), &[0x3ff49ba8]);
+    assert_bits(
+        a.to_scale_rotation_translation(),
+        &[
+            0x3fa66667, 0x3f333334, 0x40066666, 0x3c3631c3, 0x3f083c79, 0x3f56efa3, 0x3dde0032,
+            0xc04ccccd, 0x40b00000, 0x3dcccccd,
+        ],
+    );
+
+    let m = Mat3::from_quat(quat_b());
+    assert_bits(
+        m * Mat3::from_scale(Vec3::new(2.5, 0.3, 1.2)),
+        &[
+            0xbf2d5d4a, 0xbeb43be3, 0xc0185c80, 0x3e3c2706, 0x3e62b3bc, 0xbdae1698, 0x3f6422ac,
+            0xbf4abf99, 0xbe0baa27,
+        ],
+    );
+    assert_bits(
+        m.inverse(),
+        &[
+            0xbe8ab10a, 0x3f1ccb2f, 0x3f3e1ce6, 0xbe102feb, 0x3f3ceb19, 0xbf28f4fe, 0xbf73c738,
+            0xbe9112d3, 0xbde8c643,
+        ],
+    );
+
+    assert_bits(
+        Mat4::perspective_rh_gl(1.2, 16.0 / 9.0, 0.1, 100.0),
+        &[
+            0x3f527bf4, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x3fbb18d9, 0x00000000,
+            0x00000000, 0x00000000, 0x00000000, 0xbf80419a, 0xbf800000, 0x00000000, 0x00000000,
+            0xbe4d0148, 0x00000000,
+        ],
+    );
+    assert_bits(
+        Mat4::perspective_infinite_rh(1.2, 16.0 / 9.0, 0.1),
+        &[
+            0x3f527bf4, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x3fbb18d9, 0x00000000,
+            0x00000000, 0x00000000, 0x00000000, 0xbf800000, 0xbf800000, 0x00000000, 0x00000000,
+            0xbdcccccd, 0x00000000,
+        ],
+    );
+}
+
+#[test]
+fn test_quat_golden() {
+    let (a, b) = (quat_a(), quat_b());
+    assert_bits(a, &[0x3c3631c0, 0x3f083c7a, 0x3f56efa3, 0x3dde0034]);
+    assert_bits(b, &[0x3e25c2c1, 0x3f3a7b18, 0xbea5c2c1, 0x3f14e92f]);
+    assert_bits(a * b, &[0xbf4286e3, 0x3f072cda, 0x3ec01f31, 0xbd5f9249]);
+    assert_bits(
+        a * Vec3::new(0.3, -1.7, 2.9),
+        &[0x3ec535e2, 0x4055bf4d, 0xbe9756a0],
+    );
+    assert_bits(
+        a.lerp(b, 0.3),
+        &[0x3d8e7d73, 0x3f3ad4f7, 0x3f1b135b, 0x3e9e4af6],
+    );
+    assert_bits(
+        a.slerp(b, 0.3),
+        &[0x3d9b91a3, 0x3f3f3112, 0x3f1272d1, 0x3ea930f9],
+    );
+    assert_bits(
+        a.slerp(-b, 0.8),
+        &[0xbe2229f1, 0xbf0b5855, 0x3f1e1b2c, 0xbf0b9021],
+    );
+    assert_bits(
+        a.to_axis_angle(),
+        &[0x3c374637, 0x3f090b34, 0x3f5835c9, 0x403b28d8],
+    );
+    assert_bits(
+        Quat::from_xyzw(0.3, -1.7, 2.9, 0.45).normalize(),
+        &[0x3db473d1, 0xbeffa412, 0x3f5a0bf2, 0x3e0756dc],
+    );
+    assert_bits(
+        Quat::from_rotation_mat4(&mat4_a()),
+        &[0x3d2b5ecb, 0x3f2bcb33, 0x3f6e4e89, 0x3dcc2297],
+    );
+}
+
+#[test]
+fn test_trig_golden() {
+    // sin, cos and tan, including arguments which need exact range reduction
+    let cases: [(f32, [u32; 3]); 7] = [
+        (0.0, [0x00000000, 0x3f800000, 0x00000000]),
+        (0.001, [0x3a83126e, 0x3f7ffff8, 0x3a831272]),
+        (0.7, [0x3f24eb73, 0x3f43ccb3, 0x3f57a036]),
+        (-2.9, [0xbe74fdc0, 0xbf7890b7, 0x3e7c51ab]),
+        (100.0, [0xbf01a12e, 0x3f5cc0ee, 0xbf1653a7]),
+        (1.0e6, [0xbeb33259, 0x3f6fcefd, 0xbebf4bb5]),
+        (-1.0e20, [0xbf281569, 0x3f411723, 0xbf5ed890]),
+    ];
+    for &(angle, expected) in &cases {
+        let angle = Radians(angle);
+        assert_bits((angle.sin(), angle.cos(), angle.tan()), &expected);
+    }
+
+    assert_bits(Rot2::from_angle(2.3).angle(), &[0x40133333]);
+    assert_bits(Rot2::from_angle(-0.4).angle(), &[0xbecccccd]);
+
+    let dq = DualQuat::from_rotation_translation(quat_a(), Vec3::new(1.0, -2.0, 0.5));
+    let end = DualQuat::from_rotation_translation(quat_b(), Vec3::new(-3.0, 0.0, 4.0));
+    assert_bits(
+        dq.sclerp(end, 0.4).to_rotation_translation(),
+        &[
+            0x3dc2f128, 0x3f4a8d3c, 0x3eeaa500, 0x3ec97fdf, 0xbf53158c, 0xc05612f6, 0x4020d992,
+        ],
+    );
+}
+
+#[test]
+fn test_spring_golden() {
+    let mut spring = Spring::new(Vec3::new(0.3, -1.7, 2.9), Vec3::zero());
+    let mut quat_spring = QuatSpring::new(quat_a(), Vec3::zero());
+    for _ in 0..60 {
+        spring.update(Vec3::new(-4.1, 0.6, 1.3), 0.25, 1.0 / 60.0);
+        quat_spring.update(quat_b(), 0.25, 1.0 / 60.0);
+    }
+    assert_bits(
+        (spring.position, spring.velocity),
+        &[
+            0xc082c660, 0x3f17d284, 0x3fa704b0, 0xbdc1770e, 0x3d4a42cf, 0xbd0cb408,
+        ],
+    );
+    assert_bits(
+        (quat_spring.rotation, quat_spring.angular_velocity),
+        &[
+            0x3e25ae1e, 0x3f3aea9e, 0xbea3cc6d, 0x3f14e9c5, 0xbd3ff7c1, 0xbbb503c5, 0xbd156b48,
+        ],
+    );
+}
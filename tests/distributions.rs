@@ -0,0 +1,183 @@
+#![cfg(feature = "rand")]
+
+use glam::*;
+use rand::distributions::{Distribution, Uniform};
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro256Plus;
+
+const SAMPLES: usize = 20_000;
+
+fn samples<T, D: Distribution<T>>(dist: D) -> Vec<T> {
+    let rng = Xoshiro256Plus::seed_from_u64(0);
+    dist.sample_iter(rng).take(SAMPLES).collect()
+}
+
+fn mean3(v: &[Vec3]) -> Vec3 {
+    v.iter().fold(Vec3::zero(), |sum, &p| sum + p) / v.len() as f32
+}
+
+fn mean2(v: &[Vec2]) -> Vec2 {
+    v.iter().fold(Vec2::zero(), |sum, &p| sum + p) / v.len() as f32
+}
+
+fn fraction<T>(v: &[T], f: impl Fn(&T) -> bool) -> f32 {
+    v.iter().filter(|p| f(p)).count() as f32 / v.len() as f32
+}
+
+#[test]
+fn test_unit_sphere() {
+    let v = samples(UnitSphere);
+    assert!(v.iter().all(|p| p.is_normalized()));
+    assert!(mean3(&v).abs_diff_eq(Vec3::zero(), 0.02));
+    // each octant should contain an eighth of the samples
+    for octant in 0..8 {
+        let sign = Vec3::new(
+            if octant & 1 == 0 { 1.0 } else { -1.0 },
+            if octant & 2 == 0 { 1.0 } else { -1.0 },
+            if octant & 4 == 0 { 1.0 } else { -1.0 },
+        );
+        let f = fraction(&v, |p| (*p * sign).cmpge(Vec3::zero()).all());
+        assert!((f - 0.125).abs() < 0.01, "octant {} {}", octant, f);
+    }
+    // the second moment of each component is 1/3
+    let second = v.iter().fold(Vec3::zero(), |sum, &p| sum + p * p) / SAMPLES as f32;
+    assert!(second.abs_diff_eq(Vec3::splat(1.0 / 3.0), 0.01));
+}
+
+#[test]
+fn test_unit_ball() {
+    let v = samples(UnitBall);
+    assert!(v.iter().all(|p| p.length() <= 1.0));
+    assert!(mean3(&v).abs_diff_eq(Vec3::zero(), 0.02));
+    // volume within radius r is r^3
+    let f = fraction(&v, |p| p.length() < 0.5);
+    assert!((f - 0.125).abs() < 0.01, "{}", f);
+}
+
+#[test]
+fn test_unit_circle() {
+    let v = samples(UnitCircle);
+    assert!(v.iter().all(|p| p.is_normalized()));
+    assert!(mean2(&v).abs_diff_eq(Vec2::zero(), 0.02));
+    for quadrant in 0..4 {
+        let sign = Vec2::new(
+            if quadrant & 1 == 0 { 1.0 } else { -1.0 },
+            if quadrant & 2 == 0 { 1.0 } else { -1.0 },
+        );
+        let f = fraction(&v, |p| (*p * sign).cmpge(Vec2::zero()).all());
+        assert!((f - 0.25).abs() < 0.01, "quadrant {} {}", quadrant, f);
+    }
+}
+
+#[test]
+fn test_unit_disk() {
+    let v = samples(UnitDisk);
+    assert!(v.iter().all(|p| p.length() <= 1.0));
+    assert!(mean2(&v).abs_diff_eq(Vec2::zero(), 0.02));
+    // area within radius r is r^2
+    let f = fraction(&v, |p| p.length() < 0.5);
+    assert!((f - 0.25).abs() < 0.01, "{}", f);
+}
+
+#[test]
+fn test_hemisphere() {
+    let normal = Vec3::new(1.0, 2.0, -3.0).normalize();
+    let v = samples(Hemisphere::new(normal));
+    assert!(v.iter().all(|p| p.is_normalized() && p.dot(normal) >= 0.0));
+    // the mean cosine of a uniform hemisphere is 1/2
+    let mean_cos = v.iter().map(|p| p.dot(normal)).sum::<f32>() / SAMPLES as f32;
+    assert!((mean_cos - 0.5).abs() < 0.01, "{}", mean_cos);
+}
+
+#[test]
+fn test_cosine_hemisphere() {
+    let normal = Vec3::new(-1.0, 0.5, 0.25).normalize();
+    let v = samples(CosineHemisphere::new(normal));
+    assert!(v
+        .iter()
+        .all(|p| p.is_normalized() && p.dot(normal) >= -1.0e-6));
+    // the mean cosine of a cosine weighted hemisphere is 2/3
+    let mean_cos = v.iter().map(|p| p.dot(normal)).sum::<f32>() / SAMPLES as f32;
+    assert!((mean_cos - 2.0 / 3.0).abs() < 0.01, "{}", mean_cos);
+    // half of the samples are within 45 degrees of the normal
+    let f = fraction(&v, |p| p.dot(normal) > core::f32::consts::FRAC_1_SQRT_2);
+    assert!((f - 0.5).abs() < 0.01, "{}", f);
+}
+
+#[test]
+fn test_uniform_rotation() {
+    let v: Vec<Quat> = samples(UniformRotation);
+    assert!(v.iter().all(|q| q.is_normalized()));
+    // each component squared has a mean of 1/4
+    let second = v
+        .iter()
+        .fold(Vec4::zero(), |sum, &q| sum + Vec4::from(q) * Vec4::from(q))
+        / SAMPLES as f32;
+    assert!(second.abs_diff_eq(Vec4::splat(0.25), 0.01));
+    // rotating any vector produces a uniform distribution on the sphere
+    let rotated: Vec<Vec3> = v.iter().map(|&q| q * Vec3::unit_x()).collect();
+    assert!(mean3(&rotated).abs_diff_eq(Vec3::zero(), 0.02));
+    let f = fraction(&rotated, |p| p.z() > 0.5);
+    assert!((f - 0.25).abs() < 0.01, "{}", f);
+
+    // the Standard distribution is also uniform
+    let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+    let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+    assert_eq!(rng1.gen::<Quat>(), rng2.sample(UniformRotation));
+
+    let v: Vec<Rot2> = samples(UniformRotation);
+    assert!(v.iter().all(|r| r.is_normalized()));
+    let mean_angle = v.iter().map(|r| r.angle()).sum::<f32>() / SAMPLES as f32;
+    assert!(mean_angle.abs() < 0.05, "{}", mean_angle);
+}
+
+#[test]
+fn test_uniform_aabb() {
+    let min = Vec3::new(-1.0, 2.0, 10.0);
+    let max = Vec3::new(3.0, 2.5, 20.0);
+    let v = samples(UniformAabb::new(min, max));
+    assert!(v.iter().all(|p| p.cmpge(min).all() && p.cmplt(max).all()));
+    assert!(mean3(&v).abs_diff_eq((min + max) * 0.5, 0.1));
+    let f = fraction(&v, |p| p.x() < 0.0);
+    assert!((f - 0.25).abs() < 0.01, "{}", f);
+}
+
+#[test]
+fn test_uniform_triangle() {
+    let (a, b, c) = (
+        Vec3::new(0.0, 0.0, 1.0),
+        Vec3::new(3.0, 0.0, 1.0),
+        Vec3::new(0.0, 3.0, 1.0),
+    );
+    let v = samples(UniformTriangle::new(a, b, c));
+    assert!(v
+        .iter()
+        .all(|p| p.x() >= 0.0 && p.y() >= 0.0 && p.x() + p.y() <= 3.0 + 1.0e-5 && p.z() == 1.0));
+    assert!(mean3(&v).abs_diff_eq((a + b + c) / 3.0, 0.02));
+    // the triangle with half the edge lengths at vertex a has a quarter of the area
+    let f = fraction(&v, |p| p.x() + p.y() < 1.5);
+    assert!((f - 0.25).abs() < 0.01, "{}", f);
+}
+
+#[test]
+fn test_uniform_vec() {
+    let low = Vec3::new(-1.0, 0.0, 5.0);
+    let high = Vec3::new(1.0, 10.0, 6.0);
+    let v = samples(Uniform::new(low, high));
+    assert!(v.iter().all(|p| p.cmpge(low).all() && p.cmplt(high).all()));
+    assert!(mean3(&v).abs_diff_eq((low + high) * 0.5, 0.1));
+
+    let v = samples(Uniform::new_inclusive(Vec2::zero(), Vec2::one()));
+    assert!(v
+        .iter()
+        .all(|p| p.cmpge(Vec2::zero()).all() && p.cmple(Vec2::one()).all()));
+    assert!(mean2(&v).abs_diff_eq(Vec2::splat(0.5), 0.01));
+
+    let mut rng = Xoshiro256Plus::seed_from_u64(0);
+    let low = Vec4::splat(-2.0);
+    let high = Vec4::splat(2.0);
+    for _ in 0..100 {
+        let p = rng.gen_range(low, high);
+        assert!(p.cmpge(low).all() && p.cmplt(high).all());
+    }
+}
@@ -0,0 +1,187 @@
+mod support;
+
+use glam::f32::{DualQuat, Mat4, Quat, Vec3};
+use support::deg;
+
+#[test]
+fn test_dual_quat_align() {
+    use std::mem;
+    assert_eq!(32, mem::size_of::<DualQuat>());
+    if cfg!(feature = "scalar-math") {
+        assert_eq!(4, mem::align_of::<DualQuat>());
+    } else {
+        assert_eq!(16, mem::align_of::<DualQuat>());
+    }
+}
+
+#[test]
+fn test_dual_quat_identity() {
+    let dq = DualQuat::identity();
+    assert_eq!(dq, DualQuat::default());
+    assert!(dq.is_normalized());
+    assert_eq!(dq.rotation(), Quat::identity());
+    assert_eq!(dq.translation(), Vec3::zero());
+    assert_eq!(Mat4::from(dq), Mat4::identity());
+    let p = Vec3::new(1.0, 2.0, 3.0);
+    assert_eq!(dq.transform_point3(p), p);
+}
+
+#[test]
+fn test_dual_quat_rotation_translation() {
+    let r = Quat::from_rotation_ypr(deg(30.0), deg(60.0), deg(90.0));
+    let t = Vec3::new(1.0, -2.0, 3.0);
+    let dq = DualQuat::from_rotation_translation(r, t);
+    assert!(dq.is_normalized());
+    assert_approx_eq!(dq.rotation(), r);
+    assert_approx_eq!(dq.translation(), t, 1e-6);
+    let (r1, t1) = dq.to_rotation_translation();
+    assert_approx_eq!(r1, r);
+    assert_approx_eq!(t1, t, 1e-6);
+
+    let m = Mat4::from_rotation_translation(r, t);
+    assert_approx_eq!(Mat4::from(dq), m, 1e-6);
+    assert_approx_eq!(DualQuat::from_mat4(&m), dq, 1e-6);
+
+    let p = Vec3::new(-4.0, 5.0, 0.5);
+    assert_approx_eq!(dq.transform_point3(p), m.transform_point3(p), 1e-5);
+    assert_approx_eq!(dq.transform_vector3(p), m.transform_vector3(p), 1e-5);
+
+    assert_approx_eq!(
+        DualQuat::from_translation(t) * DualQuat::from_quat(r),
+        dq,
+        1e-6
+    );
+}
+
+#[test]
+fn test_dual_quat_mul() {
+    let a = DualQuat::from_rotation_translation(Quat::from_rotation_y(deg(90.0)), Vec3::unit_x());
+    let b = DualQuat::from_rotation_translation(
+        Quat::from_rotation_x(deg(45.0)),
+        Vec3::new(0.0, 2.0, -1.0),
+    );
+    let ab = a * b;
+    let p = Vec3::new(1.0, 2.0, 3.0);
+    assert_approx_eq!(
+        ab.transform_point3(p),
+        a.transform_point3(b.transform_point3(p)),
+        1e-5
+    );
+    assert_approx_eq!(Mat4::from(ab), Mat4::from(a) * Mat4::from(b), 1e-5);
+
+    let mut c = a;
+    c *= b;
+    assert_eq!(c, ab);
+
+    assert_approx_eq!(a * a.inverse(), DualQuat::identity(), 1e-6);
+    assert_approx_eq!(a.inverse().transform_point3(a.transform_point3(p)), p, 1e-5);
+    assert_eq!(a.conjugate(), a.inverse());
+}
+
+#[test]
+fn test_dual_quat_normalize() {
+    let dq = DualQuat::from_rotation_translation(Quat::from_rotation_z(deg(30.0)), Vec3::one());
+    let scaled = DualQuat::from_real_dual(
+        (glam::Vec4::from(dq.real()) * 3.0).into(),
+        (glam::Vec4::from(dq.dual()) * 3.0 + glam::Vec4::new(0.1, 0.0, 0.0, 0.0)).into(),
+    );
+    assert!(!scaled.is_normalized());
+    let n = scaled.normalize();
+    assert!(n.is_normalized());
+    assert_approx_eq!(n.rotation(), dq.rotation());
+}
+
+#[test]
+fn test_dual_quat_sclerp() {
+    let a = DualQuat::from_rotation_translation(Quat::identity(), Vec3::zero());
+    let b = DualQuat::from_rotation_translation(
+        Quat::from_rotation_z(deg(90.0)),
+        Vec3::new(2.0, 0.0, 4.0),
+    );
+    assert_approx_eq!(a.sclerp(b, 0.0), a, 1e-6);
+    assert_approx_eq!(a.sclerp(b, 1.0), b, 1e-5);
+
+    // screw motion: rotation and translation along the axis are interpolated at
+    // a constant rate
+    let mid = a.sclerp(b, 0.5);
+    assert!(mid.is_normalized());
+    assert_approx_eq!(mid.rotation(), Quat::from_rotation_z(deg(45.0)), 1e-6);
+    assert_approx_eq!(mid.translation().z(), 2.0, 1e-5);
+
+    // pure translation
+    let c = DualQuat::from_translation(Vec3::new(2.0, 4.0, -6.0));
+    assert_approx_eq!(
+        a.sclerp(c, 0.25).translation(),
+        Vec3::new(0.5, 1.0, -1.5),
+        1e-6
+    );
+
+    // takes the shortest path when real parts are in opposite hemispheres
+    let neg_b = DualQuat::from_real_dual(-b.real(), -b.dual());
+    assert_approx_eq!(Mat4::from(a.sclerp(neg_b, 0.5)), Mat4::from(mid), 1e-5);
+}
+
+#[test]
+fn test_dual_quat_blend() {
+    let a = DualQuat::from_rotation_translation(Quat::from_rotation_x(deg(10.0)), Vec3::unit_y());
+    let b = DualQuat::from_rotation_translation(Quat::from_rotation_x(deg(70.0)), Vec3::unit_z());
+
+    assert_approx_eq!(DualQuat::blend(&[a], &[1.0]), a, 1e-6);
+    assert_approx_eq!(DualQuat::blend(&[a, b], &[1.0, 0.0]), a, 1e-6);
+    assert_approx_eq!(DualQuat::blend(&[a, b], &[0.0, 1.0]), b, 1e-6);
+
+    let blended = DualQuat::blend(&[a, b], &[0.5, 0.5]);
+    assert!(blended.is_normalized());
+    assert_approx_eq!(blended.rotation(), Quat::from_rotation_x(deg(40.0)), 1e-6);
+
+    // antipodal representation of `b` gives the same result
+    let neg_b = DualQuat::from_real_dual(-b.real(), -b.dual());
+    assert_approx_eq!(DualQuat::blend(&[a, neg_b], &[0.5, 0.5]), blended, 1e-6);
+}
+
+#[test]
+#[should_panic]
+fn test_dual_quat_blend_mismatched() {
+    DualQuat::blend(&[DualQuat::identity()], &[0.5, 0.5]);
+}
+
+#[cfg(feature = "transform-types")]
+#[test]
+fn test_dual_quat_transform_rt() {
+    use glam::TransformRT;
+    let rt = TransformRT::from_rotation_translation(
+        Quat::from_rotation_y(deg(30.0)),
+        Vec3::new(1.0, 2.0, 3.0),
+    );
+    let dq = DualQuat::from(rt);
+    assert_approx_eq!(dq.transform_point3(Vec3::one()), rt * Vec3::one(), 1e-5);
+    assert_approx_eq!(TransformRT::from(dq), rt, 1e-6);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_dual_quat_rand() {
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256Plus;
+    let mut rng = Xoshiro256Plus::seed_from_u64(0);
+    for _ in 0..100 {
+        let dq: DualQuat = rng.gen();
+        assert!(dq.is_normalized());
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_dual_quat_serde() {
+    let a = DualQuat::from_translation(Vec3::new(2.0, 4.0, 6.0));
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[[0.0,0.0,0.0,1.0],[1.0,2.0,3.0,0.0]]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<DualQuat>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DualQuat>("[[0.0,0.0,0.0,1.0]]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<DualQuat>("[0.0,0.0,0.0,1.0,0.0,0.0,0.0,0.0]");
+    assert!(deserialized.is_err());
+}
@@ -0,0 +1,446 @@
+// The same tests run for each precision, `$tol` is the tolerance when
+// comparing against the `f32` types.
+macro_rules! fixed_tests {
+    ($m:ident, $tol:expr) => {
+        mod $m {
+            use glam::fixed::$m::{FMat3, FQuat, FVec2, FVec3, Fixed};
+            use glam::{Mat3, Quat, Vec2, Vec3};
+
+            fn fixed(v: f64) -> Fixed {
+                Fixed::from_f64(v)
+            }
+
+            fn int(v: i32) -> Fixed {
+                Fixed::from_int(v.into())
+            }
+
+            #[test]
+            fn test_conversions() {
+                assert_eq!(Fixed::from_f32(1.5), Fixed::ONE + Fixed::HALF);
+                assert_eq!(Fixed::from_f32(-0.25).to_f32(), -0.25);
+                assert_eq!(Fixed::from_f64(2.5).to_f64(), 2.5);
+                assert_eq!(Fixed::from_f32(f32::NAN), Fixed::ZERO);
+                assert_eq!(Fixed::from_f32(f32::INFINITY), Fixed::MAX);
+                assert_eq!(Fixed::from_f32(-1e30), Fixed::MIN);
+                // rounds to the nearest value
+                let half_ulp = 0.5 / Fixed::ONE.to_bits() as f64;
+                assert_eq!(fixed(1.0 + half_ulp * 0.9), Fixed::ONE);
+                assert_eq!(fixed(1.0 + half_ulp * 1.1), Fixed::ONE + Fixed::EPSILON);
+
+                assert_eq!(int(3).to_bits(), 3 << Fixed::FRAC_BITS);
+                assert_eq!(int(-3).to_int(), -3);
+                assert_eq!(fixed(-2.5).to_int(), -3);
+                assert_eq!(fixed(2.5).to_int(), 2);
+                assert_eq!(Fixed::from_bits(5).to_bits(), 5);
+
+                assert_eq!(format!("{}", fixed(-1.5)), "-1.5");
+                assert_eq!(
+                    format!("{:?}", FVec2::new(int(1), fixed(0.5))),
+                    "FVec2(1.0, 0.5)"
+                );
+                assert_eq!(format!("{}", FVec3::unit_y()), "[0, 1, 0]");
+                assert_eq!(format!("{}", FQuat::identity()), "[0, 0, 0, 1]");
+                assert_eq!(
+                    format!("{}", FMat3::identity()),
+                    "[[1, 0, 0], [0, 1, 0], [0, 0, 1]]"
+                );
+
+                let v = Vec3::new(1.0, -2.5, 0.125);
+                assert_eq!(Vec3::from(FVec3::from_vec3(v)), v);
+                let v = Vec2::new(-7.0, 0.75);
+                assert_eq!(Vec2::from(FVec2::from_vec2(v)), v);
+                let q = Quat::from_xyzw(0.5, -0.5, 0.5, 0.5);
+                assert_eq!(Quat::from(FQuat::from_quat(q)), q);
+                let m = Mat3::from_scale(Vec3::new(1.0, 2.0, 4.0));
+                assert_eq!(Mat3::from(FMat3::from_mat3(m)), m);
+
+                let a: [Fixed; 3] = FVec3::new(int(1), int(2), int(3)).into();
+                assert_eq!(FVec3::from(a), FVec3::new(int(1), int(2), int(3)));
+                let t: (Fixed, Fixed) = FVec2::one().into();
+                assert_eq!(FVec2::from(t), FVec2::one());
+            }
+
+            #[test]
+            fn test_arithmetic() {
+                assert_eq!(int(3) + fixed(0.5), fixed(3.5));
+                assert_eq!(int(3) - fixed(0.5), fixed(2.5));
+                assert_eq!(int(3) * fixed(-0.5), fixed(-1.5));
+                assert_eq!(int(3) / int(4), fixed(0.75));
+                assert_eq!(-int(3), int(-3));
+                assert_eq!(int(-3).abs(), int(3));
+                assert_eq!(int(4).recip(), fixed(0.25));
+                assert_eq!(int(2).min(int(3)), int(2));
+
+                // products and quotients round to nearest
+                let e = Fixed::EPSILON;
+                assert_eq!(e * Fixed::HALF, e);
+                assert_eq!((e + e) * fixed(0.25), e);
+                assert_eq!(-e * Fixed::HALF, Fixed::ZERO);
+                assert_eq!(
+                    Fixed::ONE / int(3),
+                    Fixed::from_bits(Fixed::ONE.to_bits() / 3)
+                );
+                assert_eq!(
+                    int(2) / int(3),
+                    Fixed::from_bits(Fixed::ONE.to_bits() * 2 / 3 + 1)
+                );
+                assert_eq!(int(-2) / int(3), -(int(2) / int(3)));
+
+                let mut a = int(1);
+                a += int(2);
+                a *= int(3);
+                a -= int(1);
+                a /= int(4);
+                assert_eq!(a, int(2));
+
+                assert_eq!(int(2).checked_add(int(3)), Some(int(5)));
+                assert_eq!(Fixed::MAX.checked_add(e), None);
+                assert_eq!(Fixed::MIN.checked_sub(e), None);
+                assert_eq!(int(2).checked_mul(int(3)), Some(int(6)));
+                assert_eq!(Fixed::MAX.checked_mul(int(2)), None);
+                assert_eq!(int(3).checked_div(int(2)), Some(fixed(1.5)));
+                assert_eq!(int(3).checked_div(Fixed::ZERO), None);
+                assert_eq!(Fixed::MAX.checked_div(Fixed::HALF), None);
+            }
+
+            #[test]
+            fn test_sqrt() {
+                assert_eq!(Fixed::ZERO.sqrt(), Fixed::ZERO);
+                assert_eq!(int(4).sqrt(), int(2));
+                assert_eq!(fixed(0.25).sqrt(), Fixed::HALF);
+                let mut v = 0.001;
+                while v < 10_000.0 {
+                    let sqrt = fixed(v).sqrt().to_f64();
+                    let exact = fixed(v).to_f64().sqrt();
+                    assert!(
+                        (sqrt - exact).abs() <= Fixed::EPSILON.to_f64() / 2.0,
+                        "{}",
+                        v
+                    );
+                    v *= 1.37;
+                }
+                assert!(Fixed::MAX.sqrt() > int(1));
+            }
+
+            #[test]
+            fn test_trig() {
+                let tolerance = Fixed::EPSILON.to_f64();
+                let mut angle = -20.0;
+                while angle < 20.0 {
+                    let a = fixed(angle);
+                    let exact = a.to_f64();
+                    let (sin, cos) = a.sin_cos();
+                    assert!(
+                        (sin.to_f64() - exact.sin()).abs() <= tolerance,
+                        "sin({})",
+                        angle
+                    );
+                    assert!(
+                        (cos.to_f64() - exact.cos()).abs() <= tolerance,
+                        "cos({})",
+                        angle
+                    );
+                    assert_eq!(a.sin(), sin);
+                    assert_eq!(a.cos(), cos);
+
+                    let (y, x) = (fixed(angle * 0.3), fixed(angle.cos() * 2.0));
+                    let atan2 = y.atan2(x).to_f64();
+                    assert!((atan2 - y.to_f64().atan2(x.to_f64())).abs() <= tolerance);
+                    angle += 0.0371;
+                }
+                assert_eq!(Fixed::ZERO.sin_cos(), (Fixed::ZERO, Fixed::ONE));
+                assert_eq!(Fixed::FRAC_PI_2.sin(), Fixed::ONE);
+                assert_eq!(Fixed::PI.cos(), -Fixed::ONE);
+                assert_eq!(Fixed::ONE.atan2(Fixed::ZERO), Fixed::FRAC_PI_2);
+                assert_eq!(Fixed::ZERO.atan2(-Fixed::ONE), Fixed::PI);
+                assert_eq!(Fixed::ONE.atan2(Fixed::ONE), Fixed::FRAC_PI_4);
+                assert_eq!(Fixed::ZERO.atan2(Fixed::ZERO), Fixed::ZERO);
+            }
+
+            #[test]
+            fn test_vec() {
+                let a = FVec3::new(int(1), int(2), int(3));
+                let b = FVec3::new(int(4), int(-5), fixed(0.5));
+                assert_eq!(a.dot(b), fixed(-4.5));
+                assert_eq!(a.cross(b), FVec3::new(fixed(16.0), fixed(11.5), int(-13)));
+                assert_eq!(a.cross(b).dot(a), Fixed::ZERO);
+                assert_eq!(FVec3::new(int(2), int(3), int(6)).length(), int(7));
+                assert_eq!(a.length_squared(), int(14));
+                assert_eq!(FVec2::new(int(3), int(-4)).length(), int(5));
+                assert_eq!(FVec2::unit_x().perp_dot(FVec2::unit_y()), Fixed::ONE);
+                assert_eq!(a.min(b), FVec3::new(int(1), int(-5), fixed(0.5)));
+                assert_eq!(a.max(b), FVec3::new(int(4), int(2), int(3)));
+                assert_eq!(b.min_element(), int(-5));
+                assert_eq!(b.max_element(), int(4));
+                assert_eq!(b.abs(), FVec3::new(int(4), int(5), fixed(0.5)));
+                assert_eq!(a.lerp(b, Fixed::HALF), (a + b) * Fixed::HALF);
+                assert_eq!(a * b, FVec3::new(int(4), int(-10), fixed(1.5)));
+                assert_eq!(a / int(2), FVec3::new(fixed(0.5), int(1), fixed(1.5)));
+                assert_eq!(int(2) * a, a + a);
+                assert_eq!(-a, a * int(-1));
+                assert_eq!(a - a, FVec3::zero());
+                assert_eq!(a.truncate().extend(int(3)), a);
+                assert_eq!(a.x() + a.y() + a.z(), int(6));
+
+                let mut c = a;
+                c.set_x(int(-1));
+                c += a;
+                c *= int(2);
+                assert_eq!(c, FVec3::new(int(0), int(8), int(12)));
+
+                for v in [a, b, -b, FVec3::new(fixed(0.01), int(0), int(0))].iter() {
+                    let n = v.normalize();
+                    assert!(n.is_normalized());
+                    let expected = Vec3::from(*v).normalize();
+                    assert!(Vec3::from(n).abs_diff_eq(expected, $tol));
+                }
+                assert!(!a.is_normalized());
+                assert!(FVec2::new(int(3), int(4)).normalize().is_normalized());
+                assert!(a.abs_diff_eq(a + FVec3::splat(Fixed::EPSILON), Fixed::EPSILON));
+                assert!(!a.abs_diff_eq(a + FVec3::splat(Fixed::EPSILON), Fixed::ZERO));
+            }
+
+            #[test]
+            fn test_quat() {
+                let axis = FVec3::new(int(1), int(2), int(-2)).normalize();
+                for &angle in &[0.0, 0.5, -1.25, 3.0, 6.0] {
+                    let q = FQuat::from_axis_angle(axis, fixed(angle));
+                    assert!(q.is_normalized());
+                    let expected =
+                        Quat::from_axis_angle(Vec3::from(axis).normalize(), angle as f32);
+                    assert!(Quat::from(q).abs_diff_eq(expected, $tol));
+
+                    let v = FVec3::new(int(3), int(-1), fixed(0.5));
+                    let rotated = Vec3::from(q * v);
+                    assert!(rotated.abs_diff_eq(expected * Vec3::from(v), $tol * 4.0));
+
+                    let m = FMat3::from_quat(q);
+                    assert!(Vec3::from(m * v).abs_diff_eq(rotated, $tol * 4.0));
+                    assert!(m.abs_diff_eq(
+                        FMat3::from_axis_angle(axis, fixed(angle)),
+                        Fixed::from_bits(8)
+                    ));
+
+                    let (to_axis, to_angle) = q.to_axis_angle();
+                    assert!((to_angle.to_f64() - angle.abs()).abs() < $tol as f64);
+                    if angle != 0.0 {
+                        let sign = if angle < 0.0 { -1.0 } else { 1.0 };
+                        assert!(Vec3::from(to_axis).abs_diff_eq(Vec3::from(axis) * sign, $tol));
+                    }
+                }
+                assert_eq!(
+                    FQuat::identity().to_axis_angle(),
+                    (FVec3::unit_x(), Fixed::ZERO)
+                );
+
+                let a = FQuat::from_rotation_x(fixed(0.5));
+                let b = FQuat::from_rotation_y(fixed(-1.5));
+                let expected = Quat::from_rotation_x(0.5) * Quat::from_rotation_y(-1.5);
+                assert!(Quat::from(a * b).abs_diff_eq(expected, $tol));
+                assert!((a * a.conjugate()).abs_diff_eq(FQuat::identity(), Fixed::from_bits(2)));
+                assert_eq!(
+                    FQuat::from_rotation_z(fixed(0.5)),
+                    FQuat::from_axis_angle(FVec3::unit_z(), fixed(0.5))
+                );
+                assert_eq!(FQuat::default(), FQuat::identity());
+                assert_eq!(a.length_squared(), a.dot(a));
+                assert!((a.length() - Fixed::ONE).abs() <= Fixed::from_bits(1));
+                let mut c = a;
+                c *= b;
+                assert_eq!(c, a.mul_quat(b));
+                assert_eq!((-a).w(), -a.w());
+
+                let unnormalized = FQuat::from_xyzw(int(1), int(2), int(2), int(4));
+                assert!(!unnormalized.is_normalized());
+                assert!(unnormalized.normalize().is_normalized());
+                assert_eq!(unnormalized.length(), int(5));
+
+                // the slerp midpoint is the normalized sum
+                let (qa, qb) = (Quat::from(a).normalize(), Quat::from(b).normalize());
+                for &(s, expected) in &[(0.0, qa), (0.5, qa.lerp(qb, 0.5)), (1.0, qb)] {
+                    let slerp = a.slerp(b, fixed(s));
+                    assert!(Quat::from(slerp).abs_diff_eq(expected, $tol * 4.0), "{}", s);
+                }
+                for &s in &[0.0, 0.25, 0.5, 1.0] {
+                    let expected = Quat::from_rotation_x(0.5).lerp(Quat::from_rotation_x(0.6), s);
+                    let lerp = a.lerp(FQuat::from_rotation_x(fixed(0.6)), fixed(s as f64));
+                    assert!(Quat::from(lerp).abs_diff_eq(expected, $tol));
+                    // close rotations fall back to lerp
+                    let close = FQuat::from_rotation_x(fixed(0.51));
+                    assert_eq!(
+                        a.slerp(close, fixed(s as f64)),
+                        a.lerp(close, fixed(s as f64))
+                    );
+                }
+            }
+
+            #[test]
+            fn test_mat3() {
+                let m = FMat3::from_scale_angle_translation(
+                    FVec2::new(int(2), int(3)),
+                    fixed(0.75),
+                    FVec2::new(int(-4), int(5)),
+                );
+                let expected = Mat3::from_scale_angle_translation(
+                    Vec2::new(2.0, 3.0),
+                    0.75,
+                    Vec2::new(-4.0, 5.0),
+                );
+                assert!(Mat3::from(m).abs_diff_eq(expected, $tol * 4.0));
+                let p = FVec2::new(int(1), fixed(-0.5));
+                assert!(Vec2::from(m.transform_point2(p))
+                    .abs_diff_eq(expected.transform_point2(p.into()), $tol * 8.0));
+                assert!(Vec2::from(m.transform_vector2(p))
+                    .abs_diff_eq(expected.transform_vector2(p.into()), $tol * 8.0));
+                assert!((m.determinant() - int(6)).abs() <= Fixed::from_bits(8));
+
+                let inverse = m.inverse();
+                assert!((m * inverse).abs_diff_eq(FMat3::identity(), Fixed::from_bits(16)));
+                assert!((inverse * m).abs_diff_eq(FMat3::identity(), Fixed::from_bits(16)));
+                assert_eq!(m.transpose().transpose(), m);
+                assert_eq!(
+                    m.transpose().x_axis(),
+                    FVec3::new(m.x_axis().x(), m.y_axis().x(), m.z_axis().x())
+                );
+
+                for (f, expected) in [
+                    (
+                        FMat3::from_rotation_x as fn(Fixed) -> FMat3,
+                        Mat3::from_rotation_x(1.0),
+                    ),
+                    (FMat3::from_rotation_y, Mat3::from_rotation_y(1.0)),
+                    (FMat3::from_rotation_z, Mat3::from_rotation_z(1.0)),
+                ]
+                .iter()
+                {
+                    assert!(Mat3::from(f(Fixed::ONE)).abs_diff_eq(*expected, $tol));
+                }
+                let s = FMat3::from_scale(FVec3::new(int(1), int(2), int(4)));
+                assert_eq!(s.determinant(), int(8));
+                assert_eq!(s * FVec3::one(), FVec3::new(int(1), int(2), int(4)));
+                assert_eq!(s + s, s * int(2));
+                assert_eq!(int(2) * s - s, s);
+                assert_eq!(FMat3::default(), FMat3::identity());
+                assert_eq!(FMat3::identity() - FMat3::identity(), FMat3::zero());
+                let mut t = s;
+                t.set_z_axis(FVec3::zero());
+                assert_eq!(t.z_axis(), FVec3::zero());
+            }
+
+            #[test]
+            #[cfg(debug_assertions)]
+            #[should_panic(expected = "attempt to multiply with overflow")]
+            fn test_mul_overflow() {
+                let _ = Fixed::MAX * int(2);
+            }
+
+            #[test]
+            #[cfg(debug_assertions)]
+            #[should_panic(expected = "attempt to add with overflow")]
+            fn test_add_overflow() {
+                let _ = Fixed::MAX + Fixed::EPSILON;
+            }
+
+            #[test]
+            #[cfg(debug_assertions)]
+            #[should_panic(expected = "attempt to divide with overflow")]
+            fn test_div_overflow() {
+                let _ = Fixed::MAX / Fixed::EPSILON;
+            }
+
+            #[test]
+            #[cfg(debug_assertions)]
+            #[should_panic(expected = "attempt to multiply with overflow")]
+            fn test_from_int_overflow() {
+                let _ = Fixed::from_int(Fixed::MAX.to_int() + 1);
+            }
+
+            #[test]
+            #[cfg(debug_assertions)]
+            #[should_panic(expected = "attempt to multiply with overflow")]
+            fn test_dot_overflow() {
+                let v = FVec3::splat(Fixed::MAX.sqrt());
+                let _ = v.dot(v);
+            }
+
+            #[test]
+            #[should_panic]
+            fn test_normalize_zero() {
+                let _ = FVec3::zero().normalize();
+            }
+        }
+    };
+}
+
+fixed_tests!(q16, 1e-4);
+fixed_tests!(q32, 1e-6);
+
+#[test]
+fn test_precisions() {
+    use glam::fixed::{q16, q32};
+    assert_eq!(q16::Fixed::FRAC_BITS, 16);
+    assert_eq!(q32::Fixed::FRAC_BITS, 32);
+    assert_eq!(q16::Fixed::MAX.to_f64(), 32768.0 - 1.0 / 65536.0);
+    assert_eq!(q32::Fixed::MIN.to_f64(), -2_147_483_648.0);
+    assert_eq!(q16::Fixed::PI, q16::Fixed::from_f64(core::f64::consts::PI));
+    assert_eq!(
+        q32::Fixed::TAU,
+        q32::Fixed::from_f64(core::f64::consts::PI * 2.0)
+    );
+    // the Q16.16 types are re-exported from glam::fixed
+    assert_eq!(glam::fixed::Fixed::ONE, q16::Fixed::ONE);
+}
+
+// The exact bits of some results, which must be the same on every platform.
+macro_rules! bits_test {
+    ($name:ident, $m:ident, $repr:ty, $expected:expr) => {
+        #[test]
+        fn $name() {
+            use glam::fixed::$m::{FMat3, FQuat, FVec3, Fixed};
+            let f = Fixed::from_f64;
+            let axis = FVec3::new(f(1.0), f(2.0), f(-2.0)).normalize();
+            let q = FQuat::from_axis_angle(axis, f(1.2));
+            let v = q * FVec3::new(f(3.0), f(-1.0), f(0.5));
+            let m = FMat3::from_quat(q).inverse();
+            let s = q.slerp(FQuat::from_rotation_z(f(-2.0)), f(0.3));
+            let bits: [&[Fixed]; 5] = [
+                &[axis.x(), axis.y(), axis.z()],
+                &[v.x(), v.y(), v.z(), v.length()],
+                &[m.x_axis().y(), m.z_axis().x()],
+                &[s.x(), s.y(), s.z(), s.w()],
+                &[f(2.0).sin(), f(-0.7).atan2(f(0.3)), f(2.0).sqrt()],
+            ];
+            let expected: [&[$repr]; 5] = $expected;
+            for (bits, expected) in bits.iter().zip(expected.iter()) {
+                let bits: Vec<$repr> = bits.iter().map(|v| v.to_bits()).collect();
+                assert_eq!(bits, *expected);
+            }
+        }
+    };
+}
+
+bits_test!(
+    test_q16_bits,
+    q16,
+    i32,
+    [
+        &[21845, 43691, -43691],
+        &[50876, -156093, -130650, 209816],
+        &[50007, -50007],
+        &[9014, 18029, -35889, 50998],
+        &[59592, -76408, 92682],
+    ]
+);
+
+bits_test!(
+    test_q32_bits,
+    q32,
+    i64,
+    [
+        &[1431655765, 2863311531, -2863311531],
+        &[3334584982, -10229649056, -8562356563, 13750604595],
+        &[3277307727, -3277307727],
+        &[590795885, 1181591771, -2351924935, 3342154139],
+        &[3905402711, -5007521871, 6074001000],
+    ]
+);
@@ -0,0 +1,106 @@
+use glam::f32::{CanonicalBits, Hashable, Mat4, Quat, Vec2, Vec3, Vec4};
+use glam::weld_vertices;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+#[test]
+fn test_canonical_bits() {
+    assert_eq!(
+        Vec3::new(1.0, 2.0, 3.0).canonical_bits(),
+        [1.0_f32.to_bits(), 2.0_f32.to_bits(), 3.0_f32.to_bits()]
+    );
+    assert_eq!(Vec2::new(-0.0, 0.0).canonical_bits(), [0, 0]);
+    assert_eq!(
+        Vec2::new(f32::NAN, -f32::NAN).canonical_bits(),
+        [0x7fc0_0000, 0x7fc0_0000]
+    );
+    assert_eq!(
+        Vec4::new(1.0, 2.0, 3.0, 4.0).canonical_bits(),
+        Quat::from_xyzw(1.0, 2.0, 3.0, 4.0).canonical_bits()
+    );
+    assert_eq!(Mat4::identity().canonical_bits().len(), 16);
+}
+
+#[test]
+fn test_hashable_eq() {
+    assert_eq!(Hashable(Vec3::zero()), Hashable(-Vec3::zero()));
+    assert_eq!(
+        Hashable(Vec2::new(f32::NAN, 1.0)),
+        Hashable(Vec2::new(f32::from_bits(0xffc0_0001), 1.0))
+    );
+    assert_ne!(Hashable(Vec2::new(1.0, 2.0)), Hashable(Vec2::new(2.0, 1.0)));
+    assert_eq!(Hashable(Quat::identity()), Hashable::from(Quat::identity()));
+}
+
+#[test]
+fn test_hashable_hash() {
+    let mut map = HashMap::new();
+    map.insert(Hashable(Vec3::new(0.0, 1.0, 2.0)), 0);
+    map.insert(Hashable(Vec3::new(3.0, 4.0, f32::NAN)), 1);
+    assert_eq!(map.get(&Hashable(Vec3::new(-0.0, 1.0, 2.0))), Some(&0));
+    assert_eq!(map.get(&Hashable(Vec3::new(3.0, 4.0, f32::NAN))), Some(&1));
+    assert_eq!(map.get(&Hashable(Vec3::new(3.0, 4.0, 5.0))), None);
+
+    let set: HashSet<_> = [Mat4::identity(), Mat4::zero(), Mat4::identity()]
+        .iter()
+        .map(|&m| Hashable(m))
+        .collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_hashable_ord() {
+    let a = Hashable(Vec2::new(-1.0, 0.0));
+    let b = Hashable(Vec2::new(-0.0, -1.0));
+    let c = Hashable(Vec2::new(0.0, 0.0));
+    let d = Hashable(Vec2::new(f32::INFINITY, 0.0));
+    let e = Hashable(Vec2::new(f32::NAN, 0.0));
+    assert!(a < b);
+    assert!(b < c);
+    assert!(c < d);
+    assert!(d < e);
+    assert_eq!(
+        b.cmp(&Hashable(Vec2::new(0.0, -1.0))),
+        core::cmp::Ordering::Equal
+    );
+
+    let set: BTreeSet<_> = [e, c, a, d, b].iter().cloned().collect();
+    let sorted: Vec<_> = set.into_iter().collect();
+    assert_eq!(sorted, [a, b, c, d, e]);
+}
+
+#[test]
+fn test_weld_vertices_exact() {
+    let positions = [
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::new(0.0, 0.0, 0.0),
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::new(-0.0, 0.0, -0.0),
+        Vec3::new(1.0, 2.0, 3.0001),
+    ];
+    let (unique, indices) = weld_vertices(&positions, 0.0);
+    assert_eq!(unique, [positions[0], positions[1], positions[4]]);
+    assert_eq!(indices, [0, 1, 0, 1, 2]);
+
+    let (unique, indices) = weld_vertices(&[], 0.0);
+    assert!(unique.is_empty());
+    assert!(indices.is_empty());
+}
+
+#[test]
+fn test_weld_vertices_epsilon() {
+    let positions = [
+        Vec3::new(0.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        // crosses cell boundaries
+        Vec3::new(-0.005, 0.005, -0.005),
+        Vec3::new(1.009, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, 0.02),
+        Vec3::new(0.0, 0.0, 0.029),
+    ];
+    let (unique, indices) = weld_vertices(&positions, 0.01);
+    assert_eq!(unique, [positions[0], positions[1], positions[4]]);
+    assert_eq!(indices, [0, 1, 0, 1, 2, 2]);
+    for (p, &i) in positions.iter().zip(&indices) {
+        assert!((unique[i as usize] - *p).length() <= 0.01);
+    }
+}
@@ -0,0 +1,205 @@
+mod support;
+
+use glam::{HQuat, HVec2, HVec3, HVec4, Quat, Vec2, Vec3, Vec4};
+
+#[test]
+fn test_hvec_size() {
+    use core::mem;
+    assert_eq!(4, mem::size_of::<HVec2>());
+    assert_eq!(6, mem::size_of::<HVec3>());
+    assert_eq!(8, mem::size_of::<HVec4>());
+    assert_eq!(8, mem::size_of::<HQuat>());
+    assert_eq!(2, mem::align_of::<HVec3>());
+}
+
+#[test]
+fn test_hvec_round_trip() {
+    // every half float converts to f32 and back, NaNs become quiet NaNs
+    for h in 0..=u16::MAX {
+        let is_nan = h & 0x7c00 == 0x7c00 && h & 0x3ff != 0;
+        let expected = if is_nan { h | 0x200 } else { h };
+        let v = HVec4::from_bits([h, 0, h, 0x3c00]).to_vec4();
+        assert_eq!(v.x().is_nan(), is_nan);
+        assert_eq!(
+            HVec4::from(v).to_bits(),
+            [expected, 0, expected, 0x3c00],
+            "{:x}",
+            h
+        );
+        assert_eq!(
+            HVec2::from(HVec2::from_bits([h, h]).to_vec2()).to_bits(),
+            [expected; 2]
+        );
+    }
+}
+
+#[test]
+fn test_hvec_rounding() {
+    assert_eq!(HVec2::new(1.0, -2.0).to_bits(), [0x3c00, 0xc000]);
+    assert_eq!(
+        HVec4::new(65504.0, 65520.0, -1.0e9, f32::INFINITY).to_bits(),
+        [0x7bff, 0x7c00, 0xfc00, 0x7c00]
+    );
+    let tiny = 2.0_f32.powi(-24);
+    assert_eq!(
+        HVec3::new(tiny, tiny * 0.5, -0.0).to_bits(),
+        [0x0001, 0x0000, 0x8000]
+    );
+    let ulp = 2.0_f32.powi(-10);
+    assert_eq!(
+        HVec2::new(1.0 + ulp * 0.5, 1.0 + ulp * 1.5).to_bits(),
+        [0x3c00, 0x3c02]
+    );
+    // matches the packing functions over a spread of f32 bit patterns
+    for bits in (0..u32::MAX).step_by(65_521) {
+        let v = f32::from_bits(bits);
+        let h = HVec4::new(v, -v, v * 0.5, 1.0).to_bits();
+        assert_eq!(h, Vec4::new(v, -v, v * 0.5, 1.0).pack_f16());
+    }
+}
+
+#[test]
+fn test_hvec_accessors() {
+    let v = HVec4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!((v.x(), v.y(), v.z(), v.w()), (1.0, 2.0, 3.0, 4.0));
+    assert_eq!(v.to_vec4(), Vec4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(
+        Vec3::from(HVec3::new(0.5, 0.25, 8.0)),
+        Vec3::new(0.5, 0.25, 8.0)
+    );
+    let v = HVec3::new(1.0, 0.1, -3.0);
+    assert_eq!(v.y(), 0.099_975_586);
+    assert_eq!(v.as_ref(), &v.to_bits());
+    assert_eq!(HVec2::default().to_vec2(), Vec2::zero());
+    assert_eq!(HVec2::new(0.0, 1.0), HVec2::new(-0.0, 1.0));
+    assert_ne!(HVec2::new(f32::NAN, 1.0), HVec2::new(f32::NAN, 1.0));
+    assert_eq!(format!("{:?}", HVec2::new(1.0, 2.5)), "HVec2(1.0, 2.5)");
+    assert_eq!(
+        format!("{:?}", HQuat::identity()),
+        "HQuat(0.0, 0.0, 0.0, 1.0)"
+    );
+}
+
+#[test]
+fn test_hvec_slices() {
+    let src: Vec<Vec3> = (0..100)
+        .map(|i| Vec3::new(i as f32, i as f32 * 0.1, -(i as f32)))
+        .collect();
+    let mut half = vec![HVec3::default(); src.len()];
+    HVec3::encode_slice(&src, &mut half);
+    let mut dst = vec![Vec3::zero(); src.len()];
+    HVec3::decode_slice(&half, &mut dst);
+    for ((&s, &h), &d) in src.iter().zip(half.iter()).zip(dst.iter()) {
+        assert_eq!(h, HVec3::from(s));
+        assert_eq!(d, h.to_vec3());
+        assert_approx_eq!(s, d, s.abs().max_element() / 1024.0);
+    }
+
+    let src = [Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)];
+    let mut half = [HVec2::default(); 2];
+    HVec2::encode_slice(&src, &mut half);
+    assert_eq!(half[1].to_bits(), [0x4200, 0x4400]);
+
+    // lengths which leave a remainder after converting four lanes at a time
+    for len in 0..9 {
+        let src: Vec<Vec2> = (0..len)
+            .map(|i| Vec2::new(i as f32 * 1.5, 1.0 / (i as f32 + 1.0)))
+            .collect();
+        let mut half = vec![HVec2::default(); len];
+        HVec2::encode_slice(&src, &mut half);
+        let mut dst = vec![Vec2::zero(); len];
+        HVec2::decode_slice(&half, &mut dst);
+        for ((&s, &h), &d) in src.iter().zip(half.iter()).zip(dst.iter()) {
+            assert_eq!(h, HVec2::from(s));
+            assert_eq!(d, h.to_vec2());
+        }
+
+        let src: Vec<Vec3> = (0..len)
+            .map(|i| Vec3::new(i as f32, -0.3 * i as f32, 1e-6 * i as f32))
+            .collect();
+        let mut half = vec![HVec3::default(); len];
+        HVec3::encode_slice(&src, &mut half);
+        for (&s, &h) in src.iter().zip(half.iter()) {
+            assert_eq!(h.to_bits(), HVec3::from(s).to_bits());
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_hvec_slice_length_mismatch() {
+    let mut half = [HVec4::default(); 2];
+    HVec4::encode_slice(&[Vec4::zero(); 3], &mut half);
+}
+
+#[test]
+fn test_hquat() {
+    assert_eq!(HQuat::default().to_quat(), Quat::identity());
+    assert_eq!(HQuat::from(Quat::identity()), HQuat::identity());
+    for i in 0..1000 {
+        let angle = i as f32 * 0.01;
+        let axis = Vec3::new(angle.sin(), 1.0, angle.cos() * 2.0).normalize();
+        let q = Quat::from_axis_angle(axis, angle);
+        let h = HQuat::from(q);
+        let r = h.to_quat();
+        assert!(r.is_normalized());
+        assert_approx_eq!(q, r, 1e-3);
+        assert!((h.x() - q.x()).abs() <= 1.0 / 2048.0);
+        assert!((h.w() - q.w()).abs() <= 1.0 / 2048.0);
+    }
+    let mut dst = [Quat::identity(); 2];
+    HQuat::decode_slice(
+        &[
+            HQuat::from_xyzw(0.0, 0.0, 0.0, -1.0),
+            HQuat::from_xyzw(0.0, 1.0, 0.0, 0.0),
+        ],
+        &mut dst,
+    );
+    assert_eq!(
+        dst,
+        [
+            Quat::from_xyzw(0.0, 0.0, 0.0, -1.0),
+            Quat::from_xyzw(0.0, 1.0, 0.0, 0.0)
+        ]
+    );
+
+    let src: Vec<HQuat> = (0..5)
+        .map(|i| HQuat::from(Quat::from_rotation_y(i as f32 * 0.7)))
+        .collect();
+    let mut dst = vec![Quat::identity(); src.len()];
+    HQuat::decode_slice(&src, &mut dst);
+    for (&h, &q) in src.iter().zip(dst.iter()) {
+        assert_eq!(q, h.to_quat());
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_hvec_serde() {
+    let a = HVec3::new(1.0, 0.1, -3.0);
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[1.0,0.099975586,-3.0]");
+    let deserialized: HVec3 = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a.to_bits(), deserialized.to_bits());
+    let deserialized: HVec2 = serde_json::from_str("[0.1,2.0]").unwrap();
+    assert_eq!(deserialized, HVec2::new(0.1, 2.0));
+    assert!(serde_json::from_str::<HVec4>("[1.0,2.0,3.0]").is_err());
+
+    let q = HQuat::from(Quat::from_rotation_y(1.0));
+    let serialized = serde_json::to_string(&q).unwrap();
+    let deserialized: HQuat = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(q.to_bits(), deserialized.to_bits());
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_hvec_bytemuck() {
+    let v = [HVec3::new(1.0, 2.0, 3.0), HVec3::new(-1.0, 0.5, 0.0)];
+    let bits: &[u16] = bytemuck::cast_slice(&v);
+    assert_eq!(bits, &[0x3c00, 0x4000, 0x4200, 0xbc00, 0x3800, 0]);
+    let bytes: &[u8] = bytemuck::cast_slice(&v);
+    assert_eq!(bytes.len(), 12);
+    let q: HQuat = bytemuck::cast([0_u16, 0, 0, 0x3c00]);
+    assert_eq!(q, HQuat::identity());
+    assert_eq!(<HVec2 as bytemuck::Zeroable>::zeroed(), HVec2::default());
+}
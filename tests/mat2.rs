@@ -20,6 +20,14 @@ fn test_mat2_align() {
     }
 }
 
+#[test]
+fn test_mat2_consts() {
+    const M: Mat2 = Mat2::from_cols_array_2d(&MATRIX);
+    assert_eq!(Mat2::from_cols_array_2d(&MATRIX), M);
+    assert_eq!(Mat2::identity(), Mat2::IDENTITY);
+    assert_eq!(Mat2::zero(), Mat2::ZERO);
+}
+
 #[test]
 fn test_mat2_identity() {
     let identity = Mat2::identity();
@@ -48,6 +56,22 @@ fn test_mat2_accessors() {
     assert_eq!(Vec2::new(2.0, 3.0), m.y_axis());
 }
 
+#[test]
+fn test_mat2_index() {
+    let mut m = Mat2::from_cols_array_2d(&MATRIX);
+    assert_eq!(Vec2::new(1.0, 2.0), m[0]);
+    assert_eq!(Vec2::new(3.0, 4.0), m[1]);
+    m[1] = Vec2::zero();
+    assert_eq!(Vec2::zero(), m.y_axis());
+}
+
+#[test]
+#[should_panic]
+fn test_mat2_index_out_of_bounds() {
+    let m = Mat2::identity();
+    let _ = m[2];
+}
+
 #[test]
 fn test_mat2_from_axes() {
     let a = Mat2::from_cols_array_2d(&[[1.0, 2.0], [3.0, 4.0]]);
@@ -12,13 +12,16 @@ const ZERO: [[f32; 3]; 3] = [[0.0; 3]; 3];
 #[test]
 fn test_mat3_align() {
     use std::mem;
-    if cfg!(any(feature = "packed-vec3", feature = "scalar-math")) {
-        assert_eq!(36, mem::size_of::<Mat3>());
-        assert_eq!(4, mem::align_of::<Mat3>());
-    } else {
-        assert_eq!(48, mem::size_of::<Mat3>());
-        assert_eq!(16, mem::align_of::<Mat3>());
-    }
+    assert_eq!(36, mem::size_of::<Mat3>());
+    assert_eq!(4, mem::align_of::<Mat3>());
+}
+
+#[test]
+fn test_mat3_consts() {
+    const M: Mat3 = Mat3::from_cols_array_2d(&MATRIX);
+    assert_eq!(Mat3::from_cols_array_2d(&MATRIX), M);
+    assert_eq!(Mat3::identity(), Mat3::IDENTITY);
+    assert_eq!(Mat3::zero(), Mat3::ZERO);
 }
 
 #[test]
@@ -53,6 +56,23 @@ fn test_mat3_accessors() {
     assert_eq!(Vec3::new(6.0, 7.0, 8.0), m.z_axis());
 }
 
+#[test]
+fn test_mat3_index() {
+    let mut m = Mat3::from_cols_array_2d(&MATRIX);
+    assert_eq!(Vec3::new(1.0, 2.0, 3.0), m[0]);
+    assert_eq!(Vec3::new(4.0, 5.0, 6.0), m[1]);
+    assert_eq!(Vec3::new(7.0, 8.0, 9.0), m[2]);
+    m[2] = Vec3::zero();
+    assert_eq!(Vec3::zero(), m.z_axis());
+}
+
+#[test]
+#[should_panic]
+fn test_mat3_index_out_of_bounds() {
+    let m = Mat3::identity();
+    let _ = m[3];
+}
+
 #[test]
 fn test_mat3_from_axes() {
     let a = Mat3::from_cols_array_2d(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
@@ -30,6 +30,14 @@ fn test_mat4_align() {
     }
 }
 
+#[test]
+fn test_mat4_consts() {
+    const M: Mat4 = Mat4::from_cols_array_2d(&MATRIX);
+    assert_eq!(Mat4::from_cols_array_2d(&MATRIX), M);
+    assert_eq!(Mat4::identity(), Mat4::IDENTITY);
+    assert_eq!(Mat4::zero(), Mat4::ZERO);
+}
+
 #[test]
 fn test_mat4_identity() {
     let identity = Mat4::identity();
@@ -66,6 +74,24 @@ fn test_mat4_accessors() {
     assert_eq!(Vec4::new(12.0, 13.0, 14.0, 15.0), m.w_axis());
 }
 
+#[test]
+fn test_mat4_index() {
+    let mut m = Mat4::from_cols_array_2d(&MATRIX);
+    assert_eq!(Vec4::new(1.0, 2.0, 3.0, 4.0), m[0]);
+    assert_eq!(Vec4::new(5.0, 6.0, 7.0, 8.0), m[1]);
+    assert_eq!(Vec4::new(9.0, 10.0, 11.0, 12.0), m[2]);
+    assert_eq!(Vec4::new(13.0, 14.0, 15.0, 16.0), m[3]);
+    m[3] = Vec4::zero();
+    assert_eq!(Vec4::zero(), m.w_axis());
+}
+
+#[test]
+#[should_panic]
+fn test_mat4_index_out_of_bounds() {
+    let m = Mat4::identity();
+    let _ = m[4];
+}
+
 #[test]
 fn test_mat4_from_axes() {
     let a = Mat4::from_cols_array_2d(&[
@@ -0,0 +1,216 @@
+mod support;
+
+use glam::noise::*;
+use glam::{sampling, Vec2, Vec3, Vec4};
+
+const SAMPLES: u32 = 10_000;
+
+fn points3() -> impl Iterator<Item = Vec3> {
+    (0..SAMPLES).map(|i| sampling::r3(i) * 20.0 - Vec3::splat(10.0))
+}
+
+fn points2() -> impl Iterator<Item = Vec2> {
+    points3().map(|p| p.truncate())
+}
+
+fn points4() -> impl Iterator<Item = Vec4> {
+    points3().map(|p| p.extend(p.x() * 0.7 - p.y() * 0.3))
+}
+
+/// Checks that `noise` is within [-1, 1], has a mean near zero and is not
+/// degenerate.
+fn check_range<P>(points: impl Iterator<Item = P>, noise: impl Fn(P) -> f32) {
+    let values: Vec<f32> = points.map(noise).collect();
+    assert!(values.iter().all(|v| v.abs() <= 1.0));
+    let mean = values.iter().sum::<f32>() / values.len() as f32;
+    let variance =
+        values.iter().map(|v| (v - mean) * (v - mean)).sum::<f32>() / values.len() as f32;
+    assert!(mean.abs() < 0.05, "{}", mean);
+    assert!(variance > 0.01, "{}", variance);
+}
+
+#[test]
+fn test_range() {
+    check_range(points2(), perlin2);
+    check_range(points3(), perlin3);
+    check_range(points4(), perlin4);
+    check_range(points2(), simplex2);
+    check_range(points3(), simplex3);
+    check_range(points4(), simplex4);
+}
+
+#[test]
+fn test_perlin_lattice_zero() {
+    for &(x, y, z, w) in &[
+        (0.0, 0.0, 0.0, 0.0),
+        (1.0, -2.0, 3.0, 7.0),
+        (-10.0, 5.0, 8.0, -1.0),
+    ] {
+        assert_eq!(perlin2(Vec2::new(x, y)), 0.0);
+        assert_eq!(perlin3(Vec3::new(x, y, z)), 0.0);
+        assert_eq!(perlin4(Vec4::new(x, y, z, w)), 0.0);
+    }
+}
+
+#[test]
+fn test_continuity() {
+    // the gradients of the noise functions are below 10 in length
+    let step = 1.0e-3;
+    let bound3 = 10.0 * Vec3::splat(step).length();
+    let bound2 = 10.0 * Vec2::splat(step).length();
+    let bound4 = 10.0 * Vec4::splat(step).length();
+    for p in points3().take(1000) {
+        let q = p + Vec3::splat(step);
+        assert!((perlin3(p) - perlin3(q)).abs() < bound3);
+        assert!((simplex3(p) - simplex3(q)).abs() < bound3);
+        let (p2, q2) = (p.truncate(), q.truncate());
+        assert!((perlin2(p2) - perlin2(q2)).abs() < bound2);
+        assert!((simplex2(p2) - simplex2(q2)).abs() < bound2);
+        let (p4, q4) = (p.extend(0.5), q.extend(0.5 + step));
+        assert!((perlin4(p4) - perlin4(q4)).abs() < bound4);
+        assert!((simplex4(p4) - simplex4(q4)).abs() < bound4);
+    }
+}
+
+/// Compares an analytic gradient with central differences.
+macro_rules! check_gradient {
+    ($points:expr, $noise:expr, $grad:expr, $axes:expr) => {
+        let h = 1.0e-2;
+        for p in $points.take(500) {
+            let (value, gradient) = $grad(p);
+            assert_eq!(value, $noise(p));
+            for (k, &axis) in $axes.iter().enumerate() {
+                let numeric = ($noise(p + axis * h) - $noise(p - axis * h)) / (2.0 * h);
+                let analytic = gradient.as_ref()[k];
+                assert!(
+                    (numeric - analytic).abs() < 0.02,
+                    "{:?} {} {} {}",
+                    p,
+                    k,
+                    numeric,
+                    analytic
+                );
+            }
+        }
+    };
+}
+
+#[test]
+fn test_gradients() {
+    check_gradient!(
+        points2(),
+        perlin2,
+        perlin2_grad,
+        [Vec2::unit_x(), Vec2::unit_y()]
+    );
+    check_gradient!(
+        points2(),
+        simplex2,
+        simplex2_grad,
+        [Vec2::unit_x(), Vec2::unit_y()]
+    );
+    let axes3 = [Vec3::unit_x(), Vec3::unit_y(), Vec3::unit_z()];
+    check_gradient!(points3(), perlin3, perlin3_grad, axes3);
+    check_gradient!(points3(), simplex3, simplex3_grad, axes3);
+    let axes4 = [
+        Vec4::unit_x(),
+        Vec4::unit_y(),
+        Vec4::unit_z(),
+        Vec4::unit_w(),
+    ];
+    check_gradient!(points4(), perlin4, perlin4_grad, axes4);
+    check_gradient!(points4(), simplex4, simplex4_grad, axes4);
+}
+
+#[test]
+fn test_x4() {
+    let p: Vec<Vec3> = points3().take(1000).collect();
+    for c in p.chunks(4) {
+        let p3 = [c[0], c[1], c[2], c[3]];
+        let p2 = [
+            c[0].truncate(),
+            c[1].truncate(),
+            c[2].truncate(),
+            c[3].truncate(),
+        ];
+        let expected = |f: &dyn Fn(usize) -> f32| Vec4::new(f(0), f(1), f(2), f(3));
+        assert_approx_eq!(perlin2_x4(p2), expected(&|i| perlin2(p2[i])), 1e-6);
+        assert_approx_eq!(perlin3_x4(p3), expected(&|i| perlin3(p3[i])), 1e-6);
+        assert_approx_eq!(simplex2_x4(p2), expected(&|i| simplex2(p2[i])), 1e-6);
+        assert_approx_eq!(simplex3_x4(p3), expected(&|i| simplex3(p3[i])), 1e-6);
+    }
+
+    let p: Vec<Vec4> = points4().take(1000).collect();
+    for c in p.chunks(4) {
+        let p4 = [c[0], c[1], c[2], c[3]];
+        let expected = |f: &dyn Fn(usize) -> f32| Vec4::new(f(0), f(1), f(2), f(3));
+        assert_approx_eq!(perlin4_x4(p4), expected(&|i| perlin4(p4[i])), 1e-6);
+        assert_approx_eq!(simplex4_x4(p4), expected(&|i| simplex4(p4[i])), 1e-6);
+    }
+    // negative lattice cells and lattice points
+    let p2 = [
+        Vec2::new(-1.0, -1.0),
+        Vec2::new(-0.5, -1.5),
+        Vec2::new(-100.25, 3.0),
+        Vec2::zero(),
+    ];
+    let p3 = [
+        Vec3::new(-1.0, -1.0, -1.0),
+        Vec3::new(-0.5, -1.5, 2.5),
+        Vec3::new(-100.25, 3.0, -0.0),
+        Vec3::zero(),
+    ];
+    for i in 0..4 {
+        assert_eq!(perlin2_x4(p2).as_ref()[i], perlin2(p2[i]));
+        assert_eq!(perlin3_x4(p3).as_ref()[i], perlin3(p3[i]));
+        assert_eq!(simplex2_x4(p2).as_ref()[i], simplex2(p2[i]));
+        assert_eq!(simplex3_x4(p3).as_ref()[i], simplex3(p3[i]));
+    }
+}
+
+#[test]
+fn test_worley() {
+    for p in points3().take(2000) {
+        let (f1, f2) = worley3(p);
+        assert!(f1 >= 0.0 && f1 <= f2 && f1 <= 3.0f32.sqrt());
+        let (g1, g2) = worley2(p.truncate());
+        assert!(g1 >= 0.0 && g1 <= g2 && g1 <= 2.0f32.sqrt());
+
+        // distances change at most as fast as the position
+        let q = p + Vec3::new(0.01, -0.02, 0.005);
+        let (q1, q2) = worley3(q);
+        let moved = (q - p).length() + 1.0e-5;
+        assert!((q1 - f1).abs() <= moved && (q2 - f2).abs() <= moved);
+    }
+    // on average there is one feature point per cell so the nearest one is
+    // about half a cell away
+    let mean = points2().map(|p| worley2(p).0).sum::<f32>() / SAMPLES as f32;
+    assert!(mean > 0.4 && mean < 0.6, "{}", mean);
+}
+
+#[test]
+fn test_fractal() {
+    let single = Fractal::new(1);
+    let fractal = Fractal::default();
+    assert_eq!(
+        fractal,
+        Fractal {
+            octaves: 5,
+            lacunarity: 2.0,
+            gain: 0.5
+        }
+    );
+    for p in points3().take(1000) {
+        assert_eq!(single.fbm(p, perlin3), perlin3(p));
+        assert!(fractal.fbm(p, simplex3).abs() <= 1.0);
+        let ridged = fractal.ridged(p, perlin3);
+        assert!((0.0..=1.0).contains(&ridged));
+    }
+    // the second octave is added at twice the frequency and half the amplitude
+    let two = Fractal::new(2);
+    let p = Vec2::new(0.3, 0.7);
+    assert_approx_eq!(
+        two.fbm(p, simplex2),
+        (simplex2(p) + 0.5 * simplex2(p * 2.0)) / 1.5
+    );
+}
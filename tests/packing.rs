@@ -0,0 +1,309 @@
+use glam::{sampling, Quat, Vec2, Vec3, Vec4};
+use std::f32::consts::PI;
+
+#[test]
+fn test_unorm8() {
+    for i in 0..=255_u8 {
+        let v = Vec4::unpack_unorm8([i, 0, 255, i]);
+        assert_eq!(v, Vec4::new(i as f32 / 255.0, 0.0, 1.0, i as f32 / 255.0));
+        assert_eq!(v.pack_unorm8(), [i, 0, 255, i]);
+    }
+    // halfway cases round up
+    assert_eq!(Vec2::new(0.5, 1.5 / 255.0).pack_unorm8(), [128, 2]);
+    assert_eq!(Vec2::new(2.5 / 255.0, 0.499 / 255.0).pack_unorm8(), [3, 0]);
+    // out of range, infinite and NaN values
+    assert_eq!(
+        Vec4::new(-1.0, 2.0, f32::NAN, f32::INFINITY).pack_unorm8(),
+        [0, 255, 0, 255]
+    );
+    assert_eq!(Vec2::new(f32::NEG_INFINITY, -0.0).pack_unorm8(), [0, 0]);
+    // every value in range is within half a step
+    for i in 0..=100_000 {
+        let c = i as f32 / 100_000.0;
+        let [u] = [Vec3::splat(c).pack_unorm8()[0]];
+        assert!((u as f32 / 255.0 - c).abs() <= 0.5 / 255.0 + 1e-7, "{}", c);
+    }
+}
+
+#[test]
+fn test_snorm8() {
+    for i in -127..=127_i8 {
+        let v = Vec3::unpack_snorm8([i, -i, 0]);
+        assert_eq!(v, Vec3::new(i as f32 / 127.0, -i as f32 / 127.0, 0.0));
+        assert_eq!(v.pack_snorm8(), [i, -i, 0]);
+    }
+    // the most negative value is clamped to -1
+    assert_eq!(Vec2::unpack_snorm8([-128, -127]), Vec2::new(-1.0, -1.0));
+    assert_eq!(Vec2::unpack_snorm8([-128, 0]).pack_snorm8(), [-127, 0]);
+    // halfway cases round away from zero
+    assert_eq!(Vec2::new(0.5, -0.5).pack_snorm8(), [64, -64]);
+    assert_eq!(Vec2::new(1.5 / 127.0, -1.5 / 127.0).pack_snorm8(), [2, -2]);
+    assert_eq!(
+        Vec4::new(-2.0, 2.0, f32::NAN, f32::NEG_INFINITY).pack_snorm8(),
+        [-127, 127, 0, -127]
+    );
+    for i in -100_000..=100_000 {
+        let c = i as f32 / 100_000.0;
+        let s = Vec2::splat(c).pack_snorm8()[0];
+        assert!((s as f32 / 127.0 - c).abs() <= 0.5 / 127.0 + 1e-7, "{}", c);
+    }
+}
+
+#[test]
+fn test_unorm16_snorm16() {
+    for i in 0..=u16::MAX {
+        let v = Vec2::unpack_unorm16([i, u16::MAX - i]);
+        assert_eq!(v.x(), i as f32 / 65535.0);
+        assert_eq!(v.pack_unorm16(), [i, u16::MAX - i]);
+
+        let s = i as i16;
+        let v = Vec2::unpack_snorm16([s, 0]);
+        if s == i16::MIN {
+            assert_eq!(v.x(), -1.0);
+            assert_eq!(v.pack_snorm16(), [-32767, 0]);
+        } else {
+            assert_eq!(v.x(), s as f32 / 32767.0);
+            assert_eq!(v.pack_snorm16(), [s, 0]);
+        }
+    }
+    assert_eq!(Vec2::new(0.5, -0.5).pack_snorm16(), [16384, -16384]);
+    assert_eq!(Vec2::new(0.5, 1.1).pack_unorm16(), [32768, 65535]);
+}
+
+/// Returns the nearest half float to the finite non-negative `v`, rounding
+/// halfway cases to even, by searching the ordered positive half floats.
+fn reference_f16(v: f32) -> u16 {
+    let value = |h: u16| Vec2::unpack_f16([h, 0]).x() as f64;
+    let v = v as f64;
+    // halfway between the largest finite half and the next power of two
+    if v >= 65520.0 {
+        return 0x7c00;
+    }
+    // find the first half float above `v`, the infinity encoding if none
+    let (mut lo, mut hi) = (0_u16, 0x7c00_u16);
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if value(mid) <= v {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    let lo = lo - 1;
+    if lo == 0x7bff {
+        return lo;
+    }
+    let (below, above) = (v - value(lo), value(lo + 1) - v);
+    if below < above || (below == above && lo & 1 == 0) {
+        lo
+    } else {
+        lo + 1
+    }
+}
+
+#[test]
+fn test_f16() {
+    // every half float round trips, NaNs become quiet NaNs
+    for h in 0..=u16::MAX {
+        let v = Vec2::unpack_f16([h, h]);
+        let is_nan = h & 0x7c00 == 0x7c00 && h & 0x3ff != 0;
+        assert_eq!(v.x().is_nan(), is_nan);
+        let expected = if is_nan { h | 0x200 } else { h };
+        assert_eq!(v.pack_f16(), [expected, expected]);
+    }
+
+    assert_eq!(
+        Vec4::new(1.0, -2.0, 0.5, 65504.0).pack_f16(),
+        [0x3c00, 0xc000, 0x3800, 0x7bff]
+    );
+    assert_eq!(
+        Vec4::new(-0.0, f32::INFINITY, 1.0e6, -1.0e6).pack_f16(),
+        [0x8000, 0x7c00, 0x7c00, 0xfc00]
+    );
+    // the smallest denormal and values that round to zero or to it
+    let tiny = 2.0_f32.powi(-24);
+    assert_eq!(
+        Vec4::new(tiny, tiny * 0.5, tiny * 0.51, -tiny * 1.5).pack_f16(),
+        [0x0001, 0x0000, 0x0001, 0x8002]
+    );
+    // halfway between 1 and the next half rounds to even
+    let ulp = 2.0_f32.powi(-10);
+    assert_eq!(
+        Vec3::new(1.0 + ulp * 0.5, 1.0 + ulp * 1.5, 65519.0).pack_f16(),
+        [0x3c00, 0x3c02, 0x7bff]
+    );
+    assert_eq!(Vec2::new(65520.0, 0.0).pack_f16(), [0x7c00, 0]);
+
+    // compare with the reference rounding over a spread of f32 bit patterns
+    // and every halfway point between adjacent half floats
+    for bits in (0..0x4780_0000_u32).step_by(997) {
+        let v = f32::from_bits(bits);
+        assert_eq!(Vec2::new(v, -v).pack_f16(), {
+            let h = reference_f16(v);
+            [h, h | 0x8000]
+        });
+    }
+    for h in 0..0x7bff_u16 {
+        let a = Vec2::unpack_f16([h, h + 1]);
+        let mid = (a.x() + a.y()) * 0.5;
+        assert_eq!(Vec2::new(mid, 0.0).pack_f16()[0], reference_f16(mid));
+    }
+}
+
+#[test]
+fn test_r11g11b10f() {
+    for i in 0..0x7ff_u32 {
+        // 10 bit z has one less mantissa bit
+        let packed = i | (0x7ff - i) << 11 | (i >> 1) << 22;
+        let v = Vec3::unpack_r11g11b10f(packed);
+        if v.cmpeq(v).all() {
+            assert_eq!(v.pack_r11g11b10f(), packed);
+        }
+    }
+    assert_eq!(Vec3::unpack_r11g11b10f(0), Vec3::zero());
+    assert_eq!(
+        Vec3::new(1.0, 2.0, 0.5).pack_r11g11b10f(),
+        15 << 6 | 16 << 17 | 14 << 27
+    );
+    assert_eq!(
+        Vec3::unpack_r11g11b10f(Vec3::new(1.0, 2.0, 0.5).pack_r11g11b10f()),
+        Vec3::new(1.0, 2.0, 0.5)
+    );
+    // negative values become zero, large values the largest finite value
+    let v = Vec3::unpack_r11g11b10f(Vec3::new(-1.0, 1.0e9, 1.0e9).pack_r11g11b10f());
+    assert_eq!(v, Vec3::new(0.0, 65024.0, 64512.0));
+    let v = Vec3::unpack_r11g11b10f(
+        Vec3::new(f32::NEG_INFINITY, f32::INFINITY, f32::NAN).pack_r11g11b10f(),
+    );
+    assert_eq!(v.x(), 0.0);
+    assert_eq!(v.y(), f32::INFINITY);
+    assert!(v.z().is_nan());
+    // halfway cases round to even
+    let v = Vec3::new(1.0 + 1.0 / 128.0, 1.0 + 3.0 / 128.0, 1.0 + 1.0 / 64.0);
+    assert_eq!(
+        Vec3::unpack_r11g11b10f(v.pack_r11g11b10f()),
+        Vec3::new(1.0, 1.0 + 2.0 / 64.0, 1.0)
+    );
+    // relative error is bounded by half a step
+    for i in 1..10_000 {
+        let c = i as f32 * 0.37;
+        let v = Vec3::unpack_r11g11b10f(Vec3::splat(c).pack_r11g11b10f());
+        assert!((v.x() - c).abs() <= c / 128.0, "{}", c);
+        assert!((v.z() - c).abs() <= c / 64.0, "{}", c);
+    }
+}
+
+#[test]
+fn test_rgb10a2() {
+    for i in 0..1024_u32 {
+        let packed = i | (1023 - i) << 10 | (i / 2) << 20 | (i & 3) << 30;
+        let v = Vec4::unpack_rgb10a2(packed);
+        assert_eq!(v.x(), i as f32 / 1023.0);
+        assert_eq!(v.w(), (i & 3) as f32 / 3.0);
+        assert_eq!(v.pack_rgb10a2(), packed);
+    }
+    assert_eq!(Vec4::new(1.0, 0.0, 0.0, 0.0).pack_rgb10a2(), 0x3ff);
+    assert_eq!(Vec4::new(0.0, 0.0, 0.0, 1.0).pack_rgb10a2(), 0xc000_0000);
+    assert_eq!(
+        Vec4::new(-1.0, 2.0, 0.5, 0.5).pack_rgb10a2(),
+        0x3ff << 10 | 512 << 20 | 2 << 30
+    );
+}
+
+/// Returns unit vectors spread over the sphere.
+fn sphere_points() -> impl Iterator<Item = Vec3> {
+    let halton = sampling::Halton::new();
+    (0..20_000)
+        .map(move |i| sampling::square_to_sphere(halton.sample_vec2(i)))
+        .chain(
+            [
+                Vec3::unit_x(),
+                Vec3::unit_y(),
+                Vec3::unit_z(),
+                -Vec3::unit_x(),
+                -Vec3::unit_y(),
+                -Vec3::unit_z(),
+                Vec3::new(1.0, 1.0, -1.0).normalize(),
+                Vec3::new(-1.0, 0.0, -1.0).normalize(),
+            ]
+            .to_vec(),
+        )
+}
+
+fn angle_degrees(a: Vec3, b: Vec3) -> f32 {
+    // more accurate than `acos` for small angles
+    a.cross(b).length().atan2(a.dot(b)).to_degrees()
+}
+
+#[test]
+fn test_octahedral() {
+    for n in sphere_points() {
+        let e = n.to_octahedral();
+        assert!(e.abs().cmple(Vec2::one()).all());
+        assert!(Vec3::from_octahedral(e).abs_diff_eq(n, 1e-6), "{:?}", n);
+
+        let decoded = Vec3::unpack_octahedral16(n.pack_octahedral16());
+        assert!(decoded.is_normalized());
+        assert!(angle_degrees(n, decoded) < 0.008, "{:?}", n);
+        let decoded = Vec3::unpack_octahedral8(n.pack_octahedral8());
+        assert!(angle_degrees(n, decoded) < 0.7, "{:?}", n);
+    }
+    // the axes are represented exactly
+    for &axis in &[
+        Vec3::unit_x(),
+        -Vec3::unit_y(),
+        Vec3::unit_z(),
+        -Vec3::unit_z(),
+    ] {
+        assert_eq!(Vec3::unpack_octahedral16(axis.pack_octahedral16()), axis);
+        assert_eq!(Vec3::unpack_octahedral8(axis.pack_octahedral8()), axis);
+    }
+    assert_eq!(Vec3::unit_z().to_octahedral(), Vec2::zero());
+    assert_eq!(Vec3::unit_x().pack_octahedral16(), 0x7fff);
+    assert_eq!(Vec3::unit_y().pack_octahedral8(), 0x7f00);
+}
+
+fn max_component_error(a: Quat, b: Quat) -> f32 {
+    // `q` and `-q` are the same rotation
+    (Vec4::from(a) - Vec4::from(b))
+        .abs()
+        .max_element()
+        .min((Vec4::from(a) + Vec4::from(b)).abs().max_element())
+}
+
+#[test]
+fn test_smallest_three() {
+    let halton = sampling::Halton::new();
+    let mut rotations: Vec<Quat> = (0..20_000)
+        .map(|i| {
+            let p = halton.sample_vec3(i);
+            let axis = sampling::square_to_sphere(p.truncate());
+            Quat::from_axis_angle(axis, p.z() * 2.0 * PI)
+        })
+        .collect();
+    rotations.push(Quat::identity());
+    rotations.push(Quat::from_xyzw(0.5, 0.5, 0.5, 0.5));
+    rotations.push(Quat::from_xyzw(-0.5, 0.5, -0.5, 0.5));
+    rotations.push(Quat::from_xyzw(0.0, -1.0, 0.0, 0.0));
+    rotations.push(Quat::from_xyzw(0.0, 0.0, -0.707_106_77, 0.707_106_77));
+
+    for &q in &rotations {
+        let u = Quat::unpack_smallest_three(q.pack_smallest_three());
+        assert!(u.is_normalized());
+        assert!(max_component_error(q, u) < 0.0016, "{:?} {:?}", q, u);
+        let u = Quat::unpack_smallest_three_u64(q.pack_smallest_three_u64());
+        assert!(max_component_error(q, u) < 1.7e-6, "{:?} {:?}", q, u);
+    }
+    assert_eq!(
+        Quat::unpack_smallest_three(Quat::identity().pack_smallest_three()),
+        Quat::identity()
+    );
+    assert_eq!(Quat::identity().pack_smallest_three(), 3 << 30);
+    assert_eq!(Quat::identity().pack_smallest_three_u64(), 3 << 62);
+    // the largest component is made positive
+    let q = Quat::from_xyzw(0.0, -1.0, 0.0, 0.0);
+    assert_eq!(
+        Quat::unpack_smallest_three(q.pack_smallest_three()),
+        Quat::from_xyzw(0.0, 1.0, 0.0, 0.0)
+    );
+}
@@ -0,0 +1,178 @@
+use glam::f32::*;
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+fn assert_round_trip<T>(v: T)
+where
+    T: Copy + Debug + Display + FromStr<Err = ParseError> + PartialEq,
+{
+    let s = v.to_string();
+    assert_eq!(s.parse::<T>(), Ok(v), "failed to round trip {}", s);
+}
+
+fn assert_parse_err<T>(s: &str, kind: ParseErrorKind, position: usize)
+where
+    T: Debug + FromStr<Err = ParseError>,
+{
+    let err = s.parse::<T>().unwrap_err();
+    assert_eq!((err.kind(), err.position()), (kind, position), "{}", s);
+}
+
+#[test]
+fn test_parse_vec() {
+    assert_eq!("[1, 2]".parse(), Ok(Vec2::new(1.0, 2.0)));
+    assert_eq!("[1, -2.5, 3e2]".parse(), Ok(Vec3::new(1.0, -2.5, 300.0)));
+    assert_eq!("[1, 2, 3, 4]".parse(), Ok(Vec4::new(1.0, 2.0, 3.0, 4.0)));
+    // tolerant whitespace and commas
+    assert_eq!(" [ 1 2 3 ] ".parse(), Ok(Vec3::new(1.0, 2.0, 3.0)));
+    assert_eq!("[1,2,3,]".parse(), Ok(Vec3::new(1.0, 2.0, 3.0)));
+    assert_eq!("[\n\t1 ,\n2\t,3\n]".parse(), Ok(Vec3::new(1.0, 2.0, 3.0)));
+
+    let v: Vec2 = "[inf, -inf]".parse().unwrap();
+    assert_eq!(v, Vec2::new(f32::INFINITY, f32::NEG_INFINITY));
+    let v: Vec2 = "[NaN, 0]".parse().unwrap();
+    assert!(v.x().is_nan());
+}
+
+#[test]
+fn test_parse_nested() {
+    assert_eq!("[[1, 0], [0, 1]]".parse(), Ok(Mat2::identity()));
+    assert_eq!("[[1 0 0] [0 1 0] [0 0 1]]".parse(), Ok(Mat3::identity()));
+    assert_eq!(Mat4::identity().to_string().parse(), Ok(Mat4::identity()));
+    assert_eq!(
+        "[[0, 0, 0, 1], [0, 0, 0, 0]]".parse(),
+        Ok(DualQuat::identity())
+    );
+}
+
+#[test]
+fn test_parse_mask() {
+    assert_eq!("[true, false]".parse(), Ok(Vec2Mask::new(true, false)));
+    assert_eq!(
+        "[false, true, true]".parse(),
+        Ok(Vec3Mask::new(false, true, true))
+    );
+    assert_eq!(
+        "[true, false, true, false]".parse(),
+        Ok(Vec4Mask::new(true, false, true, false))
+    );
+    assert_parse_err::<Vec2Mask>("[true, 1]", ParseErrorKind::InvalidBool, 7);
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_parse_err::<Vec2>("", ParseErrorKind::ExpectedOpenBracket, 0);
+    assert_parse_err::<Vec2>("1, 2", ParseErrorKind::ExpectedOpenBracket, 0);
+    assert_parse_err::<Vec2>("[1, x]", ParseErrorKind::InvalidFloat, 4);
+    assert_parse_err::<Vec2>("[1]", ParseErrorKind::InvalidFloat, 2);
+    assert_parse_err::<Vec2>("[1,, 2]", ParseErrorKind::InvalidFloat, 3);
+    assert_parse_err::<Vec2>("[1, 2, 3]", ParseErrorKind::ExpectedCloseBracket, 7);
+    assert_parse_err::<Vec2>("[1, 2", ParseErrorKind::ExpectedCloseBracket, 5);
+    assert_parse_err::<Vec2>("[1, 2] 3", ParseErrorKind::TrailingCharacters, 7);
+    assert_parse_err::<Mat2>("[1, 2]", ParseErrorKind::ExpectedOpenBracket, 1);
+
+    let err = "[1, x]".parse::<Vec2>().unwrap_err();
+    assert_eq!(err.to_string(), "invalid float at position 4");
+}
+
+#[test]
+fn test_round_trip() {
+    assert_round_trip(Vec2::new(0.1, -0.0));
+    assert_round_trip(Vec3::new(f32::MAX, f32::MIN_POSITIVE, 1.0e-45));
+    assert_round_trip(Vec4::new(1.0, 2.0, 3.0, 4.0) / 3.0);
+    assert_round_trip(Quat::from_rotation_y(1.0));
+    assert_round_trip(Rot2::from_angle(1.0));
+    assert_round_trip(Mat2::from_angle(1.0));
+    assert_round_trip(Mat3::from_rotation_x(1.0));
+    assert_round_trip(Mat4::from_rotation_z(1.0));
+    assert_round_trip(DualQuat::from_rotation_translation(
+        Quat::from_rotation_x(1.0),
+        Vec3::new(1.0, 2.0, 3.0),
+    ));
+    assert_round_trip(Vec2Mask::new(true, false));
+    assert_round_trip(Vec3Mask::new(false, true, false));
+    assert_round_trip(Vec4Mask::new(true, true, false, true));
+}
+
+#[cfg(feature = "transform-types")]
+#[test]
+fn test_transform_round_trip() {
+    let rt = TransformRT::from_rotation_translation(
+        Quat::from_rotation_z(0.5),
+        Vec3::new(1.0, 2.0, 3.0),
+    );
+    assert_eq!(
+        TransformRT::identity().to_string(),
+        "[[0, 0, 0, 1], [0, 0, 0]]"
+    );
+    assert_round_trip(rt);
+    assert_round_trip(TransformSRT::from_transform_rt(
+        Vec3::new(1.0, -2.0, 3.0),
+        &rt,
+    ));
+
+    let rt2 = TransformRT2::from_rotation_translation(Rot2::from_angle(0.5), Vec2::new(1.0, 2.0));
+    assert_eq!(TransformRT2::identity().to_string(), "[[1, 0], [0, 0]]");
+    assert_round_trip(rt2);
+    assert_round_trip(TransformSRT2::from_transform_rt2(
+        Vec2::new(-1.0, 2.0),
+        &rt2,
+    ));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_round_trip_rand() {
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256Plus;
+    let mut rng = Xoshiro256Plus::seed_from_u64(0);
+    // random bit patterns cover every exponent including subnormals
+    fn gen_finite<R: Rng>(rng: &mut R) -> f32 {
+        loop {
+            let f = f32::from_bits(rng.gen());
+            if f.is_finite() {
+                return f;
+            }
+        }
+    }
+    for _ in 0..1000 {
+        let mut a = [0.0; 16];
+        for f in a.iter_mut() {
+            *f = gen_finite(&mut rng);
+        }
+        assert_round_trip(Vec2::new(a[0], a[1]));
+        assert_round_trip(Vec3::new(a[0], a[1], a[2]));
+        assert_round_trip(Vec4::new(a[0], a[1], a[2], a[3]));
+        assert_round_trip(Quat::from_xyzw(a[0], a[1], a[2], a[3]));
+        assert_round_trip(Rot2::from(Vec2::new(a[0], a[1])));
+        assert_round_trip(Mat2::from_cols_array(&[a[0], a[1], a[2], a[3]]));
+        assert_round_trip(Mat3::from_cols_array(&[
+            a[0], a[1], a[2], a[3], a[4], a[5], a[6], a[7], a[8],
+        ]));
+        assert_round_trip(Mat4::from_cols_array(&a));
+        assert_round_trip(DualQuat::from_real_dual(
+            Quat::from_xyzw(a[0], a[1], a[2], a[3]),
+            Quat::from_xyzw(a[4], a[5], a[6], a[7]),
+        ));
+        #[cfg(feature = "transform-types")]
+        {
+            assert_round_trip(TransformSRT::from_scale_rotation_translation(
+                Vec3::new(a[0], a[1], a[2]),
+                Quat::from_xyzw(a[3], a[4], a[5], a[6]),
+                Vec3::new(a[7], a[8], a[9]),
+            ));
+            assert_round_trip(TransformSRT2::from_scale_rotation_translation(
+                Vec2::new(a[0], a[1]),
+                Rot2::from(Vec2::new(a[2], a[3])),
+                Vec2::new(a[4], a[5]),
+            ));
+        }
+        let bits: u32 = rng.gen();
+        assert_round_trip(Vec4Mask::new(
+            bits & 1 != 0,
+            bits & 2 != 0,
+            bits & 4 != 0,
+            bits & 8 != 0,
+        ));
+    }
+}
@@ -0,0 +1,599 @@
+use glam::predicates::{incircle, insphere, orient2d, orient3d};
+use glam::{Vec2, Vec3};
+use std::cmp::Ordering;
+
+/// An arbitrary precision integer, just enough to evaluate the determinants
+/// exactly as a reference.
+#[derive(Clone, Debug)]
+struct Int {
+    negative: bool,
+    // little endian, with no trailing zeros
+    digits: Vec<u32>,
+}
+
+impl Int {
+    fn zero() -> Self {
+        Self {
+            negative: false,
+            digits: Vec::new(),
+        }
+    }
+
+    /// Returns `x * 2^149`, which is an integer for every finite `f32`.
+    fn from_f32(x: f32) -> Self {
+        assert!(x.is_finite());
+        let bits = x.to_bits();
+        let exponent = (bits >> 23) & 0xff;
+        let mantissa = bits & 0x7f_ffff;
+        let (mantissa, shift) = if exponent == 0 {
+            (mantissa, 0)
+        } else {
+            (mantissa | 0x80_0000, exponent - 1)
+        };
+        let mut digits = vec![0; (shift / 32) as usize];
+        let wide = (mantissa as u64) << (shift % 32);
+        digits.push(wide as u32);
+        digits.push((wide >> 32) as u32);
+        Self::new(x < 0.0, digits)
+    }
+
+    fn new(negative: bool, mut digits: Vec<u32>) -> Self {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        let negative = negative && !digits.is_empty();
+        Self { negative, digits }
+    }
+
+    fn sign(&self) -> Ordering {
+        if self.digits.is_empty() {
+            Ordering::Equal
+        } else if self.negative {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+
+    fn cmp_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+        a.len()
+            .cmp(&b.len())
+            .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+    }
+
+    fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let mut digits = Vec::with_capacity(a.len().max(b.len()) + 1);
+        let mut carry = 0_u64;
+        for i in 0..a.len().max(b.len()) {
+            let sum = carry + *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64;
+            digits.push(sum as u32);
+            carry = sum >> 32;
+        }
+        digits.push(carry as u32);
+        digits
+    }
+
+    /// Returns `a - b` for `a >= b`.
+    fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let mut digits = Vec::with_capacity(a.len());
+        let mut borrow = 0_i64;
+        for (i, &digit) in a.iter().enumerate() {
+            let mut diff = digit as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+            borrow = (diff < 0) as i64;
+            diff += borrow << 32;
+            digits.push(diff as u32);
+        }
+        digits
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        if self.negative == other.negative {
+            return Self::new(
+                self.negative,
+                Self::add_magnitude(&self.digits, &other.digits),
+            );
+        }
+        match Self::cmp_magnitude(&self.digits, &other.digits) {
+            Ordering::Less => Self::new(
+                other.negative,
+                Self::sub_magnitude(&other.digits, &self.digits),
+            ),
+            _ => Self::new(
+                self.negative,
+                Self::sub_magnitude(&self.digits, &other.digits),
+            ),
+        }
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self.add(&Self::new(!other.negative, other.digits.clone()))
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        let mut digits = vec![0_u32; self.digits.len() + other.digits.len()];
+        for (i, &a) in self.digits.iter().enumerate() {
+            let mut carry = 0_u64;
+            for (j, &b) in other.digits.iter().enumerate() {
+                let product = a as u64 * b as u64 + digits[i + j] as u64 + carry;
+                digits[i + j] = product as u32;
+                carry = product >> 32;
+            }
+            digits[i + other.digits.len()] = carry as u32;
+        }
+        Self::new(self.negative != other.negative, digits)
+    }
+}
+
+fn int2(v: Vec2) -> [Int; 2] {
+    [Int::from_f32(v.x()), Int::from_f32(v.y())]
+}
+
+fn int3(v: Vec3) -> [Int; 3] {
+    [
+        Int::from_f32(v.x()),
+        Int::from_f32(v.y()),
+        Int::from_f32(v.z()),
+    ]
+}
+
+fn det2(a: &Int, b: &Int, c: &Int, d: &Int) -> Int {
+    a.mul(d).sub(&b.mul(c))
+}
+
+/// The determinant of the rows `a`, `b` and `c` by cofactor expansion.
+fn det3(a: &[Int; 3], b: &[Int; 3], c: &[Int; 3]) -> Int {
+    a[0].mul(&det2(&b[1], &b[2], &c[1], &c[2]))
+        .sub(&a[1].mul(&det2(&b[0], &b[2], &c[0], &c[2])))
+        .add(&a[2].mul(&det2(&b[0], &b[1], &c[0], &c[1])))
+}
+
+/// The determinant of the rows `(x, y, x^2 + y^2, 1)` for each point.
+fn incircle_reference(points: [Vec2; 4]) -> Ordering {
+    let rows: Vec<[Int; 3]> = points
+        .iter()
+        .map(|&p| {
+            let [x, y] = int2(p);
+            let lift = x.mul(&x).add(&y.mul(&y));
+            [x, y, lift]
+        })
+        .collect();
+    det4_with_ones(&rows).sign()
+}
+
+/// The determinant of the rows `(x, y, z, x^2 + y^2 + z^2, 1)` for each
+/// point.
+fn insphere_reference(points: [Vec3; 5]) -> Ordering {
+    let rows: Vec<[Int; 4]> = points
+        .iter()
+        .map(|&p| {
+            let [x, y, z] = int3(p);
+            let lift = x.mul(&x).add(&y.mul(&y)).add(&z.mul(&z));
+            [x, y, z, lift]
+        })
+        .collect();
+    // expand along the column of ones
+    let mut det = Int::zero();
+    for skip in 0..5 {
+        let minor: Vec<&[Int; 4]> = (0..5).filter(|&i| i != skip).map(|i| &rows[i]).collect();
+        let minor = det4(&minor);
+        det = if (skip + 4) % 2 == 0 {
+            det.add(&minor)
+        } else {
+            det.sub(&minor)
+        };
+    }
+    det.sign()
+}
+
+/// The determinant of four rows by cofactor expansion.
+fn det4(rows: &[&[Int; 4]]) -> Int {
+    let mut det = Int::zero();
+    for col in 0..4 {
+        let mut minor = Vec::new();
+        for row in &rows[1..] {
+            let mut r = row.iter().enumerate().filter(|&(c, _)| c != col);
+            minor.push([
+                r.next().unwrap().1.clone(),
+                r.next().unwrap().1.clone(),
+                r.next().unwrap().1.clone(),
+            ]);
+        }
+        let term = rows[0][col].mul(&det3(&minor[0], &minor[1], &minor[2]));
+        det = if col % 2 == 0 {
+            det.add(&term)
+        } else {
+            det.sub(&term)
+        };
+    }
+    det
+}
+
+/// The determinant of four rows with a fourth column of ones.
+fn det4_with_ones(rows: &[[Int; 3]]) -> Int {
+    let mut det = Int::zero();
+    for skip in 0..4 {
+        let minor: Vec<&[Int; 3]> = (0..4).filter(|&i| i != skip).map(|i| &rows[i]).collect();
+        let minor = det3(minor[0], minor[1], minor[2]);
+        det = if (skip + 3) % 2 == 0 {
+            det.add(&minor)
+        } else {
+            det.sub(&minor)
+        };
+    }
+    det
+}
+
+fn orient2d_reference(a: Vec2, b: Vec2, c: Vec2) -> Ordering {
+    let [ax, ay] = int2(a);
+    let [bx, by] = int2(b);
+    let [cx, cy] = int2(c);
+    det2(&ax.sub(&cx), &ay.sub(&cy), &bx.sub(&cx), &by.sub(&cy)).sign()
+}
+
+fn orient3d_reference(a: Vec3, b: Vec3, c: Vec3, d: Vec3) -> Ordering {
+    let [dx, dy, dz] = int3(d);
+    let rel = |p: Vec3| {
+        let [x, y, z] = int3(p);
+        [x.sub(&dx), y.sub(&dy), z.sub(&dz)]
+    };
+    det3(&rel(a), &rel(b), &rel(c)).sign()
+}
+
+fn sign(x: f64) -> Ordering {
+    x.partial_cmp(&0.0).unwrap()
+}
+
+fn check_orient2d(a: Vec2, b: Vec2, c: Vec2) {
+    let expected = orient2d_reference(a, b, c);
+    assert_eq!(
+        sign(orient2d(a, b, c)),
+        expected,
+        "orient2d({:?}, {:?}, {:?})",
+        a,
+        b,
+        c
+    );
+    // swapping two points reverses the orientation
+    assert_eq!(sign(orient2d(b, a, c)), expected.reverse());
+    assert_eq!(sign(orient2d(b, c, a)), expected);
+}
+
+fn check_orient3d(a: Vec3, b: Vec3, c: Vec3, d: Vec3) {
+    let expected = orient3d_reference(a, b, c, d);
+    assert_eq!(
+        sign(orient3d(a, b, c, d)),
+        expected,
+        "orient3d({:?}, {:?}, {:?}, {:?})",
+        a,
+        b,
+        c,
+        d
+    );
+    assert_eq!(sign(orient3d(b, a, c, d)), expected.reverse());
+    assert_eq!(sign(orient3d(a, b, d, c)), expected.reverse());
+}
+
+fn check_incircle(a: Vec2, b: Vec2, c: Vec2, d: Vec2) {
+    let expected = incircle_reference([a, b, c, d]);
+    assert_eq!(
+        sign(incircle(a, b, c, d)),
+        expected,
+        "incircle({:?}, {:?}, {:?}, {:?})",
+        a,
+        b,
+        c,
+        d
+    );
+    assert_eq!(sign(incircle(b, a, c, d)), expected.reverse());
+}
+
+fn check_insphere(a: Vec3, b: Vec3, c: Vec3, d: Vec3, e: Vec3) {
+    let expected = insphere_reference([a, b, c, d, e]);
+    assert_eq!(
+        sign(insphere(a, b, c, d, e)),
+        expected,
+        "insphere({:?}, {:?}, {:?}, {:?}, {:?})",
+        a,
+        b,
+        c,
+        d,
+        e
+    );
+    assert_eq!(sign(insphere(b, a, c, d, e)), expected.reverse());
+}
+
+/// Steps `x` by `n` representable values, towards positive infinity if `n` is
+/// positive.
+fn ulps(x: f32, n: i32) -> f32 {
+    let mut x = x;
+    for _ in 0..n.abs() {
+        x = match (x == 0.0, (x > 0.0) == (n > 0)) {
+            (true, _) => f32::from_bits(1).copysign(n as f32),
+            (false, true) => f32::from_bits(x.to_bits() + 1),
+            (false, false) => f32::from_bits(x.to_bits() - 1),
+        };
+    }
+    x
+}
+
+/// A small deterministic generator for the randomized tests.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next_f32(&mut self) -> f32 {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        ((self.0 >> 40) as f32 / (1 << 24) as f32) * 2.0 - 1.0
+    }
+
+    fn vec2(&mut self) -> Vec2 {
+        Vec2::new(self.next_f32(), self.next_f32())
+    }
+
+    fn vec3(&mut self) -> Vec3 {
+        Vec3::new(self.next_f32(), self.next_f32(), self.next_f32())
+    }
+}
+
+/// The scales and offsets applied to the randomized tests, from tiny to huge.
+const SCALES: [(f32, f32); 6] = [
+    (1.0, 0.0),
+    (1.0, 1000.0),
+    (1e-30, 0.0),
+    (1e-20, 1e-15),
+    (1e15, 0.0),
+    (1e9, 1e16),
+];
+
+#[test]
+fn test_orient2d_simple() {
+    let (a, b) = (Vec2::zero(), Vec2::unit_x());
+    assert!(orient2d(a, b, Vec2::unit_y()) > 0.0);
+    assert!(orient2d(a, b, -Vec2::unit_y()) < 0.0);
+    assert_eq!(orient2d(a, b, Vec2::new(2.0, 0.0)), 0.0);
+    assert_eq!(orient2d(a, b, Vec2::new(3.0, 4.0)), 4.0);
+}
+
+#[test]
+fn test_orient2d_grid() {
+    // the classic near-collinear grid from Shewchuk's paper, where a naive
+    // evaluation gives inconsistent signs
+    let b = Vec2::new(12.0, 12.0);
+    let c = Vec2::new(24.0, 24.0);
+    let mut naive_wrong = 0;
+    for i in 0..64 {
+        for j in 0..64 {
+            let a = Vec2::new(ulps(0.5, i), ulps(0.5, j));
+            check_orient2d(a, b, c);
+            let naive = (a - c).perp_dot(b - c);
+            if naive.partial_cmp(&0.0) != Some(orient2d_reference(a, b, c)) {
+                naive_wrong += 1;
+            }
+        }
+    }
+    assert!(naive_wrong > 0);
+}
+
+#[test]
+fn test_orient2d_degenerate() {
+    let mut rng = Lcg(1);
+    for &(scale, offset) in &SCALES {
+        for _ in 0..200 {
+            let a = rng.vec2() * scale + Vec2::splat(offset);
+            let b = rng.vec2() * scale + Vec2::splat(offset);
+            let t = rng.next_f32() * 2.0;
+            // c is on the line through a and b up to rounding
+            let c = a.lerp(b, t);
+            for &(i, j) in &[(0, 0), (1, 0), (-1, 0), (0, 1), (0, -1), (2, -3)] {
+                check_orient2d(a, b, Vec2::new(ulps(c.x(), i), ulps(c.y(), j)));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_orient2d_extremes() {
+    let max = f32::MAX;
+    let tiny = f32::from_bits(1);
+    check_orient2d(Vec2::new(-max, -max), Vec2::new(max, max), Vec2::zero());
+    check_orient2d(
+        Vec2::new(-max, -max),
+        Vec2::new(max, max),
+        Vec2::new(0.0, tiny),
+    );
+    check_orient2d(
+        Vec2::new(tiny, 0.0),
+        Vec2::new(0.0, tiny),
+        Vec2::new(tiny, tiny),
+    );
+    check_orient2d(
+        Vec2::new(1e-30, 1e30),
+        Vec2::new(-1e30, 1e-30),
+        Vec2::new(tiny, -tiny),
+    );
+    check_orient2d(Vec2::new(1.0, 1.0), Vec2::new(1e-40, 1e-40), Vec2::zero());
+}
+
+#[test]
+fn test_orient3d_simple() {
+    let (a, b, c) = (Vec3::zero(), Vec3::unit_x(), Vec3::unit_y());
+    // a, b and c are counterclockwise seen from above, d is below
+    assert!(orient3d(a, b, c, -Vec3::unit_z()) > 0.0);
+    assert!(orient3d(a, b, c, Vec3::unit_z()) < 0.0);
+    assert_eq!(orient3d(a, b, c, Vec3::new(5.0, -7.0, 0.0)), 0.0);
+    assert_eq!(orient3d(a, b, c, Vec3::new(0.0, 0.0, -2.0)), 2.0);
+}
+
+#[test]
+fn test_orient3d_degenerate() {
+    let mut rng = Lcg(2);
+    for &(scale, offset) in &SCALES {
+        for _ in 0..100 {
+            let a = rng.vec3() * scale + Vec3::splat(offset);
+            let b = rng.vec3() * scale + Vec3::splat(offset);
+            let c = rng.vec3() * scale + Vec3::splat(offset);
+            // d is on the plane through a, b and c up to rounding
+            let d = a + (b - a) * rng.next_f32() + (c - a) * rng.next_f32();
+            for &(i, j, k) in &[(0, 0, 0), (1, 0, 0), (0, -1, 0), (0, 0, 1), (-2, 1, 3)] {
+                let d = Vec3::new(ulps(d.x(), i), ulps(d.y(), j), ulps(d.z(), k));
+                check_orient3d(a, b, c, d);
+            }
+            // collinear and coincident points
+            check_orient3d(a, b, a.lerp(b, 0.5), d);
+            check_orient3d(a, a, c, d);
+        }
+    }
+}
+
+#[test]
+fn test_orient3d_grid() {
+    let b = Vec3::new(12.0, 12.0, 12.0);
+    let c = Vec3::new(24.0, 24.0, 24.0);
+    let d = Vec3::new(1.0, -3.0, 2.0);
+    for i in 0..16 {
+        for j in 0..16 {
+            for k in 0..4 {
+                let a = Vec3::new(ulps(0.5, i), ulps(0.5, j), ulps(0.5, k));
+                check_orient3d(a, b, c, d);
+                // d nearly on the line through a, b and c
+                check_orient3d(b, c, Vec3::new(36.0, 36.0, 36.0), a);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_incircle_simple() {
+    let (a, b, c) = (Vec2::unit_x(), Vec2::unit_y(), -Vec2::unit_x());
+    assert!(incircle(a, b, c, Vec2::zero()) > 0.0);
+    assert!(incircle(a, b, c, Vec2::new(2.0, 0.0)) < 0.0);
+    assert_eq!(incircle(a, b, c, -Vec2::unit_y()), 0.0);
+    // clockwise order reverses the sign
+    assert!(incircle(c, b, a, Vec2::zero()) < 0.0);
+}
+
+#[test]
+fn test_incircle_degenerate() {
+    // integer points on a circle of radius 5, which are exactly cocircular
+    let circle = [
+        Vec2::new(5.0, 0.0),
+        Vec2::new(4.0, 3.0),
+        Vec2::new(3.0, 4.0),
+        Vec2::new(0.0, 5.0),
+        Vec2::new(-3.0, 4.0),
+        Vec2::new(-4.0, -3.0),
+        Vec2::new(0.0, -5.0),
+        Vec2::new(4.0, -3.0),
+    ];
+    // scaling by a power of two keeps the points exactly cocircular
+    for &(scale, offset) in &[
+        (1.0, 0.0),
+        (2_f32.powi(-100), 0.0),
+        (2_f32.powi(70), 0.0),
+        (1.0, 1e4),
+    ] {
+        let p: Vec<Vec2> = circle
+            .iter()
+            .map(|&p| p * scale + Vec2::splat(offset))
+            .collect();
+        assert_eq!(incircle(p[0], p[2], p[4], p[6]), 0.0);
+        for i in 0..p.len() {
+            let (a, b, c) = (p[i], p[(i + 2) % 8], p[(i + 5) % 8]);
+            let d = p[(i + 7) % 8];
+            for &(x, y) in &[(0, 0), (1, 0), (-1, 0), (0, 1), (0, -1), (3, -2)] {
+                check_incircle(a, b, c, Vec2::new(ulps(d.x(), x), ulps(d.y(), y)));
+            }
+        }
+    }
+
+    // points on a circle up to rounding
+    let mut rng = Lcg(3);
+    for &(scale, offset) in &SCALES {
+        for _ in 0..100 {
+            let center = rng.vec2() * scale + Vec2::splat(offset);
+            let radius = (rng.next_f32().abs() + 0.1) * scale;
+            let mut point = || {
+                let angle = rng.next_f32() * 3.0;
+                center + Vec2::new(angle.cos(), angle.sin()) * radius
+            };
+            let (a, b, c, d) = (point(), point(), point(), point());
+            check_incircle(a, b, c, d);
+            check_incircle(a, b, c, Vec2::new(ulps(d.x(), 1), d.y()));
+            check_incircle(a, b, c, Vec2::new(d.x(), ulps(d.y(), -1)));
+            // collinear points
+            check_incircle(a, b, a.lerp(b, 0.5), d);
+        }
+    }
+}
+
+#[test]
+fn test_insphere_simple() {
+    let (a, b, c, d) = (
+        Vec3::unit_x(),
+        Vec3::unit_y(),
+        -Vec3::unit_x(),
+        Vec3::unit_z(),
+    );
+    assert!(orient3d(a, b, c, d) < 0.0);
+    // swap two points for a positive orientation
+    let (a, b) = (b, a);
+    assert!(insphere(a, b, c, d, Vec3::zero()) > 0.0);
+    assert!(insphere(a, b, c, d, Vec3::splat(1.0)) < 0.0);
+    assert_eq!(insphere(a, b, c, d, -Vec3::unit_z()), 0.0);
+}
+
+#[test]
+fn test_insphere_degenerate() {
+    // integer points on a sphere of radius 3, which are exactly cospherical
+    let sphere = [
+        Vec3::new(3.0, 0.0, 0.0),
+        Vec3::new(1.0, 2.0, 2.0),
+        Vec3::new(-2.0, 1.0, 2.0),
+        Vec3::new(2.0, -2.0, 1.0),
+        Vec3::new(0.0, 0.0, -3.0),
+        Vec3::new(-2.0, -1.0, -2.0),
+        Vec3::new(0.0, 3.0, 0.0),
+    ];
+    for &(scale, offset) in &[
+        (1.0, 0.0),
+        (2_f32.powi(-80), 0.0),
+        (2_f32.powi(50), 0.0),
+        (1.0, 1e3),
+    ] {
+        let p: Vec<Vec3> = sphere
+            .iter()
+            .map(|&p| p * scale + Vec3::splat(offset))
+            .collect();
+        assert_eq!(insphere(p[0], p[1], p[2], p[3], p[4]), 0.0);
+        for i in 0..p.len() {
+            let points: Vec<Vec3> = (0..5).map(|j| p[(i + j) % 7]).collect();
+            let e = points[4];
+            for &(x, y, z) in &[(0, 0, 0), (1, 0, 0), (0, -1, 0), (0, 0, 1), (2, -1, -3)] {
+                let e = Vec3::new(ulps(e.x(), x), ulps(e.y(), y), ulps(e.z(), z));
+                check_insphere(points[0], points[1], points[2], points[3], e);
+            }
+        }
+    }
+
+    // points on a sphere up to rounding
+    let mut rng = Lcg(4);
+    for &(scale, offset) in &SCALES {
+        for _ in 0..50 {
+            let center = rng.vec3() * scale + Vec3::splat(offset);
+            let radius = (rng.next_f32().abs() + 0.1) * scale;
+            let mut point = || {
+                let (theta, phi) = (rng.next_f32() * 3.0, rng.next_f32() * 1.5);
+                let (sin_phi, cos_phi) = phi.sin_cos();
+                center + Vec3::new(theta.cos() * cos_phi, theta.sin() * cos_phi, sin_phi) * radius
+            };
+            let (a, b, c, d, e) = (point(), point(), point(), point(), point());
+            check_insphere(a, b, c, d, e);
+            check_insphere(a, b, c, d, Vec3::new(ulps(e.x(), 1), e.y(), e.z()));
+            check_insphere(a, b, c, d, Vec3::new(e.x(), e.y(), ulps(e.z(), -1)));
+            // coplanar points
+            check_insphere(a, b, c, a + (b - a) + (c - a), e);
+        }
+    }
+}
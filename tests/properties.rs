@@ -0,0 +1,176 @@
+#![cfg(feature = "proptest")]
+
+use glam::proptest::*;
+use glam::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec4};
+use proptest::prelude::*;
+
+/// The largest scale magnitude used for invertible matrices, giving a
+/// condition number of at most 16.
+const SCALE: core::ops::Range<f32> = 0.25..4.0;
+
+macro_rules! impl_mat_properties {
+    ($t:ident, $mat:ident, $invertible:ident, $orthonormal:ident, $inverse:ident, $transpose:ident, $orthonormal_inverse:ident) => {
+        proptest! {
+            #[test]
+            fn $inverse(m in $invertible(SCALE)) {
+                let inverse = m.inverse();
+                prop_assert!((m * inverse).abs_diff_eq($t::identity(), 1e-4));
+                prop_assert!((inverse * m).abs_diff_eq($t::identity(), 1e-4));
+                prop_assert!(inverse.inverse().abs_diff_eq(m, 1e-3));
+                prop_assert!((m.determinant() * inverse.determinant() - 1.0).abs() < 1e-4);
+            }
+
+            #[test]
+            fn $transpose(a in $mat(-10.0..10.0), b in $mat(-10.0..10.0)) {
+                prop_assert_eq!(a.transpose().transpose(), a);
+                prop_assert!((a * b)
+                    .transpose()
+                    .abs_diff_eq(b.transpose() * a.transpose(), 1e-3));
+                prop_assert!((a.determinant() - a.transpose().determinant()).abs() < 1e-1);
+            }
+
+            #[test]
+            fn $orthonormal_inverse(m in $orthonormal()) {
+                prop_assert!(m.transpose().abs_diff_eq(m.inverse(), 1e-5));
+                prop_assert!((m.determinant() - 1.0).abs() < 1e-5);
+            }
+        }
+    };
+}
+
+impl_mat_properties!(
+    Mat2,
+    mat2,
+    invertible_mat2,
+    orthonormal_mat2,
+    test_mat2_inverse,
+    test_mat2_transpose,
+    test_mat2_orthonormal_inverse
+);
+impl_mat_properties!(
+    Mat3,
+    mat3,
+    invertible_mat3,
+    orthonormal_mat3,
+    test_mat3_inverse,
+    test_mat3_transpose,
+    test_mat3_orthonormal_inverse
+);
+impl_mat_properties!(
+    Mat3A,
+    mat3a,
+    invertible_mat3a,
+    orthonormal_mat3a,
+    test_mat3a_inverse,
+    test_mat3a_transpose,
+    test_mat3a_orthonormal_inverse
+);
+impl_mat_properties!(
+    Mat4,
+    mat4,
+    invertible_mat4,
+    orthonormal_mat4,
+    test_mat4_inverse,
+    test_mat4_transpose,
+    test_mat4_orthonormal_inverse
+);
+
+/// Returns the angle between two quaternions in 4D, which slerp interpolates
+/// linearly. Unlike `acos` of the dot product this is accurate for small
+/// angles.
+fn quat_angle(a: Quat, b: Quat) -> f32 {
+    let (a, b) = (Vec4::from(a), Vec4::from(b));
+    2.0 * (a - b).length().atan2((a + b).length())
+}
+
+proptest! {
+    #[test]
+    fn test_quat_slerp(a in quat(), b in quat(), s in 0.0f32..1.0) {
+        // the SSE2 version uses an approximate reciprocal
+        prop_assert!(a.slerp(b, 0.0).abs_diff_eq(a, 1e-3));
+        prop_assert!(a.slerp(b, 1.0).abs_diff_eq(b, 1e-3));
+
+        let q = a.slerp(b, s);
+        prop_assert!((q.length() - 1.0).abs() < 1e-3);
+        let theta = quat_angle(a, b);
+        prop_assert!((quat_angle(a, q) - s * theta).abs() < 1e-2);
+        prop_assert!((quat_angle(q, b) - (1.0 - s) * theta).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_mat4_to_scale_rotation_translation(
+        scale in vec3(0.25..4.0),
+        rotation in quat(),
+        translation in vec3(-100.0..100.0),
+    ) {
+        let m = Mat4::from_scale_rotation_translation(scale, rotation, translation);
+        let (s, r, t) = m.to_scale_rotation_translation();
+        prop_assert!(s.abs_diff_eq(scale, 1e-4));
+        // q and -q are the same rotation
+        prop_assert!((r.dot(rotation).abs() - 1.0).abs() < 1e-4);
+        prop_assert_eq!(t, translation);
+    }
+
+    #[test]
+    fn test_mat4_affine_round_trip(m in affine_mat4(0.25..4.0, -100.0..100.0)) {
+        let (s, r, t) = m.to_scale_rotation_translation();
+        prop_assert!(r.is_normalized());
+        prop_assert!(Mat4::from_scale_rotation_translation(s, r, t).abs_diff_eq(m, 1e-4));
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck_properties {
+    use glam::{Affine, Invertible, Mat2, Mat3, Mat4, Orthonormal, Quat};
+    use quickcheck::quickcheck;
+
+    #[test]
+    fn test_quickcheck_subsets() {
+        fn quat(q: Quat) -> bool {
+            q.is_normalized()
+        }
+        fn orthonormal(m: Orthonormal<Mat3>) -> bool {
+            (m.0 * m.0.transpose()).abs_diff_eq(Mat3::identity(), 1e-5)
+        }
+        fn invertible(m: Invertible<Mat2>) -> bool {
+            (m.0 * m.0.inverse()).abs_diff_eq(Mat2::identity(), 1e-4)
+        }
+        fn affine(m: Affine<Mat4>) -> bool {
+            let (s, r, t) = m.0.to_scale_rotation_translation();
+            Mat4::from_scale_rotation_translation(s, r, t).abs_diff_eq(m.0, 1e-3)
+        }
+        quickcheck(quat as fn(Quat) -> bool);
+        quickcheck(orthonormal as fn(Orthonormal<Mat3>) -> bool);
+        quickcheck(invertible as fn(Invertible<Mat2>) -> bool);
+        quickcheck(affine as fn(Affine<Mat4>) -> bool);
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_properties {
+    use arbitrary::{Arbitrary, Unstructured};
+    use glam::{Invertible, Mat3A, Mat4, Quat, Vec4};
+
+    #[test]
+    fn test_arbitrary_subsets() {
+        let bytes: Vec<u8> = (0..8192_u32)
+            .map(|i| (i.wrapping_mul(0x9e37_79b9) >> 24) as u8)
+            .collect();
+        let mut u = Unstructured::new(&bytes);
+        for _ in 0..32 {
+            assert!(Quat::arbitrary(&mut u).unwrap().is_normalized());
+            let m = Invertible::<Mat4>::arbitrary(&mut u).unwrap().0;
+            assert!((m * m.inverse()).abs_diff_eq(Mat4::identity(), 1e-3));
+            let m = Invertible::<Mat3A>::arbitrary(&mut u).unwrap().0;
+            assert!((m * m.inverse()).abs_diff_eq(Mat3A::identity(), 1e-3));
+        }
+        // elements are any bit pattern
+        let v = Vec4::arbitrary(&mut Unstructured::new(&[0xff; 16])).unwrap();
+        assert!(v.x().is_nan());
+        assert_eq!(
+            Vec4::size_hint(0),
+            (16, Some(16)),
+            "vectors read one f32 per element"
+        );
+    }
+}
@@ -212,6 +212,13 @@ fn test_quat_fmt() {
     assert_eq!(format!("{}", a), "[0, 0, 0, 1]");
 }
 
+#[test]
+fn test_quat_consts() {
+    const Q: Quat = quat(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(Quat::from_xyzw(1.0, 2.0, 3.0, 4.0), Q);
+    assert_eq!(Quat::identity(), Quat::IDENTITY);
+}
+
 #[test]
 fn test_quat_identity() {
     let identity = Quat::identity();
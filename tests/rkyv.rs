@@ -0,0 +1,134 @@
+#![cfg(feature = "rkyv")]
+
+use glam::f32::*;
+use rkyv::{archived_root, Archive, Deserialize, Infallible};
+use std::fmt::Debug;
+use std::mem;
+
+/// Returns the expected archive of a type containing the given floats.
+///
+/// The archived layout does not depend on whether SIMD is enabled, so this is
+/// the same for default and `scalar-math` builds.
+fn expected_bytes(floats: &[f32]) -> Vec<u8> {
+    floats
+        .iter()
+        .flat_map(|f| f.to_ne_bytes().to_vec())
+        .collect()
+}
+
+fn assert_archive<T>(value: T, floats: &[f32])
+where
+    T: Archive + rkyv::Serialize<rkyv::ser::serializers::AllocSerializer<256>> + Debug + PartialEq,
+    T::Archived: Deserialize<T, Infallible>,
+{
+    let bytes = rkyv::to_bytes::<_, 256>(&value).unwrap();
+    assert_eq!(bytes.as_slice(), expected_bytes(floats).as_slice());
+    assert_eq!(mem::size_of::<T::Archived>(), floats.len() * 4);
+    assert_eq!(mem::align_of::<T::Archived>(), 4);
+
+    let archived = unsafe { archived_root::<T>(&bytes) };
+    let deserialized: T = archived.deserialize(&mut Infallible).unwrap();
+    assert_eq!(deserialized, value);
+}
+
+#[test]
+fn test_archive_vec() {
+    assert_archive(Vec2::new(1.0, 2.0), &[1.0, 2.0]);
+    assert_archive(Vec3::new(1.0, 2.0, 3.0), &[1.0, 2.0, 3.0]);
+    assert_archive(Vec4::new(1.0, 2.0, 3.0, 4.0), &[1.0, 2.0, 3.0, 4.0]);
+    assert_archive(Vec3A::new(1.0, 2.0, 3.0), &[1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn test_archive_rotation() {
+    assert_archive(Quat::from_xyzw(0.0, 0.6, 0.0, 0.8), &[0.0, 0.6, 0.0, 0.8]);
+    assert_archive(Rot2::from_cos_sin(0.6, 0.8), &[0.6, 0.8]);
+    assert_archive(
+        DualQuat::from_translation(Vec3::new(2.0, 4.0, 6.0)),
+        &[0.0, 0.0, 0.0, 1.0, 1.0, 2.0, 3.0, 0.0],
+    );
+}
+
+#[test]
+fn test_archive_mat() {
+    assert_archive(
+        Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]),
+        &[1.0, 2.0, 3.0, 4.0],
+    );
+    let m3 = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+    assert_archive(Mat3::from_cols_array(&m3), &m3);
+    assert_archive(Mat3A::from_cols_array(&m3), &m3);
+    let m4 = [
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    ];
+    assert_archive(Mat4::from_cols_array(&m4), &m4);
+}
+
+#[test]
+fn test_archive_layout() {
+    // the SIMD types archive to the same bytes as their unaligned
+    // counterparts, so archives written by a `scalar-math` build can be read
+    // by a default build and vice versa
+    let v = Vec3A::new(1.0, 2.0, 3.0);
+    assert_eq!(
+        rkyv::to_bytes::<_, 256>(&v).unwrap().as_slice(),
+        rkyv::to_bytes::<_, 256>(&Vec3::from(v)).unwrap().as_slice()
+    );
+    assert_eq!(
+        mem::size_of::<<Vec3A as Archive>::Archived>(),
+        mem::size_of::<<Vec3 as Archive>::Archived>()
+    );
+
+    let m = Mat3A::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    assert_eq!(
+        rkyv::to_bytes::<_, 256>(&m).unwrap().as_slice(),
+        rkyv::to_bytes::<_, 256>(&Mat3::from(m)).unwrap().as_slice()
+    );
+    assert_eq!(
+        mem::size_of::<<Mat3A as Archive>::Archived>(),
+        mem::size_of::<<Mat3 as Archive>::Archived>()
+    );
+
+    // the archive of a `Vec4` or `Mat4` does not depend on SIMD alignment
+    assert_eq!(mem::size_of::<<Vec4 as Archive>::Archived>(), 16);
+    assert_eq!(mem::align_of::<<Vec4 as Archive>::Archived>(), 4);
+    assert_eq!(mem::size_of::<<Mat4 as Archive>::Archived>(), 64);
+    assert_eq!(mem::align_of::<<Mat4 as Archive>::Archived>(), 4);
+}
+
+#[cfg(feature = "transform-types")]
+#[test]
+fn test_archive_transform() {
+    let rotation = Quat::from_xyzw(0.0, 0.6, 0.0, 0.8);
+    let rt = TransformRT::from_rotation_translation(rotation, Vec3::new(1.0, 2.0, 3.0));
+    assert_archive(rt, &[0.0, 0.6, 0.0, 0.8, 1.0, 2.0, 3.0]);
+    assert_archive(
+        TransformSRT::from_transform_rt(Vec3::new(4.0, 5.0, 6.0), &rt),
+        &[4.0, 5.0, 6.0, 0.0, 0.6, 0.0, 0.8, 1.0, 2.0, 3.0],
+    );
+
+    let rt2 =
+        TransformRT2::from_rotation_translation(Rot2::from_cos_sin(0.6, 0.8), Vec2::new(1.0, 2.0));
+    assert_archive(rt2, &[0.6, 0.8, 1.0, 2.0]);
+    assert_archive(
+        TransformSRT2::from_transform_rt2(Vec2::new(3.0, 4.0), &rt2),
+        &[3.0, 4.0, 0.6, 0.8, 1.0, 2.0],
+    );
+}
+
+#[test]
+fn test_archive_zero_copy() {
+    let positions: Vec<Vec3> = (0..100)
+        .map(|i| Vec3::new(i as f32, (i * 2) as f32, (i * 3) as f32))
+        .collect();
+    let bytes = rkyv::to_bytes::<_, 4096>(&positions).unwrap();
+    let archived = unsafe { archived_root::<Vec<Vec3>>(&bytes) };
+    assert_eq!(archived.len(), positions.len());
+    for (a, p) in archived.iter().zip(&positions) {
+        assert_eq!(a.x, p.x());
+        assert_eq!(a.y, p.y());
+        assert_eq!(a.z, p.z());
+    }
+    let deserialized: Vec<Vec3> = archived.deserialize(&mut Infallible).unwrap();
+    assert_eq!(deserialized, positions);
+}
@@ -0,0 +1,131 @@
+mod support;
+
+use glam::f32::{Mat2, Rot2, Vec2};
+use support::deg;
+
+#[test]
+fn test_rot2_identity() {
+    let r = Rot2::identity();
+    assert_eq!(r, Rot2::default());
+    assert_eq!(r.cos(), 1.0);
+    assert_eq!(r.sin(), 0.0);
+    assert_eq!(r.angle(), 0.0);
+    assert_eq!(r * Vec2::new(1.0, 2.0), Vec2::new(1.0, 2.0));
+    assert_eq!(Mat2::from(r), Mat2::identity());
+}
+
+#[test]
+fn test_rot2_from_angle() {
+    let r = Rot2::from_angle(deg(90.0));
+    assert!(r.is_normalized());
+    assert_approx_eq!(r.angle(), deg(90.0));
+    assert_approx_eq!(r * Vec2::unit_x(), Vec2::unit_y());
+    assert_approx_eq!(r * Vec2::unit_y(), -Vec2::unit_x());
+    assert_approx_eq!(Mat2::from(r), Mat2::from_angle(deg(90.0)));
+    assert_approx_eq!(Rot2::from_rotation_mat2(&Mat2::from_angle(deg(90.0))), r);
+
+    let r = Rot2::from_angle(deg(-135.0));
+    assert_approx_eq!(r.angle(), deg(-135.0), 1e-6);
+    assert_approx_eq!(r, Rot2::from_cos_sin(deg(-135.0).cos(), deg(-135.0).sin()));
+}
+
+#[test]
+fn test_rot2_from_rotation_arc() {
+    let from = Vec2::new(1.0, 1.0).normalize();
+    let to = Vec2::new(-1.0, 0.0);
+    let r = Rot2::from_rotation_arc(from, to);
+    assert!(r.is_normalized());
+    assert_approx_eq!(r * from, to);
+    assert_approx_eq!(r.angle(), deg(135.0), 1e-6);
+    let r = Rot2::from_rotation_arc(to, from);
+    assert_approx_eq!(r.angle(), deg(-135.0), 1e-6);
+    assert_approx_eq!(Rot2::from_rotation_arc(to, to), Rot2::identity());
+}
+
+#[test]
+fn test_rot2_mul() {
+    let a = Rot2::from_angle(deg(30.0));
+    let b = Rot2::from_angle(deg(60.0));
+    assert_approx_eq!(a * b, Rot2::from_angle(deg(90.0)));
+    assert_approx_eq!(a * a.inverse(), Rot2::identity());
+    assert_approx_eq!(a.inverse(), Rot2::from_angle(deg(-30.0)));
+    let mut c = a;
+    c *= b;
+    assert_eq!(c, a * b);
+
+    let v = Vec2::new(2.0, -3.0);
+    assert_approx_eq!((a * b) * v, a * (b * v));
+    assert_approx_eq!(a * v, Mat2::from(a) * v);
+}
+
+#[test]
+fn test_rot2_drift() {
+    // repeated multiplication should stay close to unit length and be cheap to renormalize
+    let step = Rot2::from_angle(deg(1.0));
+    let mut r = Rot2::identity();
+    for _ in 0..360 {
+        r *= step;
+    }
+    assert_approx_eq!(r.length(), 1.0, 1e-5);
+    assert_approx_eq!(r.normalize(), Rot2::identity(), 1e-4);
+    assert!(r.normalize().is_normalized());
+}
+
+#[test]
+fn test_rot2_slerp() {
+    let a = Rot2::from_angle(deg(10.0));
+    let b = Rot2::from_angle(deg(70.0));
+    assert_approx_eq!(a.slerp(b, 0.0), a);
+    assert_approx_eq!(a.slerp(b, 1.0), b);
+    assert_approx_eq!(a.slerp(b, 0.5), Rot2::from_angle(deg(40.0)));
+    assert_approx_eq!(a.slerp(b, 0.25), Rot2::from_angle(deg(25.0)));
+
+    // takes the shortest path across the -PI/PI boundary
+    let a = Rot2::from_angle(deg(170.0));
+    let b = Rot2::from_angle(deg(-170.0));
+    assert_approx_eq!(a.slerp(b, 0.5), Rot2::from_angle(deg(180.0)), 1e-6);
+}
+
+#[test]
+fn test_rot2_nlerp() {
+    let a = Rot2::from_angle(deg(10.0));
+    let b = Rot2::from_angle(deg(70.0));
+    assert_approx_eq!(a.nlerp(b, 0.0), a);
+    assert_approx_eq!(a.nlerp(b, 1.0), b);
+    assert_approx_eq!(a.nlerp(b, 0.5), Rot2::from_angle(deg(40.0)));
+    assert!(a.nlerp(b, 0.3).is_normalized());
+}
+
+#[test]
+fn test_rot2_fmt() {
+    let a = Rot2::identity();
+    assert_eq!(format!("{}", a), "[1, 0]");
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_rot2_rand() {
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256Plus;
+    let mut rng = Xoshiro256Plus::seed_from_u64(0);
+    for _ in 0..100 {
+        let r: Rot2 = rng.gen();
+        assert!(r.is_normalized());
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_rot2_serde() {
+    let a = Rot2::from_cos_sin(0.0, 1.0);
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[0.0,1.0]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<Rot2>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Rot2>("[1.0]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Rot2>("[1.0,2.0,3.0]");
+    assert!(deserialized.is_err());
+}
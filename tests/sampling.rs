@@ -0,0 +1,271 @@
+mod support;
+
+use glam::sampling::*;
+use glam::{Quat, Vec2, Vec3};
+
+/// Returns true if each of the `n * n` cells of the unit square contains
+/// exactly one of the given `n * n` points.
+fn is_stratified(points: &[Vec2], n: usize) -> bool {
+    let mut counts = vec![0; n * n];
+    for p in points {
+        let x = (p.x() * n as f32) as usize;
+        let y = (p.y() * n as f32) as usize;
+        counts[y * n + x] += 1;
+    }
+    counts.iter().all(|&c| c == 1)
+}
+
+/// Returns true if the first `2^m` points form a (0, m, 2)-net, every
+/// elementary interval of area `1 / 2^m` contains exactly one point.
+fn is_net(points: &[Vec2], m: u32) -> bool {
+    (0..=m).all(|j| {
+        let (nx, ny) = (1 << j, 1 << (m - j));
+        let mut counts = vec![0; nx * ny];
+        for p in points {
+            let x = (p.x() * nx as f32) as usize;
+            let y = (p.y() * ny as f32) as usize;
+            counts[y * nx + x] += 1;
+        }
+        counts.iter().all(|&c| c == 1)
+    })
+}
+
+fn in_unit_range(v: &[f32]) -> bool {
+    v.iter().all(|&x| (0.0..1.0).contains(&x))
+}
+
+#[test]
+fn test_radical_inverse() {
+    let base2: Vec<f32> = (0..8).map(radical_inverse_base2).collect();
+    assert_eq!(base2, [0.0, 0.5, 0.25, 0.75, 0.125, 0.625, 0.375, 0.875]);
+    for i in 0..100 {
+        assert_eq!(radical_inverse(2, i), radical_inverse_base2(i));
+    }
+    assert_approx_eq!(radical_inverse(3, 1), 1.0 / 3.0);
+    assert_approx_eq!(radical_inverse(3, 2), 2.0 / 3.0);
+    assert_approx_eq!(radical_inverse(3, 3), 1.0 / 9.0);
+    assert_approx_eq!(radical_inverse(5, 7), 2.0 / 5.0 + 1.0 / 25.0);
+    assert!(radical_inverse(3, u32::MAX) < 1.0);
+}
+
+#[test]
+fn test_halton() {
+    let halton = Halton::new();
+    assert_eq!(halton.sample_vec2(0), Vec2::zero());
+    assert_approx_eq!(halton.sample_vec2(1), Vec2::new(0.5, 1.0 / 3.0));
+    assert_approx_eq!(
+        halton.sample_vec3(4),
+        Vec3::new(0.125, 4.0 / 9.0, 4.0 / 5.0)
+    );
+    assert_eq!(halton.sample_vec3(6).truncate(), halton.sample_vec2(6));
+
+    // 6 points of bases 2 and 3 stratify a 2x3 grid
+    let points: Vec<f32> = (0..6).map(|i| halton.sample(1, i)).collect();
+    let mut cells: Vec<usize> = points.iter().map(|&y| (y * 3.0) as usize).collect();
+    cells.sort();
+    assert_eq!(cells, [0, 0, 1, 1, 2, 2]);
+}
+
+#[test]
+fn test_halton_scrambled() {
+    let a = Halton::scrambled(1);
+    let b = Halton::scrambled(2);
+    assert_ne!(a.sample_vec3(0), Vec3::zero());
+    assert_ne!(a.sample_vec3(0), b.sample_vec3(0));
+    assert_eq!(a.sample_vec3(10), Halton::scrambled(1).sample_vec3(10));
+
+    for &seed in &[0, 1, 12345] {
+        let halton = Halton::scrambled(seed);
+        for dimension in 0..3 {
+            let v: Vec<f32> = (0..1000).map(|i| halton.sample(dimension, i)).collect();
+            assert!(in_unit_range(&v));
+            // scrambling preserves the stratification of each dimension
+            let base = [2, 3, 5][dimension];
+            let n = base * base;
+            let mut counts = vec![0; n];
+            for &x in &v[..n] {
+                counts[(x * n as f32) as usize] += 1;
+            }
+            assert!(counts.iter().all(|&c| c == 1), "{} {:?}", seed, counts);
+        }
+    }
+}
+
+#[test]
+fn test_sobol() {
+    let sobol = Sobol::new();
+    let expected = [
+        (0.0, 0.0, 0.0),
+        (0.5, 0.5, 0.5),
+        (0.25, 0.75, 0.75),
+        (0.75, 0.25, 0.25),
+        (0.125, 0.625, 0.375),
+        (0.625, 0.125, 0.875),
+        (0.375, 0.375, 0.625),
+        (0.875, 0.875, 0.125),
+    ];
+    for (i, &(x, y, z)) in expected.iter().enumerate() {
+        assert_eq!(sobol.sample_vec3(i as u32), Vec3::new(x, y, z));
+        assert_eq!(sobol.sample_vec2(i as u32), Vec2::new(x, y));
+    }
+
+    let points: Vec<Vec2> = (0..256).map(|i| sobol.sample_vec2(i)).collect();
+    assert!(is_net(&points, 8));
+    assert!(is_stratified(&points, 16));
+    let v: Vec<f32> = (0..1 << 16).map(|i| sobol.sample(2, i)).collect();
+    assert!(in_unit_range(&v));
+}
+
+#[test]
+fn test_sobol_scrambled() {
+    for &seed in &[0, 1, 12345] {
+        let sobol = Sobol::scrambled(seed);
+        assert_ne!(sobol.sample_vec3(0), Vec3::zero());
+        let points: Vec<Vec2> = (0..256).map(|i| sobol.sample_vec2(i)).collect();
+        assert!(is_net(&points, 8));
+        let points: Vec<Vec2> = (0..64)
+            .map(|i| sobol.sample_vec3(i))
+            .map(|p| Vec2::new(p.x(), p.z()))
+            .collect();
+        assert!(is_stratified(&points, 8));
+    }
+    assert_ne!(
+        Sobol::scrambled(1).sample_vec2(5),
+        Sobol::scrambled(2).sample_vec2(5)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_sobol_dimension_out_of_range() {
+    Sobol::new().sample(3, 0);
+}
+
+#[test]
+fn test_r2_r3() {
+    assert_eq!(r2(0), Vec2::splat(0.5));
+    assert_eq!(r3(0), Vec3::splat(0.5));
+    let g: f64 = 1.324_717_957_244_746;
+    let expected = Vec2::new(
+        ((0.5 + 10.0 / g) % 1.0) as f32,
+        ((0.5 + 10.0 / (g * g)) % 1.0) as f32,
+    );
+    assert_approx_eq!(r2(10), expected, 1e-6);
+
+    let points: Vec<Vec2> = (0..1000).map(r2).collect();
+    assert!(points.iter().all(|p| in_unit_range(&[p.x(), p.y()])));
+    // no two points of the first 1000 are closer than 0.015
+    let min_distance = points
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| points[i + 1..].iter().map(move |b| (*a - *b).length()))
+        .fold(1.0, f32::min);
+    assert!(min_distance > 0.015, "{}", min_distance);
+
+    let mean = (0..1000).map(r3).fold(Vec3::zero(), |sum, p| sum + p) / 1000.0;
+    assert!(mean.abs_diff_eq(Vec3::splat(0.5), 0.01));
+}
+
+#[test]
+fn test_hammersley() {
+    assert_eq!(hammersley_vec2(0, 4), Vec2::zero());
+    assert_eq!(hammersley_vec2(1, 4), Vec2::new(0.25, 0.5));
+    assert_eq!(hammersley_vec2(3, 4), Vec2::new(0.75, 0.75));
+    assert_approx_eq!(hammersley_vec3(2, 8), Vec3::new(0.25, 0.25, 2.0 / 3.0));
+    let points: Vec<Vec2> = (0..64).map(|i| hammersley_vec2(i, 64)).collect();
+    assert!(is_stratified(&points, 8));
+    assert!(is_net(&points, 6));
+}
+
+#[test]
+fn test_square_to_concentric_disk() {
+    assert_eq!(square_to_concentric_disk(Vec2::splat(0.5)), Vec2::zero());
+    assert_approx_eq!(
+        square_to_concentric_disk(Vec2::new(1.0, 0.5)),
+        Vec2::unit_x()
+    );
+    assert_approx_eq!(
+        square_to_concentric_disk(Vec2::new(0.5, 0.0)),
+        -Vec2::unit_y()
+    );
+    // corners map to the diagonals
+    assert_approx_eq!(
+        square_to_concentric_disk(Vec2::one()),
+        Vec2::splat(core::f32::consts::FRAC_1_SQRT_2)
+    );
+
+    // equal area: a quarter of the samples are within radius 0.5
+    let sobol = Sobol::new();
+    let points: Vec<Vec2> = (0..4096)
+        .map(|i| square_to_concentric_disk(sobol.sample_vec2(i)))
+        .collect();
+    assert!(points.iter().all(|p| p.length() <= 1.0 + 1e-6));
+    let inside = points.iter().filter(|p| p.length() < 0.5).count();
+    assert!((inside as f32 / 4096.0 - 0.25).abs() < 0.01, "{}", inside);
+}
+
+#[test]
+fn test_square_to_sphere() {
+    assert_approx_eq!(square_to_sphere(Vec2::zero()), Vec3::unit_z());
+    assert_approx_eq!(square_to_sphere(Vec2::new(1.0, 0.0)), -Vec3::unit_z());
+    assert_approx_eq!(square_to_sphere(Vec2::new(0.5, 0.25)), Vec3::unit_y());
+
+    let halton = Halton::new();
+    let points: Vec<Vec3> = (0..4096)
+        .map(|i| square_to_sphere(halton.sample_vec2(i)))
+        .collect();
+    assert!(points.iter().all(|p| p.is_normalized()));
+    let mean = points.iter().fold(Vec3::zero(), |sum, &p| sum + p) / 4096.0;
+    assert!(mean.abs_diff_eq(Vec3::zero(), 0.01));
+}
+
+#[test]
+fn test_square_to_hemisphere() {
+    assert_approx_eq!(square_to_hemisphere(Vec2::new(1.0, 0.0)), Vec3::unit_z());
+    assert_approx_eq!(square_to_hemisphere(Vec2::zero()), Vec3::unit_x());
+
+    let sobol = Sobol::new();
+    let points: Vec<Vec3> = (0..4096)
+        .map(|i| square_to_hemisphere(sobol.sample_vec2(i)))
+        .collect();
+    assert!(points.iter().all(|p| p.is_normalized() && p.z() >= 0.0));
+    let mean_cos = points.iter().map(|p| p.z()).sum::<f32>() / 4096.0;
+    assert!((mean_cos - 0.5).abs() < 0.01, "{}", mean_cos);
+}
+
+#[test]
+fn test_square_to_cosine_hemisphere() {
+    assert_eq!(
+        square_to_cosine_hemisphere(Vec2::splat(0.5)),
+        Vec3::unit_z()
+    );
+
+    let sobol = Sobol::new();
+    let points: Vec<Vec3> = (0..4096)
+        .map(|i| square_to_cosine_hemisphere(sobol.sample_vec2(i)))
+        .collect();
+    assert!(points.iter().all(|p| p.is_normalized() && p.z() >= 0.0));
+    // the mean cosine of a cosine weighted hemisphere is 2/3
+    let mean_cos = points.iter().map(|p| p.z()).sum::<f32>() / 4096.0;
+    assert!((mean_cos - 2.0 / 3.0).abs() < 0.01, "{}", mean_cos);
+}
+
+#[test]
+fn test_cube_to_rotation() {
+    assert_eq!(
+        cube_to_rotation(Vec3::zero()),
+        Quat::from_xyzw(0.0, 1.0, 0.0, 0.0)
+    );
+
+    let halton = Halton::new();
+    let rotations: Vec<Quat> = (0..4096)
+        .map(|i| cube_to_rotation(halton.sample_vec3(i)))
+        .collect();
+    assert!(rotations.iter().all(|q| q.is_normalized()));
+    // uniformly distributed rotations move a vector anywhere on the sphere
+    let mean = rotations
+        .iter()
+        .fold(Vec3::zero(), |sum, &q| sum + q * Vec3::unit_x())
+        / 4096.0;
+    assert!(mean.abs_diff_eq(Vec3::zero(), 0.01), "{}", mean);
+}
@@ -0,0 +1,242 @@
+#![cfg(feature = "serde")]
+
+mod support;
+
+use glam::f32::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+use serde::{Deserialize, Serialize};
+use support::deg;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Named {
+    #[serde(with = "glam::serde_repr::named")]
+    v2: Vec2,
+    #[serde(with = "glam::serde_repr::named")]
+    v3: Vec3,
+    #[serde(with = "glam::serde_repr::named")]
+    v4: Vec4,
+    #[serde(with = "glam::serde_repr::named")]
+    q: Quat,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct NamedMat<T: glam::serde_repr::named::Named> {
+    #[serde(with = "glam::serde_repr::named")]
+    m: T,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct RowMajor<T: glam::serde_repr::row_major::RowMajor> {
+    #[serde(with = "glam::serde_repr::row_major")]
+    m: T,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct ColsArray2d<T: glam::serde_repr::cols_array_2d::ColsArray2d> {
+    #[serde(with = "glam::serde_repr::cols_array_2d")]
+    m: T,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct EulerDegrees {
+    #[serde(with = "glam::serde_repr::euler_degrees")]
+    q: Quat,
+}
+
+#[test]
+fn test_named() {
+    let a = Named {
+        v2: Vec2::new(1.0, 2.0),
+        v3: Vec3::new(1.0, 2.0, 3.0),
+        v4: Vec4::new(1.0, 2.0, 3.0, 4.0),
+        q: Quat::identity(),
+    };
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(
+        serialized,
+        concat!(
+            r#"{"v2":{"x":1.0,"y":2.0},"v3":{"x":1.0,"y":2.0,"z":3.0},"#,
+            r#""v4":{"x":1.0,"y":2.0,"z":3.0,"w":4.0},"q":{"x":0.0,"y":0.0,"z":0.0,"w":1.0}}"#
+        )
+    );
+    assert_eq!(a, serde_json::from_str(&serialized).unwrap());
+
+    // fields may be in any order
+    let deserialized: NamedMat<Vec3> =
+        serde_json::from_str(r#"{"m":{"z":3.0,"x":1.0,"y":2.0}}"#).unwrap();
+    assert_eq!(deserialized.m, Vec3::new(1.0, 2.0, 3.0));
+
+    assert!(serde_json::from_str::<NamedMat<Vec3>>(r#"{"m":{"x":1.0,"y":2.0}}"#).is_err());
+
+    // Vec3A has the same representation as Vec3
+    let a = NamedMat {
+        m: Vec3A::new(1.0, 2.0, 3.0),
+    };
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, r#"{"m":{"x":1.0,"y":2.0,"z":3.0}}"#);
+    assert_eq!(a, serde_json::from_str(&serialized).unwrap());
+}
+
+#[test]
+fn test_named_mat() {
+    let a = NamedMat {
+        m: Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]),
+    };
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(
+        serialized,
+        r#"{"m":{"x_axis":{"x":1.0,"y":2.0},"y_axis":{"x":3.0,"y":4.0}}}"#
+    );
+    assert_eq!(a, serde_json::from_str(&serialized).unwrap());
+
+    let a = NamedMat {
+        m: Mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+    };
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(
+        serialized,
+        concat!(
+            r#"{"m":{"x_axis":{"x":1.0,"y":2.0,"z":3.0},"y_axis":{"x":4.0,"y":5.0,"z":6.0},"#,
+            r#""z_axis":{"x":7.0,"y":8.0,"z":9.0}}}"#
+        )
+    );
+    assert_eq!(a, serde_json::from_str(&serialized).unwrap());
+
+    let b = NamedMat {
+        m: Mat3A::from(a.m),
+    };
+    assert_eq!(serde_json::to_string(&b).unwrap(), serialized);
+    assert_eq!(b, serde_json::from_str(&serialized).unwrap());
+
+    let a = NamedMat {
+        m: Mat4::from_scale_rotation_translation(
+            Vec3::new(1.0, 2.0, 3.0),
+            Quat::from_rotation_y(deg(90.0)),
+            Vec3::new(4.0, 5.0, 6.0),
+        ),
+    };
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert!(serialized.contains(r#""w_axis":{"x":4.0,"y":5.0,"z":6.0,"w":1.0}"#));
+    assert_eq!(a, serde_json::from_str(&serialized).unwrap());
+}
+
+#[test]
+fn test_row_major() {
+    let a = RowMajor {
+        m: Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]),
+    };
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, r#"{"m":[1.0,3.0,2.0,4.0]}"#);
+    assert_eq!(a, serde_json::from_str(&serialized).unwrap());
+
+    let a = RowMajor {
+        m: Mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+    };
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, r#"{"m":[1.0,4.0,7.0,2.0,5.0,8.0,3.0,6.0,9.0]}"#);
+    assert_eq!(a, serde_json::from_str(&serialized).unwrap());
+
+    let b = RowMajor {
+        m: Mat3A::from(a.m),
+    };
+    assert_eq!(serde_json::to_string(&b).unwrap(), serialized);
+    assert_eq!(b, serde_json::from_str(&serialized).unwrap());
+
+    let a = RowMajor {
+        m: Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0)),
+    };
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(
+        serialized,
+        r#"{"m":[1.0,0.0,0.0,1.0,0.0,1.0,0.0,2.0,0.0,0.0,1.0,3.0,0.0,0.0,0.0,1.0]}"#
+    );
+    assert_eq!(a, serde_json::from_str(&serialized).unwrap());
+
+    assert!(serde_json::from_str::<RowMajor<Mat2>>(r#"{"m":[1.0,2.0,3.0]}"#).is_err());
+    assert!(serde_json::from_str::<RowMajor<Mat2>>(r#"{"m":[[1.0,2.0],[3.0,4.0]]}"#).is_err());
+}
+
+#[test]
+fn test_cols_array_2d() {
+    let a = ColsArray2d {
+        m: Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]),
+    };
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, r#"{"m":[[1.0,2.0],[3.0,4.0]]}"#);
+    assert_eq!(a, serde_json::from_str(&serialized).unwrap());
+
+    let a = ColsArray2d {
+        m: Mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+    };
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(
+        serialized,
+        r#"{"m":[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]}"#
+    );
+    assert_eq!(a, serde_json::from_str(&serialized).unwrap());
+
+    let b = ColsArray2d {
+        m: Mat3A::from(a.m),
+    };
+    assert_eq!(serde_json::to_string(&b).unwrap(), serialized);
+    assert_eq!(b, serde_json::from_str(&serialized).unwrap());
+
+    let a = ColsArray2d {
+        m: Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0)),
+    };
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(
+        serialized,
+        r#"{"m":[[1.0,0.0,0.0,0.0],[0.0,1.0,0.0,0.0],[0.0,0.0,1.0,0.0],[1.0,2.0,3.0,1.0]]}"#
+    );
+    assert_eq!(a, serde_json::from_str(&serialized).unwrap());
+
+    assert!(serde_json::from_str::<ColsArray2d<Mat2>>(r#"{"m":[1.0,2.0,3.0,4.0]}"#).is_err());
+    assert!(serde_json::from_str::<ColsArray2d<Mat2>>(r#"{"m":[[1.0,2.0],[3.0]]}"#).is_err());
+}
+
+#[test]
+fn test_euler_degrees() {
+    let deserialized: EulerDegrees =
+        serde_json::from_str(r#"{"q":{"yaw":90.0,"pitch":0.0,"roll":0.0}}"#).unwrap();
+    assert_approx_eq!(deserialized.q, Quat::from_rotation_y(deg(90.0)));
+
+    let deserialized: EulerDegrees =
+        serde_json::from_str(r#"{"q":{"roll":-45.0,"yaw":30.0,"pitch":60.0}}"#).unwrap();
+    assert_approx_eq!(
+        deserialized.q,
+        Quat::from_rotation_ypr(deg(30.0), deg(60.0), deg(-45.0))
+    );
+
+    for &(yaw, pitch, roll) in &[
+        (0.0, 0.0, 0.0),
+        (30.0, 0.0, 0.0),
+        (0.0, 30.0, 0.0),
+        (0.0, 0.0, 30.0),
+        (-120.0, 45.0, 170.0),
+        (10.0, -80.0, -60.0),
+    ] {
+        let a = EulerDegrees {
+            q: Quat::from_rotation_ypr(deg(yaw), deg(pitch), deg(roll)),
+        };
+        let serialized = serde_json::to_string(&a).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&serialized).unwrap();
+        assert_approx_eq!(value["q"]["yaw"].as_f64().unwrap() as f32, yaw, 1e-3);
+        assert_approx_eq!(value["q"]["pitch"].as_f64().unwrap() as f32, pitch, 1e-3);
+        assert_approx_eq!(value["q"]["roll"].as_f64().unwrap() as f32, roll, 1e-3);
+        let deserialized: EulerDegrees = serde_json::from_str(&serialized).unwrap();
+        assert_approx_eq!(deserialized.q, a.q, 1e-6);
+    }
+
+    // gimbal lock, roll is folded into yaw
+    for &pitch in &[90.0, -90.0] {
+        let a = EulerDegrees {
+            q: Quat::from_rotation_ypr(deg(20.0), deg(pitch), deg(10.0)),
+        };
+        let serialized = serde_json::to_string(&a).unwrap();
+        let deserialized: EulerDegrees = serde_json::from_str(&serialized).unwrap();
+        assert!(deserialized.q.abs_diff_eq(a.q, 1e-5) || deserialized.q.abs_diff_eq(-a.q, 1e-5));
+    }
+
+    assert!(serde_json::from_str::<EulerDegrees>(r#"{"q":{"yaw":90.0,"pitch":0.0}}"#).is_err());
+    assert!(serde_json::from_str::<EulerDegrees>(r#"{"q":[0.0,0.0,0.0,1.0]}"#).is_err());
+}
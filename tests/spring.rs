@@ -0,0 +1,210 @@
+mod support;
+
+use glam::f32::{Quat, Vec2, Vec3, Vec4};
+use glam::spring::{smooth_damp, smooth_damp_quat, QuatSpring, Spring};
+use support::deg;
+
+const DT: f32 = 1.0 / 60.0;
+
+#[test]
+fn test_smooth_damp_converges() {
+    let target = 10.0_f32;
+    let mut x = 0.0_f32;
+    let mut v = 0.0_f32;
+    let mut prev = x;
+    for _ in 0..240 {
+        x = smooth_damp(x, target, &mut v, 0.3, f32::INFINITY, DT);
+        // moves monotonically towards the target
+        assert!(x >= prev);
+        assert!(x <= target);
+        prev = x;
+    }
+    assert_approx_eq!(x, target, 1e-3);
+    assert_approx_eq!(v, 0.0, 1e-2);
+}
+
+#[test]
+fn test_smooth_damp_vectors() {
+    let target = Vec2::new(-3.0, 4.0);
+    let mut x = Vec2::zero();
+    let mut v = Vec2::zero();
+    for _ in 0..240 {
+        x = smooth_damp(x, target, &mut v, 0.3, f32::INFINITY, DT);
+    }
+    assert_approx_eq!(x, target, 1e-3);
+
+    let target = Vec3::new(1.0, 2.0, 3.0);
+    let mut x = Vec3::zero();
+    let mut v = Vec3::zero();
+    for _ in 0..240 {
+        x = smooth_damp(x, target, &mut v, 0.3, f32::INFINITY, DT);
+    }
+    assert_approx_eq!(x, target, 1e-3);
+
+    let target = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    let mut x = Vec4::zero();
+    let mut v = Vec4::zero();
+    for _ in 0..240 {
+        x = smooth_damp(x, target, &mut v, 0.3, f32::INFINITY, DT);
+    }
+    assert_approx_eq!(x, target, 1e-3);
+}
+
+#[test]
+fn test_smooth_damp_max_speed() {
+    let target = Vec3::new(100.0, 0.0, 0.0);
+    let mut x = Vec3::zero();
+    let mut v = Vec3::zero();
+    let max_speed = 5.0;
+    for _ in 0..60 {
+        let prev = x;
+        x = smooth_damp(x, target, &mut v, 0.1, max_speed, DT);
+        assert!((x - prev).length() / DT <= max_speed * 1.001);
+    }
+    assert!(x.x() > 4.0 && x.x() <= 5.0);
+}
+
+#[test]
+fn test_smooth_damp_large_dt() {
+    // huge time steps snap towards the target without overshooting or NaNs
+    let mut x = 0.0_f32;
+    let mut v = 50.0_f32;
+    for &dt in &[1.0, 10.0, 1000.0, 1.0e20] {
+        x = smooth_damp(x, 1.0, &mut v, 0.1, f32::INFINITY, dt);
+        assert!(x.is_finite() && v.is_finite());
+        assert!(x <= 1.0);
+    }
+    assert_approx_eq!(x, 1.0, 1e-3);
+
+    // zero and negative dt leave the state unchanged
+    let mut v = 2.0;
+    assert_eq!(smooth_damp(0.5, 1.0, &mut v, 0.1, f32::INFINITY, 0.0), 0.5);
+    assert_eq!(smooth_damp(0.5, 1.0, &mut v, 0.1, f32::INFINITY, -1.0), 0.5);
+    assert_eq!(v, 2.0);
+}
+
+#[test]
+fn test_smooth_damp_deterministic() {
+    let run = || {
+        let mut x = Vec3::zero();
+        let mut v = Vec3::new(1.0, -2.0, 0.5);
+        for i in 0..100 {
+            let dt = if i % 3 == 0 { 0.5 } else { DT };
+            x = smooth_damp(x, Vec3::one(), &mut v, 0.25, 20.0, dt);
+        }
+        (x, v)
+    };
+    assert_eq!(run(), run());
+}
+
+#[test]
+fn test_smooth_damp_quat() {
+    let target = Quat::from_rotation_y(deg(120.0));
+    let mut q = Quat::identity();
+    let mut w = Vec3::zero();
+    for _ in 0..240 {
+        q = smooth_damp_quat(q, target, &mut w, 0.3, f32::INFINITY, DT);
+        assert!(q.is_normalized());
+    }
+    assert!(q.dot(target).abs() > 0.999_99);
+    // angular velocity is about the rotation axis
+    let mut q = Quat::identity();
+    let mut w = Vec3::zero();
+    smooth_damp_quat(q, target, &mut w, 0.3, f32::INFINITY, DT);
+    assert_approx_eq!(w.normalize(), Vec3::unit_y(), 1e-4);
+
+    // max speed is respected
+    let max_speed = deg(90.0);
+    let mut w = Vec3::zero();
+    for _ in 0..30 {
+        q = smooth_damp_quat(q, target, &mut w, 0.05, max_speed, DT);
+    }
+    let (_, angle) = q.to_axis_angle();
+    assert!(angle <= max_speed * 30.0 * DT + 1e-3);
+
+    // large dt
+    let mut w = Vec3::zero();
+    let q = smooth_damp_quat(Quat::identity(), target, &mut w, 0.1, f32::INFINITY, 100.0);
+    assert!(q.dot(target).abs() > 0.999);
+}
+
+#[test]
+fn test_spring_converges() {
+    let mut s = Spring::new(0.0_f32, 0.0);
+    let mut max = 0.0_f32;
+    for _ in 0..240 {
+        s.update(1.0, 0.3, DT);
+        max = max.max(s.position);
+    }
+    assert_approx_eq!(s.position, 1.0, 1e-3);
+    assert_approx_eq!(s.velocity, 0.0, 1e-2);
+    // critically damped springs starting at rest do not overshoot
+    assert!(max <= 1.0);
+
+    let mut s = Spring::new(Vec3::zero(), Vec3::new(0.0, 5.0, 0.0));
+    for _ in 0..240 {
+        s.update(Vec3::new(1.0, 2.0, 3.0), 0.3, DT);
+    }
+    assert_approx_eq!(s.position, Vec3::new(1.0, 2.0, 3.0), 1e-3);
+
+    let mut s = Spring::<Vec2>::default();
+    s.update(Vec2::one(), 0.3, 0.0);
+    assert_eq!(s, Spring::default());
+}
+
+#[test]
+fn test_spring_step_independent() {
+    // analytic integration gives the same result for one big step or many
+    // small ones
+    let mut a = Spring::new(Vec4::zero(), Vec4::one());
+    let mut b = a;
+    a.update(Vec4::splat(2.0), 0.5, 1.0);
+    for _ in 0..100 {
+        b.update(Vec4::splat(2.0), 0.5, 0.01);
+    }
+    assert_approx_eq!(a.position, b.position, 1e-4);
+    assert_approx_eq!(a.velocity, b.velocity, 1e-4);
+
+    let mut s = Spring::new(0.0_f32, 100.0);
+    s.update(1.0, 0.1, 1.0e20);
+    assert_eq!(s.position, 1.0);
+    assert_eq!(s.velocity, 0.0);
+}
+
+#[test]
+fn test_quat_spring() {
+    let target = Quat::from_rotation_ypr(deg(30.0), deg(45.0), deg(-60.0));
+    let mut s = QuatSpring::new(Quat::identity(), Vec3::zero());
+    for _ in 0..240 {
+        s.update(target, 0.3, DT);
+        assert!(s.rotation.is_normalized());
+    }
+    assert!(s.rotation.dot(target).abs() > 0.999_99);
+    assert_approx_eq!(s.angular_velocity, Vec3::zero(), 1e-2);
+
+    let mut s = QuatSpring::default();
+    s.update(target, 0.3, 1.0e6);
+    assert!(s.rotation.dot(target).abs() > 0.999);
+}
+
+#[test]
+fn test_quat_small_offset() {
+    // small offsets where `w` rounds to 1 still converge
+    let target = Quat::from_rotation_x(1.0e-4);
+    let mut s = QuatSpring::default();
+    s.update(target, 0.3, DT);
+    assert!(s.angular_velocity.x() > 0.0);
+    for _ in 0..240 {
+        s.update(target, 0.3, DT);
+    }
+    assert_approx_eq!(s.rotation, target, 1.0e-7);
+
+    let mut q = Quat::identity();
+    let mut w = Vec3::zero();
+    q = smooth_damp_quat(q, target, &mut w, 0.3, f32::INFINITY, DT);
+    assert!(w.x() > 0.0);
+    for _ in 0..240 {
+        q = smooth_damp_quat(q, target, &mut w, 0.3, f32::INFINITY, DT);
+    }
+    assert_approx_eq!(q, target, 1.0e-7);
+}
@@ -1,10 +1,12 @@
 #[macro_use]
 mod macros;
 
-use glam::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
+use glam::{
+    DualQuat, LinearRgba, Mat2, Mat3, Mat3A, Mat4, Quat, Rot2, SRgba, Vec2, Vec3, Vec3A, Vec4,
+};
 
 #[cfg(feature = "transform-types")]
-use glam::{TransformRT, TransformSRT};
+use glam::{TransformRT, TransformRT2, TransformSRT, TransformSRT2};
 
 /// Helper function for migrating away from `glam::angle::deg`.
 #[allow(dead_code)]
@@ -41,6 +43,20 @@ impl FloatCompare for f32 {
     }
 }
 
+impl FloatCompare for DualQuat {
+    #[inline]
+    fn approx_eq(&self, other: &DualQuat, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &DualQuat) -> DualQuat {
+        DualQuat::from_real_dual(
+            self.real().abs_diff(&other.real()),
+            self.dual().abs_diff(&other.dual()),
+        )
+    }
+}
+
 impl FloatCompare for Mat2 {
     #[inline]
     fn approx_eq(&self, other: &Mat2, max_abs_diff: f32) -> bool {
@@ -70,6 +86,21 @@ impl FloatCompare for Mat3 {
     }
 }
 
+impl FloatCompare for Mat3A {
+    #[inline]
+    fn approx_eq(&self, other: &Mat3A, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Mat3A) -> Mat3A {
+        Mat3A::from_cols(
+            (self.x_axis() - other.x_axis()).abs(),
+            (self.y_axis() - other.y_axis()).abs(),
+            (self.z_axis() - other.z_axis()).abs(),
+        )
+    }
+}
+
 impl FloatCompare for Mat4 {
     #[inline]
     fn approx_eq(&self, other: &Mat4, max_abs_diff: f32) -> bool {
@@ -99,6 +130,41 @@ impl FloatCompare for Quat {
     }
 }
 
+impl FloatCompare for Rot2 {
+    #[inline]
+    fn approx_eq(&self, other: &Rot2, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Rot2) -> Rot2 {
+        let a: Vec2 = (*self).into();
+        let b: Vec2 = (*other).into();
+        (a - b).abs().into()
+    }
+}
+
+impl FloatCompare for LinearRgba {
+    #[inline]
+    fn approx_eq(&self, other: &LinearRgba, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &LinearRgba) -> LinearRgba {
+        (self.to_vec4() - other.to_vec4()).abs().into()
+    }
+}
+
+impl FloatCompare for SRgba {
+    #[inline]
+    fn approx_eq(&self, other: &SRgba, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &SRgba) -> SRgba {
+        (self.to_vec4() - other.to_vec4()).abs().into()
+    }
+}
+
 impl FloatCompare for Vec2 {
     #[inline]
     fn approx_eq(&self, other: &Vec2, max_abs_diff: f32) -> bool {
@@ -121,6 +187,17 @@ impl FloatCompare for Vec3 {
     }
 }
 
+impl FloatCompare for Vec3A {
+    #[inline]
+    fn approx_eq(&self, other: &Vec3A, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Vec3A) -> Vec3A {
+        (*self - *other).abs()
+    }
+}
+
 impl FloatCompare for Vec4 {
     #[inline]
     fn approx_eq(&self, other: &Vec4, max_abs_diff: f32) -> bool {
@@ -164,3 +241,36 @@ impl FloatCompare for TransformRT {
         )
     }
 }
+
+#[cfg(feature = "transform-types")]
+impl FloatCompare for TransformSRT2 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_scale_rotation_translation(
+            self.scale.abs_diff(&other.scale),
+            self.rotation.abs_diff(&other.rotation),
+            self.translation.abs_diff(&other.translation),
+        )
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl FloatCompare for TransformRT2 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_rotation_translation(
+            self.rotation.abs_diff(&other.rotation),
+            self.translation.abs_diff(&other.translation),
+        )
+    }
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "testing")]
+
+use glam::testing::{check_approx_eq, check_rotation_approx_eq, deg, rad};
+use glam::{assert_approx_eq, assert_rotation_approx_eq};
+use glam::{DualQuat, LinearRgba, Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
+
+#[test]
+fn test_assert_approx_eq() {
+    assert_approx_eq!(1.0, 1.0 + f32::EPSILON);
+    assert_approx_eq!(Vec3::new(1.0, 2.0, 3.0), Vec3::new(1.0, 2.0, 3.0));
+    assert_approx_eq!(Vec4::splat(1.0), Vec4::splat(1.01), 0.02);
+    assert_approx_eq!(
+        Mat4::from_rotation_x(deg(90.0)) * Vec4::unit_y(),
+        Vec4::unit_z(),
+        1e-6,
+    );
+    assert_approx_eq!(
+        Mat3::identity(),
+        Mat3::identity(),
+        0.0,
+        "with a {}",
+        "message"
+    );
+    assert_approx_eq!(rad(1.0), 1.0);
+    assert_approx_eq!(deg(180.0), core::f32::consts::PI);
+}
+
+#[test]
+#[should_panic(expected = "x_axis.y: left 0, right 0.5, diff 0.5")]
+fn test_assert_approx_eq_fail() {
+    let mut m = Mat3::identity();
+    m.set_x_axis(Vec3::new(1.0, 0.5, 0.0));
+    assert_approx_eq!(Mat3::identity(), m, 1e-6);
+}
+
+#[test]
+#[should_panic(expected = "diff 0.5\nat step 3")]
+fn test_assert_approx_eq_message() {
+    assert_approx_eq!(0.0, 0.5, 1e-6, "at step {}", 3);
+}
+
+#[test]
+fn test_mismatch_display() {
+    let mismatch = check_approx_eq(&Vec2::new(1.0, 2.0), &Vec2::new(1.0, 2.5), 0.1).unwrap_err();
+    let message = mismatch.to_string();
+    assert!(message.starts_with("assertion failed: `left ≈ right` (max_abs_diff: 0.1)\n"));
+    // only the elements which differ are listed
+    assert!(message.ends_with("\ny: left 2, right 2.5, diff 0.5"));
+    assert!(!message.contains("\nx:"));
+
+    let mismatch = check_approx_eq(&1.0, &f32::NAN, 1.0).unwrap_err();
+    assert!(mismatch
+        .to_string()
+        .ends_with("\nvalue: left 1, right NaN, diff NaN"));
+
+    let color = LinearRgba::new(1.0, 0.5, 0.25, 1.0);
+    let mismatch = check_approx_eq(&color, &LinearRgba::new(1.0, 0.5, 0.25, 0.0), 0.1);
+    assert!(mismatch
+        .unwrap_err()
+        .to_string()
+        .ends_with("\na: left 1, right 0, diff 1"));
+}
+
+#[test]
+fn test_assert_rotation_approx_eq() {
+    let q = Quat::from_rotation_ypr(0.1, 0.2, 0.3);
+    assert_rotation_approx_eq!(q, q);
+    assert_rotation_approx_eq!(q, -q);
+    assert!(check_approx_eq(&q, &-q, 1e-6).is_err());
+
+    let dq = DualQuat::from_rotation_translation(q, Vec3::new(1.0, 2.0, 3.0));
+    assert_rotation_approx_eq!(dq, DualQuat::from_real_dual(-dq.real(), -dq.dual()), 1e-6);
+
+    // differences are reported against whichever sign is closer
+    let mismatch =
+        check_rotation_approx_eq(&q, &-Quat::from_rotation_ypr(0.1, 0.2, 0.4), 1e-6).unwrap_err();
+    assert!(mismatch.antipode);
+    assert!(mismatch
+        .to_string()
+        .starts_with("assertion failed: `left ≈ -right`"));
+}
+
+#[test]
+#[should_panic(expected = "left ≈ right")]
+fn test_assert_rotation_approx_eq_fail() {
+    assert_rotation_approx_eq!(Quat::identity(), Quat::from_rotation_z(0.5));
+}
+
+#[cfg(feature = "transform-types")]
+#[test]
+fn test_transforms() {
+    use glam::{TransformRT2, TransformSRT};
+    let t = TransformSRT::from_scale_rotation_translation(
+        Vec3::one(),
+        Quat::from_rotation_y(1.0),
+        Vec3::zero(),
+    );
+    let flipped =
+        TransformSRT::from_scale_rotation_translation(t.scale, -t.rotation, t.translation);
+    assert_rotation_approx_eq!(t, flipped);
+    let moved = TransformSRT::from_scale_rotation_translation(t.scale, t.rotation, Vec3::unit_x());
+    let mismatch = check_approx_eq(&t, &moved, 1e-6).unwrap_err();
+    assert!(mismatch
+        .to_string()
+        .ends_with("\ntranslation.x: left 0, right 1, diff 1"));
+
+    let t = TransformRT2::from_rotation_translation(glam::Rot2::from_angle(0.5), Vec2::one());
+    assert_approx_eq!(t, t);
+}
@@ -85,4 +85,170 @@ mod transform {
         let inv_srt = srt.inverse();
         assert_eq!(srt * inv_srt, TransformSRT::identity());
     }
+
+    #[test]
+    fn test_identity_2d() {
+        let tr = TransformRT2::identity();
+        assert_eq!(tr.rotation, Rot2::identity());
+        assert_eq!(tr.translation, Vec2::zero());
+
+        let srt = TransformSRT2::identity();
+        assert_eq!(srt.scale, Vec2::one());
+        assert_eq!(srt.rotation, Rot2::identity());
+        assert_eq!(srt.translation, Vec2::zero());
+
+        assert_eq!(srt, tr.into());
+
+        assert_eq!(TransformRT2::identity(), TransformRT2::default());
+        assert_eq!(TransformSRT2::identity(), TransformSRT2::default());
+        assert_eq!(Mat3::from(tr), Mat3::identity());
+        assert_eq!(Mat3::from(srt), Mat3::identity());
+    }
+
+    #[test]
+    fn test_new_2d() {
+        let t = Vec2::new(1.0, 2.0);
+        let r = Rot2::from_angle(90.0_f32.to_radians());
+        let s = Vec2::new(-1.0, -2.0);
+
+        let mut srt = TransformSRT2::from_scale_rotation_translation(s, r, t);
+        assert_eq!(srt.scale, s);
+        assert_eq!(srt.rotation, r);
+        assert_eq!(srt.translation, t);
+
+        let tr = TransformRT2::from_rotation_translation(r, t);
+        assert_eq!(srt, TransformSRT2::from_transform_rt2(s, &tr));
+        assert_eq!(srt.as_ref(), &tr);
+        srt.as_mut().translation = Vec2::zero();
+        assert_eq!(srt.translation, Vec2::zero());
+    }
+
+    #[test]
+    fn test_mat3_2d() {
+        let s = Vec2::new(2.0, 3.0);
+        let angle = 30.0_f32.to_radians();
+        let t = Vec2::new(-4.0, 5.0);
+        let srt = TransformSRT2::from_scale_rotation_translation(s, Rot2::from_angle(angle), t);
+        assert_approx_eq!(
+            Mat3::from(srt),
+            Mat3::from_scale_angle_translation(s, angle, t),
+            1e-6
+        );
+        let v = Vec2::new(1.0, -1.0);
+        assert_approx_eq!(Mat3::from(srt).transform_point2(v), srt * v, 1e-6);
+
+        let tr = TransformRT2::from_rotation_translation(Rot2::from_angle(angle), t);
+        assert_approx_eq!(
+            Mat3::from(tr),
+            Mat3::from_scale_angle_translation(Vec2::one(), angle, t),
+            1e-6
+        );
+    }
+
+    #[test]
+    fn test_mul_2d() {
+        let tr = TransformRT2::from_rotation_translation(
+            Rot2::from_angle(-90.0_f32.to_radians()),
+            Vec2::unit_x(),
+        );
+        let v0 = Vec2::unit_y();
+        let v1 = tr * v0;
+        assert_approx_eq!(v1, Vec2::unit_x() * 2.0);
+        let inv_tr = tr.inverse();
+        let v2 = inv_tr * v1;
+        assert_approx_eq!(v0, v2);
+
+        assert_eq!(tr * TransformRT2::identity(), tr);
+        assert_approx_eq!(tr * inv_tr, TransformRT2::identity());
+
+        // lhs is applied first
+        let tr2 = TransformRT2::from_rotation_translation(
+            Rot2::from_angle(45.0_f32.to_radians()),
+            Vec2::new(3.0, -1.0),
+        );
+        let v = Vec2::new(0.5, 2.0);
+        assert_approx_eq!((tr * tr2) * v, tr2 * (tr * v), 1e-6);
+
+        assert_eq!(tr * TransformSRT2::identity(), TransformSRT2::from(tr));
+        assert_eq!(TransformSRT2::identity() * tr, TransformSRT2::from(tr));
+
+        let s = Vec2::splat(2.0);
+        let r = Rot2::from_angle(180.0_f32.to_radians());
+        let t = -Vec2::unit_y();
+        let srt = TransformSRT2::from_scale_rotation_translation(s, r, t);
+        let v0 = Vec2::unit_x();
+        let v1 = srt * v0;
+        assert_approx_eq!(v1, (r * (v0 * s)) + t);
+        let inv_srt = srt.inverse();
+        let v2 = inv_srt * v1;
+        assert_approx_eq!(v0, v2);
+
+        assert_eq!(srt * TransformSRT2::identity(), srt);
+        assert_approx_eq!(srt * inv_srt, TransformSRT2::identity());
+
+        // negative scale mul test
+        let s = Vec2::splat(-2.0);
+        let srt = TransformSRT2::from_scale_rotation_translation(s, r, t);
+        let inv_srt = srt.inverse();
+        assert_approx_eq!(srt * inv_srt, TransformSRT2::identity(), 1e-6);
+        let srt2 = TransformSRT2::from_scale_rotation_translation(
+            Vec2::new(-1.0, -1.0),
+            Rot2::from_angle(30.0_f32.to_radians()),
+            Vec2::new(1.0, 2.0),
+        );
+        assert_approx_eq!((srt * srt2) * v0, srt2 * (srt * v0), 1e-5);
+
+        // non-uniform scale inverse
+        let s = Vec2::new(2.0, 3.0);
+        let t = Vec2::new(1.0, -2.0);
+        let srt = TransformSRT2::from_scale_rotation_translation(s, r, t);
+        let inv_srt = srt.inverse();
+        let v = Vec2::new(0.5, 2.0);
+        assert_approx_eq!(inv_srt * (srt * v), v, 1e-6);
+        assert_approx_eq!(srt * inv_srt, TransformSRT2::identity(), 1e-6);
+
+        let r = Rot2::from_angle(30.0_f32.to_radians());
+        let srt = TransformSRT2::from_scale_rotation_translation(s, r, t);
+        let inv_mat = Mat3::from(srt).inverse();
+        assert_approx_eq!(srt.inverse().translation, inv_mat.z_axis().truncate(), 1e-6);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let rt = TransformRT::from_rotation_translation(Quat::identity(), Vec3::new(1.0, 2.0, 3.0));
+        let serialized = serde_json::to_string(&rt).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"rotation":[0.0,0.0,0.0,1.0],"translation":[1.0,2.0,3.0]}"#
+        );
+        assert_eq!(rt, serde_json::from_str(&serialized).unwrap());
+
+        let srt = TransformSRT::from_transform_rt(Vec3::new(4.0, 5.0, 6.0), &rt);
+        let serialized = serde_json::to_string(&srt).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"scale":[4.0,5.0,6.0],"rotation":[0.0,0.0,0.0,1.0],"translation":[1.0,2.0,3.0]}"#
+        );
+        assert_eq!(srt, serde_json::from_str(&serialized).unwrap());
+
+        let rt2 = TransformRT2::from_rotation_translation(Rot2::identity(), Vec2::new(1.0, 2.0));
+        let serialized = serde_json::to_string(&rt2).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"rotation":[1.0,0.0],"translation":[1.0,2.0]}"#
+        );
+        assert_eq!(rt2, serde_json::from_str(&serialized).unwrap());
+
+        let srt2 = TransformSRT2::from_transform_rt2(Vec2::new(3.0, 4.0), &rt2);
+        let serialized = serde_json::to_string(&srt2).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"scale":[3.0,4.0],"rotation":[1.0,0.0],"translation":[1.0,2.0]}"#
+        );
+        assert_eq!(srt2, serde_json::from_str(&serialized).unwrap());
+
+        assert!(serde_json::from_str::<TransformRT>(r#"{"rotation":[0.0,0.0,0.0,1.0]}"#).is_err());
+        assert!(serde_json::from_str::<TransformSRT2>("[]").is_err());
+    }
 }
@@ -12,6 +12,18 @@ fn test_vec2_align() {
     assert_eq!(4, mem::align_of::<Vec2Mask>());
 }
 
+#[test]
+fn test_vec2_consts() {
+    const V: Vec2 = vec2(1.0, 2.0);
+    const M: Vec2Mask = Vec2Mask::new(true, false);
+    assert_eq!(Vec2::new(1.0, 2.0), V);
+    assert_eq!(0b01, M.bitmask());
+    assert_eq!(Vec2::zero(), Vec2::ZERO);
+    assert_eq!(Vec2::one(), Vec2::ONE);
+    assert_eq!(Vec2::unit_x(), Vec2::X);
+    assert_eq!(Vec2::unit_y(), Vec2::Y);
+}
+
 #[test]
 fn test_vec2_new() {
     let v = vec2(1.0, 2.0);
@@ -476,6 +488,22 @@ fn test_vec2_serde() {
     assert!(deserialized.is_err());
 }
 
+#[cfg(feature = "serde")]
+#[test]
+fn test_vec2mask_serde() {
+    let a = Vec2Mask::new(true, false);
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[true,false]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<Vec2Mask>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Vec2Mask>("[true]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Vec2Mask>("[1.0,1.0]");
+    assert!(deserialized.is_err());
+}
+
 #[cfg(feature = "rand")]
 #[test]
 fn test_vec2_rand() {
@@ -6,17 +6,23 @@ use std::f32;
 #[test]
 fn test_vec3_align() {
     use std::mem;
-    if cfg!(any(feature = "packed-vec3", feature = "scalar-math")) {
-        assert_eq!(12, mem::size_of::<Vec3>());
-        assert_eq!(4, mem::align_of::<Vec3>());
-        assert_eq!(12, mem::size_of::<Vec3Mask>());
-        assert_eq!(4, mem::align_of::<Vec3Mask>());
-    } else {
-        assert_eq!(16, mem::size_of::<Vec3>());
-        assert_eq!(16, mem::align_of::<Vec3>());
-        assert_eq!(16, mem::size_of::<Vec3Mask>());
-        assert_eq!(16, mem::align_of::<Vec3Mask>());
-    }
+    assert_eq!(12, mem::size_of::<Vec3>());
+    assert_eq!(4, mem::align_of::<Vec3>());
+    assert_eq!(12, mem::size_of::<Vec3Mask>());
+    assert_eq!(4, mem::align_of::<Vec3Mask>());
+}
+
+#[test]
+fn test_vec3_consts() {
+    const V: Vec3 = vec3(1.0, 2.0, 3.0);
+    const M: Vec3Mask = Vec3Mask::new(true, false, true);
+    assert_eq!(Vec3::new(1.0, 2.0, 3.0), V);
+    assert_eq!(0b101, M.bitmask());
+    assert_eq!(Vec3::zero(), Vec3::ZERO);
+    assert_eq!(Vec3::one(), Vec3::ONE);
+    assert_eq!(Vec3::unit_x(), Vec3::X);
+    assert_eq!(Vec3::unit_y(), Vec3::Y);
+    assert_eq!(Vec3::unit_z(), Vec3::Z);
 }
 
 #[test]
@@ -47,17 +53,6 @@ fn test_vec3_new() {
 #[test]
 fn test_vec3_fmt() {
     let a = Vec3::new(1.0, 2.0, 3.0);
-    #[cfg(all(
-        target_feature = "sse2",
-        not(feature = "packed-vec3"),
-        not(feature = "scalar-math")
-    ))]
-    assert_eq!(format!("{:?}", a), "Vec3(__m128(1.0, 2.0, 3.0, 3.0))");
-    #[cfg(any(
-        not(target_feature = "sse2"),
-        feature = "packed-vec3",
-        feature = "scalar-math"
-    ))]
     assert_eq!(format!("{:?}", a), "Vec3(1.0, 2.0, 3.0)");
     // assert_eq!(format!("{:#?}", a), "Vec3(\n    1.0,\n    2.0,\n    3.0\n)");
     assert_eq!(format!("{}", a), "[1, 2, 3]");
@@ -402,33 +397,9 @@ fn test_vec3mask_fmt() {
     let a = Vec3Mask::new(true, false, false);
 
     // debug fmt
-    #[cfg(all(
-        target_feature = "sse2",
-        not(feature = "packed-vec3"),
-        not(feature = "scalar-math")
-    ))]
-    assert_eq!(format!("{:?}", a), "Vec3Mask(0xffffffff, 0x0, 0x0)");
-
-    #[cfg(any(
-        not(target_feature = "sse2"),
-        feature = "packed-vec3",
-        feature = "scalar-math"
-    ))]
     assert_eq!(format!("{:?}", a), "Vec3Mask(0xffffffff, 0x0, 0x0)");
 
     // display fmt
-    #[cfg(all(
-        target_feature = "sse2",
-        not(feature = "packed-vec3"),
-        not(feature = "scalar-math")
-    ))]
-    assert_eq!(format!("{}", a), "[true, false, false]");
-
-    #[cfg(any(
-        not(target_feature = "sse2"),
-        feature = "packed-vec3",
-        feature = "scalar-math"
-    ))]
     assert_eq!(format!("{}", a), "[true, false, false]");
 }
 
@@ -585,6 +556,22 @@ fn test_vec3_serde() {
     assert!(deserialized.is_err());
 }
 
+#[cfg(feature = "serde")]
+#[test]
+fn test_vec3mask_serde() {
+    let a = Vec3Mask::new(true, false, true);
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[true,false,true]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<Vec3Mask>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Vec3Mask>("[true]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Vec3Mask>("[1.0,1.0,1.0]");
+    assert!(deserialized.is_err());
+}
+
 #[cfg(feature = "rand")]
 #[test]
 fn test_vec3_rand() {
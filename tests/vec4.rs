@@ -17,6 +17,22 @@ fn test_vec4_align() {
     }
 }
 
+#[test]
+fn test_vec4_consts() {
+    const V: Vec4 = vec4(1.0, 2.0, 3.0, 4.0);
+    const M: Vec4Mask = Vec4Mask::new(true, false, true, false);
+    static TABLE: [Vec4; 2] = [Vec4::ZERO, Vec4::splat(2.0)];
+    assert_eq!(Vec4::new(1.0, 2.0, 3.0, 4.0), V);
+    assert_eq!(0b0101, M.bitmask());
+    assert_eq!(Vec4::splat(2.0), TABLE[1]);
+    assert_eq!(Vec4::zero(), Vec4::ZERO);
+    assert_eq!(Vec4::one(), Vec4::ONE);
+    assert_eq!(Vec4::unit_x(), Vec4::X);
+    assert_eq!(Vec4::unit_y(), Vec4::Y);
+    assert_eq!(Vec4::unit_z(), Vec4::Z);
+    assert_eq!(Vec4::unit_w(), Vec4::W);
+}
+
 #[test]
 fn test_vec4_new() {
     let v = vec4(1.0, 2.0, 3.0, 4.0);
@@ -529,6 +545,10 @@ fn test_vec4_round() {
     assert_eq!(Vec4::new(0.0, 21.1, 0.0, 0.0).round().y(), 21.0);
     assert_eq!(Vec4::new(0.0, 0.0, 0.0, 11.123).round().w(), 11.0);
     assert_eq!(Vec4::new(0.0, 0.0, 11.501, 0.0).round().z(), 12.0);
+    assert_eq!(
+        Vec4::new(0.5, -0.5, 2.5, -2.5).round(),
+        Vec4::new(1.0, -1.0, 3.0, -3.0)
+    );
     assert_eq!(
         Vec4::new(f32::NEG_INFINITY, f32::INFINITY, 1.0, -1.0).round(),
         Vec4::new(f32::NEG_INFINITY, f32::INFINITY, 1.0, -1.0)
@@ -607,6 +627,22 @@ fn test_vec4_serde() {
     assert!(deserialized.is_err());
 }
 
+#[cfg(feature = "serde")]
+#[test]
+fn test_vec4mask_serde() {
+    let a = Vec4Mask::new(true, false, true, false);
+    let serialized = serde_json::to_string(&a).unwrap();
+    assert_eq!(serialized, "[true,false,true,false]");
+    let deserialized = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(a, deserialized);
+    let deserialized = serde_json::from_str::<Vec4Mask>("[]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Vec4Mask>("[true]");
+    assert!(deserialized.is_err());
+    let deserialized = serde_json::from_str::<Vec4Mask>("[1.0,1.0,1.0,1.0]");
+    assert!(deserialized.is_err());
+}
+
 #[cfg(feature = "rand")]
 #[test]
 fn test_vec4_rand() {